@@ -0,0 +1,110 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use floatguard::{GuardedF64, UnguardedF64};
+use noisy_float::types::R64;
+use ordered_float::NotNan;
+
+macro_rules! bench {
+    ($id:ident, $group:literal, $( ($bench:literal, $expr:expr) ),* $(,)?) => {
+        fn $id(c: &mut Criterion) {
+            let mut group = c.benchmark_group($group);
+
+            $(
+                group.bench_function($bench, $expr);
+            )*
+
+            group.finish();
+        }
+    };
+}
+
+/// Benchmarks a chain of the same operation over four operands for each of the compared types.
+macro_rules! bench_chain {
+    ($id:ident, $group:literal, $op:tt) => {
+        bench!(
+            $id,
+            $group,
+            ("f64", |b| {
+                let [w, x, y, z] = std::hint::black_box([42.0f64, 2.0, 3.0, 4.0]);
+                b.iter(|| w $op x $op y $op z)
+            }),
+            ("GuardedF64", |b| {
+                let [w, x, y, z] = std::hint::black_box([42.0f64, 2.0, 3.0, 4.0])
+                    .map(|v| GuardedF64::new(v).unwrap());
+                b.iter(|| (w $op x $op y $op z).check())
+            }),
+            ("UnguardedF64", |b| {
+                let [w, x, y, z] =
+                    std::hint::black_box([42.0f64, 2.0, 3.0, 4.0]).map(UnguardedF64::new);
+                b.iter(|| (w $op x $op y $op z).check())
+            }),
+            ("ordered_float::NotNan", |b| {
+                let [w, x, y, z] = std::hint::black_box([42.0f64, 2.0, 3.0, 4.0])
+                    .map(|v| NotNan::new(v).unwrap());
+                b.iter(|| w $op x $op y $op z)
+            }),
+            ("noisy_float::R64", |b| {
+                let [w, x, y, z] = std::hint::black_box([42.0f64, 2.0, 3.0, 4.0]).map(R64::new);
+                b.iter(|| w $op x $op y $op z)
+            }),
+        );
+    };
+}
+
+bench!(
+    bench_construct,
+    "Compare: Construction",
+    ("GuardedF64::new", |b| {
+        let value = std::hint::black_box(42.0f64);
+        b.iter(|| GuardedF64::new(std::hint::black_box(value)))
+    }),
+    ("ordered_float::NotNan::new", |b| {
+        let value = std::hint::black_box(42.0f64);
+        b.iter(|| NotNan::new(std::hint::black_box(value)))
+    }),
+    ("noisy_float::R64::try_new", |b| {
+        let value = std::hint::black_box(42.0f64);
+        b.iter(|| R64::try_new(std::hint::black_box(value)))
+    }),
+);
+
+bench_chain!(bench_add_chain, "Compare: Addition Chain", +);
+bench_chain!(bench_mul_chain, "Compare: Multiplication Chain", *);
+bench_chain!(bench_div_chain, "Compare: Division Chain", /);
+
+bench!(
+    bench_check_placement,
+    "Compare: Check Placement",
+    ("GuardedF64 (check at end)", |b| {
+        let [w, x, y, z] =
+            std::hint::black_box([42.0f64, 2.0, 3.0, 4.0]).map(|v| GuardedF64::new(v).unwrap());
+        b.iter(|| ((w + x) * y / z).check())
+    }),
+    ("GuardedF64 (check each step)", |b| {
+        let [w, x, y, z] =
+            std::hint::black_box([42.0f64, 2.0, 3.0, 4.0]).map(|v| GuardedF64::new(v).unwrap());
+        b.iter(|| {
+            let sum = (w + x).check()?;
+            let product = (sum * y).check()?;
+            (product / z).check()
+        })
+    }),
+    ("ordered_float::NotNan", |b| {
+        let [w, x, y, z] =
+            std::hint::black_box([42.0f64, 2.0, 3.0, 4.0]).map(|v| NotNan::new(v).unwrap());
+        b.iter(|| (w + x) * y / z)
+    }),
+    ("noisy_float::R64", |b| {
+        let [w, x, y, z] = std::hint::black_box([42.0f64, 2.0, 3.0, 4.0]).map(R64::new);
+        b.iter(|| (w + x) * y / z)
+    }),
+);
+
+criterion_group!(
+    benches,
+    bench_construct,
+    bench_add_chain,
+    bench_mul_chain,
+    bench_div_chain,
+    bench_check_placement,
+);
+criterion_main!(benches);
@@ -735,6 +735,35 @@ bench!(
     }),
 );
 
+bench!(
+    bench_mul_pow2,
+    "Multiply by Power of Two",
+    ("f64 * powi", |b| {
+        let (value, n) = std::hint::black_box((42.0f64, 12));
+        b.iter(|| std::hint::black_box(value) * 2f64.powi(std::hint::black_box(n)));
+    }),
+    ("GuardedF64::mul_pow2", |b| {
+        let (value, n) = std::hint::black_box((GuardedF64::new(42.0f64).unwrap(), 12));
+        b.iter(|| std::hint::black_box(value).mul_pow2(std::hint::black_box(n)));
+    }),
+    ("UnguardedF64::mul_pow2", |b| {
+        let (value, n) = std::hint::black_box((UnguardedF64::new(42.0f64), 12));
+        b.iter(|| std::hint::black_box(value).mul_pow2(std::hint::black_box(n)));
+    }),
+    ("f32 * powi", |b| {
+        let (value, n) = std::hint::black_box((42.0f32, 12));
+        b.iter(|| std::hint::black_box(value) * 2f32.powi(std::hint::black_box(n)));
+    }),
+    ("GuardedF32::mul_pow2", |b| {
+        let (value, n) = std::hint::black_box((GuardedF32::new(42.0f32).unwrap(), 12));
+        b.iter(|| std::hint::black_box(value).mul_pow2(std::hint::black_box(n)));
+    }),
+    ("UnguardedF32::mul_pow2", |b| {
+        let (value, n) = std::hint::black_box((UnguardedF32::new(42.0f32), 12));
+        b.iter(|| std::hint::black_box(value).mul_pow2(std::hint::black_box(n)));
+    }),
+);
+
 criterion_group!(
     benches,
     bench_abs,
@@ -761,5 +790,6 @@ criterion_group!(
     bench_tanh,
     bench_atanh,
     bench_atan2,
+    bench_mul_pow2,
 );
 criterion_main!(benches);
@@ -0,0 +1,254 @@
+//! Derive macros for the [`floatguard`](https://docs.rs/floatguard) crate.
+//!
+//! These macros are re-exported by `floatguard` when its `derive` feature is enabled, and should be
+//! used through that re-export rather than by depending on this crate directly.
+#![deny(clippy::all, clippy::pedantic, clippy::nursery)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Type, parse_macro_input, spanned::Spanned};
+
+/// Derives arithmetic, formatting, conversion, and serialization traits for a newtype wrapper around
+/// `GuardedF64` or `GuardedF32`.
+///
+/// Given `struct Meters(GuardedF64)`, the derive generates:
+///
+/// - `Add`, `Sub`, and `Rem` between two `Meters`, returning `Result<Meters, FloatError>`.
+/// - `Mul` and `Div` between `Meters` and a scalar (`f64`, `GuardedF64`, or `UnguardedF64`),
+///   returning `Result<Meters, FloatError>`, and `Mul` with the scalar on the left-hand side.
+/// - `Div` between two `Meters`, returning the dimensionless ratio as an `UnguardedF64`.
+/// - `Neg`, returning `Meters`, since negating a finite value is always valid.
+/// - `Display`, which formats the inner value.
+/// - `From<Meters>` for `GuardedF64` and `f64`, `From<GuardedF64>` for `Meters`, and `TryFrom<f64>`
+///   and `TryFrom<UnguardedF64>` for `Meters`.
+/// - `Serialize` and `Deserialize`, if the `serde` feature of `floatguard` is enabled.
+///
+/// Arithmetic between a newtype and itself returns a `Result` rather than an unguarded value, so
+/// the result keeps its unit and is validated once per operation.
+///
+/// There is no `From<Meters>` for `UnguardedF64`: the guarded types accept any right-hand side that
+/// converts into an unguarded value, so that conversion would turn `GuardedF64 * Meters` into a
+/// unitless `UnguardedF64`. Convert through `GuardedF64` instead.
+#[proc_macro_derive(GuardedNewtype)]
+pub fn derive_guarded_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The width of the guarded type wrapped by a newtype.
+struct Width {
+    guarded: Ident,
+    unguarded: Ident,
+    float: Ident,
+}
+
+impl Width {
+    /// Determines the width from the wrapped type, which must be a path ending in `GuardedF64` or
+    /// `GuardedF32`.
+    fn from_type(ty: &Type) -> syn::Result<Self> {
+        let segment = match ty {
+            Type::Path(path) if path.qself.is_none() => path.path.segments.last(),
+            _ => None,
+        };
+
+        let (guarded, unguarded, float) = match segment.map(|s| s.ident.to_string()).as_deref() {
+            Some("GuardedF64") => ("GuardedF64", "UnguardedF64", "f64"),
+            Some("GuardedF32") => ("GuardedF32", "UnguardedF32", "f32"),
+            _ => {
+                return Err(syn::Error::new(
+                    ty.span(),
+                    "GuardedNewtype requires a field of type `GuardedF64` or `GuardedF32`",
+                ));
+            }
+        };
+
+        Ok(Self {
+            guarded: Ident::new(guarded, Span::call_site()),
+            unguarded: Ident::new(unguarded, Span::call_site()),
+            float: Ident::new(float, Span::call_site()),
+        })
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "GuardedNewtype does not support generic structs",
+        ));
+    }
+
+    let field = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first(),
+            _ => None,
+        },
+        _ => None,
+    }
+    .ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            "GuardedNewtype can only be derived for tuple structs with a single field",
+        )
+    })?;
+
+    let name = &input.ident;
+    let width = Width::from_type(&field.ty)?;
+    let arithmetic = expand_arithmetic(name, &width);
+    let conversions = expand_conversions(name, &width);
+    let guarded = &width.guarded;
+
+    Ok(quote! {
+        #arithmetic
+
+        #conversions
+
+        impl ::core::fmt::Display for #name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        ::floatguard::__impl_newtype_serde!(#name, ::floatguard::#guarded);
+    })
+}
+
+/// Generates the arithmetic operators between the newtype, itself, and scalars.
+fn expand_arithmetic(name: &Ident, width: &Width) -> TokenStream2 {
+    let Width {
+        guarded,
+        unguarded,
+        float,
+    } = width;
+    let error = quote!(::floatguard::FloatError);
+    let scalars = [
+        quote!(#float),
+        quote!(::floatguard::#guarded),
+        quote!(::floatguard::#unguarded),
+    ];
+
+    let same_type_ops = [
+        (quote!(Add), quote!(add)),
+        (quote!(Sub), quote!(sub)),
+        (quote!(Rem), quote!(rem)),
+    ]
+    .into_iter()
+    .map(|(op_trait, op_method)| {
+        quote! {
+            impl ::core::ops::#op_trait for #name {
+                type Output = ::core::result::Result<#name, #error>;
+
+                #[inline]
+                fn #op_method(self, rhs: Self) -> Self::Output {
+                    ::core::ops::#op_trait::#op_method(self.0, rhs.0).check().map(Self)
+                }
+            }
+        }
+    });
+
+    let scalar_ops = scalars.iter().map(|scalar| {
+        quote! {
+            impl ::core::ops::Mul<#scalar> for #name {
+                type Output = ::core::result::Result<#name, #error>;
+
+                #[inline]
+                fn mul(self, rhs: #scalar) -> Self::Output {
+                    (self.0 * rhs).check().map(Self)
+                }
+            }
+
+            impl ::core::ops::Mul<#name> for #scalar {
+                type Output = ::core::result::Result<#name, #error>;
+
+                #[inline]
+                fn mul(self, rhs: #name) -> Self::Output {
+                    (self * rhs.0).check().map(#name)
+                }
+            }
+
+            impl ::core::ops::Div<#scalar> for #name {
+                type Output = ::core::result::Result<#name, #error>;
+
+                #[inline]
+                fn div(self, rhs: #scalar) -> Self::Output {
+                    (self.0 / rhs).check().map(Self)
+                }
+            }
+        }
+    });
+
+    quote! {
+        #(#same_type_ops)*
+
+        #(#scalar_ops)*
+
+        impl ::core::ops::Div for #name {
+            type Output = ::floatguard::#unguarded;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self::Output {
+                self.0 / rhs.0
+            }
+        }
+
+        impl ::core::ops::Neg for #name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+    }
+}
+
+/// Generates the conversions between the newtype, the guarded types, and the primitive type.
+fn expand_conversions(name: &Ident, width: &Width) -> TokenStream2 {
+    let Width {
+        guarded,
+        unguarded,
+        float,
+    } = width;
+    let error = quote!(::floatguard::FloatError);
+    let guarded = quote!(::floatguard::#guarded);
+    let unguarded = quote!(::floatguard::#unguarded);
+
+    quote! {
+        impl ::core::convert::From<#name> for #guarded {
+            fn from(value: #name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::convert::From<#name> for #float {
+            fn from(value: #name) -> Self {
+                Self::from(value.0)
+            }
+        }
+
+        impl ::core::convert::From<#guarded> for #name {
+            fn from(value: #guarded) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::core::convert::TryFrom<#float> for #name {
+            type Error = #error;
+
+            fn try_from(value: #float) -> ::core::result::Result<Self, Self::Error> {
+                #guarded::new(value).map(Self)
+            }
+        }
+
+        impl ::core::convert::TryFrom<#unguarded> for #name {
+            type Error = #error;
+
+            fn try_from(value: #unguarded) -> ::core::result::Result<Self, Self::Error> {
+                value.check().map(Self)
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! This module provides the `ApproxConfig` type used by `GuardedF64::approx_eq` and
+//! `GuardedF32::approx_eq`, along with the `assert_guarded_approx_eq!` test macro.
+use crate::GuardedF64;
+
+/// Tolerance settings for approximate equality comparisons between guarded values.
+///
+/// Two values are considered approximately equal if their absolute difference is within
+/// `epsilon`, or if they are within `ulps` representable values (units in the last place) of
+/// each other. The absolute check handles values near zero, where ULP distances are
+/// meaningless, while the ULP check scales with the magnitude of the values being compared.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{ApproxConfig, GuardedF64};
+///
+/// let a = (GuardedF64::new(0.1).unwrap() + 0.2).check().unwrap();
+/// let b = GuardedF64::new(0.3).unwrap();
+///
+/// assert_ne!(a, b);
+/// assert!(a.approx_eq(b, ApproxConfig::default()));
+/// assert!(a.approx_eq(b, ApproxConfig::ulps(1)));
+/// assert!(!a.approx_eq(b, ApproxConfig::ulps(0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApproxConfig {
+    /// The maximum absolute difference between two values that are considered equal.
+    pub epsilon: GuardedF64,
+
+    /// The maximum number of representable values between two values that are considered equal.
+    pub ulps: u64,
+}
+
+impl ApproxConfig {
+    /// The default tolerance: an absolute difference of `f64::EPSILON`, or 4 ULPs.
+    pub const DEFAULT: Self = Self::new(GuardedF64::EPSILON, 4);
+
+    /// Creates a new `ApproxConfig` with both an absolute and a ULP tolerance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{ApproxConfig, GuardedF64};
+    ///
+    /// let config = ApproxConfig::new(GuardedF64::new(1e-9).unwrap(), 2);
+    /// assert_eq!(config.ulps, 2);
+    /// ```
+    #[must_use]
+    pub const fn new(epsilon: GuardedF64, ulps: u64) -> Self {
+        Self { epsilon, ulps }
+    }
+
+    /// Creates a new `ApproxConfig` that only uses an absolute tolerance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{ApproxConfig, GuardedF64};
+    ///
+    /// let config = ApproxConfig::epsilon(GuardedF64::new(0.5).unwrap());
+    /// let a = GuardedF64::new(1.0).unwrap();
+    ///
+    /// assert!(a.approx_eq(GuardedF64::new(1.5).unwrap(), config));
+    /// assert!(!a.approx_eq(GuardedF64::new(1.6).unwrap(), config));
+    /// ```
+    #[must_use]
+    pub const fn epsilon(epsilon: GuardedF64) -> Self {
+        Self::new(epsilon, 0)
+    }
+
+    /// Creates a new `ApproxConfig` that only uses a ULP tolerance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{ApproxConfig, GuardedF64};
+    ///
+    /// let a = GuardedF64::new(1.0).unwrap();
+    /// let b = GuardedF64::new(1.0 + f64::EPSILON).unwrap();
+    ///
+    /// assert!(a.approx_eq(b, ApproxConfig::ulps(1)));
+    /// assert!(!a.approx_eq(b, ApproxConfig::ulps(0)));
+    /// ```
+    #[must_use]
+    pub const fn ulps(ulps: u64) -> Self {
+        Self::new(GuardedF64(0.0), ulps)
+    }
+}
+
+impl Default for ApproxConfig {
+    /// Returns [`ApproxConfig::DEFAULT`].
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Asserts that two guarded values are approximately equal.
+///
+/// Accepts an optional third argument with the `ApproxConfig` to compare with. If omitted,
+/// `ApproxConfig::default()` is used. On failure, the panic message includes both values and the
+/// configuration used for the comparison.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{assert_guarded_approx_eq, ApproxConfig, GuardedF64};
+///
+/// let a = (GuardedF64::new(0.1).unwrap() + 0.2).check().unwrap();
+/// let b = GuardedF64::new(0.3).unwrap();
+///
+/// assert_guarded_approx_eq!(a, b);
+/// assert_guarded_approx_eq!(a, b, ApproxConfig::epsilon(GuardedF64::new(1e-12).unwrap()));
+/// ```
+///
+/// ```rust,should_panic
+/// use floatguard::{assert_guarded_approx_eq, GuardedF64};
+///
+/// assert_guarded_approx_eq!(GuardedF64::new(1.0).unwrap(), GuardedF64::new(1.1).unwrap());
+/// ```
+#[macro_export]
+macro_rules! assert_guarded_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_guarded_approx_eq!($left, $right, $crate::ApproxConfig::default())
+    };
+    ($left:expr, $right:expr, $config:expr $(,)?) => {
+        match (&$left, &$right, &$config) {
+            (left, right, config) => {
+                if !left.approx_eq(*right, *config) {
+                    ::core::panic!(
+                        "assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}\nconfig: {:?}",
+                        left,
+                        right,
+                        config,
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(ApproxConfig::default(), ApproxConfig::DEFAULT);
+        assert_eq!(ApproxConfig::default().epsilon, GuardedF64::EPSILON);
+        assert_eq!(ApproxConfig::default().ulps, 4);
+    }
+
+    #[test]
+    fn test_constructors() {
+        let epsilon = GuardedF64::new(0.5).unwrap();
+
+        assert_eq!(
+            ApproxConfig::epsilon(epsilon),
+            ApproxConfig::new(epsilon, 0)
+        );
+        assert_eq!(ApproxConfig::ulps(3), ApproxConfig::new(GuardedF64(0.0), 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `left ≈ right` failed")]
+    fn test_assert_macro_panics() {
+        assert_guarded_approx_eq!(
+            GuardedF64::new(1.0).unwrap(),
+            GuardedF64::new(2.0).unwrap(),
+            ApproxConfig::ulps(1),
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! This module provides the `assert_finite!` and `assert_guard_err!` test macros.
+use crate::{FloatError, GuardedF32, GuardedF64, UnguardedF32, UnguardedF64};
+
+/// A value that can be validated by `assert_finite!` and `assert_guard_err!`. Not part of the public
+/// API.
+#[doc(hidden)]
+pub trait Validate: std::fmt::Debug {
+    /// The guarded type produced by a successful validation.
+    type Guarded;
+
+    /// Validates the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the value is not valid.
+    fn validate(&self) -> Result<Self::Guarded, FloatError>;
+}
+
+macro_rules! validate {
+    ($( ($guarded:ty, $unguarded:ty) ),*) => {
+        $(
+            impl Validate for $guarded {
+                type Guarded = Self;
+
+                fn validate(&self) -> Result<Self, FloatError> {
+                    Ok(*self)
+                }
+            }
+
+            impl Validate for $unguarded {
+                type Guarded = $guarded;
+
+                fn validate(&self) -> Result<$guarded, FloatError> {
+                    self.check_reported()
+                }
+            }
+        )*
+    };
+}
+
+validate!((GuardedF64, UnguardedF64), (GuardedF32, UnguardedF32));
+
+impl<T: Copy + std::fmt::Debug> Validate for Result<T, FloatError> {
+    type Guarded = T;
+
+    fn validate(&self) -> Self {
+        *self
+    }
+}
+
+/// Asserts that a value is valid, and evaluates to the guarded value.
+///
+/// Accepts guarded and unguarded values, and `Result`s with a `FloatError`, so the end of a lazy
+/// chain can be checked and unwrapped in one step. On failure, the panic message includes the
+/// expression, the raw value, and the error.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{assert_finite, GuardedF64};
+///
+/// let a = GuardedF64::new(2.0).unwrap();
+/// let sum: GuardedF64 = assert_finite!(a + 1.0);
+/// assert_eq!(sum, 3.0);
+///
+/// assert_finite!(GuardedF64::new(1.0));
+/// ```
+///
+/// ```rust,should_panic
+/// use floatguard::{assert_finite, GuardedF64};
+///
+/// let a = GuardedF64::new(2.0).unwrap();
+/// assert_finite!(a / 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_finite {
+    ($value:expr $(,)?) => {
+        match &$value {
+            value => match $crate::__private::Validate::validate(value) {
+                ::core::result::Result::Ok(guarded) => guarded,
+                ::core::result::Result::Err(error) => ::core::panic!(
+                    "assertion failed: `{}` is not finite\nvalue: {:?}\nerror: {:?}",
+                    ::core::stringify!($value),
+                    value,
+                    error,
+                ),
+            },
+        }
+    };
+}
+
+/// Asserts that a value is invalid with a specific `FloatError`.
+///
+/// Accepts the same values as `assert_finite!`. On failure, the panic message includes the
+/// expression, the raw value, and the expected and actual results.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{assert_guard_err, FloatError, GuardedF64};
+///
+/// let a = GuardedF64::new(2.0).unwrap();
+/// assert_guard_err!(a / 0.0, FloatError::Infinity);
+/// assert_guard_err!(GuardedF64::new(f64::NAN), FloatError::NaN);
+/// ```
+///
+/// ```rust,should_panic
+/// use floatguard::{assert_guard_err, FloatError, GuardedF64};
+///
+/// let a = GuardedF64::new(2.0).unwrap();
+/// assert_guard_err!(a + 1.0, FloatError::NaN);
+/// ```
+#[macro_export]
+macro_rules! assert_guard_err {
+    ($value:expr, $error:expr $(,)?) => {
+        match (&$value, &$error) {
+            (value, expected) => {
+                let actual = $crate::__private::Validate::validate(value);
+                if actual.as_ref().err() != ::core::option::Option::Some(expected) {
+                    ::core::panic!(
+                        "assertion failed: `{}` is not `Err({:?})`\n value: {:?}\nresult: {:?}",
+                        ::core::stringify!($value),
+                        expected,
+                        value,
+                        actual,
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FloatError, GuardedF32, GuardedF64, UnguardedF32, UnguardedF64};
+
+    #[test]
+    fn test_assert_finite() {
+        let value: GuardedF64 = assert_finite!(UnguardedF64::new(1.0) * 2.0);
+        assert_eq!(value, GuardedF64::new(2.0).unwrap());
+
+        let value: GuardedF32 = assert_finite!(GuardedF32::new(1.5).unwrap());
+        assert_eq!(value, GuardedF32::new(1.5).unwrap());
+    }
+
+    #[test]
+    fn test_assert_guard_err() {
+        assert_guard_err!(UnguardedF32::new(f32::NAN), FloatError::NaN);
+        assert_guard_err!(GuardedF64::MAX.to_exact_int::<i8>(), FloatError::Inexact);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not finite\nvalue: UnguardedF64(inf)\nerror: Infinity")]
+    fn test_assert_finite_panics() {
+        assert_finite!(GuardedF64::MAX * 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not `Err(NaN)`")]
+    fn test_assert_guard_err_panics() {
+        assert_guard_err!(GuardedF64::new(f64::INFINITY), FloatError::NaN);
+    }
+}
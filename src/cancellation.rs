@@ -0,0 +1,150 @@
+//! This module provides subtraction that reports how much precision cancellation cost.
+use crate::{GuardedF64, UnguardedF64};
+
+/// A report of the significant bits lost to cancellation in a subtraction.
+///
+/// Subtracting two nearly equal values is exact, but the leading bits that the operands shared
+/// cancel out, so any error the operands already carried becomes a larger share of the result.
+/// When most of the bits cancel, the result may be mostly noise, even though it is finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+///
+/// let a = GuardedF64::new(1.000_001).unwrap();
+/// let b = GuardedF64::new(1.0).unwrap();
+/// let (difference, info) = a.sub_detect_cancellation(b, 16);
+///
+/// assert!((difference - 1e-6).abs().check().unwrap() < 1e-15);
+/// assert_eq!(info.bits_lost(), 20);
+/// assert!(info.is_catastrophic());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CancellationInfo {
+    bits_lost: u32,
+    catastrophic: bool,
+}
+
+impl CancellationInfo {
+    /// Returns the number of leading significant bits that cancelled, from zero to
+    /// `f64::MANTISSA_DIGITS`. Subtracting two equal, non-zero values loses every bit.
+    #[must_use]
+    pub const fn bits_lost(self) -> u32 {
+        self.bits_lost
+    }
+
+    /// Returns `true` if more bits were lost than the threshold passed to
+    /// `sub_detect_cancellation`.
+    #[must_use]
+    pub const fn is_catastrophic(self) -> bool {
+        self.catastrophic
+    }
+}
+
+impl GuardedF64 {
+    /// Subtracts `rhs` from `self`, and reports how many significant bits were lost to
+    /// cancellation.
+    ///
+    /// The loss is the difference between the binary exponent of the larger operand and the binary
+    /// exponent of the result, so it measures how much the relative error of the operands is
+    /// amplified. The subtraction is flagged as catastrophic when more than `threshold` bits are
+    /// lost. A result that overflows loses no bits, and is left for `check` to report.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let a = GuardedF64::new(10.0).unwrap();
+    /// let (difference, info) = a.sub_detect_cancellation(GuardedF64::new(2.0).unwrap(), 8);
+    /// assert_eq!(difference.check(), GuardedF64::new(8.0));
+    /// assert_eq!(info.bits_lost(), 0);
+    /// assert!(!info.is_catastrophic());
+    ///
+    /// let (difference, info) = a.sub_detect_cancellation(a, 8);
+    /// assert_eq!(difference.check(), GuardedF64::new(0.0));
+    /// assert_eq!(info.bits_lost(), f64::MANTISSA_DIGITS);
+    /// assert!(info.is_catastrophic());
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn sub_detect_cancellation(
+        self,
+        rhs: Self,
+        threshold: u32,
+    ) -> (UnguardedF64, CancellationInfo) {
+        let difference = self - rhs;
+        let bits_lost = bits_lost(self, rhs, difference);
+
+        (
+            difference,
+            CancellationInfo {
+                bits_lost,
+                catastrophic: bits_lost > threshold,
+            },
+        )
+    }
+}
+
+/// Returns the number of significant bits lost when `lhs - rhs` rounded to `difference`.
+fn bits_lost(lhs: GuardedF64, rhs: GuardedF64, difference: UnguardedF64) -> u32 {
+    let Ok(larger) = lhs.abs().max(rhs.abs()).ilog2_checked() else {
+        // Both operands are zero, so there were no significant bits to lose.
+        return 0;
+    };
+    let Ok(difference) = difference.check_reported() else {
+        return 0;
+    };
+
+    // A difference of zero means that every bit cancelled.
+    difference
+        .abs()
+        .ilog2_checked()
+        .map_or(f64::MANTISSA_DIGITS, |exponent| {
+            u32::try_from(larger - exponent).map_or(0, |lost| lost.min(f64::MANTISSA_DIGITS))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_edge_cases() {
+        let zero = GuardedF64(0.0);
+        assert_eq!(zero.sub_detect_cancellation(zero, 0).1.bits_lost(), 0);
+        assert_eq!(
+            GuardedF64::MAX
+                .sub_detect_cancellation(GuardedF64::MIN, 0)
+                .1
+                .bits_lost(),
+            0
+        );
+
+        let tiny = GuardedF64(f64::from_bits(3));
+        let (_, info) = tiny.sub_detect_cancellation(GuardedF64(f64::from_bits(2)), 0);
+        assert_eq!(info.bits_lost(), 1);
+        assert!(info.is_catastrophic());
+    }
+
+    proptest! {
+        #[test]
+        fn test_bits_lost_bounds_relative_size(a in valid_f64(), b in valid_f64()) {
+            let (difference, info) = GuardedF64(a).sub_detect_cancellation(GuardedF64(b), 26);
+            prop_assert!(info.bits_lost() <= f64::MANTISSA_DIGITS);
+            prop_assert_eq!(info.is_catastrophic(), info.bits_lost() > 26);
+
+            if let Ok(difference) = difference.check()
+                && difference != 0.0
+                && info.bits_lost() > 0
+            {
+                // The result is smaller than the larger operand by at least `2^(bits_lost - 1)`.
+                let larger = a.abs().max(b.abs());
+                let ratio = larger / difference.0.abs();
+                prop_assert!(ratio >= 2f64.powi(i32::try_from(info.bits_lost()).unwrap() - 1));
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Conventional engineering constants and unit conversion factors, in SI units.
+use crate::GuardedF64;
+use crate::macros::consts::copy_const_value;
+
+copy_const_value!(
+    r"
+        Standard acceleration of gravity, <i>g<sub>n</sub></i>, in metres per second squared (m&sdot;s<sup>-2</sup>). Exact.
+    "
+    pub const STANDARD_GRAVITY: GuardedF64 = 9.806_65
+);
+
+copy_const_value!(
+    r"
+        Standard atmosphere, in pascals (Pa). Exact.
+    "
+    pub const STANDARD_ATMOSPHERE: GuardedF64 = 101_325.0
+);
+
+copy_const_value!(
+    r"
+        Zero degrees Celsius, in kelvin (K). Exact.
+    "
+    pub const ZERO_CELSIUS: GuardedF64 = 273.15
+);
+
+copy_const_value!(
+    r"
+        One international inch, in metres (m). Exact.
+    "
+    pub const INCH: GuardedF64 = 0.0254
+);
+
+copy_const_value!(
+    r"
+        One international foot, in metres (m). Exact.
+    "
+    pub const FOOT: GuardedF64 = 0.3048
+);
+
+copy_const_value!(
+    r"
+        One international mile, in metres (m). Exact.
+    "
+    pub const MILE: GuardedF64 = 1_609.344
+);
+
+copy_const_value!(
+    r"
+        One nautical mile, in metres (m). Exact.
+    "
+    pub const NAUTICAL_MILE: GuardedF64 = 1_852.0
+);
+
+copy_const_value!(
+    r"
+        One international avoirdupois pound, in kilograms (kg). Exact.
+    "
+    pub const POUND: GuardedF64 = 0.453_592_37
+);
+
+copy_const_value!(
+    r"
+        One thermochemical calorie, in joules (J). Exact.
+    "
+    pub const CALORIE: GuardedF64 = 4.184
+);
+
+copy_const_value!(
+    r"
+        One mechanical horsepower, in watts (W).
+    "
+    pub const HORSEPOWER: GuardedF64 = 745.699_871_582_270_2
+);
@@ -0,0 +1,60 @@
+//! Guarded physical and engineering constants.
+//!
+//! Every constant is a `GuardedF64` that is validated at compile time, so the values can be used
+//! directly in guarded computations without `GuardedF64::new(..).unwrap()`.
+//!
+//! This module is only available when the `constants` feature is enabled.
+//!
+//! # Example
+//!
+//! ```rust
+//! use floatguard::constants::{engineering, physics};
+//!
+//! // Weight of a 2 kg mass at standard gravity, in newtons.
+//! let weight = (engineering::STANDARD_GRAVITY * 2.0).check().unwrap();
+//! assert_eq!(weight, 19.6133);
+//!
+//! // Thermal energy at room temperature, in joules.
+//! let energy = (physics::BOLTZMANN * 300.0).check().unwrap();
+//! assert!(energy > 0.0);
+//! ```
+pub mod engineering;
+pub mod physics;
+
+#[cfg(test)]
+mod tests {
+    use super::{engineering, physics};
+    use crate::{ApproxConfig, GuardedF64, assert_guarded_approx_eq};
+
+    /// Relative tolerance matching the number of significant digits in the CODATA values.
+    fn relative(value: GuardedF64) -> ApproxConfig {
+        ApproxConfig::epsilon((value * 1e-9).abs().check().unwrap())
+    }
+
+    #[test]
+    fn test_derived_physics_constants() {
+        let gas_constant = (physics::AVOGADRO * physics::BOLTZMANN).check().unwrap();
+        assert_guarded_approx_eq!(gas_constant, physics::GAS_CONSTANT, relative(gas_constant));
+
+        let faraday = (physics::AVOGADRO * physics::ELEMENTARY_CHARGE)
+            .check()
+            .unwrap();
+        assert_guarded_approx_eq!(faraday, physics::FARADAY, relative(faraday));
+
+        let reduced_planck = (physics::PLANCK / GuardedF64::TAU).check().unwrap();
+        assert_guarded_approx_eq!(
+            reduced_planck,
+            physics::REDUCED_PLANCK,
+            relative(reduced_planck)
+        );
+    }
+
+    #[test]
+    fn test_engineering_conversions() {
+        let feet_per_mile = (engineering::MILE / engineering::FOOT).check().unwrap();
+        assert_guarded_approx_eq!(feet_per_mile, GuardedF64::new(5280.0).unwrap());
+
+        let inches_per_foot = (engineering::FOOT / engineering::INCH).check().unwrap();
+        assert_guarded_approx_eq!(inches_per_foot, GuardedF64::new(12.0).unwrap());
+    }
+}
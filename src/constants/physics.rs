@@ -0,0 +1,124 @@
+//! Fundamental physical constants, using the CODATA 2018 recommended values in SI units.
+//!
+//! Constants marked as exact are defined exactly by the 2019 redefinition of the SI base units.
+use crate::GuardedF64;
+use crate::macros::consts::copy_const_value;
+
+copy_const_value!(
+    r"
+        Speed of light in vacuum, <i>c</i>, in metres per second (m&sdot;s<sup>-1</sup>). Exact.
+    "
+    pub const SPEED_OF_LIGHT: GuardedF64 = 299_792_458.0
+);
+
+copy_const_value!(
+    r"
+        Planck constant, <i>h</i>, in joule seconds (J&sdot;s). Exact.
+    "
+    pub const PLANCK: GuardedF64 = 6.626_070_15e-34
+);
+
+copy_const_value!(
+    r"
+        Reduced Planck constant, <i>&hbar;</i> = <i>h</i> / 2&pi;, in joule seconds (J&sdot;s).
+    "
+    pub const REDUCED_PLANCK: GuardedF64 = 1.054_571_817e-34
+);
+
+copy_const_value!(
+    r"
+        Elementary charge, <i>e</i>, in coulombs (C). Exact.
+    "
+    pub const ELEMENTARY_CHARGE: GuardedF64 = 1.602_176_634e-19
+);
+
+copy_const_value!(
+    r"
+        Boltzmann constant, <i>k</i>, in joules per kelvin (J&sdot;K<sup>-1</sup>). Exact.
+    "
+    pub const BOLTZMANN: GuardedF64 = 1.380_649e-23
+);
+
+copy_const_value!(
+    r"
+        Avogadro constant, <i>N<sub>A</sub></i>, in reciprocal moles (mol<sup>-1</sup>). Exact.
+    "
+    pub const AVOGADRO: GuardedF64 = 6.022_140_76e23
+);
+
+copy_const_value!(
+    r"
+        Molar gas constant, <i>R</i> = <i>N<sub>A</sub></i><i>k</i>, in joules per mole kelvin (J&sdot;mol<sup>-1</sup>&sdot;K<sup>-1</sup>).
+    "
+    pub const GAS_CONSTANT: GuardedF64 = 8.314_462_618
+);
+
+copy_const_value!(
+    r"
+        Faraday constant, <i>F</i> = <i>N<sub>A</sub></i><i>e</i>, in coulombs per mole (C&sdot;mol<sup>-1</sup>).
+    "
+    pub const FARADAY: GuardedF64 = 96_485.332_12
+);
+
+copy_const_value!(
+    r"
+        Newtonian constant of gravitation, <i>G</i>, in m<sup>3</sup>&sdot;kg<sup>-1</sup>&sdot;s<sup>-2</sup>.
+    "
+    pub const GRAVITATIONAL: GuardedF64 = 6.674_30e-11
+);
+
+copy_const_value!(
+    r"
+        Vacuum electric permittivity, <i>&epsilon;<sub>0</sub></i>, in farads per metre (F&sdot;m<sup>-1</sup>).
+    "
+    pub const VACUUM_PERMITTIVITY: GuardedF64 = 8.854_187_812_8e-12
+);
+
+copy_const_value!(
+    r"
+        Vacuum magnetic permeability, <i>&mu;<sub>0</sub></i>, in newtons per square ampere (N&sdot;A<sup>-2</sup>).
+    "
+    pub const VACUUM_PERMEABILITY: GuardedF64 = 1.256_637_062_12e-6
+);
+
+copy_const_value!(
+    r"
+        Stefan&ndash;Boltzmann constant, <i>&sigma;</i>, in W&sdot;m<sup>-2</sup>&sdot;K<sup>-4</sup>.
+    "
+    pub const STEFAN_BOLTZMANN: GuardedF64 = 5.670_374_419e-8
+);
+
+copy_const_value!(
+    r"
+        Fine-structure constant, <i>&alpha;</i>. Dimensionless.
+    "
+    pub const FINE_STRUCTURE: GuardedF64 = 7.297_352_569_3e-3
+);
+
+copy_const_value!(
+    r"
+        Electron mass, <i>m<sub>e</sub></i>, in kilograms (kg).
+    "
+    pub const ELECTRON_MASS: GuardedF64 = 9.109_383_701_5e-31
+);
+
+copy_const_value!(
+    r"
+        Proton mass, <i>m<sub>p</sub></i>, in kilograms (kg).
+    "
+    pub const PROTON_MASS: GuardedF64 = 1.672_621_923_69e-27
+);
+
+copy_const_value!(
+    r"
+        Neutron mass, <i>m<sub>n</sub></i>, in kilograms (kg).
+    "
+    pub const NEUTRON_MASS: GuardedF64 = 1.674_927_498_04e-27
+);
+
+copy_const_value!(
+    r"
+        Atomic mass constant, <i>m<sub>u</sub></i>, in kilograms (kg).
+    "
+    pub const ATOMIC_MASS: GuardedF64 = 1.660_539_066_60e-27
+);
@@ -0,0 +1,219 @@
+//! Exponential decay helpers for guarded values.
+//!
+//! The decay helpers compute `initial * exp(exponent)`. If `exp(exponent)` overflows or underflows
+//! on its own, the product is computed in log space instead, so that a large `initial` can still
+//! absorb a tiny factor (and vice versa) without the intermediate value leaving the finite range.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// Computes `initial * exp(-rate * t)`.
+///
+/// A negative `rate` or `t` describes exponential growth instead of decay. If `rate * t` overflows,
+/// the exponent saturates, so decay approaches zero and growth is reported as `FloatError::Infinity`.
+///
+/// # Errors
+///
+/// Returns `FloatError::Infinity` if the result is too large to represent.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::decay::exp_decay;
+///
+/// let initial = GuardedF64::new(100.0).unwrap();
+/// let rate = GuardedF64::new(0.5).unwrap();
+///
+/// let value = exp_decay(initial, rate, GuardedF64::new(2.0).unwrap()).unwrap();
+/// assert!((value - 100.0 * (-1.0f64).exp()).abs().check().unwrap() < 1e-12);
+///
+/// assert_eq!(exp_decay(initial, rate, GuardedF64::MAX), Ok(GuardedF64::new(0.0).unwrap()));
+/// assert!(exp_decay(initial, -rate, GuardedF64::MAX).is_err());
+/// ```
+pub fn exp_decay(
+    initial: GuardedF64,
+    rate: GuardedF64,
+    t: GuardedF64,
+) -> Result<GuardedF64, FloatError> {
+    scale_by_exp(initial, -(rate * t))
+}
+
+/// Computes `initial * 2^(-t / half_life)`.
+///
+/// A `half_life` of zero decays any positive `t` to zero immediately, and a negative `half_life` or
+/// `t` describes exponential growth instead of decay.
+///
+/// # Errors
+///
+/// Returns `FloatError::NaN` if both `half_life` and `t` are zero, or `FloatError::Infinity` if the
+/// result is too large to represent.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::decay::half_life_decay;
+///
+/// let initial = GuardedF64::new(80.0).unwrap();
+/// let half_life = GuardedF64::new(3.0).unwrap();
+///
+/// let value = half_life_decay(initial, half_life, GuardedF64::new(6.0).unwrap()).unwrap();
+/// assert!((value - 20.0).abs().check().unwrap() < 1e-12);
+///
+/// let zero = GuardedF64::new(0.0).unwrap();
+/// assert_eq!(half_life_decay(initial, zero, zero), Err(FloatError::NaN));
+/// ```
+pub fn half_life_decay(
+    initial: GuardedF64,
+    half_life: GuardedF64,
+    t: GuardedF64,
+) -> Result<GuardedF64, FloatError> {
+    scale_by_exp(initial, -(t / half_life) * GuardedF64::LN_2)
+}
+
+/// Computes the smoothing coefficient `1 - exp(-dt / tau)` of a first-order low-pass filter with
+/// time constant `tau`, sampled every `dt`.
+///
+/// The coefficient is computed as `-exp_m1(-dt / tau)`, which stays accurate when `dt` is much
+/// smaller than `tau`, where `1 - exp(-dt / tau)` would cancel to zero or lose most of its digits.
+/// The result is always in `[0.0, 1.0]`.
+///
+/// # Errors
+///
+/// Returns `FloatError::Domain` if `tau` is not strictly positive or `dt` is negative.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::decay::smoothing_alpha;
+///
+/// let dt = GuardedF64::new(1e-20).unwrap();
+/// let tau = GuardedF64::new(1.0).unwrap();
+/// assert_eq!(smoothing_alpha(dt, tau), Ok(dt));
+///
+/// let zero = GuardedF64::new(0.0).unwrap();
+/// assert_eq!(smoothing_alpha(dt, zero), Err(FloatError::Domain));
+/// assert_eq!(smoothing_alpha(-tau, tau), Err(FloatError::Domain));
+/// ```
+pub fn smoothing_alpha(dt: GuardedF64, tau: GuardedF64) -> Result<GuardedF64, FloatError> {
+    UnguardedF64::from(tau).check_where(|tau| tau > 0.0, FloatError::Domain)?;
+    UnguardedF64::from(dt).check_where(|dt| dt >= 0.0, FloatError::Domain)?;
+
+    // A tiny `tau` can overflow the ratio, but `exp_m1(-inf)` is exactly -1.
+    UnguardedF64::new(-(-(dt.0 / tau.0)).exp_m1()).check_reported()
+}
+
+/// Computes `initial * exp(exponent)`, falling back to log space if the factor alone is not a
+/// finite, non-zero value.
+pub(crate) fn scale_by_exp(
+    initial: GuardedF64,
+    exponent: UnguardedF64,
+) -> Result<GuardedF64, FloatError> {
+    let exponent = exponent.check_clamped()?;
+    if initial == 0.0 {
+        return Ok(initial);
+    }
+
+    let factor = exponent.exp();
+    if factor.0.is_finite() && factor.0 != 0.0 {
+        return (initial * factor).check_reported();
+    }
+
+    (initial.signum() * (initial.abs().ln() + exponent).exp()).check_reported()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_extreme_exponents() {
+        let large = GuardedF64::new(1e300).unwrap();
+        let small = GuardedF64::new(1e-300).unwrap();
+        let rate = GuardedF64::new(800.0).unwrap();
+        let one = GuardedF64::new(1.0).unwrap();
+
+        let expected = (1e300f64.ln() - 800.0).exp();
+        let value = exp_decay(large, rate, one).unwrap();
+        assert!(value > 0.0);
+        assert!(((value - expected) / expected).abs().check().unwrap() < 1e-9);
+
+        let expected = (1e-300f64.ln() + 800.0).exp();
+        let value = exp_decay(small, -rate, one).unwrap();
+        assert!(((value - expected) / expected).abs().check().unwrap() < 1e-9);
+
+        assert_eq!(exp_decay(-large, -rate, one), Err(FloatError::Infinity));
+        assert_eq!(
+            exp_decay(GuardedF64::new(0.0).unwrap(), -rate, GuardedF64::MAX),
+            Ok(GuardedF64::new(0.0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_smoothing_alpha_extremes() {
+        let one = GuardedF64::new(1.0).unwrap();
+        let tiny = GuardedF64::new(f64::MIN_POSITIVE).unwrap();
+
+        assert_eq!(smoothing_alpha(GuardedF64::MAX, tiny), Ok(one));
+        assert_eq!(
+            smoothing_alpha(tiny, GuardedF64::MAX),
+            Ok(GuardedF64::new(0.0).unwrap())
+        );
+        assert_eq!(
+            smoothing_alpha(one, GuardedF64::new(0.0).unwrap()),
+            Err(FloatError::Domain)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_decay_is_bounded(
+            initial in valid_f64(),
+            rate in 0.0..=f64::MAX,
+            t in 0.0..=f64::MAX,
+        ) {
+            let initial = GuardedF64::new(initial).unwrap();
+            let rate = GuardedF64::new(rate).unwrap();
+            let t = GuardedF64::new(t).unwrap();
+
+            let value = exp_decay(initial, rate, t).unwrap();
+            prop_assert!(value.abs() <= initial.abs());
+            prop_assert!(value == 0.0 || value.signum() == initial.signum());
+
+            let value = half_life_decay(initial, rate, t);
+            if rate != 0.0 || t != 0.0 {
+                let value = value.unwrap();
+                prop_assert!(value.abs() <= initial.abs());
+            }
+        }
+
+        #[test]
+        fn test_half_life_matches_exp_decay(
+            initial in -1e6..1e6f64,
+            half_life in 0.1..100.0f64,
+            t in 0.0..1000.0f64,
+        ) {
+            let initial = GuardedF64::new(initial).unwrap();
+            let half_life = GuardedF64::new(half_life).unwrap();
+            let t = GuardedF64::new(t).unwrap();
+            let rate = (GuardedF64::LN_2 / half_life).check().unwrap();
+
+            let expected = exp_decay(initial, rate, t).unwrap();
+            let value = half_life_decay(initial, half_life, t).unwrap();
+            let tolerance = expected.abs().0.mul_add(1e-12, 1e-300);
+            prop_assert!((value - expected).abs().check().unwrap() <= tolerance);
+        }
+
+        #[test]
+        fn test_smoothing_alpha_matches_naive(dt in 1e-3..1e3f64, tau in 1e-3..1e3f64) {
+            let alpha = smoothing_alpha(GuardedF64::new(dt).unwrap(), GuardedF64::new(tau).unwrap());
+            let expected = 1.0 - (-dt / tau).exp();
+
+            let alpha = alpha.unwrap();
+            prop_assert!((0.0..=1.0).contains(&alpha.0));
+            prop_assert!((alpha.0 - expected).abs() <= 1e-12);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Support for the `GuardedNewtype` derive macro, which is re-exported from `floatguard-derive`.
+//!
+//! The derive macro expands to code that calls the hidden macros in this module, so that the
+//! generated implementations follow the features enabled on `floatguard` rather than on the crate
+//! that uses the derive.
+pub use floatguard_derive::GuardedNewtype;
+
+/// Implements `Serialize` and `Deserialize` for a guarded newtype by delegating to the wrapped
+/// guarded type.
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! __impl_newtype_serde {
+    ($name:ident, $guarded:ty) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                <$guarded as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer)
+                    .map(Self)
+            }
+        }
+    };
+}
+
+/// Does nothing, because the `serde` feature is disabled.
+#[doc(hidden)]
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! __impl_newtype_serde {
+    ($name:ident, $guarded:ty) => {};
+}
@@ -0,0 +1,220 @@
+//! Easing functions over the unit interval, for animation and interpolation.
+//!
+//! Each function takes the progress of an animation as a `UnitF64` and returns the eased progress
+//! as a `UnitF64`, so inputs outside of [0, 1] are rejected when the progress is created, and the
+//! result can be used as a blend weight or passed to another easing function. Every function maps
+//! `0.0` to `0.0` and `1.0` to `1.0`. The polynomials can round a hair past the ends of the range
+//! near `0.0` and `1.0`, so the results are clamped back into it.
+use crate::{GuardedF64, UnitF64};
+
+/// Starts slowly and accelerates, with `t^2`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnitF64;
+/// use floatguard::ease::ease_in_quad;
+///
+/// let t = UnitF64::new(0.5).unwrap();
+/// assert_eq!(ease_in_quad(t).get(), 0.25);
+/// ```
+#[must_use]
+pub fn ease_in_quad(t: UnitF64) -> UnitF64 {
+    let t = progress(t);
+    unit(t * t)
+}
+
+/// Starts quickly and decelerates, with `1 - (1 - t)^2`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnitF64;
+/// use floatguard::ease::ease_out_quad;
+///
+/// let t = UnitF64::new(0.5).unwrap();
+/// assert_eq!(ease_out_quad(t).get(), 0.75);
+/// ```
+#[must_use]
+pub fn ease_out_quad(t: UnitF64) -> UnitF64 {
+    let t = progress(t);
+    unit(t * (2.0 - t))
+}
+
+/// Accelerates through the first half and decelerates through the second, with two quadratics
+/// that meet at `0.5`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnitF64;
+/// use floatguard::ease::ease_in_out_quad;
+///
+/// assert_eq!(ease_in_out_quad(UnitF64::new(0.25).unwrap()).get(), 0.125);
+/// assert_eq!(ease_in_out_quad(UnitF64::new(0.5).unwrap()).get(), 0.5);
+/// assert_eq!(ease_in_out_quad(UnitF64::new(0.75).unwrap()).get(), 0.875);
+/// ```
+#[must_use]
+pub fn ease_in_out_quad(t: UnitF64) -> UnitF64 {
+    let t = progress(t);
+    if t < 0.5 {
+        unit(2.0 * t * t)
+    } else {
+        let rest = 1.0 - t;
+        unit(2.0f64.mul_add(-rest * rest, 1.0))
+    }
+}
+
+/// Starts slowly and accelerates, with `t^3`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnitF64;
+/// use floatguard::ease::ease_in_cubic;
+///
+/// let t = UnitF64::new(0.5).unwrap();
+/// assert_eq!(ease_in_cubic(t).get(), 0.125);
+/// ```
+#[must_use]
+pub fn ease_in_cubic(t: UnitF64) -> UnitF64 {
+    let t = progress(t);
+    unit(t * t * t)
+}
+
+/// Starts quickly and decelerates, with `1 - (1 - t)^3`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnitF64;
+/// use floatguard::ease::ease_out_cubic;
+///
+/// let t = UnitF64::new(0.5).unwrap();
+/// assert_eq!(ease_out_cubic(t).get(), 0.875);
+/// ```
+#[must_use]
+pub fn ease_out_cubic(t: UnitF64) -> UnitF64 {
+    let rest = 1.0 - progress(t);
+    unit((rest * rest).mul_add(-rest, 1.0))
+}
+
+/// Accelerates through the first half and decelerates through the second, with two cubics that
+/// meet at `0.5`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnitF64;
+/// use floatguard::ease::ease_in_out_cubic;
+///
+/// assert_eq!(ease_in_out_cubic(UnitF64::new(0.25).unwrap()).get(), 0.0625);
+/// assert_eq!(ease_in_out_cubic(UnitF64::new(0.75).unwrap()).get(), 0.9375);
+/// ```
+#[must_use]
+pub fn ease_in_out_cubic(t: UnitF64) -> UnitF64 {
+    let t = progress(t);
+    if t < 0.5 {
+        unit(4.0 * t * t * t)
+    } else {
+        let rest = 1.0 - t;
+        unit(4.0f64.mul_add(-rest * rest * rest, 1.0))
+    }
+}
+
+/// Eases in and out with the cubic Hermite curve `3t^2 - 2t^3`, whose slope is zero at both ends.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnitF64;
+/// use floatguard::ease::smoothstep;
+///
+/// assert_eq!(smoothstep(UnitF64::new(0.25).unwrap()).get(), 0.156_25);
+/// assert_eq!(smoothstep(UnitF64::new(0.5).unwrap()).get(), 0.5);
+/// ```
+#[must_use]
+pub fn smoothstep(t: UnitF64) -> UnitF64 {
+    let t = progress(t);
+    unit(t * t * 2.0f64.mul_add(-t, 3.0))
+}
+
+/// Eases in and out with Perlin's quintic curve `6t^5 - 15t^4 + 10t^3`, whose slope and curvature
+/// are both zero at the ends.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnitF64;
+/// use floatguard::ease::smootherstep;
+///
+/// assert_eq!(smootherstep(UnitF64::new(0.5).unwrap()).get(), 0.5);
+/// assert_eq!(smootherstep(UnitF64::ONE), UnitF64::ONE);
+/// ```
+#[must_use]
+pub fn smootherstep(t: UnitF64) -> UnitF64 {
+    let t = progress(t);
+    unit(t * t * t * t.mul_add(6.0f64.mul_add(t, -15.0), 10.0))
+}
+
+/// Returns the raw progress, which is in the range [0, 1].
+const fn progress(t: UnitF64) -> f64 {
+    t.get().0
+}
+
+/// Clamps a finite result back into the range [0, 1], in case it was rounded past an end.
+const fn unit(value: f64) -> UnitF64 {
+    GuardedF64(value).clamp_unit()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use proptest::prelude::*;
+
+    type Easing = fn(UnitF64) -> UnitF64;
+
+    const EASINGS: [Easing; 8] = [
+        ease_in_quad,
+        ease_out_quad,
+        ease_in_out_quad,
+        ease_in_cubic,
+        ease_out_cubic,
+        ease_in_out_cubic,
+        smoothstep,
+        smootherstep,
+    ];
+
+    #[test]
+    fn test_endpoints() {
+        for ease in EASINGS {
+            assert_eq!(ease(UnitF64::ZERO), UnitF64::ZERO);
+            assert_eq!(ease(UnitF64::ONE), UnitF64::ONE);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_monotonic(a in 0.0..=1.0f64, b in 0.0..=1.0f64) {
+            let (low, high) = (UnitF64::new(a.min(b)).unwrap(), UnitF64::new(a.max(b)).unwrap());
+            for ease in EASINGS {
+                prop_assert!(ease(low).get().0 <= ease(high).get().0 + f64::EPSILON);
+            }
+        }
+
+        #[test]
+        fn test_symmetric(t in 0.0..=1.0f64) {
+            let (t, mirrored) = (UnitF64::new(t).unwrap(), UnitF64::new(1.0 - t).unwrap());
+            let pairs: [(Easing, Easing); 2] =
+                [(ease_in_quad, ease_out_quad), (ease_in_cubic, ease_out_cubic)];
+            for (ease_in, ease_out) in pairs {
+                prop_assert!((ease_in(t).get().0 - ease_out(mirrored).complement().get().0).abs() < 1e-14);
+            }
+            for ease in [ease_in_out_quad, ease_in_out_cubic, smoothstep, smootherstep] {
+                prop_assert!((ease(t).get().0 - ease(mirrored).complement().get().0).abs() < 1e-14);
+            }
+        }
+    }
+}
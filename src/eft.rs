@@ -0,0 +1,120 @@
+//! Error-free transformations of guarded values.
+//!
+//! An error-free transformation returns the rounded result of an operation along with the exact
+//! rounding error, so that `result + error` equals the exact mathematical result. These are the
+//! building blocks of compensated algorithms, like compensated summation and dot products, which
+//! carry the error terms forward to recover precision that would otherwise be lost.
+use crate::{FloatError, GuardedF64};
+
+/// Computes `a + b` along with the exact rounding error of the sum.
+///
+/// Uses Knuth's branch-free `TwoSum` algorithm, so the result is exact regardless of the relative
+/// magnitudes of `a` and `b`.
+///
+/// # Errors
+///
+/// Returns `FloatError::Infinity` if the sum overflows, or if one of the intermediate values of the
+/// algorithm overflows.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::eft::two_sum;
+///
+/// let a = GuardedF64::new(1.0).unwrap();
+/// let b = GuardedF64::new(1e-20).unwrap();
+/// let (sum, error) = two_sum(a, b).unwrap();
+/// assert_eq!(sum, 1.0);
+/// assert_eq!(error, 1e-20);
+///
+/// assert_eq!(two_sum(GuardedF64::MAX, GuardedF64::MAX), Err(FloatError::Infinity));
+/// ```
+pub fn two_sum(a: GuardedF64, b: GuardedF64) -> Result<(GuardedF64, GuardedF64), FloatError> {
+    let (a, b) = (a.0, b.0);
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    let error = (a - a_virtual) + (b - b_virtual);
+
+    Ok((
+        GuardedF64::new_reported(sum)?,
+        GuardedF64::new_reported(error)?,
+    ))
+}
+
+/// Computes `a * b` along with the exact rounding error of the product.
+///
+/// The error is computed with a fused multiply-add, so it is exact unless it is too small to
+/// represent, which can only happen when the product is close to the subnormal range.
+///
+/// # Errors
+///
+/// Returns `FloatError::Infinity` if the product overflows.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::eft::two_prod;
+///
+/// let a = GuardedF64::new(1.0 + f64::EPSILON).unwrap();
+/// let (product, error) = two_prod(a, a).unwrap();
+/// assert_eq!(product, 1.0 + 2.0 * f64::EPSILON);
+/// assert_eq!(error, f64::EPSILON * f64::EPSILON);
+///
+/// assert_eq!(two_prod(GuardedF64::MAX, GuardedF64::MAX), Err(FloatError::Infinity));
+/// ```
+pub fn two_prod(a: GuardedF64, b: GuardedF64) -> Result<(GuardedF64, GuardedF64), FloatError> {
+    let (a, b) = (a.0, b.0);
+    let product = GuardedF64::new_reported(a * b)?;
+    let error = a.mul_add(b, -product.0);
+
+    Ok((product, GuardedF64::new_reported(error)?))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::float_cmp
+    )]
+
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    const LIMIT: i64 = 1 << 53;
+
+    proptest! {
+        #[test]
+        fn test_two_sum_is_exact(a in any::<i64>(), b in any::<i64>()) {
+            let (sum, error) = two_sum(GuardedF64(a as f64), GuardedF64(b as f64)).unwrap();
+            let exact = (a as f64) as i128 + (b as f64) as i128;
+            prop_assert_eq!(sum.0 as i128 + error.0 as i128, exact);
+        }
+
+        #[test]
+        fn test_two_prod_is_exact(a in -LIMIT..LIMIT, b in -LIMIT..LIMIT) {
+            let (product, error) = two_prod(GuardedF64(a as f64), GuardedF64(b as f64)).unwrap();
+            let exact = i128::from(a) * i128::from(b);
+            prop_assert_eq!(product.0 as i128 + error.0 as i128, exact);
+        }
+
+        #[test]
+        fn test_result_is_rounded(a in valid_f64(), b in valid_f64()) {
+            let (a, b) = (GuardedF64(a), GuardedF64(b));
+
+            if let Ok((sum, error)) = two_sum(a, b) {
+                prop_assert_eq!(sum.0, a.0 + b.0);
+                prop_assert_eq!(sum.0 + error.0, sum.0);
+            }
+
+            if let Ok((product, error)) = two_prod(a, b) {
+                prop_assert_eq!(product.0, a.0 * b.0);
+                prop_assert_eq!(product.0 + error.0, product.0);
+            }
+        }
+    }
+}
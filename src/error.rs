@@ -1,3 +1,5 @@
+use crate::IeeeException;
+
 /// An error occurred while processing a floating-point value, indicating that
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
 pub enum Error {
@@ -6,6 +8,141 @@ pub enum Error {
 
     /// Indicates that the floating-point value is an infinity.
     Infinity,
+
+    /// Indicates that an operation would have divided by zero, such as solving a singular system of
+    /// equations.
+    DivisionByZero,
+
+    /// Indicates that a non-zero result was too small to represent and was rounded to zero.
+    Underflow,
+
+    /// Indicates that a value could not be converted exactly, such as converting a fractional or
+    /// out-of-range value to an integer.
+    Inexact,
+
+    /// Indicates that an input was outside of the domain of an operation, such as the square root
+    /// of a negative number or the logarithm of zero.
+    Domain,
+}
+
+impl Error {
+    /// Every variant of `Error`, in declaration order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::FloatError;
+    ///
+    /// for error in FloatError::ALL {
+    ///     println!("{}: {error}", error.code());
+    /// }
+    /// ```
+    pub const ALL: &'static [Self] = &[
+        Self::NaN,
+        Self::Infinity,
+        Self::DivisionByZero,
+        Self::Underflow,
+        Self::Inexact,
+        Self::Domain,
+    ];
+
+    /// Returns a stable, machine-readable code for the error.
+    ///
+    /// Unlike the `Display` output, codes never change once they are published, so they can be
+    /// used as keys for localized messages or as metric labels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, UnguardedF64};
+    ///
+    /// let error = UnguardedF64::new(f64::NAN).check().unwrap_err();
+    /// assert_eq!(error.code(), "FG_NAN");
+    /// ```
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::NaN => "FG_NAN",
+            Self::Infinity => "FG_INF",
+            Self::DivisionByZero => "FG_DIV_ZERO",
+            Self::Underflow => "FG_UNDERFLOW",
+            Self::Inexact => "FG_INEXACT",
+            Self::Domain => "FG_DOMAIN",
+        }
+    }
+
+    /// Returns the IEEE 754 exception that the error corresponds to.
+    ///
+    /// NaN results and domain errors are invalid operations, and infinite results are overflows.
+    /// An infinite result can also come from dividing a finite value by zero, which the hardware
+    /// signals as a division by zero instead, but that cannot be told apart from the value alone.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, IeeeException, UnguardedF64};
+    ///
+    /// let error = (UnguardedF64::new(-1.0).sqrt()).check().unwrap_err();
+    /// assert_eq!(error.ieee_class(), IeeeException::InvalidOperation);
+    ///
+    /// assert_eq!(FloatError::Infinity.ieee_class(), IeeeException::Overflow);
+    /// ```
+    #[must_use]
+    pub const fn ieee_class(self) -> IeeeException {
+        match self {
+            Self::NaN | Self::Domain => IeeeException::InvalidOperation,
+            Self::Infinity => IeeeException::Overflow,
+            Self::DivisionByZero => IeeeException::DivisionByZero,
+            Self::Underflow => IeeeException::Underflow,
+            Self::Inexact => IeeeException::Inexact,
+        }
+    }
+
+    /// Returns a suggestion of what commonly causes the error, to point debugging in the right
+    /// direction.
+    ///
+    /// The hint is a fixed suggestion for each kind of error, since a `FloatError` does not record
+    /// the operation that caused it. `ExprError::hint` is based on the operation that failed in an
+    /// expression, and with the `fp-flags` feature, `hint_with_flags` uses the IEEE 754 exceptions
+    /// that were signaled during the computation to tell apart causes of the same kind of error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::UnguardedF64;
+    ///
+    /// let error = (UnguardedF64::new(-1.0).ln()).check().unwrap_err();
+    /// assert!(error.hint().contains("logarithm of a negative intermediate value"));
+    /// ```
+    #[must_use]
+    pub const fn hint(self) -> &'static str {
+        match self {
+            Self::NaN => {
+                "Check for 0/0, infinity minus infinity, zero times infinity, or the square root or \
+                 logarithm of a negative intermediate value"
+            }
+            Self::Infinity => {
+                "Check for division by a value that may be zero, or for an intermediate value that \
+                 grew too large, such as the exponential of a large number"
+            }
+            Self::DivisionByZero => {
+                "Check for division by a value that may be zero, such as a length, a range, or a \
+                 determinant that vanished"
+            }
+            Self::Underflow => {
+                "Check for products of very small values, or the exponential of a large negative \
+                 number; rescaling or working in log space may help"
+            }
+            Self::Inexact => {
+                "Check that the value is a whole number within the range of the target type before \
+                 converting it"
+            }
+            Self::Domain => {
+                "Check for the logarithm of a non-positive intermediate value, the square root of a \
+                 negative one, or an inverse sine or cosine outside of [-1, 1]"
+            }
+        }
+    }
 }
 
 impl std::error::Error for Error {}
@@ -14,6 +151,75 @@ impl std::error::Error for Error {}
 /// description of the error.
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "The floating-point value is poisoned")
+        match self {
+            Self::NaN => write!(f, "The floating-point value is NaN"),
+            Self::Infinity => write!(f, "The floating-point value is infinite"),
+            Self::DivisionByZero => write!(f, "The operation divided by zero"),
+            Self::Underflow => write!(f, "The non-zero result underflowed to zero"),
+            Self::Inexact => write!(f, "The value cannot be represented exactly"),
+            Self::Domain => write!(f, "The input is outside of the domain of the operation"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_variants_have_unique_codes() {
+        for (index, error) in Error::ALL.iter().enumerate() {
+            // Fails to compile when a variant is added, as a reminder to add it to `ALL` too.
+            match error {
+                Error::NaN
+                | Error::Infinity
+                | Error::DivisionByZero
+                | Error::Underflow
+                | Error::Inexact
+                | Error::Domain => {}
+            }
+
+            assert!(error.code().starts_with("FG_"));
+            assert!(
+                Error::ALL[..index]
+                    .iter()
+                    .all(|other| other.code() != error.code())
+            );
+        }
+        assert_eq!(Error::ALL.len(), 6);
+    }
+
+    #[test]
+    fn test_every_exception_has_an_error() {
+        for exception in IeeeException::ALL {
+            assert!(
+                Error::ALL
+                    .iter()
+                    .any(|error| error.ieee_class() == *exception)
+            );
+        }
+    }
+
+    #[test]
+    fn test_all_variants_have_unique_hints() {
+        for (index, error) in Error::ALL.iter().enumerate() {
+            assert!(error.hint().starts_with("Check "));
+            assert!(
+                Error::ALL[..index]
+                    .iter()
+                    .all(|other| other.hint() != error.hint())
+            );
+        }
+    }
+
+    #[test]
+    fn test_all_variants_have_unique_messages() {
+        for (index, error) in Error::ALL.iter().enumerate() {
+            assert!(
+                Error::ALL[..index]
+                    .iter()
+                    .all(|other| other.to_string() != error.to_string())
+            );
+        }
     }
 }
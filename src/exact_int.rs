@@ -0,0 +1,165 @@
+//! This module provides the `ExactInt` trait, which lists the integer types that guarded values
+//! can be converted to exactly.
+use crate::{FloatError, GuardedF32, GuardedF64};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An integer type that `GuardedF64::to_exact_int` and `GuardedF32::to_exact_int` can convert to.
+///
+/// This trait is sealed and implemented for every primitive integer type.
+pub trait ExactInt: private::Sealed + Sized {
+    /// Converts `value` to `Self` if it is an integer within the range of `Self`.
+    #[doc(hidden)]
+    fn from_f64_exact(value: f64) -> Option<Self>;
+}
+
+macro_rules! exact_int {
+    ($($T:ty),*) => {
+        $(
+            impl private::Sealed for $T {}
+
+            impl ExactInt for $T {
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_precision_loss,
+                    clippy::cast_sign_loss,
+                    clippy::cast_lossless
+                )]
+                fn from_f64_exact(value: f64) -> Option<Self> {
+                    // `MIN` is zero or a power of two, and `MAX + 1` is a power of two, so both
+                    // bounds are exact even when `MAX` itself is not representable.
+                    let in_range = value >= <$T>::MIN as f64 && value < <$T>::MAX as f64 + 1.0;
+                    (in_range && value.fract() == 0.0).then_some(value as $T)
+                }
+            }
+        )*
+    };
+}
+
+exact_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl GuardedF64 {
+    /// Returns `true` if the value is an integer, with no fractional part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// assert!(GuardedF64::new(-3.0).unwrap().is_integer());
+    /// assert!(GuardedF64::new(1e300).unwrap().is_integer());
+    /// assert!(!GuardedF64::new(2.5).unwrap().is_integer());
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn is_integer(self) -> bool {
+        self.0.fract() == 0.0
+    }
+
+    /// Converts the value to an integer type, only if the conversion is exact.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Inexact` if the value has a fractional part or is outside of the range
+    /// of `T`, rather than rounding or saturating it like an `as` cast would.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// assert_eq!(GuardedF64::new(42.0).unwrap().to_exact_int::<i64>(), Ok(42));
+    /// assert_eq!(GuardedF64::new(42.5).unwrap().to_exact_int::<i64>(), Err(FloatError::Inexact));
+    /// assert_eq!(GuardedF64::new(-1.0).unwrap().to_exact_int::<u32>(), Err(FloatError::Inexact));
+    /// assert_eq!(GuardedF64::new(256.0).unwrap().to_exact_int::<u8>(), Err(FloatError::Inexact));
+    /// ```
+    pub fn to_exact_int<T: ExactInt>(self) -> Result<T, FloatError> {
+        T::from_f64_exact(self.0).ok_or(FloatError::Inexact)
+    }
+}
+
+impl GuardedF32 {
+    /// Returns `true` if the value is an integer, with no fractional part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF32;
+    ///
+    /// assert!(GuardedF32::new(-3.0).unwrap().is_integer());
+    /// assert!(!GuardedF32::new(2.5).unwrap().is_integer());
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn is_integer(self) -> bool {
+        self.0.fract() == 0.0
+    }
+
+    /// Converts the value to an integer type, only if the conversion is exact.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Inexact` if the value has a fractional part or is outside of the range
+    /// of `T`, rather than rounding or saturating it like an `as` cast would.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(42.0).unwrap().to_exact_int::<i16>(), Ok(42));
+    /// assert_eq!(GuardedF32::new(0.5).unwrap().to_exact_int::<i16>(), Err(FloatError::Inexact));
+    /// ```
+    pub fn to_exact_int<T: ExactInt>(self) -> Result<T, FloatError> {
+        T::from_f64_exact(f64::from(self.0)).ok_or(FloatError::Inexact)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::cast_precision_loss, clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_range_boundaries() {
+        let pow = |exp: i32| GuardedF64(2f64.powi(exp));
+
+        assert_eq!(pow(63).to_exact_int::<i64>(), Err(FloatError::Inexact));
+        assert_eq!((-pow(63)).to_exact_int::<i64>(), Ok(i64::MIN));
+        assert_eq!(pow(64).to_exact_int::<u64>(), Err(FloatError::Inexact));
+        assert_eq!(pow(127).to_exact_int::<u128>(), Ok(1u128 << 127));
+        assert_eq!(GuardedF64(-0.0).to_exact_int::<u8>(), Ok(0));
+        assert_eq!(GuardedF64(255.0).to_exact_int::<u8>(), Ok(u8::MAX));
+        assert_eq!(
+            GuardedF64(-129.0).to_exact_int::<i8>(),
+            Err(FloatError::Inexact)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_exact_int_round_trip(a in any::<i64>()) {
+            let value = GuardedF64(a as f64);
+            let exact = value.to_exact_int::<i64>();
+
+            prop_assert!(value.is_integer());
+            if let Ok(int) = exact {
+                prop_assert_eq!(int as f64, value.0);
+            } else {
+                prop_assert_eq!(value.0, 2f64.powi(63));
+            }
+        }
+
+        #[test]
+        fn test_fractional_values(a in valid_f64()) {
+            let value = GuardedF64(a);
+            prop_assert_eq!(value.is_integer(), a.fract() == 0.0);
+            prop_assert_eq!(value.to_exact_int::<i128>().is_ok(), value.is_integer() && a.abs() < 2f64.powi(127));
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::FloatError;
+
+/// An error occurred while parsing or evaluating an `Expr`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExprError {
+    /// Indicates that the expression has unexpected input, such as an unknown character, a
+    /// malformed number, or a missing operand, at the given byte offset.
+    Syntax {
+        /// The byte offset of the unexpected input.
+        position: usize,
+    },
+
+    /// Indicates that the expression ended where more input was expected, such as after an
+    /// operator or before a closing parenthesis.
+    UnexpectedEnd,
+
+    /// Indicates that the expression nests parentheses, signs, or function calls too deeply.
+    TooDeep,
+
+    /// Indicates that the expression calls a function that does not exist.
+    UnknownFunction {
+        /// The name of the function.
+        name: String,
+
+        /// The byte offset of the function name.
+        position: usize,
+    },
+
+    /// Indicates that a function was called with the wrong number of arguments.
+    ArgumentCount {
+        /// The name of the function.
+        name: String,
+
+        /// The number of arguments the function takes.
+        expected: usize,
+
+        /// The number of arguments it was called with.
+        found: usize,
+    },
+
+    /// Indicates that the expression uses a variable that was not given a value.
+    UnknownVariable {
+        /// The name of the variable.
+        name: String,
+    },
+
+    /// Indicates that the expression evaluated to NaN or infinity.
+    Invalid {
+        /// The kind of invalid value.
+        error: FloatError,
+
+        /// The operation that first produced NaN or infinity from finite operands, like `/` or
+        /// `ln`, or `None` if the expression contains a number too large to represent.
+        operation: Option<&'static str>,
+    },
+}
+
+impl ExprError {
+    /// Returns a suggestion of what caused an invalid result, based on the operation that first
+    /// produced it, or `None` if the error is not `ExprError::Invalid`.
+    ///
+    /// Without a recorded operation, this falls back to `FloatError::hint`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use floatguard::expr::Expr;
+    ///
+    /// let expr = Expr::parse("sqrt(x) * ln(x - 1)").unwrap();
+    /// let error = expr.eval(&[("x", GuardedF64::new(0.5).unwrap())]).unwrap_err();
+    /// assert!(error.hint().unwrap().contains("logarithm of a non-positive intermediate value"));
+    ///
+    /// let expr = Expr::parse("1 / (x - 1)").unwrap();
+    /// let error = expr.eval(&[("x", GuardedF64::new(1.0).unwrap())]).unwrap_err();
+    /// assert!(error.hint().unwrap().contains("division by a value that may be zero"));
+    /// ```
+    #[must_use]
+    pub fn hint(&self) -> Option<&'static str> {
+        let Self::Invalid { error, operation } = self else {
+            return None;
+        };
+        Some(match *operation {
+            Some("/") => {
+                "Check for division by a value that may be zero, or for a quotient that grew too \
+                 large"
+            }
+            Some("%") => "Check for the remainder of a division by a value that may be zero",
+            Some("+" | "-" | "*") => {
+                "Check for an intermediate value that grew too large, and consider rescaling the \
+                 inputs"
+            }
+            Some("^") => {
+                "Check for a negative base raised to a fractional power, zero raised to a negative \
+                 power, or a power that grew too large"
+            }
+            Some("sqrt") => "Check for the square root of a negative intermediate value",
+            Some("ln" | "log2" | "log10") => {
+                "Check for the logarithm of a non-positive intermediate value"
+            }
+            Some("asin" | "acos") => {
+                "Check for an inverse sine or cosine of an intermediate value outside of [-1, 1]"
+            }
+            Some("exp" | "sinh" | "cosh") => {
+                "Check for the exponential of a large intermediate value, and consider working in \
+                 log space"
+            }
+            _ => error.hint(),
+        })
+    }
+}
+
+impl std::error::Error for ExprError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Implements the `Display` trait for the `ExprError` enum, providing a user-friendly description
+/// of the error.
+impl std::fmt::Display for ExprError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax { position } => write!(f, "Unexpected input at byte {position}"),
+            Self::UnexpectedEnd => write!(f, "The expression ended unexpectedly"),
+            Self::TooDeep => write!(f, "The expression is nested too deeply"),
+            Self::UnknownFunction { name, position } => {
+                write!(f, "Unknown function `{name}` at byte {position}")
+            }
+            Self::ArgumentCount {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The function `{name}` takes {expected} arguments but was given {found}"
+            ),
+            Self::UnknownVariable { name } => write!(f, "The variable `{name}` has no value"),
+            Self::Invalid {
+                error,
+                operation: Some(operation),
+            } => write!(
+                f,
+                "The `{operation}` operation produced an invalid value: {error}"
+            ),
+            Self::Invalid {
+                error,
+                operation: None,
+            } => write!(f, "The expression evaluated to an invalid value: {error}"),
+        }
+    }
+}
@@ -0,0 +1,535 @@
+//! Arithmetic expressions parsed at runtime and evaluated with the crate's guarantees.
+//!
+//! An `Expr` is parsed once from a formula like `"a * sin(b) / (c - 1)"`, and can then be evaluated
+//! any number of times with different values for its variables. Evaluation uses the unguarded
+//! semantics of `UnguardedF64`, so intermediate NaN and infinite values propagate, and the result
+//! is checked once, at the end. Every failure, from a typo in the formula to a division by zero at
+//! runtime, is reported as an `ExprError`.
+//!
+//! Expressions support numbers like `2`, `0.5`, and `1e-3`, variables, parentheses, unary `+` and
+//! `-`, and the binary operators `+`, `-`, `*`, `/`, `%`, and `^`, which is right-associative and
+//! binds more tightly than unary minus, so `-2^2` is `-4`. The functions `abs`, `sqrt`, `exp`,
+//! `ln`, `log2`, `log10`, `sin`, `cos`, `tan`, `asin`, `acos`, `atan`, `sinh`, `cosh`, and `tanh`
+//! take one argument, and `atan2` and `pow` take two.
+mod error;
+mod parser;
+
+pub use error::ExprError;
+
+use crate::{GuardedF64, UnguardedF64};
+use std::str::FromStr;
+
+/// A parsed arithmetic expression.
+///
+/// The expression is stored as a sequence of stack instructions rather than a tree, so evaluating
+/// it does not recurse, however long the expression is.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::expr::{Expr, ExprError};
+///
+/// let expr = Expr::parse("a * sin(b) / (c - 1)").unwrap();
+/// let [a, b, c] = [4.0, 0.0, 3.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(expr.eval(&[("a", a), ("b", b), ("c", c)]), Ok(GuardedF64::new(0.0).unwrap()));
+///
+/// let c = GuardedF64::new(1.0).unwrap();
+/// let a = GuardedF64::new(-4.0).unwrap();
+/// let b = GuardedF64::new(1.0).unwrap();
+/// let error = ExprError::Invalid { error: FloatError::Infinity, operation: Some("/") };
+/// assert_eq!(expr.eval(&[("a", a), ("b", b), ("c", c)]), Err(error));
+/// assert_eq!(expr.eval(&[("a", a)]), Err(ExprError::UnknownVariable { name: "b".to_string() }));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Expr {
+    program: Vec<Instruction>,
+}
+
+impl Expr {
+    /// Parses an expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExprError::Syntax` or `ExprError::UnexpectedEnd` if the expression is malformed,
+    /// `ExprError::TooDeep` if it is nested too deeply, `ExprError::UnknownFunction` if it calls a
+    /// function that does not exist, or `ExprError::ArgumentCount` if it calls a function with the
+    /// wrong number of arguments.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::expr::{Expr, ExprError};
+    ///
+    /// assert!(Expr::parse("2 ^ -x").is_ok());
+    /// assert_eq!(Expr::parse("2 * (x + 1").unwrap_err(), ExprError::UnexpectedEnd);
+    /// assert_eq!(Expr::parse("2 $ x").unwrap_err(), ExprError::Syntax { position: 2 });
+    ///
+    /// let error = Expr::parse("sine(x)").unwrap_err();
+    /// assert_eq!(error, ExprError::UnknownFunction { name: "sine".to_string(), position: 0 });
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, ExprError> {
+        parser::parse(source).map(|program| Self { program })
+    }
+
+    /// Returns the names of the variables in the expression, each once, in the order they first
+    /// appear.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::expr::Expr;
+    ///
+    /// let expr = Expr::parse("y * x + y").unwrap();
+    /// assert_eq!(expr.variables(), ["y", "x"]);
+    /// ```
+    #[must_use]
+    pub fn variables(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for instruction in &self.program {
+            if let Instruction::Load(name) = instruction
+                && !names.contains(&name.as_str())
+            {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Evaluates the expression with the variables given as name and value pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExprError::UnknownVariable` if the expression uses a variable that is not in
+    /// `variables`, or `ExprError::Invalid`, with the operation that first went wrong, if the result
+    /// is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use floatguard::expr::Expr;
+    ///
+    /// let expr = Expr::parse("sqrt(x^2 + y^2)").unwrap();
+    /// let [x, y] = [3.0, 4.0].map(|v| GuardedF64::new(v).unwrap());
+    /// assert_eq!(expr.eval(&[("x", x), ("y", y)]), Ok(GuardedF64::new(5.0).unwrap()));
+    /// ```
+    pub fn eval(&self, variables: &[(&str, GuardedF64)]) -> Result<GuardedF64, ExprError> {
+        self.eval_with(|name| {
+            variables
+                .iter()
+                .find(|(variable, _)| *variable == name)
+                .map(|(_, value)| *value)
+        })
+    }
+
+    /// Evaluates the expression, looking up the value of each variable with `lookup`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExprError::UnknownVariable` if `lookup` returns `None` for a variable, or
+    /// `ExprError::Invalid`, with the operation that first went wrong, if the result is NaN or
+    /// infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use floatguard::expr::{Expr, ExprError};
+    /// use std::collections::HashMap;
+    ///
+    /// let variables = HashMap::from([("rate", GuardedF64::new(0.25).unwrap())]);
+    /// let expr = Expr::parse("ln(rate) / ln(1 - rate)").unwrap();
+    /// assert!(expr.eval_with(|name| variables.get(name).copied()).is_ok());
+    ///
+    /// let expr = Expr::parse("1 / (rate - 0.25)").unwrap();
+    /// let error = expr.eval_with(|name| variables.get(name).copied());
+    /// assert_eq!(error, Err(ExprError::Invalid { error: FloatError::Infinity, operation: Some("/") }));
+    /// ```
+    pub fn eval_with(
+        &self,
+        lookup: impl Fn(&str) -> Option<GuardedF64>,
+    ) -> Result<GuardedF64, ExprError> {
+        let mut stack: Vec<Operand> = Vec::new();
+        for instruction in &self.program {
+            let operand = match instruction {
+                Instruction::Push(value) => Operand::new(*value),
+                Instruction::Load(name) => lookup(name)
+                    .map(|value| Operand::new(value.into()))
+                    .ok_or_else(|| ExprError::UnknownVariable { name: name.clone() })?,
+                Instruction::Unary(op) => {
+                    let x = pop(&mut stack);
+                    Operand::traced(op.apply(x.value), op.name(), &[x])
+                }
+                Instruction::Binary(op) => {
+                    let rhs = pop(&mut stack);
+                    let lhs = pop(&mut stack);
+                    Operand::traced(op.apply(lhs.value, rhs.value), op.name(), &[lhs, rhs])
+                }
+            };
+            stack.push(operand);
+        }
+
+        let Operand { value, origin } = pop(&mut stack);
+        value.check_reported().map_err(|error| ExprError::Invalid {
+            error,
+            operation: origin,
+        })
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ExprError;
+
+    /// Parses an expression, like `Expr::parse`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `Expr::parse`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// A value on the evaluation stack, with the operation it came from if it is NaN or infinite.
+#[derive(Debug, Clone, Copy)]
+struct Operand {
+    value: UnguardedF64,
+
+    /// The name of the operation that first produced NaN or infinity from finite operands, if the
+    /// value is invalid because of one.
+    origin: Option<&'static str>,
+}
+
+impl Operand {
+    /// Creates an operand that did not come from an operation.
+    const fn new(value: UnguardedF64) -> Self {
+        Self {
+            value,
+            origin: None,
+        }
+    }
+
+    /// Creates an operand that `operation` computed from `operands`.
+    ///
+    /// An invalid result keeps the origin of the first invalid operand, so the origin is always
+    /// the operation where the expression first went wrong, rather than one that passed an
+    /// invalid value along.
+    fn traced(value: UnguardedF64, operation: &'static str, operands: &[Self]) -> Self {
+        let origin = if value.0.is_finite() {
+            None
+        } else {
+            operands
+                .iter()
+                .find(|operand| !operand.value.0.is_finite())
+                .map_or(Some(operation), |operand| operand.origin)
+        };
+        Self { value, origin }
+    }
+}
+
+/// Pops the top operand from the evaluation stack.
+///
+/// The parser emits every operator after its operands, so the stack is never empty here. If it
+/// were, the missing operand would be NaN, and the final check would report it.
+fn pop(stack: &mut Vec<Operand>) -> Operand {
+    stack.pop().unwrap_or(Operand::new(UnguardedF64(f64::NAN)))
+}
+
+/// A single step of an expression, in postfix order.
+#[derive(Debug, Clone)]
+enum Instruction {
+    /// Pushes a number.
+    Push(UnguardedF64),
+
+    /// Pushes the value of a variable.
+    Load(String),
+
+    /// Replaces the top value with the result of a unary operation on it.
+    Unary(Unary),
+
+    /// Replaces the top two values with the result of a binary operation on them.
+    Binary(Binary),
+}
+
+/// An operation with one operand.
+#[derive(Debug, Clone, Copy)]
+enum Unary {
+    Neg,
+    Abs,
+    Sqrt,
+    Exp,
+    Ln,
+    Log2,
+    Log10,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Sinh,
+    Cosh,
+    Tanh,
+}
+
+impl Unary {
+    /// The unary operations that can be called as functions, by name.
+    const FUNCTIONS: &[(&str, Self)] = &[
+        ("abs", Self::Abs),
+        ("sqrt", Self::Sqrt),
+        ("exp", Self::Exp),
+        ("ln", Self::Ln),
+        ("log2", Self::Log2),
+        ("log10", Self::Log10),
+        ("sin", Self::Sin),
+        ("cos", Self::Cos),
+        ("tan", Self::Tan),
+        ("asin", Self::Asin),
+        ("acos", Self::Acos),
+        ("atan", Self::Atan),
+        ("sinh", Self::Sinh),
+        ("cosh", Self::Cosh),
+        ("tanh", Self::Tanh),
+    ];
+
+    /// Returns the name of the operation, as it is written in an expression.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Neg => "-",
+            Self::Abs => "abs",
+            Self::Sqrt => "sqrt",
+            Self::Exp => "exp",
+            Self::Ln => "ln",
+            Self::Log2 => "log2",
+            Self::Log10 => "log10",
+            Self::Sin => "sin",
+            Self::Cos => "cos",
+            Self::Tan => "tan",
+            Self::Asin => "asin",
+            Self::Acos => "acos",
+            Self::Atan => "atan",
+            Self::Sinh => "sinh",
+            Self::Cosh => "cosh",
+            Self::Tanh => "tanh",
+        }
+    }
+
+    fn apply(self, x: UnguardedF64) -> UnguardedF64 {
+        match self {
+            Self::Neg => -x,
+            Self::Abs => x.abs(),
+            Self::Sqrt => x.sqrt(),
+            Self::Exp => x.exp(),
+            Self::Ln => x.ln(),
+            Self::Log2 => x.log2(),
+            Self::Log10 => x.log10(),
+            Self::Sin => x.sin(),
+            Self::Cos => x.cos(),
+            Self::Tan => x.tan(),
+            Self::Asin => x.asin(),
+            Self::Acos => x.acos(),
+            Self::Atan => x.atan(),
+            Self::Sinh => x.sinh(),
+            Self::Cosh => x.cosh(),
+            Self::Tanh => x.tanh(),
+        }
+    }
+}
+
+/// An operation with two operands.
+#[derive(Debug, Clone, Copy)]
+enum Binary {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Atan2,
+}
+
+impl Binary {
+    /// The binary operations that can be called as functions, by name.
+    const FUNCTIONS: &[(&str, Self)] = &[("atan2", Self::Atan2), ("pow", Self::Pow)];
+
+    /// Returns the name of the operation, as it is written in an expression.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Rem => "%",
+            Self::Pow => "^",
+            Self::Atan2 => "atan2",
+        }
+    }
+
+    fn apply(self, lhs: UnguardedF64, rhs: UnguardedF64) -> UnguardedF64 {
+        match self {
+            Self::Add => lhs + rhs,
+            Self::Sub => lhs - rhs,
+            Self::Mul => lhs * rhs,
+            Self::Div => lhs / rhs,
+            Self::Rem => lhs % rhs,
+            Self::Pow => lhs.powf(rhs),
+            Self::Atan2 => lhs.atan2(rhs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use proptest::prelude::*;
+
+    fn eval(source: &str) -> Result<GuardedF64, ExprError> {
+        Expr::parse(source)?.eval(&[("x", GuardedF64(2.0)), ("y", GuardedF64(-3.0))])
+    }
+
+    #[test]
+    fn test_precedence() {
+        for (source, expected) in [
+            ("1 + 2 * 3", 7.0),
+            ("(1 + 2) * 3", 9.0),
+            ("2 ^ 3 ^ 2", 512.0),
+            ("-2 ^ 2", -4.0),
+            ("2 ^ -1", 0.5),
+            ("10 - 4 - 3", 3.0),
+            ("24 / 4 / 3", 2.0),
+            ("7 % 4 * 2", 6.0),
+            ("- - x", 2.0),
+            ("+x * y", -6.0),
+            ("pow(x, 3) - atan2(0, 1)", 8.0),
+            ("abs(y) + sqrt(4) * exp(0)", 5.0),
+            ("1.5e1 + .5", 15.5),
+        ] {
+            assert_eq!(eval(source), Ok(GuardedF64(expected)), "{source}");
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        for (source, expected) in [
+            ("", ExprError::UnexpectedEnd),
+            ("1 +", ExprError::UnexpectedEnd),
+            ("sin(x", ExprError::UnexpectedEnd),
+            ("1 2", ExprError::Syntax { position: 2 }),
+            ("1 + * 2", ExprError::Syntax { position: 4 }),
+            ("(x))", ExprError::Syntax { position: 3 }),
+            ("1..2", ExprError::Syntax { position: 0 }),
+            ("x ° 2", ExprError::Syntax { position: 2 }),
+            ("pow(1,)", ExprError::Syntax { position: 6 }),
+            (
+                "1 + cbrt(8)",
+                ExprError::UnknownFunction {
+                    name: "cbrt".to_string(),
+                    position: 4,
+                },
+            ),
+            (
+                "atan2(1)",
+                ExprError::ArgumentCount {
+                    name: "atan2".to_string(),
+                    expected: 2,
+                    found: 1,
+                },
+            ),
+            (
+                "sin()",
+                ExprError::ArgumentCount {
+                    name: "sin".to_string(),
+                    expected: 1,
+                    found: 0,
+                },
+            ),
+            (
+                "z + 1",
+                ExprError::UnknownVariable {
+                    name: "z".to_string(),
+                },
+            ),
+            (
+                "sqrt(y)",
+                ExprError::Invalid {
+                    error: FloatError::NaN,
+                    operation: Some("sqrt"),
+                },
+            ),
+            (
+                "1e999 - 1e999",
+                ExprError::Invalid {
+                    error: FloatError::NaN,
+                    operation: None,
+                },
+            ),
+        ] {
+            assert_eq!(eval(source), Err(expected), "{source}");
+        }
+    }
+
+    #[test]
+    fn test_records_failing_operation() {
+        for (source, operation) in [
+            ("ln(y) + 1", Some("ln")),
+            ("atan(1 / 0) + ln(y)", Some("ln")),
+            ("exp(1000) - exp(1000)", Some("exp")),
+            ("x % (x - 2) * 10", Some("%")),
+            ("1e308 * x", Some("*")),
+            ("-1e999", None),
+        ] {
+            let Err(ExprError::Invalid {
+                operation: found, ..
+            }) = eval(source)
+            else {
+                panic!("{source} should be invalid");
+            };
+            assert_eq!(found, operation, "{source}");
+        }
+
+        let error = eval("asin(x)").unwrap_err();
+        assert!(error.hint().unwrap().contains("outside of [-1, 1]"));
+        assert_eq!(
+            eval("-1e999").unwrap_err().hint(),
+            Some(FloatError::Infinity.hint())
+        );
+        assert_eq!(eval("z").unwrap_err().hint(), None);
+    }
+
+    #[test]
+    fn test_nesting() {
+        let deep = format!("{}x{}", "(".repeat(1000), ")".repeat(1000));
+        assert_eq!(Expr::parse(&deep).unwrap_err(), ExprError::TooDeep);
+        assert_eq!(
+            Expr::parse(&"-".repeat(1000)).unwrap_err(),
+            ExprError::TooDeep
+        );
+
+        let long = vec!["x"; 100_000].join(" + ");
+        assert_eq!(eval(&long), Ok(GuardedF64(200_000.0)));
+    }
+
+    proptest! {
+        #[test]
+        fn test_never_panics(source in "[-+*/%^(), .0-9a-z]{0,32}") {
+            if let Ok(expr) = Expr::parse(&source) {
+                let _ = expr.eval_with(|_| Some(GuardedF64(1.0)));
+            }
+        }
+
+        #[test]
+        fn test_matches_direct_evaluation(a in -1e6..1e6f64, b in -1e6..1e6f64) {
+            let expr = Expr::parse("a * sin(b) / (a - b + 0.5)").unwrap();
+            let result = expr.eval(&[("a", GuardedF64(a)), ("b", GuardedF64(b))]);
+            let expected = (UnguardedF64(a) * UnguardedF64(b).sin()) / (UnguardedF64(a) - b + 0.5);
+            prop_assert_eq!(
+                result.map_err(|error| error.to_string()),
+                expected.check().map_err(|error| {
+                    format!("The `/` operation produced an invalid value: {error}")
+                })
+            );
+        }
+    }
+}
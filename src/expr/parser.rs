@@ -0,0 +1,277 @@
+use super::{Binary, ExprError, Instruction, Unary};
+use crate::UnguardedF64;
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+/// The deepest nesting of parentheses, signs, and function calls that the parser accepts, which
+/// bounds its recursion.
+const MAX_DEPTH: usize = 64;
+
+/// A lexical token of an expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Number(f64),
+    Name(&'a str),
+    Operator(u8),
+    Open,
+    Close,
+    Comma,
+}
+
+/// Parses `source` into a sequence of instructions in postfix order.
+pub fn parse(source: &str) -> Result<Vec<Instruction>, ExprError> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?.into_iter().peekable(),
+        program: Vec::new(),
+        depth: 0,
+    };
+
+    parser.expression()?;
+    match parser.tokens.next() {
+        Some((position, _)) => Err(ExprError::Syntax { position }),
+        None => Ok(parser.program),
+    }
+}
+
+/// Splits `source` into tokens, each with the byte offset where it starts.
+fn tokenize(source: &str) -> Result<Vec<(usize, Token<'_>)>, ExprError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while let Some(&byte) = bytes.get(index) {
+        let start = index;
+        let token = match byte {
+            b' ' | b'\t' | b'\r' | b'\n' => {
+                index += 1;
+                continue;
+            }
+            b'0'..=b'9' | b'.' => {
+                index = number_end(bytes, index);
+                source
+                    .get(start..index)
+                    .and_then(|number| number.parse().ok())
+                    .map(Token::Number)
+                    .ok_or(ExprError::Syntax { position: start })?
+            }
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' => {
+                while bytes
+                    .get(index)
+                    .is_some_and(|byte| byte.is_ascii_alphanumeric() || *byte == b'_')
+                {
+                    index += 1;
+                }
+                Token::Name(source.get(start..index).unwrap_or_default())
+            }
+            b'+' | b'-' | b'*' | b'/' | b'%' | b'^' => {
+                index += 1;
+                Token::Operator(byte)
+            }
+            b'(' => {
+                index += 1;
+                Token::Open
+            }
+            b')' => {
+                index += 1;
+                Token::Close
+            }
+            b',' => {
+                index += 1;
+                Token::Comma
+            }
+            _ => return Err(ExprError::Syntax { position: start }),
+        };
+        tokens.push((start, token));
+    }
+
+    Ok(tokens)
+}
+
+/// Returns the end of the number that starts at `index`: digits and points, followed by an
+/// optional exponent. Malformed numbers like `1.2.3` are left for `str::parse` to reject.
+fn number_end(bytes: &[u8], mut index: usize) -> usize {
+    let is_digit = |index: usize| bytes.get(index).is_some_and(u8::is_ascii_digit);
+
+    while is_digit(index) || bytes.get(index) == Some(&b'.') {
+        index += 1;
+    }
+
+    if matches!(bytes.get(index), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(index + 1), Some(b'+' | b'-')));
+        if is_digit(index + 1 + sign) {
+            index += 1 + sign;
+            while is_digit(index) {
+                index += 1;
+            }
+        }
+    }
+
+    index
+}
+
+/// A recursive-descent parser that emits instructions as it recognizes them.
+struct Parser<'a> {
+    tokens: Peekable<IntoIter<(usize, Token<'a>)>>,
+    program: Vec<Instruction>,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Parses a sum or difference of terms.
+    fn expression(&mut self) -> Result<(), ExprError> {
+        self.term()?;
+        while let Some(op) = self.operator(|byte| match byte {
+            b'+' => Some(Binary::Add),
+            b'-' => Some(Binary::Sub),
+            _ => None,
+        }) {
+            self.term()?;
+            self.program.push(Instruction::Binary(op));
+        }
+        Ok(())
+    }
+
+    /// Parses a product, quotient, or remainder of signed factors.
+    fn term(&mut self) -> Result<(), ExprError> {
+        self.signed()?;
+        while let Some(op) = self.operator(|byte| match byte {
+            b'*' => Some(Binary::Mul),
+            b'/' => Some(Binary::Div),
+            b'%' => Some(Binary::Rem),
+            _ => None,
+        }) {
+            self.signed()?;
+            self.program.push(Instruction::Binary(op));
+        }
+        Ok(())
+    }
+
+    /// Parses a factor with any number of leading signs. This is the only recursive entry point
+    /// that does not consume a token first, so it is where nesting is limited.
+    fn signed(&mut self) -> Result<(), ExprError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(ExprError::TooDeep);
+        }
+
+        let result = match self.operator(|byte| matches!(byte, b'+' | b'-').then_some(byte)) {
+            Some(b'-') => self
+                .signed()
+                .map(|()| self.program.push(Instruction::Unary(Unary::Neg))),
+            Some(_) => self.signed(),
+            None => self.power(),
+        };
+
+        self.depth -= 1;
+        result
+    }
+
+    /// Parses a primary value, optionally raised to a signed, right-associative power.
+    fn power(&mut self) -> Result<(), ExprError> {
+        self.primary()?;
+        if self.operator(|byte| (byte == b'^').then_some(())).is_some() {
+            self.signed()?;
+            self.program.push(Instruction::Binary(Binary::Pow));
+        }
+        Ok(())
+    }
+
+    /// Parses a number, a variable, a function call, or a parenthesized expression.
+    fn primary(&mut self) -> Result<(), ExprError> {
+        match self.tokens.next() {
+            Some((_, Token::Number(value))) => {
+                self.program.push(Instruction::Push(UnguardedF64(value)));
+                Ok(())
+            }
+            Some((position, Token::Name(name))) => {
+                if self
+                    .tokens
+                    .next_if(|(_, token)| *token == Token::Open)
+                    .is_some()
+                {
+                    self.call(name, position)
+                } else {
+                    self.program.push(Instruction::Load(name.to_string()));
+                    Ok(())
+                }
+            }
+            Some((_, Token::Open)) => {
+                self.expression()?;
+                self.expect_close()
+            }
+            Some((position, _)) => Err(ExprError::Syntax { position }),
+            None => Err(ExprError::UnexpectedEnd),
+        }
+    }
+
+    /// Parses the arguments of a call to the function `name`, after its opening parenthesis.
+    fn call(&mut self, name: &'a str, position: usize) -> Result<(), ExprError> {
+        let (instruction, expected) = find(Unary::FUNCTIONS, name)
+            .map(|op| (Instruction::Unary(op), 1))
+            .or_else(|| find(Binary::FUNCTIONS, name).map(|op| (Instruction::Binary(op), 2)))
+            .ok_or_else(|| ExprError::UnknownFunction {
+                name: name.to_string(),
+                position,
+            })?;
+
+        let mut found = 0;
+        if self
+            .tokens
+            .next_if(|(_, token)| *token == Token::Close)
+            .is_none()
+        {
+            loop {
+                self.expression()?;
+                found += 1;
+                if self
+                    .tokens
+                    .next_if(|(_, token)| *token == Token::Comma)
+                    .is_none()
+                {
+                    break;
+                }
+            }
+            self.expect_close()?;
+        }
+
+        if found != expected {
+            return Err(ExprError::ArgumentCount {
+                name: name.to_string(),
+                expected,
+                found,
+            });
+        }
+        self.program.push(instruction);
+        Ok(())
+    }
+
+    /// Consumes the next token if it is an operator that `select` accepts, and returns the
+    /// selection.
+    fn operator<T>(&mut self, select: impl Fn(u8) -> Option<T>) -> Option<T> {
+        let selected = match self.tokens.peek() {
+            Some((_, Token::Operator(byte))) => select(*byte),
+            _ => None,
+        };
+        if selected.is_some() {
+            self.tokens.next();
+        }
+        selected
+    }
+
+    /// Consumes a closing parenthesis.
+    fn expect_close(&mut self) -> Result<(), ExprError> {
+        match self.tokens.next() {
+            Some((_, Token::Close)) => Ok(()),
+            Some((position, _)) => Err(ExprError::Syntax { position }),
+            None => Err(ExprError::UnexpectedEnd),
+        }
+    }
+}
+
+/// Looks up a function by name.
+fn find<T: Copy>(functions: &[(&str, T)], name: &str) -> Option<T> {
+    functions
+        .iter()
+        .find(|(function, _)| *function == name)
+        .map(|(_, op)| *op)
+}
@@ -0,0 +1,139 @@
+use super::{GuardedF32, UnguardedF32};
+use crate::macros::math::math;
+
+math!(
+    (GuardedF32, UnguardedF32)
+    r"
+        Computes the logistic sigmoid of `self`, `1 / (1 + exp(-self))`. `GuardedF32::sigmoid`
+        returns a `GuardedF32` type because the result is always in the range [0, 1].
+
+        The naive formula overflows `exp` for large negative inputs. This evaluates the exponential
+        of a non-positive value instead, so it never overflows.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF32, UnguardedF32};
+
+        let zero = GuardedF32::new(0.0_f32).unwrap();
+        assert_eq!(zero.sigmoid(), 0.5);
+
+        let large = GuardedF32::new(-200.0_f32).unwrap();
+        assert_eq!(large.sigmoid(), 0.0);
+
+        let invalid = UnguardedF32::new(f32::NAN);
+        assert!(invalid.sigmoid().check().is_err());
+        ```
+    "
+    fn sigmoid(value: f32) -> Self {
+        if value >= 0.0 {
+            Self(1.0 / (1.0 + (-value).exp()))
+        } else {
+            let exp = value.exp();
+            Self(exp / (1.0 + exp))
+        }
+    }
+);
+
+math!(
+    (GuardedF32, UnguardedF32)
+    r"
+        Computes the logit of `self`, `ln(self / (1 - self))`, which is the inverse of `sigmoid`.
+
+        The logit is only defined in the range (0, 1). Inputs of 0 and 1 produce infinities, and
+        inputs outside of the range produce NaN, so the result must be checked.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF32, FloatError};
+
+        let half = GuardedF32::new(0.5_f32).unwrap();
+        assert_eq!(half.logit().check(), GuardedF32::new(0.0));
+
+        let one = GuardedF32::new(1.0_f32).unwrap();
+        assert_eq!(one.logit().check(), Err(FloatError::Infinity));
+
+        let outside = GuardedF32::new(1.5_f32).unwrap();
+        assert_eq!(outside.logit().check(), Err(FloatError::NaN));
+        ```
+    "
+    fn logit(value: f32) -> UnguardedF32 {
+        UnguardedF32(value.ln() - (-value).ln_1p())
+    }
+);
+
+math!(
+    (GuardedF32, UnguardedF32)
+    r"
+        Computes the softplus of `self`, `ln(1 + exp(self))`. `GuardedF32::softplus` returns a
+        `GuardedF32` type because the result is never larger than `max(self, 0) + ln(2)`.
+
+        The naive formula overflows `exp` for large positive inputs. This is computed as
+        `max(self, 0) + ln(1 + exp(-|self|))` instead, so it never overflows.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF32, UnguardedF32};
+
+        let large = GuardedF32::new(200.0_f32).unwrap();
+        assert_eq!(large.softplus(), 200.0);
+
+        let zero = GuardedF32::new(0.0_f32).unwrap();
+        assert_eq!(zero.softplus(), std::f32::consts::LN_2);
+
+        let invalid = UnguardedF32::new(f32::NAN);
+        assert!(invalid.softplus().check().is_err());
+        ```
+    "
+    fn softplus(value: f32) -> Self {
+        Self(value.max(0.0) + (-value.abs()).exp().ln_1p())
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f32::tests::{invalid_f32, valid_f32};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_softplus_max() {
+        let max = GuardedF32::MAX;
+        assert_eq!(max.softplus(), max);
+        assert_eq!(UnguardedF32::from(max).softplus().check(), Ok(max));
+    }
+
+    proptest! {
+        #[test]
+        fn test_sigmoid_bounded(a in valid_f32()) {
+            let sigmoid = GuardedF32::new(a).unwrap().sigmoid();
+            prop_assert!((0.0..=1.0).contains(&sigmoid.0));
+            prop_assert_eq!(UnguardedF32::new(a).sigmoid().check(), Ok(sigmoid));
+        }
+
+        #[test]
+        fn test_softplus_bounded(a in valid_f32()) {
+            let softplus = GuardedF32::new(a).unwrap().softplus();
+            prop_assert!(softplus.0 >= a.max(0.0));
+            prop_assert!(softplus.0 <= a.max(0.0) + std::f32::consts::LN_2);
+            prop_assert_eq!(UnguardedF32::new(a).softplus().check(), Ok(softplus));
+        }
+
+        // Large positive inputs have sigmoids that round to one, where the logit cannot recover them.
+        #[test]
+        fn test_logit_inverts_sigmoid(a in -10.0..2.0f32) {
+            let a = GuardedF32::new(a).unwrap();
+            let logit = a.sigmoid().logit().check().unwrap();
+            prop_assert!((logit - a).abs().check().unwrap() <= 1e-4);
+        }
+
+        #[test]
+        fn test_invalid_propagates(a in invalid_f32()) {
+            let invalid = UnguardedF32::new(a);
+            prop_assert!(invalid.logit().check().is_err());
+            prop_assert_eq!(invalid.softplus().check().is_err(), a.is_nan() || a > 0.0);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::{GuardedF32, UnguardedF32};
-
+use crate::GuardedF64;
 use crate::macros::consts::copy_const_value;
 
 copy_const_value!(
@@ -304,6 +304,66 @@ copy_const_value!(
     LN_10: GuardedF32 = GuardedF32(std::f32::consts::LN_10)
 );
 
+copy_const_value!(
+    (GuardedF32, UnguardedF32)
+    r"
+        1/&radic;(2&pi;), the peak of the standard normal distribution.
+
+        Rounded from [`GuardedF64::FRAC_1_SQRT_2PI`] at compile time.
+    "
+    FRAC_1_SQRT_2PI: GuardedF32 = derived(GuardedF64::FRAC_1_SQRT_2PI.0 as f32)
+);
+
+copy_const_value!(
+    (GuardedF32, UnguardedF32)
+    r"
+        &radic;(2&pi;)
+
+        Rounded from [`GuardedF64::SQRT_2PI`] at compile time.
+    "
+    SQRT_2PI: GuardedF32 = derived(GuardedF64::SQRT_2PI.0 as f32)
+);
+
+copy_const_value!(
+    (GuardedF32, UnguardedF32)
+    r"
+        &radic;&pi;
+
+        Rounded from [`GuardedF64::SQRT_PI`] at compile time.
+    "
+    SQRT_PI: GuardedF32 = derived(GuardedF64::SQRT_PI.0 as f32)
+);
+
+copy_const_value!(
+    (GuardedF32, UnguardedF32)
+    r"
+        1/&tau;, or 1/(2&pi;)
+
+        Rounded from [`GuardedF64::FRAC_1_TAU`] at compile time.
+    "
+    FRAC_1_TAU: GuardedF32 = derived(GuardedF64::FRAC_1_TAU.0 as f32)
+);
+
+copy_const_value!(
+    (GuardedF32, UnguardedF32)
+    r"
+        The number of radians in one degree, &pi;/180.
+
+        Rounded from [`GuardedF64::DEG_TO_RAD`] at compile time.
+    "
+    DEG_TO_RAD: GuardedF32 = derived(GuardedF64::DEG_TO_RAD.0 as f32)
+);
+
+copy_const_value!(
+    (GuardedF32, UnguardedF32)
+    r"
+        The number of degrees in one radian, 180/&pi;.
+
+        Rounded from [`GuardedF64::RAD_TO_DEG`] at compile time.
+    "
+    RAD_TO_DEG: GuardedF32 = derived(GuardedF64::RAD_TO_DEG.0 as f32)
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -415,4 +475,19 @@ mod tests {
         assert_eq!(UnguardedF32::MAX_10_EXP, f32::MAX_10_EXP);
         assert_type_eq!(UnguardedF32::MAX_10_EXP, i32);
     }
+
+    #[test]
+    fn test_derived() {
+        for (value, expected) in [
+            (GuardedF32::FRAC_1_SQRT_2PI, 0.398_942_3),
+            (GuardedF32::SQRT_2PI, 2.506_628_3),
+            (GuardedF32::SQRT_PI, 1.772_453_9),
+            (GuardedF32::FRAC_1_TAU, 0.159_154_94),
+            (GuardedF32::DEG_TO_RAD, 0.017_453_292),
+            (GuardedF32::RAD_TO_DEG, 57.295_78),
+        ] {
+            assert_eq!(value, expected);
+        }
+        assert_type_eq!(UnguardedF32::SQRT_2PI, GuardedF32);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::FloatError;
+use crate::macros::ops_bool::bool_conversion;
 
 use super::{GuardedF32, UnguardedF32};
 
@@ -33,6 +34,117 @@ impl UnguardedF32 {
     pub const fn check(self) -> Result<GuardedF32, FloatError> {
         GuardedF32::new(self.0)
     }
+
+    /// Checks if the `UnguardedF32` value is valid (finite), like `check`, and reports the validation
+    /// to the invalid-value hook and the validation counters.
+    ///
+    /// `check` is a `const fn`, so it can do neither. Use this function wherever validations should
+    /// be reported, as the `TryFrom<UnguardedF32>` conversion and the rest of the crate do. Without
+    /// the `hooks` and `validation-stats` features, it is the same as `check`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the value is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32, UnguardedF32};
+    ///
+    /// assert_eq!(UnguardedF32::new(1.0).check_reported(), GuardedF32::new(1.0));
+    /// assert_eq!((UnguardedF32::new(1.0) / 0.0).check_reported(), Err(FloatError::Infinity));
+    /// ```
+    pub fn check_reported(self) -> Result<GuardedF32, FloatError> {
+        GuardedF32::new_reported(self.0)
+    }
+
+    /// Checks if the `UnguardedF32` value is valid, clamping infinities to the finite range.
+    ///
+    /// Positive infinity is mapped to `f32::MAX` and negative infinity to `f32::MIN`, so only a
+    /// NaN value is rejected. This is useful when overflow should saturate rather than propagate
+    /// an error, such as when rendering.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `GuardedF32` if the value is finite or infinite, otherwise returns an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::NaN` if the value is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF32, FloatError, GuardedF32};
+    ///
+    /// let value = UnguardedF32::new(1.0);
+    /// assert_eq!(value.check_clamped(), GuardedF32::new(1.0));
+    ///
+    /// let overflow = UnguardedF32::new(f32::MAX) * 2.0;
+    /// assert_eq!(overflow.check_clamped(), Ok(GuardedF32::MAX));
+    ///
+    /// let underflow = UnguardedF32::new(f32::MIN) * 2.0;
+    /// assert_eq!(underflow.check_clamped(), Ok(GuardedF32::MIN));
+    ///
+    /// let invalid = UnguardedF32::new(f32::NAN);
+    /// assert_eq!(invalid.check_clamped(), Err(FloatError::NaN));
+    /// ```
+    pub const fn check_clamped(self) -> Result<GuardedF32, FloatError> {
+        if self.0.is_nan() {
+            Err(FloatError::NaN)
+        } else {
+            Ok(GuardedF32(self.0.clamp(f32::MIN, f32::MAX)))
+        }
+    }
+
+    /// Checks if the `UnguardedF32` value is valid (finite) and satisfies `predicate`, in one pass.
+    ///
+    /// This is useful when the result of a computation must also be within a domain, such as a
+    /// probability that must be in `[0, 1]`. The predicate is only called for finite values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `GuardedF32` if the value is finite and `predicate` returns `true` for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the value is NaN or infinite, or `error` if `predicate` returns
+    /// `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF32, FloatError, GuardedF32};
+    ///
+    /// let is_probability = |p: f32| (0.0..=1.0).contains(&p);
+    ///
+    /// let value = UnguardedF32::new(0.25);
+    /// assert_eq!(value.check_where(is_probability, FloatError::NaN), GuardedF32::new(0.25));
+    ///
+    /// let value = UnguardedF32::new(1.5);
+    /// assert_eq!(value.check_where(is_probability, FloatError::NaN), Err(FloatError::NaN));
+    ///
+    /// let value = UnguardedF32::new(f32::INFINITY);
+    /// assert_eq!(value.check_where(is_probability, FloatError::NaN), Err(FloatError::Infinity));
+    /// ```
+    pub fn check_where(
+        self,
+        predicate: impl FnOnce(f32) -> bool,
+        error: FloatError,
+    ) -> Result<GuardedF32, FloatError> {
+        let result = self.check().and_then(|value| {
+            if predicate(value.0) {
+                Ok(value)
+            } else {
+                Err(error)
+            }
+        });
+
+        #[cfg(any(feature = "hooks", feature = "validation-stats"))]
+        crate::report::validation(result.err(), f64::from(self.0));
+
+        result
+    }
 }
 
 impl TryFrom<UnguardedF32> for GuardedF32 {
@@ -63,7 +175,7 @@ impl TryFrom<UnguardedF32> for GuardedF32 {
     /// assert_eq!(GuardedF32::try_from(inf_value), Err(FloatError::Infinity));
     /// ```
     fn try_from(value: UnguardedF32) -> Result<Self, Self::Error> {
-        value.check()
+        value.check_reported()
     }
 }
 
@@ -88,6 +200,24 @@ impl From<GuardedF32> for UnguardedF32 {
     }
 }
 
+impl From<&GuardedF32> for UnguardedF32 {
+    /// Converts a reference to a `GuardedF32` into an `UnguardedF32`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF32, GuardedF32};
+    ///
+    /// let checked = GuardedF32::new(3.14).unwrap();
+    /// assert_eq!(UnguardedF32::from(&checked).check(), GuardedF32::new(3.14));
+    /// ```
+    fn from(value: &GuardedF32) -> Self {
+        Self(value.0)
+    }
+}
+
+bool_conversion!((GuardedF32, UnguardedF32) as f32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,6 +226,22 @@ mod tests {
     use proptest::prelude::*;
 
     proptest! {
+        #[test]
+        fn test_bool_helpers(a in valid_f32(), mask: bool) {
+            let guarded = GuardedF32::new(a).unwrap();
+            prop_assert_eq!(guarded.mask(mask).get().to_bits(), (a * f32::from(u8::from(mask))).to_bits());
+            prop_assert_eq!((guarded * mask).check(), Ok(guarded.mask(mask)));
+            prop_assert_eq!(guarded.indicator(|v| v > 0.0), GuardedF32::from(a > 0.0));
+            prop_assert_eq!(UnguardedF32::new(a).indicator(|v| v > 0.0).check(), Ok(GuardedF32::from_bool(a > 0.0)));
+        }
+
+        #[test]
+        fn test_bool_helpers_keep_invalid(a in invalid_f32(), mask: bool) {
+            let unguarded = UnguardedF32::new(a);
+            prop_assert!(unguarded.indicator(|_| true).check().is_err());
+            prop_assert!(unguarded.mask(mask).check().is_err());
+        }
+
         #[test]
         fn test_from_valid(a in valid_f32()) {
             let checked_a = UnguardedF32::new(a);
@@ -119,6 +265,37 @@ mod tests {
             prop_assert_eq!(f32::try_from(checked_a), Err(float_error));
         }
 
+        #[test]
+        fn test_check_where(a in any::<f32>(), bound in valid_f32()) {
+            let result = UnguardedF32::new(a).check_where(|v| v <= bound, FloatError::Underflow);
+            let expected = match GuardedF32::new(a) {
+                Ok(_) if a > bound => Err(FloatError::Underflow),
+                other => other,
+            };
+            prop_assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn test_check_clamped(a in any::<f32>()) {
+            let expected = if a.is_nan() {
+                Err(FloatError::NaN)
+            } else if a == f32::INFINITY {
+                Ok(GuardedF32::MAX)
+            } else if a == f32::NEG_INFINITY {
+                Ok(GuardedF32::MIN)
+            } else {
+                GuardedF32::new(a)
+            };
+
+            prop_assert_eq!(UnguardedF32::new(a).check_clamped(), expected);
+        }
+
+        #[test]
+        fn test_check_clamped_invalid(a in invalid_f32()) {
+            let clamped = UnguardedF32::new(a).check_clamped();
+            prop_assert_eq!(clamped.is_err(), a.is_nan());
+        }
+
         #[test]
         fn test_from_guarded(guarded in valid_f32()) {
             let guarded_f32 = GuardedF32::new(guarded).unwrap();
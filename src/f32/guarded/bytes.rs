@@ -0,0 +1,151 @@
+use super::GuardedF32;
+use crate::{FloatError, GuardedF64};
+
+impl GuardedF32 {
+    /// Returns the memory representation of the value as a byte array in little-endian order.
+    ///
+    /// This is the same encoding that `bincode` and `postcard` use for `f32` values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF32;
+    ///
+    /// let value = GuardedF32::new(1.0).unwrap();
+    /// assert_eq!(value.to_le_bytes(), [0, 0, 0x80, 0x3f]);
+    /// ```
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the memory representation of the value as a byte array in big-endian order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF32;
+    ///
+    /// let value = GuardedF32::new(1.0).unwrap();
+    /// assert_eq!(value.to_be_bytes(), [0x3f, 0x80, 0, 0]);
+    /// ```
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns a 64-bit hash of the value that is the same on every platform, in every process, and
+    /// in every version of this crate.
+    ///
+    /// The value is widened to `f64`, which is exact, and hashed with `GuardedF64::stable_hash64`.
+    /// This means that `-0.0` and `0.0` hash to the same value, and that a number hashes to the same
+    /// value whether it is stored as a `GuardedF32` or a `GuardedF64`.
+    ///
+    /// Changing the hash of any value is a breaking change, and will only happen in a new major
+    /// version.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF32, GuardedF64};
+    ///
+    /// let value = GuardedF32::new(1.0).unwrap();
+    /// assert_eq!(value.stable_hash64(), 0x88d7_c35b_bbf3_eded);
+    /// assert_eq!(value.stable_hash64(), GuardedF64::new(1.0).unwrap().stable_hash64());
+    /// ```
+    #[must_use]
+    pub fn stable_hash64(self) -> u64 {
+        GuardedF64(f64::from(self.0)).stable_hash64()
+    }
+
+    /// Creates a `GuardedF32` from its representation as a byte array in little-endian order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the bytes encode NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// let bytes = [0, 0, 0x80, 0x3f];
+    /// assert_eq!(GuardedF32::from_le_bytes(bytes), GuardedF32::new(1.0));
+    ///
+    /// let bytes = f32::NAN.to_le_bytes();
+    /// assert_eq!(GuardedF32::from_le_bytes(bytes), Err(FloatError::NaN));
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; 4]) -> Result<Self, FloatError> {
+        Self::new(f32::from_le_bytes(bytes))
+    }
+
+    /// Creates a `GuardedF32` from its representation as a byte array in big-endian order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the bytes encode NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// let bytes = [0x3f, 0x80, 0, 0];
+    /// assert_eq!(GuardedF32::from_be_bytes(bytes), GuardedF32::new(1.0));
+    ///
+    /// let bytes = f32::INFINITY.to_be_bytes();
+    /// assert_eq!(GuardedF32::from_be_bytes(bytes), Err(FloatError::Infinity));
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; 4]) -> Result<Self, FloatError> {
+        Self::new(f32::from_be_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f32::tests::{invalid_f32, valid_f32};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f32()) {
+            let value = GuardedF32::new(a).unwrap();
+            prop_assert_eq!(GuardedF32::from_le_bytes(value.to_le_bytes()), Ok(value));
+            prop_assert_eq!(GuardedF32::from_be_bytes(value.to_be_bytes()), Ok(value));
+        }
+
+        #[test]
+        fn test_stable_hash(a in valid_f32()) {
+            let value = GuardedF32::new(a).unwrap();
+            prop_assert_eq!(value.stable_hash64(), GuardedF64(f64::from(a)).stable_hash64());
+        }
+
+        #[test]
+        fn test_invalid_bytes(a in invalid_f32()) {
+            let expected = GuardedF32::new(a);
+            prop_assert_eq!(GuardedF32::from_le_bytes(a.to_le_bytes()), expected);
+            prop_assert_eq!(GuardedF32::from_be_bytes(a.to_be_bytes()), expected);
+        }
+
+        #[test]
+        #[cfg(feature = "serde")]
+        fn test_binary_formats(a in valid_f32()) {
+            let value = GuardedF32::new(a).unwrap();
+            let bincode = bincode::serialize(&value).unwrap();
+            let postcard = postcard::to_allocvec(&value).unwrap();
+
+            prop_assert_eq!(&bincode[..], &value.to_le_bytes()[..]);
+            prop_assert_eq!(&postcard[..], &value.to_le_bytes()[..]);
+            prop_assert_eq!(bincode::deserialize::<GuardedF32>(&bincode).unwrap(), value);
+            prop_assert_eq!(postcard::from_bytes::<GuardedF32>(&postcard).unwrap(), value);
+        }
+
+        #[test]
+        #[cfg(feature = "serde")]
+        fn test_binary_formats_reject_invalid(a in invalid_f32()) {
+            prop_assert!(bincode::deserialize::<GuardedF32>(&a.to_le_bytes()).is_err());
+            prop_assert!(postcard::from_bytes::<GuardedF32>(&a.to_le_bytes()).is_err());
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use super::GuardedF32;
+use crate::UnguardedF32;
+use crate::parse::ParseError;
+
+impl GuardedF32 {
+    /// Formats the value as a fixed-width, bit-exact dump of its fields, like
+    /// `+/+003/400000/0x41400000`, for golden files in regression tests.
+    ///
+    /// The fields are separated by slashes: the sign, `+` or `-`; the unbiased exponent, which is
+    /// `-127` for zeros and subnormals, as in `exponent_unbiased`; the 23-bit mantissa field in
+    /// hexadecimal; and the whole bit pattern in hexadecimal. Decimal formatting hides differences
+    /// in the last bit, which this format makes visible, and every value has the same width, so
+    /// golden files diff cleanly line by line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF32;
+    ///
+    /// let value = GuardedF32::new(12.0).unwrap();
+    /// assert_eq!(value.to_canonical_string(), "+/+003/400000/0x41400000");
+    ///
+    /// let value = GuardedF32::new(-0.1).unwrap();
+    /// assert_eq!(value.to_canonical_string(), "-/-004/4ccccd/0xbdcccccd");
+    ///
+    /// let value = GuardedF32::new(-0.0).unwrap();
+    /// assert_eq!(value.to_canonical_string(), "-/-127/000000/0x80000000");
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn to_canonical_string(self) -> String {
+        canonical(self.0.to_bits())
+    }
+
+    /// Parses a `GuardedF32` from the format written by `to_canonical_string`.
+    ///
+    /// The bit pattern in the last field is authoritative, and the other fields must agree with
+    /// it, so a golden file that was edited by hand cannot silently mean something else. The
+    /// format is strict: hexadecimal digits are lowercase, and every field has its full width.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not in the canonical format or its fields
+    /// disagree, or `ParseError::Invalid` if the bit pattern is NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    /// use floatguard::parse::ParseError;
+    ///
+    /// let value = GuardedF32::from_canonical_string("+/+003/400000/0x41400000");
+    /// assert_eq!(value, GuardedF32::new(12.0).map_err(ParseError::Invalid));
+    ///
+    /// let value = GuardedF32::from_canonical_string("+/+004/400000/0x41400000");
+    /// assert_eq!(value, Err(ParseError::Malformed));
+    ///
+    /// let value = GuardedF32::from_canonical_string("+/+128/000000/0x7f800000");
+    /// assert_eq!(value, Err(ParseError::Invalid(FloatError::Infinity)));
+    /// ```
+    pub fn from_canonical_string(s: &str) -> Result<Self, ParseError> {
+        let bits = s
+            .rsplit_once('/')
+            .and_then(|(_, bits)| bits.strip_prefix("0x"))
+            .and_then(|bits| u32::from_str_radix(bits, 16).ok())
+            .filter(|bits| canonical(*bits) == s)
+            .ok_or(ParseError::Malformed)?;
+
+        UnguardedF32::new(f32::from_bits(bits))
+            .check_reported()
+            .map_err(ParseError::Invalid)
+    }
+}
+
+/// Formats the fields of an `f32` bit pattern in the canonical format.
+fn canonical(bits: u32) -> String {
+    let sign = if bits >> 31 == 0 { '+' } else { '-' };
+    #[allow(clippy::cast_possible_wrap)]
+    let exponent = ((bits >> 23) & 0xff) as i32 - 127;
+    let mantissa = bits & ((1 << 23) - 1);
+    format!("{sign}/{exponent:+04}/{mantissa:06x}/{bits:#010x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f32::tests::valid_f32;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_extremes() {
+        assert_eq!(
+            GuardedF32::MAX.to_canonical_string(),
+            "+/+127/7fffff/0x7f7fffff"
+        );
+        assert_eq!(
+            GuardedF32(1e-45).to_canonical_string(),
+            "+/-127/000001/0x00000001"
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_canonical() {
+        for s in [
+            "",
+            "0x41400000",
+            "+/+3/400000/0x41400000",
+            "+/+003/400000/0x41400000 ",
+            "+/+003/400000/0X41400000",
+            "+/+003/400000/0x41400000/0x41400000",
+            "-/+003/400000/0x41400000",
+            "+/+003/400000/0x+1400000",
+        ] {
+            assert_eq!(
+                GuardedF32::from_canonical_string(s),
+                Err(ParseError::Malformed),
+                "{s}"
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f32()) {
+            let canonical = GuardedF32(a).to_canonical_string();
+            prop_assert_eq!(canonical.len(), 24);
+            prop_assert_eq!(
+                GuardedF32::from_canonical_string(&canonical).map(|v| v.0.to_bits()),
+                Ok(a.to_bits())
+            );
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use super::GuardedF32;
+use crate::{FloatError, UnguardedF32};
+
+/// The largest argument for which `exp` does not overflow, `ln(f32::MAX)`.
+const EXP_OVERFLOW: f32 = 88.722_83;
+
+/// The largest magnitude for which `sinh` and `cosh` do not overflow, `ln(2 * f32::MAX)`.
+const HYPERBOLIC_OVERFLOW: f32 = 89.415_985;
+
+/// The smallest logarithm for which `powf` always overflows, `ln(2^128)`. The estimate of `powf` is
+/// computed in `f64`, where it is precise enough to compare against this bound.
+const POWF_OVERFLOW: f64 = 88.722_839_111_673;
+
+impl GuardedF32 {
+    /// Returns <math>e<sup>(`self`)</sup></math>, or an error without computing it if the argument
+    /// is above the overflow threshold of roughly `88.72`.
+    ///
+    /// This is a cheap way to validate user-controlled exponents. Arguments within rounding
+    /// distance of the threshold are computed and checked as usual.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the result would overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// let small = GuardedF32::new(1.0).unwrap();
+    /// assert_eq!(small.exp_checked(), small.exp().check());
+    ///
+    /// let large = GuardedF32::new(89.0).unwrap();
+    /// assert_eq!(large.exp_checked(), Err(FloatError::Infinity));
+    /// ```
+    pub fn exp_checked(self) -> Result<Self, FloatError> {
+        if self.0 > EXP_OVERFLOW {
+            return UnguardedF32::new(f32::INFINITY).check_reported();
+        }
+
+        self.exp().check_reported()
+    }
+
+    /// Raises `self` to the power of `power`, or returns an error without computing it if the
+    /// magnitude of the result would overflow.
+    ///
+    /// The magnitude is estimated as `power * ln(|self|)` and compared to the threshold of `exp`,
+    /// and a negative base is rejected unless `power` is an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the result would overflow, including zero raised to a
+    /// negative power, `FloatError::Domain` if a negative base is raised to a fractional power, or
+    /// the error of `power` if it is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// let base = GuardedF32::new(10.0).unwrap();
+    /// assert_eq!(base.powf_checked(2.0), GuardedF32::new(100.0));
+    /// assert_eq!(base.powf_checked(39.0), Err(FloatError::Infinity));
+    ///
+    /// let negative = GuardedF32::new(-8.0).unwrap();
+    /// assert_eq!(negative.powf_checked(0.5), Err(FloatError::Domain));
+    /// ```
+    pub fn powf_checked(self, power: impl Into<UnguardedF32>) -> Result<Self, FloatError> {
+        let power = power.into().check_reported()?;
+        let base = UnguardedF32::from(self).check_where(
+            |value| value >= 0.0 || power.0.fract() == 0.0,
+            FloatError::Domain,
+        )?;
+        if f64::from(power.0) * f64::from(base.0.abs()).ln() > POWF_OVERFLOW {
+            return UnguardedF32::new(f32::INFINITY).check_reported();
+        }
+
+        base.powf(power).check_reported()
+    }
+
+    /// Computes the hyperbolic sine of `self`, or returns an error without computing it if the
+    /// magnitude of the argument is above the overflow threshold of roughly `89.42`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the result would overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// let small = GuardedF32::new(1.0).unwrap();
+    /// assert_eq!(small.sinh_checked(), small.sinh().check());
+    ///
+    /// let large = GuardedF32::new(-90.0).unwrap();
+    /// assert_eq!(large.sinh_checked(), Err(FloatError::Infinity));
+    /// ```
+    pub fn sinh_checked(self) -> Result<Self, FloatError> {
+        if self.0.abs() > HYPERBOLIC_OVERFLOW {
+            return UnguardedF32::new(f32::INFINITY).check_reported();
+        }
+
+        self.sinh().check_reported()
+    }
+
+    /// Computes the hyperbolic cosine of `self`, or returns an error without computing it if the
+    /// magnitude of the argument is above the overflow threshold of roughly `89.42`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the result would overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// let small = GuardedF32::new(1.0).unwrap();
+    /// assert_eq!(small.cosh_checked(), small.cosh().check());
+    ///
+    /// let large = GuardedF32::new(-90.0).unwrap();
+    /// assert_eq!(large.cosh_checked(), Err(FloatError::Infinity));
+    /// ```
+    pub fn cosh_checked(self) -> Result<Self, FloatError> {
+        if self.0.abs() > HYPERBOLIC_OVERFLOW {
+            return UnguardedF32::new(f32::INFINITY).check_reported();
+        }
+
+        self.cosh().check_reported()
+    }
+
+    /// Takes the reciprocal of `self`, or returns an error without computing it if `self` is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if `self` is zero, or `FloatError::Infinity` if the
+    /// reciprocal of a subnormal value overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(4.0).unwrap().recip_checked(), GuardedF32::new(0.25));
+    /// assert_eq!(GuardedF32::new(-0.0).unwrap().recip_checked(), Err(FloatError::DivisionByZero));
+    /// ```
+    pub fn recip_checked(self) -> Result<Self, FloatError> {
+        UnguardedF32::from(self)
+            .check_where(|value| value != 0.0, FloatError::DivisionByZero)?
+            .recip()
+            .check_reported()
+    }
+
+    /// Computes the quotient and remainder of dividing `self` by `rhs`, like `div_rem`, or returns an
+    /// error without computing them if `rhs` is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if `rhs` is zero, `FloatError::Infinity` if the quotient
+    /// overflows, or the error of `rhs` if it is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// let items = GuardedF32::new(45.0).unwrap();
+    /// let pages = items.div_rem_checked(20.0).unwrap();
+    /// assert_eq!(pages, (GuardedF32::new(2.0).unwrap(), GuardedF32::new(5.0).unwrap()));
+    ///
+    /// assert_eq!(items.div_rem_checked(0.0), Err(FloatError::DivisionByZero));
+    /// ```
+    pub fn div_rem_checked(self, rhs: impl Into<UnguardedF32>) -> Result<(Self, Self), FloatError> {
+        let rhs = rhs
+            .into()
+            .check_where(|value| value != 0.0, FloatError::DivisionByZero)?;
+        let (quotient, remainder) = self.div_rem(rhs);
+
+        Ok((quotient.check_reported()?, remainder.check_reported()?))
+    }
+
+    /// Returns the square root of `self`, or returns an error without computing it if `self` is
+    /// negative. Negative zero is allowed, and its square root is negative zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(4.0).unwrap().sqrt_checked(), GuardedF32::new(2.0));
+    /// assert_eq!(GuardedF32::new(-4.0).unwrap().sqrt_checked(), Err(FloatError::Domain));
+    /// ```
+    pub fn sqrt_checked(self) -> Result<Self, FloatError> {
+        UnguardedF32::from(self)
+            .check_where(|value| value >= 0.0, FloatError::Domain)?
+            .sqrt()
+            .check_reported()
+    }
+
+    /// Returns the natural logarithm of `self`, or returns an error without computing it if `self`
+    /// is not positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(1.0).unwrap().ln_checked(), GuardedF32::new(0.0));
+    /// assert_eq!(GuardedF32::new(0.0).unwrap().ln_checked(), Err(FloatError::Domain));
+    /// ```
+    pub fn ln_checked(self) -> Result<Self, FloatError> {
+        UnguardedF32::from(self)
+            .check_where(|value| value > 0.0, FloatError::Domain)?
+            .ln()
+            .check_reported()
+    }
+
+    /// Returns the base 2 logarithm of `self`, or returns an error without computing it if `self`
+    /// is not positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(8.0).unwrap().log2_checked(), GuardedF32::new(3.0));
+    /// assert_eq!(GuardedF32::new(-8.0).unwrap().log2_checked(), Err(FloatError::Domain));
+    /// ```
+    pub fn log2_checked(self) -> Result<Self, FloatError> {
+        UnguardedF32::from(self)
+            .check_where(|value| value > 0.0, FloatError::Domain)?
+            .log2()
+            .check_reported()
+    }
+
+    /// Returns the base 10 logarithm of `self`, or returns an error without computing it if `self`
+    /// is not positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(100.0).unwrap().log10_checked(), GuardedF32::new(2.0));
+    /// assert_eq!(GuardedF32::new(-0.0).unwrap().log10_checked(), Err(FloatError::Domain));
+    /// ```
+    pub fn log10_checked(self) -> Result<Self, FloatError> {
+        UnguardedF32::from(self)
+            .check_where(|value| value > 0.0, FloatError::Domain)?
+            .log10()
+            .check_reported()
+    }
+
+    /// Computes the arcsine of `self`, or returns an error without computing it if `self` is
+    /// outside of the range [-1, 1].
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is outside of the range [-1, 1].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(0.0).unwrap().asin_checked(), GuardedF32::new(0.0));
+    /// assert_eq!(GuardedF32::new(1.5).unwrap().asin_checked(), Err(FloatError::Domain));
+    /// ```
+    pub fn asin_checked(self) -> Result<Self, FloatError> {
+        UnguardedF32::from(self)
+            .check_where(|value| (-1.0..=1.0).contains(&value), FloatError::Domain)?
+            .asin()
+            .check_reported()
+    }
+
+    /// Computes the arccosine of `self`, or returns an error without computing it if `self` is
+    /// outside of the range [-1, 1].
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is outside of the range [-1, 1].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(1.0).unwrap().acos_checked(), GuardedF32::new(0.0));
+    /// assert_eq!(GuardedF32::new(-1.5).unwrap().acos_checked(), Err(FloatError::Domain));
+    /// ```
+    pub fn acos_checked(self) -> Result<Self, FloatError> {
+        UnguardedF32::from(self)
+            .check_where(|value| (-1.0..=1.0).contains(&value), FloatError::Domain)?
+            .acos()
+            .check_reported()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f32::tests::valid_f32;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_thresholds() {
+        assert!(EXP_OVERFLOW.exp().is_finite());
+        assert!(HYPERBOLIC_OVERFLOW.sinh().is_finite());
+        assert!(HYPERBOLIC_OVERFLOW.cosh().is_finite());
+
+        let zero = GuardedF32(0.0);
+        assert_eq!(zero.powf_checked(-1.0), Err(FloatError::Infinity));
+        assert_eq!(zero.powf_checked(0.0), GuardedF32::new(1.0));
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_unchecked(a in valid_f32(), b in valid_f32()) {
+            let a = GuardedF32::new(a).unwrap();
+            let b = GuardedF32::new(b).unwrap();
+
+            prop_assert_eq!(a.exp_checked(), a.exp().check());
+            let power = if a.0 < 0.0 && b.0.fract() != 0.0 { Err(FloatError::Domain) } else { a.powf(b).check() };
+            prop_assert_eq!(a.powf_checked(b), power);
+            prop_assert_eq!(a.sinh_checked(), a.sinh().check());
+            prop_assert_eq!(a.cosh_checked(), a.cosh().check());
+        }
+
+        #[test]
+        fn test_near_thresholds(a in 80.0..100.0f32, b in 35.0..45.0f32) {
+            let a = GuardedF32::new(a).unwrap();
+            let b = GuardedF32::new(b).unwrap();
+
+            prop_assert_eq!(a.exp_checked(), a.exp().check());
+            prop_assert_eq!(GuardedF32(10.0).powf_checked(b), GuardedF32(10.0).powf(b).check());
+            prop_assert_eq!(a.sinh_checked(), a.sinh().check());
+            prop_assert_eq!((-a).cosh_checked(), (-a).cosh().check());
+        }
+
+        #[test]
+        fn test_domain_checked(a in valid_f32()) {
+            let value = GuardedF32::new(a).unwrap();
+            let domain = |valid: bool, result: UnguardedF32| {
+                if valid { result.check() } else { Err(FloatError::Domain) }
+            };
+
+            prop_assert_eq!(value.sqrt_checked(), domain(a >= 0.0, value.sqrt()));
+            prop_assert_eq!(value.ln_checked(), domain(a > 0.0, value.ln()));
+            prop_assert_eq!(value.log2_checked(), domain(a > 0.0, value.log2()));
+            prop_assert_eq!(value.log10_checked(), domain(a > 0.0, value.log10()));
+            prop_assert_eq!(value.asin_checked(), domain(a.abs() <= 1.0, value.asin()));
+            prop_assert_eq!(value.acos_checked(), domain(a.abs() <= 1.0, value.acos()));
+            let division = if a == 0.0 { Err(FloatError::DivisionByZero) } else { value.recip().check() };
+            prop_assert_eq!(value.recip_checked(), division);
+        }
+
+        #[test]
+        fn test_div_rem(a in valid_f32(), b in valid_f32()) {
+            let a = GuardedF32::new(a).unwrap();
+            let b = GuardedF32::new(b).unwrap();
+
+            match a.div_rem_checked(b) {
+                Ok((quotient, remainder)) => {
+                    prop_assert_eq!(quotient.0.fract(), 0.0);
+                    prop_assert_eq!(remainder, (a % b).check().unwrap());
+                    prop_assert!(remainder.0.abs() < b.0.abs());
+                    prop_assert!(remainder.0 == 0.0 || remainder.0.signum() == a.0.signum());
+                    prop_assert!(quotient.0 == 0.0 || quotient.0.signum() == a.0.signum() * b.0.signum());
+                }
+                Err(error) => prop_assert!(b.0 == 0.0 || error == FloatError::Infinity),
+            }
+        }
+    }
+}
@@ -1,10 +1,57 @@
 //! This module implements the `PartialEq` and `PartialOrd` traits for `GuardedF32`.
 //!
 //! The `PartialEq` trait allows for equality comparisons between `GuardedF32` instances and `f32`
-//! values, while the `PartialOrd` trait enables ordering comparisons.
+//! values, while the `PartialOrd` trait enables ordering comparisons. It also provides
+//! `GuardedF32::approx_eq` for tolerance-based comparisons of computed values.
 use super::GuardedF32;
+use crate::ApproxConfig;
 use std::cmp::{Ordering, PartialEq, PartialOrd};
 
+impl GuardedF32 {
+    /// Checks whether two `GuardedF32` values are approximately equal.
+    ///
+    /// The values are considered equal if their absolute difference is within `config.epsilon`,
+    /// or if they are no more than `config.ulps` representable values apart.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the values are within either tolerance, otherwise returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{ApproxConfig, GuardedF32, GuardedF64};
+    ///
+    /// let a = (GuardedF32::new(0.1).unwrap() + 0.2).check().unwrap();
+    /// let b = GuardedF32::new(0.3).unwrap();
+    /// assert!(a.approx_eq(b, ApproxConfig::default()));
+    ///
+    /// let c = GuardedF32::new(0.31).unwrap();
+    /// assert!(!a.approx_eq(c, ApproxConfig::default()));
+    /// assert!(a.approx_eq(c, ApproxConfig::epsilon(GuardedF64::new(0.02).unwrap())));
+    /// ```
+    #[must_use = "method returns a comparison result and does not mutate the original value"]
+    pub const fn approx_eq(self, other: Self, config: ApproxConfig) -> bool {
+        let lhs = self.0;
+        let rhs = other.0;
+
+        if (lhs as f64 - rhs as f64).abs() <= config.epsilon.0 {
+            return true;
+        }
+
+        (ordered_bits(lhs).abs_diff(ordered_bits(rhs)) as u64) <= config.ulps
+    }
+}
+
+/// Maps the bits of a `f32` onto a monotonically increasing integer, so that the distance between
+/// two mapped values is the number of representable values between them.
+const fn ordered_bits(value: f32) -> u32 {
+    const SIGN: u32 = 0x8000_0000;
+
+    let bits = value.to_bits();
+    if bits & SIGN == 0 { bits | SIGN } else { !bits }
+}
+
 impl PartialEq for GuardedF32 {
     /// Compares two `GuardedF32` values for equality.
     ///
@@ -199,9 +246,24 @@ impl PartialOrd<GuardedF32> for f32 {
 
 #[cfg(test)]
 mod tests {
-    use crate::{GuardedF32, f32::tests::valid_f32};
+    use crate::{ApproxConfig, GuardedF32, f32::tests::valid_f32};
     use proptest::prelude::*;
 
+    #[test]
+    fn test_approx_eq_ulps() {
+        let one = GuardedF32::new(1.0).unwrap();
+        let next = GuardedF32::new(f32::from_bits(1.0f32.to_bits() + 2)).unwrap();
+
+        assert!(one.approx_eq(next, ApproxConfig::ulps(2)));
+        assert!(!one.approx_eq(next, ApproxConfig::ulps(1)));
+        assert!(
+            GuardedF32::new(0.0)
+                .unwrap()
+                .approx_eq(GuardedF32::new(-0.0).unwrap(), ApproxConfig::ulps(1))
+        );
+        assert!(!GuardedF32::MAX.approx_eq(GuardedF32::MIN, ApproxConfig::ulps(4)));
+    }
+
     proptest! {
         // Ordering
         #[test]
@@ -236,5 +298,22 @@ mod tests {
             prop_assert_eq!(a, checked_a);
             prop_assert_eq!(checked_a, checked_a);
         }
+
+        #[test]
+        fn test_approx_eq_reflexive(a in valid_f32()) {
+            let checked_a = GuardedF32::new(a).unwrap();
+
+            prop_assert!(checked_a.approx_eq(checked_a, ApproxConfig::ulps(0)));
+            prop_assert!(checked_a.approx_eq(checked_a, ApproxConfig::epsilon(crate::GuardedF64::new(0.0).unwrap())));
+        }
+
+        #[test]
+        fn test_approx_eq_symmetric(a in valid_f32(), b in valid_f32(), ulps in 0u64..16) {
+            let checked_a = GuardedF32::new(a).unwrap();
+            let checked_b = GuardedF32::new(b).unwrap();
+            let config = ApproxConfig::ulps(ulps);
+
+            prop_assert_eq!(checked_a.approx_eq(checked_b, config), checked_b.approx_eq(checked_a, config));
+        }
     }
 }
@@ -1,5 +1,7 @@
 use super::GuardedF32;
 use crate::FloatError;
+use crate::macros::ops_nonzero::nonzero_conversion;
+use std::num::{NonZeroI8, NonZeroI16, NonZeroU8, NonZeroU16};
 
 impl TryFrom<f32> for GuardedF32 {
     type Error = FloatError;
@@ -29,7 +31,7 @@ impl TryFrom<f32> for GuardedF32 {
     /// assert!(inf_value.is_err());
     /// ```
     fn try_from(value: f32) -> Result<Self, Self::Error> {
-        Self::new(value)
+        Self::new_reported(value)
     }
 }
 
@@ -66,10 +68,30 @@ impl From<GuardedF32> for f32 {
     }
 }
 
+nonzero_conversion!(
+    impl From<...(NonZeroI8, NonZeroI16, NonZeroU8, NonZeroU16)> for GuardedF32 as f32;
+    r"
+        Converts a non-zero integer to `GuardedF32`. Every value of the integer type is exactly
+        representable, so the conversion cannot fail.
+
+        # Example
+
+        ```rust
+        use floatguard::GuardedF32;
+        use std::num::NonZeroU16;
+
+        let value = NonZeroU16::new(42).unwrap();
+        assert_eq!(GuardedF32::from(value), GuardedF32::new(42.0).unwrap());
+        ```
+    "
+);
+
+#[cfg(not(feature = "strict-encapsulation"))]
 impl std::ops::Deref for GuardedF32 {
     type Target = f32;
 
-    /// Dereferences `GuardedF32` to its inner `f32` value.
+    /// Dereferences `GuardedF32` to its inner `f32` value. Unavailable when the `strict-encapsulation`
+    /// feature is enabled; use `GuardedF32::get` instead.
     ///
     /// # Returns
     ///
@@ -101,7 +123,7 @@ mod tests {
         fn test_from_valid(a in valid_f32()) {
             prop_assert_eq!(GuardedF32::new(a), Ok(GuardedF32(a)));
             prop_assert_eq!(GuardedF32::new(a).map(f32::from), Ok(a));
-            prop_assert_eq!(*GuardedF32::new(a).unwrap(), a);
+            prop_assert_eq!(GuardedF32::new(a).unwrap().get(), a);
 
             prop_assert_eq!(GuardedF32::try_from(a), Ok(GuardedF32(a)));
         }
@@ -119,5 +141,11 @@ mod tests {
 
             prop_assert_eq!(GuardedF32::try_from(a), Err(float_error));
         }
+
+        #[test]
+        fn test_from_nonzero(a in any::<NonZeroI16>(), b in any::<NonZeroU16>()) {
+            prop_assert_eq!(GuardedF32::from(a), GuardedF32(f32::from(a.get())));
+            prop_assert_eq!(GuardedF32::from(b), GuardedF32(f32::from(b.get())));
+        }
     }
 }
@@ -1,7 +1,14 @@
 //! This module provides a checked floating-point number type, `GuardedF32`, which ensures that the
 //! value is neither NaN nor infinite.
+mod bytes;
+mod canonical;
+mod checked;
 mod cmp;
+mod color;
 mod convert;
+mod db;
+mod fmt;
+mod snap;
 
 use crate::FloatError;
 
@@ -22,6 +29,7 @@ use crate::FloatError;
 /// assert_eq!((checked_f32 % f32::NAN).check(), Err(FloatError::NaN));
 /// ```
 #[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
 pub struct GuardedF32(pub(crate) f32);
 
 impl GuardedF32 {
@@ -60,6 +68,54 @@ impl GuardedF32 {
             })
         }
     }
+
+    /// Creates a new `GuardedF32` instance, like `new`, and reports the validation to the
+    /// invalid-value hook and the validation counters.
+    ///
+    /// `new` is a `const fn`, so it can do neither. Use this function wherever validations should be
+    /// reported, as the `TryFrom<f32>` conversion and the rest of the crate do. Without the `hooks`
+    /// and `validation-stats` features, it is the same as `new`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the value is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new_reported(2.0), GuardedF32::new(2.0));
+    /// assert_eq!(GuardedF32::new_reported(f32::NAN), Err(FloatError::NaN));
+    /// ```
+    // Reporting is not `const`, so this stays a plain `fn` under every feature set.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn new_reported(value: f32) -> Result<Self, FloatError> {
+        let result = Self::new(value);
+
+        #[cfg(any(feature = "hooks", feature = "validation-stats"))]
+        crate::report::validation(result.err(), f64::from(value));
+
+        result
+    }
+
+    /// Returns the inner `f32` value, which is always finite.
+    ///
+    /// This is the explicit alternative to dereferencing, which is unavailable when the
+    /// `strict-encapsulation` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF32;
+    ///
+    /// let value = GuardedF32::new(2.0).unwrap();
+    /// assert_eq!(value.get(), 2.0);
+    /// ```
+    #[must_use]
+    pub const fn get(self) -> f32 {
+        self.0
+    }
 }
 
 impl std::fmt::Display for GuardedF32 {
@@ -95,7 +151,7 @@ mod tests {
         fn test_new_valid(a in valid_f32()) {
             prop_assert_eq!(GuardedF32::new(a), Ok(GuardedF32(a)));
             prop_assert_eq!(GuardedF32::new(a).map(f32::from), Ok(a));
-            prop_assert_eq!(*GuardedF32::new(a).unwrap(), a);
+            prop_assert_eq!(GuardedF32::new(a).unwrap().get(), a);
         }
 
         #[test]
@@ -1,29 +1,41 @@
+mod activation;
 mod consts;
 mod convert;
 mod guarded;
 mod math;
 mod ops_binary;
 mod ops_unary;
+mod parse;
+mod pow2;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "special")]
+mod special;
 mod unguarded;
+#[cfg(feature = "utoipa")]
+mod utoipa;
 
 pub use guarded::GuardedF32;
 pub use unguarded::UnguardedF32;
 
 #[cfg(test)]
-mod tests {
+pub mod tests {
+    use crate::fixtures::{INVALID_F32, VALID_F32};
     use proptest::prelude::*;
 
-    const INVALID_VALUES: &[f32; 3] = &[f32::NAN, f32::INFINITY, f32::NEG_INFINITY];
-
     pub fn valid_f32() -> impl Strategy<Value = f32> {
-        // Avoid NaN, ±∞, and subnormals.
-        // This gives good coverage while staying in safe computation territory.
-        (f32::MIN..=f32::MAX).prop_filter("Reject NaN and infinities", |v| {
-            v.is_finite() && !v.is_nan()
-        })
+        // Mostly random finite values, with the edge cases of the fixture corpus mixed in.
+        prop_oneof![
+            9 => (f32::MIN..=f32::MAX).prop_filter("Reject NaN and infinities", |v| {
+                v.is_finite() && !v.is_nan()
+            }),
+            1 => prop::sample::select(&VALID_F32[..]),
+        ]
     }
 
     pub fn invalid_f32() -> impl Strategy<Value = f32> {
-        prop::sample::select(INVALID_VALUES)
+        prop::sample::select(&INVALID_F32[..])
     }
 }
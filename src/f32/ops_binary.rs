@@ -1,9 +1,14 @@
 use super::{GuardedF32, UnguardedF32};
 use crate::macros::ops_binary::binary_operation;
-use std::ops::{Add, Div, Mul, Rem, Sub};
+use crate::macros::ops_nonzero::nonzero_division;
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+use std::ops::{Add, Div, DivAssign, Mul, Rem, Sub};
 
 binary_operation!(
-    impl Add for ...(GuardedF32, UnguardedF32) {
+    impl Add for ...(GuardedF32, UnguardedF32) and ...(i8, i16, u8, u16) {
         r"
             Adds two `GuardedF32` values or a `GuardedF32` and a `f32`.
 
@@ -26,7 +31,7 @@ binary_operation!(
 );
 
 binary_operation!(
-    impl Sub for ...(GuardedF32, UnguardedF32) {
+    impl Sub for ...(GuardedF32, UnguardedF32) and ...(i8, i16, u8, u16) {
         r"
             Subtracts one `GuardedF32` value from another or a `f32` from a `GuardedF32`.
 
@@ -49,7 +54,7 @@ binary_operation!(
 );
 
 binary_operation!(
-    impl Mul for ...(GuardedF32, UnguardedF32) {
+    impl Mul for ...(GuardedF32, UnguardedF32) and ...(i8, i16, u8, u16) {
         r"
             Multiplies two `GuardedF32` values or a `GuardedF32` and a `f32`.
 
@@ -72,7 +77,7 @@ binary_operation!(
 );
 
 binary_operation!(
-    impl Div for ...(GuardedF32, UnguardedF32) {
+    impl Div for ...(GuardedF32, UnguardedF32) and ...(i8, i16, u8, u16) {
         r"
             Divides one `GuardedF32` value by another or a `f32` by a `GuardedF32`.
 
@@ -117,7 +122,7 @@ binary_operation!(
 );
 
 binary_operation!(
-    impl Rem for ...(GuardedF32, UnguardedF32) {
+    impl Rem for ...(GuardedF32, UnguardedF32) and ...(i8, i16, u8, u16) {
         r"
             Computes the remainder of division between two `GuardedF32` values or a `GuardedF32` and
             a `f32`.
@@ -164,12 +169,37 @@ binary_operation!(
     }
 );
 
+nonzero_division!(
+    impl Div<...(NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize)> for (GuardedF32, UnguardedF32) as f32;
+    r"
+        Divides a `GuardedF32` or `UnguardedF32` value by a non-zero integer.
+
+        The divisor has a magnitude of at least one, so dividing a `GuardedF32` cannot overflow or
+        produce NaN, and the result is a `GuardedF32` that does not need to be checked.
+
+        # Example
+
+        ```rust
+        use floatguard::{GuardedF32, UnguardedF32};
+        use std::num::{NonZeroI32, NonZeroU64};
+
+        let value = GuardedF32::new(6.0).unwrap();
+        assert_eq!(value / NonZeroI32::new(-3).unwrap(), GuardedF32::new(-2.0).unwrap());
+        assert_eq!(GuardedF32::MAX / NonZeroU64::new(1).unwrap(), GuardedF32::MAX);
+
+        let value = UnguardedF32::new(f32::INFINITY);
+        assert!((value / NonZeroU64::new(2).unwrap()).check().is_err());
+        ```
+    "
+);
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::op_ref)]
 
     use crate::{GuardedF32, UnguardedF32};
     use proptest::prelude::*;
+    use std::num::{NonZeroI64, NonZeroU128};
 
     proptest! {
         #[test]
@@ -438,5 +468,36 @@ mod tests {
             prop_assert_eq!((&a % unguarded_b).check(), expected);
             prop_assert_eq!((&a % &unguarded_b).check(), expected);
         }
+
+        #[test]
+        #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+        fn test_division_by_nonzero(a in any::<f32>(), b in any::<NonZeroI64>(), c in any::<NonZeroU128>()) {
+            let unguarded_a = UnguardedF32::new(a);
+            prop_assert_eq!((unguarded_a / b).check(), GuardedF32::new(a / b.get() as f32));
+            prop_assert_eq!((&unguarded_a / &c).check(), GuardedF32::new(a / c.get() as f32));
+
+            if a.is_finite() {
+                let mut guarded_a = GuardedF32::new(a).unwrap();
+                prop_assert_eq!(guarded_a / b, GuardedF32::new(a / b.get() as f32).unwrap());
+                prop_assert_eq!(&guarded_a / &c, GuardedF32::new(a / c.get() as f32).unwrap());
+
+                guarded_a /= b;
+                prop_assert_eq!(guarded_a, GuardedF32::new(a / b.get() as f32).unwrap());
+            }
+        }
+
+        #[test]
+        fn test_integer_operands(a in any::<f32>(), b in any::<i16>(), c in any::<u8>()) {
+            let unguarded_a = UnguardedF32::new(a);
+            prop_assert_eq!((unguarded_a * b).check(), GuardedF32::new(a * f32::from(b)));
+            prop_assert_eq!((&c - unguarded_a).check(), GuardedF32::new(f32::from(c) - a));
+
+            if a.is_finite() {
+                let guarded_a = GuardedF32::new(a).unwrap();
+                prop_assert_eq!((guarded_a + &b).check(), GuardedF32::new(a + f32::from(b)));
+                prop_assert_eq!((b / guarded_a).check(), (f32::from(b) / guarded_a).check());
+                prop_assert_eq!((c % &guarded_a).check(), GuardedF32::new(f32::from(c) % a));
+            }
+        }
     }
 }
@@ -0,0 +1,301 @@
+use super::{GuardedF32, UnguardedF32};
+use crate::parse::{
+    Encoding, Exactness, Format, ParseError, ParseOptions, PercentOptions, detect,
+    parse_hex_prefix, percent_to_decimal,
+};
+use std::str::FromStr;
+
+impl GuardedF32 {
+    /// Parses a `GuardedF32` from a string, rejecting numbers whose decimal exponent is beyond the
+    /// limit in `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::ExponentOutOfRange` if the decimal exponent is beyond the limit,
+    /// `ParseError::Malformed` if the string is not a number, or `ParseError::Invalid` if it parses
+    /// to NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    /// use floatguard::parse::{ParseError, ParseOptions};
+    ///
+    /// let options = ParseOptions::default().with_max_exponent(38);
+    /// assert_eq!(GuardedF32::parse_with("-2.5e3", options), GuardedF32::new(-2500.0).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF32::parse_with("1e-50", options), Err(ParseError::ExponentOutOfRange { exponent: -50 }));
+    /// assert_eq!(GuardedF32::parse_with("3.5e38", options), Err(ParseError::Invalid(FloatError::Infinity)));
+    /// assert_eq!(GuardedF32::parse_with("twelve", options), Err(ParseError::Malformed));
+    /// ```
+    pub fn parse_with(s: &str, options: ParseOptions) -> Result<Self, ParseError> {
+        options
+            .parse(s, |s| s.parse::<f32>().ok())
+            .and_then(|value| {
+                UnguardedF32::new(value)
+                    .check_reported()
+                    .map_err(ParseError::Invalid)
+            })
+    }
+
+    /// Parses a percentage, like `12.5%`, as the fraction it stands for, like `0.125`.
+    ///
+    /// The percent sign may come before or after the number, and be separated from it by
+    /// whitespace. Both decimal points and decimal commas are accepted: when a comma and a point
+    /// both appear, the last one is the decimal separator and the other one groups thousands, a
+    /// single comma is a decimal comma, and repeated commas group thousands. The fraction is
+    /// rounded once, from the decimal digits, so `0.1%` parses to the closest `f32` to `0.001`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a percentage, or `ParseError::Invalid`
+    /// if the fraction is too large to be finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF32;
+    /// use floatguard::parse::ParseError;
+    ///
+    /// assert_eq!(GuardedF32::parse_percent("12.5%"), GuardedF32::new(0.125).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF32::parse_percent("-1.234,5 %"), GuardedF32::new(-12.345).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF32::parse_percent("12.5"), Err(ParseError::Malformed));
+    /// ```
+    pub fn parse_percent(s: &str) -> Result<Self, ParseError> {
+        Self::parse_percent_with(s, PercentOptions::default())
+    }
+
+    /// Parses a percentage like `parse_percent`, and checks that the fraction is within the range
+    /// in `options`.
+    ///
+    /// The range in `options` is compared with the value after it is rounded to an `f32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a percentage, `ParseError::Invalid` if
+    /// the fraction is too large to be finite, or `ParseError::Invalid(FloatError::Domain)` if it
+    /// is outside of the range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    /// use floatguard::parse::{ParseError, PercentOptions};
+    ///
+    /// let options = PercentOptions::default().with_range(0.0, 1.0);
+    /// assert_eq!(GuardedF32::parse_percent_with("% 75", options), GuardedF32::new(0.75).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF32::parse_percent_with("-5%", options), Err(ParseError::Invalid(FloatError::Domain)));
+    /// ```
+    pub fn parse_percent_with(s: &str, options: PercentOptions) -> Result<Self, ParseError> {
+        let value = percent_to_decimal(s)
+            .and_then(|decimal| decimal.parse::<f32>().ok())
+            .ok_or(ParseError::Malformed)?;
+        let value = UnguardedF32::new(value)
+            .check_reported()
+            .map_err(ParseError::Invalid)?;
+
+        options.check(f64::from(value.0))?;
+        Ok(value)
+    }
+
+    /// Parses a `GuardedF32` from a decimal number, such as a database `NUMERIC` value, and reports
+    /// whether it was rounded to fit, and by how much, so precision loss can be logged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a number, or `ParseError::Invalid` if it
+    /// parses to NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF32, GuardedF64};
+    /// use floatguard::parse::{Exactness, ParseError};
+    ///
+    /// let (value, exactness) = GuardedF32::from_numeric_str("-1234.5000").unwrap();
+    /// assert_eq!(value, -1234.5);
+    /// assert_eq!(exactness, Exactness::Exact);
+    ///
+    /// let (_, exactness) = GuardedF32::from_numeric_str("0.1").unwrap();
+    /// assert_eq!(exactness, Exactness::Rounded { error: GuardedF64::new(-1.4901161193847657e-9).unwrap() });
+    ///
+    /// assert_eq!(GuardedF32::from_numeric_str("12,5"), Err(ParseError::Malformed));
+    /// ```
+    pub fn from_numeric_str(s: &str) -> Result<(Self, Exactness), ParseError> {
+        let value = s.parse::<Self>()?;
+        Ok((value, Exactness::of(s, f64::from(value.0))))
+    }
+
+    /// Parses a `GuardedF32` from a hexadecimal floating-point number, like `0x1.8p3`, as written
+    /// by `to_hex_string`.
+    ///
+    /// The number has an optional sign, a `0x` prefix, hexadecimal digits with an optional point,
+    /// and an optional binary exponent. Numbers with more significant digits than fit in an `f32`
+    /// are rounded to the nearest value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a hex-float, or `ParseError::Invalid` if
+    /// the number is too large to be finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    /// use floatguard::parse::ParseError;
+    ///
+    /// assert_eq!(GuardedF32::from_hex_str("-0x1.8p3"), GuardedF32::new(-12.0).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF32::from_hex_str("0x1p99999"), Err(ParseError::Invalid(FloatError::Infinity)));
+    /// assert_eq!(GuardedF32::from_hex_str("12.0"), Err(ParseError::Malformed));
+    /// ```
+    pub fn from_hex_str(s: &str) -> Result<Self, ParseError> {
+        let value = match parse_hex_prefix(s.as_bytes(), Format::F32) {
+            #[allow(clippy::cast_possible_truncation)]
+            Some((bits, consumed)) if consumed == s.len() => f32::from_bits(bits as u32),
+            _ => return Err(ParseError::Malformed),
+        };
+
+        UnguardedF32::new(value)
+            .check_reported()
+            .map_err(ParseError::Invalid)
+    }
+
+    /// Parses a `GuardedF32` from the start of `bytes`, detecting whether it is written as a
+    /// decimal number, a hex-float like `0x1.8p3`, or a little-endian binary `f32`, and returns it
+    /// with the number of bytes consumed.
+    ///
+    /// Bytes that start like a number, after an optional sign, are parsed as text up to the end of
+    /// the number, so a value can be read from the front of a larger buffer. Anything else is read
+    /// as the 4 bytes of a binary `f32`. Hex-floats with more significant digits than fit are
+    /// rounded to the nearest value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if binary input is shorter than 4 bytes, or
+    /// `ParseError::Invalid` if the number is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    /// use floatguard::parse::ParseError;
+    ///
+    /// let twelve = GuardedF32::new(12.0).unwrap();
+    /// assert_eq!(GuardedF32::from_bytes_auto(b"0x1.8p3 rest"), Ok((twelve, 7)));
+    /// assert_eq!(GuardedF32::from_bytes_auto(b"1.2e1,"), Ok((twelve, 5)));
+    /// assert_eq!(GuardedF32::from_bytes_auto(&12f32.to_le_bytes()), Ok((twelve, 4)));
+    ///
+    /// assert_eq!(GuardedF32::from_bytes_auto(b"1e999"), Err(ParseError::Invalid(FloatError::Infinity)));
+    /// assert_eq!(GuardedF32::from_bytes_auto(b"abc"), Err(ParseError::Malformed));
+    /// ```
+    pub fn from_bytes_auto(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let (value, consumed) = match detect(bytes) {
+            #[allow(clippy::cast_possible_truncation)]
+            Encoding::Hex => parse_hex_prefix(bytes, Format::F32)
+                .map(|(bits, consumed)| (f32::from_bits(bits as u32), consumed))
+                .ok_or(ParseError::Malformed)?,
+            Encoding::Decimal(len) => bytes
+                .get(..len)
+                .and_then(|digits| std::str::from_utf8(digits).ok())
+                .and_then(|s| s.parse::<f32>().ok())
+                .map(|value| (value, len))
+                .ok_or(ParseError::Malformed)?,
+            Encoding::Binary => bytes
+                .first_chunk()
+                .map(|chunk| (f32::from_le_bytes(*chunk), 4))
+                .ok_or(ParseError::Malformed)?,
+        };
+
+        UnguardedF32::new(value)
+            .check_reported()
+            .map(|value| (value, consumed))
+            .map_err(ParseError::Invalid)
+    }
+}
+
+impl FromStr for GuardedF32 {
+    type Err = ParseError;
+
+    /// Parses a `GuardedF32` from a string, without a limit on the exponent.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a number, or `ParseError::Invalid` if it
+    /// parses to NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    /// use floatguard::parse::ParseError;
+    ///
+    /// assert_eq!("1.5".parse::<GuardedF32>(), GuardedF32::new(1.5).map_err(ParseError::Invalid));
+    /// assert_eq!("1e39".parse::<GuardedF32>(), Err(ParseError::Invalid(FloatError::Infinity)));
+    /// assert_eq!("NaN".parse::<GuardedF32>(), Err(ParseError::Invalid(FloatError::NaN)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, ParseOptions::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use crate::f32::tests::valid_f32;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_from_bytes_auto_errors() {
+        assert_eq!(
+            GuardedF32::from_bytes_auto(&f32::NAN.to_le_bytes()),
+            Err(ParseError::Invalid(FloatError::NaN))
+        );
+        assert_eq!(
+            GuardedF32::from_bytes_auto(b"-0x1p99999"),
+            Err(ParseError::Invalid(FloatError::Infinity))
+        );
+        assert_eq!(
+            GuardedF32::from_bytes_auto(b"x"),
+            Err(ParseError::Malformed)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f32()) {
+            let options = ParseOptions::default().with_max_exponent(308);
+            prop_assert_eq!(a.to_string().parse::<GuardedF32>(), Ok(GuardedF32(a)));
+            prop_assert_eq!(GuardedF32::parse_with(&format!("{a:e}"), options), Ok(GuardedF32(a)));
+        }
+
+        #[test]
+        fn test_exponent_limit(exponent in -60i64..60, limit in 0u32..60) {
+            let options = ParseOptions::default().with_max_exponent(limit);
+            let result = GuardedF32::parse_with(&format!("1e{exponent}"), options);
+
+            if exponent.unsigned_abs() > u64::from(limit) {
+                prop_assert_eq!(result, Err(ParseError::ExponentOutOfRange { exponent }));
+            } else if exponent > 38 {
+                prop_assert_eq!(result, Err(ParseError::Invalid(FloatError::Infinity)));
+            } else {
+                prop_assert!(result.is_ok());
+            }
+        }
+
+        #[test]
+        fn test_from_bytes_auto_round_trip(a in valid_f32()) {
+            let text = format!("{a};{a:e}");
+            let (first, consumed) = GuardedF32::from_bytes_auto(text.as_bytes()).unwrap();
+            prop_assert_eq!(first, GuardedF32(a));
+            prop_assert_eq!(text.as_bytes()[consumed], b';');
+            prop_assert_eq!(
+                GuardedF32::from_bytes_auto(&text.as_bytes()[consumed + 1..]),
+                Ok((GuardedF32(a), text.len() - consumed - 1))
+            );
+
+            let bytes = a.to_le_bytes();
+            prop_assume!(detect(&bytes) == Encoding::Binary);
+            prop_assert_eq!(GuardedF32::from_bytes_auto(&bytes), Ok((GuardedF32(a), 4)));
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use super::{GuardedF32, UnguardedF32};
+use crate::FloatError;
+
+/// The mask of the exponent field of an `f32`.
+const EXPONENT_MASK: u32 = 0xff << 23;
+
+/// Builds `2^n` for an `n` in the normal range, `-126..=127`.
+#[inline]
+const fn pow2(n: i64) -> f32 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    f32::from_bits(((n + 127) as u32) << 23)
+}
+
+/// Multiplies `value` by `2^n`, rounding only if the result is subnormal.
+///
+/// For normal values whose result is also normal, this only rewrites the exponent field.
+#[inline]
+const fn scale(value: f32, n: i64) -> f32 {
+    let bits = value.to_bits();
+    let exponent = ((bits & EXPONENT_MASK) >> 23) as i64;
+
+    if exponent == 0xff || value == 0.0 {
+        // NaN, infinity, and zero are unchanged by scaling.
+        return value;
+    }
+
+    if exponent == 0 {
+        // Subnormal values are normalized first, which is exact.
+        return if n <= -126 {
+            0.0 * value
+        } else if n < 0 {
+            value * pow2(n)
+        } else {
+            scale(value * pow2(25), n - 25)
+        };
+    }
+
+    let scaled = exponent.saturating_add(n);
+    if scaled >= 0xff {
+        return if value.is_sign_negative() {
+            f32::NEG_INFINITY
+        } else {
+            f32::INFINITY
+        };
+    }
+
+    if scaled <= 0 {
+        // Move to the smallest normal exponent exactly, then round once into the subnormal range.
+        let shift = scaled - 1;
+        let smallest = f32::from_bits((bits & !EXPONENT_MASK) | (1 << 23));
+        return if shift < -30 {
+            0.0 * value
+        } else {
+            smallest * pow2(shift)
+        };
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    f32::from_bits((bits & !EXPONENT_MASK) | ((scaled as u32) << 23))
+}
+
+impl GuardedF32 {
+    /// Returns the exponent field of `self`, minus its bias, by reading the bits directly.
+    ///
+    /// For normal values, this is `floor(log2(|self|))`. Zero and subnormal values share the
+    /// smallest exponent field, so they all return `-127`; use `ilog2_checked` for an exact
+    /// result for subnormal values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF32;
+    ///
+    /// assert_eq!(GuardedF32::new(10.0).unwrap().exponent_unbiased(), 3);
+    /// assert_eq!(GuardedF32::new(-0.75).unwrap().exponent_unbiased(), -1);
+    /// assert_eq!(GuardedF32::new(0.0).unwrap().exponent_unbiased(), -127);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn exponent_unbiased(self) -> i32 {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let exponent = ((self.0.to_bits() & EXPONENT_MASK) >> 23) as i32;
+        exponent - 127
+    }
+
+    /// Returns `floor(log2(self))` as an integer, computed exactly from the bits of `self` instead
+    /// of rounding the result of `log2`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is zero or negative, where the logarithm is undefined.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(1024.0).unwrap().ilog2_checked(), Ok(10));
+    /// assert_eq!(GuardedF32::new(0.3).unwrap().ilog2_checked(), Ok(-2));
+    /// assert_eq!(GuardedF32::new(f32::from_bits(1)).unwrap().ilog2_checked(), Ok(-149));
+    /// assert_eq!(GuardedF32::new(0.0).unwrap().ilog2_checked(), Err(FloatError::Domain));
+    /// ```
+    #[inline]
+    pub const fn ilog2_checked(self) -> Result<i32, FloatError> {
+        if self.0 <= 0.0 {
+            return Err(FloatError::Domain);
+        }
+
+        let exponent = self.exponent_unbiased();
+        if exponent > -127 {
+            return Ok(exponent);
+        }
+
+        // A subnormal value is its bits times `2^-149`.
+        #[allow(clippy::cast_possible_wrap)]
+        let highest_bit = self.0.to_bits().ilog2() as i32;
+        Ok(-149 + highest_bit)
+    }
+
+    /// Multiplies `self` by `2^n` by adjusting its exponent directly.
+    ///
+    /// The result is exact unless it is subnormal, in which case it is rounded once, like `self *
+    /// 2^n`. Results that are too large to represent are infinite, so the result must be checked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// let value = GuardedF32::new(3.0).unwrap();
+    /// assert_eq!(value.mul_pow2(4).check(), GuardedF32::new(48.0));
+    /// assert_eq!(value.mul_pow2(128).check(), Err(FloatError::Infinity));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original value"]
+    #[inline]
+    pub const fn mul_pow2(self, n: i32) -> UnguardedF32 {
+        UnguardedF32(scale(self.0, n as i64))
+    }
+
+    /// Divides `self` by `2^n` by adjusting its exponent directly. See `mul_pow2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF32;
+    ///
+    /// let value = GuardedF32::new(3.0).unwrap();
+    /// assert_eq!(value.div_pow2(2).check(), GuardedF32::new(0.75));
+    /// assert_eq!(value.div_pow2(200).check(), GuardedF32::new(0.0));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original value"]
+    #[inline]
+    pub const fn div_pow2(self, n: i32) -> UnguardedF32 {
+        UnguardedF32(scale(self.0, -(n as i64)))
+    }
+}
+
+impl UnguardedF32 {
+    /// Multiplies `self` by `2^n` by adjusting its exponent directly. NaN and infinite values are
+    /// returned unchanged. See `GuardedF32::mul_pow2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32, UnguardedF32};
+    ///
+    /// let value = UnguardedF32::new(-3.0);
+    /// assert_eq!(value.mul_pow2(-1).check(), GuardedF32::new(-1.5));
+    ///
+    /// let invalid = UnguardedF32::new(f32::NAN);
+    /// assert_eq!(invalid.mul_pow2(2).check(), Err(FloatError::NaN));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original value"]
+    #[inline]
+    pub const fn mul_pow2(self, n: i32) -> Self {
+        Self(scale(self.0, n as i64))
+    }
+
+    /// Divides `self` by `2^n` by adjusting its exponent directly. NaN and infinite values are
+    /// returned unchanged. See `GuardedF32::mul_pow2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF32, UnguardedF32};
+    ///
+    /// let value = UnguardedF32::new(-3.0);
+    /// assert_eq!(value.div_pow2(-1).check(), GuardedF32::new(-6.0));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original value"]
+    #[inline]
+    pub const fn div_pow2(self, n: i32) -> Self {
+        Self(scale(self.0, -(n as i64)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f32::tests::{invalid_f32, valid_f32};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_ilog2(a in valid_f32()) {
+            let value = GuardedF32(a);
+            match value.ilog2_checked() {
+                Ok(exponent) => {
+                    prop_assert!(a > 0.0);
+                    prop_assert!(value.div_pow2(exponent).0 >= 1.0);
+                    prop_assert!(value.div_pow2(exponent).0 < 2.0);
+                    if a >= f32::MIN_POSITIVE {
+                        prop_assert_eq!(value.exponent_unbiased(), exponent);
+                        prop_assert_eq!((-value).exponent_unbiased(), exponent);
+                    }
+                }
+                Err(error) => {
+                    prop_assert!(a <= 0.0);
+                    prop_assert_eq!(error, FloatError::Domain);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extremes() {
+        let max = GuardedF32::MAX;
+        assert_eq!(max.mul_pow2(i32::MAX).0, f32::INFINITY);
+        assert_eq!((-max).div_pow2(i32::MIN).0, f32::NEG_INFINITY);
+        assert_eq!(max.div_pow2(i32::MAX).0.to_bits(), 0.0f32.to_bits());
+        assert_eq!((-max).mul_pow2(i32::MIN).0.to_bits(), (-0.0f32).to_bits());
+
+        let smallest = GuardedF32(f32::from_bits(1));
+        assert_eq!(smallest.mul_pow2(149).0, 1.0);
+        assert_eq!(smallest.div_pow2(1).0, 0.0);
+        assert_eq!(GuardedF32(1.0).div_pow2(149).0, smallest.0);
+        assert_eq!(GuardedF32(1.5).div_pow2(149).0, 2.0 * smallest.0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_multiplication(a in valid_f32(), n in -126i32..=127) {
+            let expected = a * 2f32.powi(n);
+            prop_assert_eq!(GuardedF32(a).mul_pow2(n).0.to_bits(), expected.to_bits());
+            prop_assert_eq!(UnguardedF32(a).div_pow2(-n).0.to_bits(), expected.to_bits());
+        }
+
+        #[test]
+        fn test_subnormal_round_trip(bits in 1u32..(1 << 23), n in 0i32..200) {
+            let value = GuardedF32(f32::from_bits(bits));
+            prop_assert_eq!(value.mul_pow2(n).div_pow2(n).0.to_bits(), bits);
+        }
+
+        #[test]
+        fn test_invalid_unchanged(a in invalid_f32(), n in any::<i32>()) {
+            prop_assert_eq!(UnguardedF32(a).mul_pow2(n).0.to_bits(), a.to_bits());
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! This module implements `JsonSchema` for `GuardedF32`.
+//!
+//! The schema describes a JSON number with the `float` format. JSON cannot represent NaN or
+//! infinities in the first place, and `GuardedF32` rejects them when it is deserialized.
+use super::GuardedF32;
+use ::schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use std::borrow::Cow;
+
+impl JsonSchema for GuardedF32 {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "GuardedF32".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "floatguard::GuardedF32".into()
+    }
+
+    /// Describes a finite `f32` value.
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "format": "float",
+            "description": "A finite float-precision number. NaN and infinite values are rejected."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema() {
+        let schema = ::schemars::schema_for!(GuardedF32);
+        assert_eq!(schema.get("type"), Some(&"number".into()));
+        assert_eq!(schema.get("format"), Some(&"float".into()));
+    }
+}
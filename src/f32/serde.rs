@@ -0,0 +1,134 @@
+//! This module implements `Serialize` and `Deserialize` for `GuardedF32` and `UnguardedF32`.
+//!
+//! `GuardedF32` values are validated when they are deserialized, so a NaN or infinite input is
+//! rejected with an error. `UnguardedF32` values are passed through as-is, and human-readable
+//! formats may also encode them as the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`, which is
+//! how producers that cannot write NaN or infinite numbers commonly encode them.
+use super::{GuardedF32, UnguardedF32};
+use ::serde::de::{Error, Unexpected, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for GuardedF32 {
+    /// Serializes the inner `f32` value.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GuardedF32 {
+    /// Deserializes a `f32` value and validates that it is finite.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a number, or if it is NaN or infinite.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = f32::deserialize(deserializer)?;
+        Self::new(value).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for UnguardedF32 {
+    /// Serializes the inner `f32` value, which may be NaN or infinite.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UnguardedF32 {
+    /// Deserializes a `f32` value without validating it.
+    ///
+    /// Human-readable formats also accept the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+    /// Binary formats like `bincode` cannot describe their values, and encode NaN and infinite
+    /// numbers natively anyway, so they are only asked for a number.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(UnguardedVisitor)
+        } else {
+            deserializer.deserialize_f32(UnguardedVisitor)
+        }
+    }
+}
+
+/// Parses the strings that lenient producers write in place of NaN or infinite numbers.
+fn parse_special(value: &str) -> Option<f32> {
+    match value {
+        "NaN" => Some(f32::NAN),
+        "Infinity" => Some(f32::INFINITY),
+        "-Infinity" => Some(f32::NEG_INFINITY),
+        _ => None,
+    }
+}
+
+/// Visits a number or one of the special strings.
+struct UnguardedVisitor;
+
+impl Visitor<'_> for UnguardedVisitor {
+    type Value = UnguardedF32;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a number or one of \"NaN\", \"Infinity\", or \"-Infinity\"")
+    }
+
+    fn visit_f32<E: Error>(self, value: f32) -> Result<Self::Value, E> {
+        Ok(UnguardedF32(value))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn visit_f64<E: Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(UnguardedF32(value as f32))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(UnguardedF32(value as f32))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(UnguardedF32(value as f32))
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_special(value)
+            .map(UnguardedF32)
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f32::tests::valid_f32;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f32()) {
+            let guarded = GuardedF32::new(a).unwrap();
+            let json = serde_json::to_string(&guarded).unwrap();
+
+            prop_assert_eq!(serde_json::from_str::<GuardedF32>(&json).unwrap(), guarded);
+            prop_assert_eq!(serde_json::from_str::<UnguardedF32>(&json).unwrap().check(), Ok(guarded));
+        }
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(serde_json::from_str::<GuardedF32>("null").is_err());
+        assert!(serde_json::from_str::<GuardedF32>("\"1.0\"").is_err());
+        assert!(serde_json::from_str::<GuardedF32>("1e999").is_err());
+        assert!(serde_json::from_str::<GuardedF32>("\"NaN\"").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_special_strings() {
+        let parse = |json| serde_json::from_str::<UnguardedF32>(json).map(|value| value.0);
+
+        assert!(parse("\"NaN\"").unwrap().is_nan());
+        assert_eq!(parse("\"Infinity\"").unwrap(), f32::INFINITY);
+        assert_eq!(parse("\"-Infinity\"").unwrap(), f32::NEG_INFINITY);
+        assert!(parse("null").is_err());
+    }
+}
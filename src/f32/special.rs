@@ -0,0 +1,189 @@
+use super::{GuardedF32, UnguardedF32};
+use crate::macros::math::math;
+
+math!(
+    (GuardedF32, UnguardedF32)
+    r"
+        Computes the error function of `self`. `GuardedF32::erf` returns a `GuardedF32` type because
+        the result is always in the range [-1, 1].
+
+        See: [`libm::erff`]
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF32, UnguardedF32};
+
+        let x = GuardedF32::new(0.5_f32).unwrap();
+        assert!((x.erf() - 0.520_499_9).abs().check().unwrap() < 1e-6);
+
+        let invalid = UnguardedF32::new(f32::NAN);
+        assert!(invalid.erf().check().is_err());
+        ```
+    "
+    fn erf(value: f32) -> Self {
+        Self(libm::erff(value))
+    }
+);
+
+math!(
+    (GuardedF32, UnguardedF32)
+    r"
+        Computes the complementary error function of `self`, `1 - erf(self)`, without the loss of
+        precision of the subtraction for large values. `GuardedF32::erfc` returns a `GuardedF32`
+        type because the result is always in the range [0, 2].
+
+        See: [`libm::erfcf`]
+
+        # Examples
+
+        ```rust
+        use floatguard::GuardedF32;
+
+        let x = GuardedF32::new(5.0_f32).unwrap();
+        assert!(x.erfc() > 0.0);
+        assert!((x.erfc() - 1.537_46e-12).abs().check().unwrap() < 1e-17);
+        ```
+    "
+    fn erfc(value: f32) -> Self {
+        Self(libm::erfcf(value))
+    }
+);
+
+math!(
+    (GuardedF32, UnguardedF32)
+    r"
+        Computes the gamma function of `self`. The result is invalid at zero and the negative
+        integers, where the function has poles, and overflows for arguments above roughly `35.04`.
+
+        See: [`libm::tgammaf`]
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF32, FloatError};
+
+        let five = GuardedF32::new(5.0_f32).unwrap();
+        assert_eq!(five.gamma().check(), GuardedF32::new(24.0));
+
+        let pole = GuardedF32::new(-2.0_f32).unwrap();
+        assert!(pole.gamma().check().is_err());
+
+        let large = GuardedF32::new(36.0_f32).unwrap();
+        assert_eq!(large.gamma().check(), Err(FloatError::Infinity));
+        ```
+    "
+    fn gamma(value: f32) -> UnguardedF32 {
+        UnguardedF32(libm::tgammaf(value))
+    }
+);
+
+math!(
+    (GuardedF32, UnguardedF32)
+    r"
+        Computes the natural logarithm of the absolute value of the gamma function of `self`. This
+        stays finite for arguments where `gamma` overflows, but is invalid at the poles of the gamma
+        function.
+
+        See: [`libm::lgammaf`]
+
+        # Examples
+
+        ```rust
+        use floatguard::GuardedF32;
+
+        let large = GuardedF32::new(50.0_f32).unwrap();
+        assert!(large.gamma().check().is_err());
+        assert!((large.lgamma() - 144.565_74).abs().check().unwrap() < 1e-3);
+
+        let pole = GuardedF32::new(0.0_f32).unwrap();
+        assert!(pole.lgamma().check().is_err());
+        ```
+    "
+    fn lgamma(value: f32) -> UnguardedF32 {
+        UnguardedF32(libm::lgammaf(value))
+    }
+);
+
+math!(
+    (GuardedF32, UnguardedF32)
+    r"
+        Computes the beta function of `self` and `other`, `gamma(self) * gamma(other) / gamma(self +
+        other)`.
+
+        The result is computed from the logarithms of the gamma functions, so it stays finite when
+        the individual gamma functions overflow.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF32, UnguardedF32};
+
+        let a = GuardedF32::new(2.0_f32).unwrap();
+        let b = GuardedF32::new(3.0_f32).unwrap();
+        assert!((a.beta(b) - 1.0 / 12.0).abs().check().unwrap() < 1e-6);
+
+        let large = GuardedF32::new(50.0_f32).unwrap();
+        assert!(large.beta(large).check().unwrap() > 0.0);
+
+        let invalid = UnguardedF32::new(f32::NAN);
+        assert!(a.beta(invalid).check().is_err());
+        ```
+    "
+    fn beta(base: f32, other: impl Into<UnguardedF32>) -> UnguardedF32 {
+        let UnguardedF32(other) = other.into();
+        let (ln_a, sign_a) = libm::lgammaf_r(base);
+        let (ln_b, sign_b) = libm::lgammaf_r(other);
+        let (ln_sum, sign_sum) = libm::lgammaf_r(base + other);
+
+        let magnitude = (ln_a + ln_b - ln_sum).exp();
+        UnguardedF32(if sign_a * sign_b * sign_sum < 0 {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f32::tests::{invalid_f32, valid_f32};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_erf_bounded(a in valid_f32()) {
+            let value = GuardedF32::new(a).unwrap();
+            prop_assert!((-1.0..=1.0).contains(&value.erf().0));
+            prop_assert!((0.0..=2.0).contains(&value.erfc().0));
+            prop_assert_eq!(UnguardedF32::new(a).erf().check(), Ok(value.erf()));
+        }
+
+        #[test]
+        fn test_invalid_propagates(a in invalid_f32(), b in valid_f32()) {
+            let invalid = UnguardedF32::new(a);
+            let valid = GuardedF32::new(b).unwrap();
+
+            prop_assert!(invalid.gamma().check().is_err());
+            prop_assert!(invalid.lgamma().check().is_err());
+            prop_assert!(valid.beta(invalid).check().is_err());
+        }
+
+        #[test]
+        fn test_gamma_recurrence(a in 0.1..30.0f32) {
+            let value = GuardedF32::new(a).unwrap();
+            let next = (value + 1.0).check().unwrap().gamma().check().unwrap();
+            let expected = (value.gamma() * a).check().unwrap();
+            prop_assert!((next - expected).abs().check().unwrap() <= expected.0 * 1e-4);
+        }
+
+        #[test]
+        fn test_beta_matches_gamma(a in 0.1..10.0f32, b in 0.1..10.0f32) {
+            let (a, b) = (GuardedF32::new(a).unwrap(), GuardedF32::new(b).unwrap());
+            let expected = (a.gamma() * b.gamma() / (a + b).gamma()).check().unwrap();
+            let beta = a.beta(b).check().unwrap();
+            prop_assert!((beta - expected).abs().check().unwrap() <= expected.0 * 1e-4);
+        }
+    }
+}
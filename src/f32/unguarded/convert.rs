@@ -1,6 +1,7 @@
 use crate::FloatError;
 
 use super::UnguardedF32;
+use crate::macros::ops_binary::integer_conversion;
 
 /// Implementing the ability to convert `GuardedF32` to `f32` safely.
 ///
@@ -33,7 +34,7 @@ impl TryFrom<UnguardedF32> for f32 {
     /// assert_eq!(f32::try_from(inf_value), Err(FloatError::Infinity));
     /// ```
     fn try_from(value: UnguardedF32) -> Result<Self, Self::Error> {
-        value.check().map(Self::from)
+        value.check_reported().map(Self::from)
     }
 }
 
@@ -58,6 +59,58 @@ impl From<f32> for UnguardedF32 {
     }
 }
 
+impl From<&f32> for UnguardedF32 {
+    /// Converts a reference to an `f32` into an `UnguardedF32`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF32, GuardedF32};
+    ///
+    /// let value = 3.14;
+    /// assert_eq!(UnguardedF32::from(&value).check(), GuardedF32::new(3.14));
+    /// ```
+    fn from(value: &f32) -> Self {
+        Self::new(*value)
+    }
+}
+
+impl From<&Self> for UnguardedF32 {
+    /// Copies the `UnguardedF32` behind a reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF32, GuardedF32};
+    ///
+    /// let value = UnguardedF32::new(3.14);
+    /// assert_eq!(UnguardedF32::from(&value).check(), GuardedF32::new(3.14));
+    /// ```
+    fn from(value: &Self) -> Self {
+        *value
+    }
+}
+
+integer_conversion!(
+    impl From<...(i8, i16, u8, u16)> for UnguardedF32 as f32;
+    r"
+        Converts an integer to `UnguardedF32`. Every value of the integer type is exactly representable,
+        so integers can be used in arithmetic with guarded and unguarded values without rounding.
+
+        # Example
+
+        ```rust
+        use floatguard::{GuardedF32, UnguardedF32};
+
+        assert_eq!(UnguardedF32::from(-3i16).check(), GuardedF32::new(-3.0));
+
+        let value = GuardedF32::new(1.5).unwrap();
+        assert_eq!((value * 2i16).check(), GuardedF32::new(3.0));
+        assert_eq!((4u8 - value).check(), GuardedF32::new(2.5));
+        ```
+    "
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
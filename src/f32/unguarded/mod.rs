@@ -1,5 +1,6 @@
 mod convert;
 mod ops_assign;
+mod sum;
 
 /// Represents a checked floating-point number that ensures it is neither NaN nor infinite.
 ///
@@ -16,6 +17,7 @@ mod ops_assign;
 /// assert_eq!((unchecked_f32 - f32::INFINITY).check(), Err(FloatError::Infinity));
 /// ```
 #[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
 pub struct UnguardedF32(pub(crate) f32);
 
 impl UnguardedF32 {
@@ -28,6 +30,21 @@ impl UnguardedF32 {
     pub const fn new(value: f32) -> Self {
         Self(value)
     }
+
+    /// Returns the inner `f32` value without checking it, so it may be NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::UnguardedF32;
+    ///
+    /// let value = UnguardedF32::new(1.0) / 0.0;
+    /// assert_eq!(value.get(), f32::INFINITY);
+    /// ```
+    #[must_use]
+    pub const fn get(self) -> f32 {
+        self.0
+    }
 }
 
 impl std::fmt::Display for UnguardedF32 {
@@ -0,0 +1,41 @@
+use super::UnguardedF32;
+use std::iter::Sum;
+
+impl<T: Into<Self>> Sum<T> for UnguardedF32 {
+    /// Sums guarded or unguarded values, deferring the check to the end like the `+` operator.
+    ///
+    /// An empty iterator sums to zero. With the `rayon` feature, this also implements parallel
+    /// sums, which may add the values in a different order on every run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32, UnguardedF32};
+    ///
+    /// let values = [1.0, 2.0, 3.5].map(|v| GuardedF32::new(v).unwrap());
+    /// assert_eq!(values.iter().sum::<UnguardedF32>().check(), GuardedF32::new(6.5));
+    ///
+    /// let values = [UnguardedF32::new(1.0), UnguardedF32::new(f32::NAN)];
+    /// assert_eq!(values.into_iter().sum::<UnguardedF32>().check(), Err(FloatError::NaN));
+    /// ```
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::new(0.0), |total, value| total + value.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GuardedF32;
+    use crate::f32::tests::valid_f32;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_matches_fold(values in prop::collection::vec(valid_f32(), 0..32)) {
+            let guarded: Vec<_> = values.iter().map(|&v| GuardedF32::new(v).unwrap()).collect();
+            let expected = values.iter().fold(UnguardedF32::new(0.0), |total, &v| total + v);
+            prop_assert_eq!(guarded.iter().sum::<UnguardedF32>().check(), expected.check());
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! This module implements `ToSchema` for `GuardedF32`.
+//!
+//! The schema describes a JSON number with the `float` format. JSON cannot represent NaN or
+//! infinities in the first place, and `GuardedF32` rejects them when it is deserialized.
+use super::GuardedF32;
+use ::utoipa::openapi::RefOr;
+use ::utoipa::openapi::schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type};
+use ::utoipa::{PartialSchema, ToSchema};
+
+impl PartialSchema for GuardedF32 {
+    /// Describes a finite `f32` value.
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::Number)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Float)))
+            .description(Some(
+                "A finite float-precision number. NaN and infinite values are rejected.",
+            ))
+            .into()
+    }
+}
+
+impl ToSchema for GuardedF32 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema() {
+        let schema = serde_json::to_value(GuardedF32::schema()).unwrap();
+        assert_eq!(schema["type"], "number");
+        assert_eq!(schema["format"], "float");
+        assert_eq!(GuardedF32::name(), "GuardedF32");
+    }
+}
@@ -0,0 +1,139 @@
+use super::{GuardedF64, UnguardedF64};
+use crate::macros::math::math;
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Computes the logistic sigmoid of `self`, `1 / (1 + exp(-self))`. `GuardedF64::sigmoid`
+        returns a `GuardedF64` type because the result is always in the range [0, 1].
+
+        The naive formula overflows `exp` for large negative inputs. This evaluates the exponential
+        of a non-positive value instead, so it never overflows.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+
+        let zero = GuardedF64::new(0.0_f64).unwrap();
+        assert_eq!(zero.sigmoid(), 0.5);
+
+        let large = GuardedF64::new(-1000.0_f64).unwrap();
+        assert_eq!(large.sigmoid(), 0.0);
+
+        let invalid = UnguardedF64::new(f64::NAN);
+        assert!(invalid.sigmoid().check().is_err());
+        ```
+    "
+    fn sigmoid(value: f64) -> Self {
+        if value >= 0.0 {
+            Self(1.0 / (1.0 + (-value).exp()))
+        } else {
+            let exp = value.exp();
+            Self(exp / (1.0 + exp))
+        }
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Computes the logit of `self`, `ln(self / (1 - self))`, which is the inverse of `sigmoid`.
+
+        The logit is only defined in the range (0, 1). Inputs of 0 and 1 produce infinities, and
+        inputs outside of the range produce NaN, so the result must be checked.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, FloatError};
+
+        let half = GuardedF64::new(0.5_f64).unwrap();
+        assert_eq!(half.logit().check(), GuardedF64::new(0.0));
+
+        let one = GuardedF64::new(1.0_f64).unwrap();
+        assert_eq!(one.logit().check(), Err(FloatError::Infinity));
+
+        let outside = GuardedF64::new(1.5_f64).unwrap();
+        assert_eq!(outside.logit().check(), Err(FloatError::NaN));
+        ```
+    "
+    fn logit(value: f64) -> UnguardedF64 {
+        UnguardedF64(value.ln() - (-value).ln_1p())
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Computes the softplus of `self`, `ln(1 + exp(self))`. `GuardedF64::softplus` returns a
+        `GuardedF64` type because the result is never larger than `max(self, 0) + ln(2)`.
+
+        The naive formula overflows `exp` for large positive inputs. This is computed as
+        `max(self, 0) + ln(1 + exp(-|self|))` instead, so it never overflows.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+
+        let large = GuardedF64::new(1000.0_f64).unwrap();
+        assert_eq!(large.softplus(), 1000.0);
+
+        let zero = GuardedF64::new(0.0_f64).unwrap();
+        assert_eq!(zero.softplus(), std::f64::consts::LN_2);
+
+        let invalid = UnguardedF64::new(f64::NAN);
+        assert!(invalid.softplus().check().is_err());
+        ```
+    "
+    fn softplus(value: f64) -> Self {
+        Self(value.max(0.0) + (-value.abs()).exp().ln_1p())
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_softplus_max() {
+        let max = GuardedF64::MAX;
+        assert_eq!(max.softplus(), max);
+        assert_eq!(UnguardedF64::from(max).softplus().check(), Ok(max));
+    }
+
+    proptest! {
+        #[test]
+        fn test_sigmoid_bounded(a in valid_f64()) {
+            let sigmoid = GuardedF64::new(a).unwrap().sigmoid();
+            prop_assert!((0.0..=1.0).contains(&sigmoid.0));
+            prop_assert_eq!(UnguardedF64::new(a).sigmoid().check(), Ok(sigmoid));
+        }
+
+        #[test]
+        fn test_softplus_bounded(a in valid_f64()) {
+            let softplus = GuardedF64::new(a).unwrap().softplus();
+            prop_assert!(softplus.0 >= a.max(0.0));
+            prop_assert!(softplus.0 <= a.max(0.0) + std::f64::consts::LN_2);
+            prop_assert_eq!(UnguardedF64::new(a).softplus().check(), Ok(softplus));
+        }
+
+        // Large positive inputs have sigmoids that round to one, where the logit cannot recover them.
+        #[test]
+        fn test_logit_inverts_sigmoid(a in -30.0..2.0f64) {
+            let a = GuardedF64::new(a).unwrap();
+            let logit = a.sigmoid().logit().check().unwrap();
+            prop_assert!((logit - a).abs().check().unwrap() <= 1e-9);
+        }
+
+        #[test]
+        fn test_invalid_propagates(a in invalid_f64()) {
+            let invalid = UnguardedF64::new(a);
+            prop_assert!(invalid.logit().check().is_err());
+            prop_assert_eq!(invalid.softplus().check().is_err(), a.is_nan() || a > 0.0);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! This module implements conversions between `GuardedF64` seconds and the `chrono` durations and
+//! timestamps.
+//!
+//! Every `TimeDelta` and `DateTime` converts to a finite number of seconds, so those conversions
+//! cannot fail. Converting back rounds to the nearest nanosecond, and reports values outside of the
+//! range of the `chrono` type as `FloatError::Inexact`.
+use super::GuardedF64;
+use super::seconds::{join_seconds, split_seconds};
+use crate::FloatError;
+use ::chrono::{DateTime, TimeDelta, TimeZone, Utc};
+
+impl From<TimeDelta> for GuardedF64 {
+    /// Converts a `TimeDelta` into a number of seconds.
+    fn from(delta: TimeDelta) -> Self {
+        join_seconds(delta.num_seconds(), delta.subsec_nanos())
+    }
+}
+
+impl TryFrom<GuardedF64> for TimeDelta {
+    type Error = FloatError;
+
+    /// Converts a number of seconds into a `TimeDelta`, rounded to the nearest nanosecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Inexact` if the duration is outside of the range of `TimeDelta`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::TimeDelta;
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// let seconds = GuardedF64::new(-1.5).unwrap();
+    /// assert_eq!(TimeDelta::try_from(seconds), Ok(TimeDelta::milliseconds(-1500)));
+    /// assert_eq!(GuardedF64::from(TimeDelta::milliseconds(-1500)), seconds);
+    ///
+    /// assert_eq!(TimeDelta::try_from(GuardedF64::MAX), Err(FloatError::Inexact));
+    /// ```
+    fn try_from(seconds: GuardedF64) -> Result<Self, Self::Error> {
+        let (whole, nanos) = split_seconds(seconds)?;
+        Self::new(whole, nanos).ok_or(FloatError::Inexact)
+    }
+}
+
+impl GuardedF64 {
+    /// Converts a `DateTime` into fractional seconds since the Unix epoch.
+    ///
+    /// Near the present, an `f64` resolves timestamps to about a quarter of a microsecond.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::DateTime;
+    /// use floatguard::GuardedF64;
+    ///
+    /// let time = DateTime::from_timestamp(1_700_000_000, 250_000_000).unwrap();
+    /// assert_eq!(GuardedF64::from_datetime(&time), GuardedF64::new(1_700_000_000.25).unwrap());
+    /// ```
+    #[must_use]
+    pub fn from_datetime<Tz: TimeZone>(time: &DateTime<Tz>) -> Self {
+        join_seconds(time.timestamp(), time.timestamp_subsec_nanos())
+    }
+
+    /// Converts fractional seconds since the Unix epoch into a UTC `DateTime`, rounded to the
+    /// nearest nanosecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Inexact` if the timestamp is outside of the range of `DateTime`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::DateTime;
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// let seconds = GuardedF64::new(-0.5).unwrap();
+    /// assert_eq!(seconds.to_datetime(), Ok(DateTime::from_timestamp(-1, 500_000_000).unwrap()));
+    ///
+    /// assert_eq!(GuardedF64::new(1e18).unwrap().to_datetime(), Err(FloatError::Inexact));
+    /// ```
+    pub fn to_datetime(self) -> Result<DateTime<Utc>, FloatError> {
+        let (whole, nanos) = split_seconds(self)?;
+        DateTime::from_timestamp(whole, nanos).ok_or(FloatError::Inexact)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_time_delta_round_trip(secs in -1_000_000i64..1_000_000, nanos in 0u32..1_000_000_000) {
+            let delta = TimeDelta::new(secs, nanos).unwrap();
+            prop_assert_eq!(TimeDelta::try_from(GuardedF64::from(delta)), Ok(delta));
+        }
+
+        #[test]
+        fn test_datetime_round_trip(secs in -1_000_000i64..1_000_000, micros in 0u32..1_000_000) {
+            let time = DateTime::from_timestamp(secs, micros * 1000).unwrap();
+            prop_assert_eq!(GuardedF64::from_datetime(&time).to_datetime(), Ok(time));
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        let max = GuardedF64::from(TimeDelta::MAX);
+        assert!(TimeDelta::try_from(max).is_err());
+        assert!(TimeDelta::try_from(GuardedF64(max.0 * 0.999_999)).is_ok());
+        assert_eq!(GuardedF64::MIN.to_datetime(), Err(FloatError::Inexact));
+    }
+}
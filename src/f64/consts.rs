@@ -303,6 +303,70 @@ copy_const_value!(
     LN_10: GuardedF64 = GuardedF64(std::f64::consts::LN_10)
 );
 
+copy_const_value!(
+    (GuardedF64, UnguardedF64)
+    r"
+        1/&radic;(2&pi;), the peak of the standard normal distribution.
+
+        Derived from the constants in [`std::f64::consts`] at compile time.
+    "
+    FRAC_1_SQRT_2PI: GuardedF64 = derived(
+        std::f64::consts::FRAC_2_SQRT_PI * std::f64::consts::FRAC_1_SQRT_2 / 2.0
+    )
+);
+
+copy_const_value!(
+    (GuardedF64, UnguardedF64)
+    r"
+        &radic;(2&pi;)
+
+        Derived from the constants in [`std::f64::consts`] at compile time.
+    "
+    SQRT_2PI: GuardedF64 = derived(
+        2.0 * std::f64::consts::SQRT_2 / std::f64::consts::FRAC_2_SQRT_PI
+    )
+);
+
+copy_const_value!(
+    (GuardedF64, UnguardedF64)
+    r"
+        &radic;&pi;
+
+        Derived from the constants in [`std::f64::consts`] at compile time.
+    "
+    SQRT_PI: GuardedF64 = derived(2.0 / std::f64::consts::FRAC_2_SQRT_PI)
+);
+
+copy_const_value!(
+    (GuardedF64, UnguardedF64)
+    r"
+        1/&tau;, or 1/(2&pi;)
+
+        Derived from the constants in [`std::f64::consts`] at compile time.
+    "
+    FRAC_1_TAU: GuardedF64 = derived(std::f64::consts::FRAC_1_PI / 2.0)
+);
+
+copy_const_value!(
+    (GuardedF64, UnguardedF64)
+    r"
+        The number of radians in one degree, &pi;/180.
+
+        Derived from the constants in [`std::f64::consts`] at compile time.
+    "
+    DEG_TO_RAD: GuardedF64 = derived(std::f64::consts::PI / 180.0)
+);
+
+copy_const_value!(
+    (GuardedF64, UnguardedF64)
+    r"
+        The number of degrees in one radian, 180/&pi;.
+
+        Derived from the constants in [`std::f64::consts`] at compile time.
+    "
+    RAD_TO_DEG: GuardedF64 = derived(180.0 / std::f64::consts::PI)
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -414,4 +478,19 @@ mod tests {
         assert_eq!(UnguardedF64::MAX_10_EXP, f64::MAX_10_EXP);
         assert_type_eq!(UnguardedF64::MAX_10_EXP, i32);
     }
+
+    #[test]
+    fn test_derived() {
+        for (value, expected) in [
+            (GuardedF64::FRAC_1_SQRT_2PI, 0.398_942_280_401_432_7),
+            (GuardedF64::SQRT_2PI, 2.506_628_274_631_000_7),
+            (GuardedF64::SQRT_PI, 1.772_453_850_905_516),
+            (GuardedF64::FRAC_1_TAU, 0.159_154_943_091_895_35),
+            (GuardedF64::DEG_TO_RAD, 0.017_453_292_519_943_295),
+            (GuardedF64::RAD_TO_DEG, 57.295_779_513_082_32),
+        ] {
+            assert_eq!(value, expected);
+        }
+        assert_type_eq!(UnguardedF64::SQRT_2PI, GuardedF64);
+    }
 }
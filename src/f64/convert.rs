@@ -1,4 +1,5 @@
 use crate::FloatError;
+use crate::macros::ops_bool::bool_conversion;
 
 use super::{GuardedF64, UnguardedF64};
 
@@ -33,6 +34,117 @@ impl UnguardedF64 {
     pub const fn check(self) -> Result<GuardedF64, FloatError> {
         GuardedF64::new(self.0)
     }
+
+    /// Checks if the `UnguardedF64` value is valid (finite), like `check`, and reports the validation
+    /// to the invalid-value hook and the validation counters.
+    ///
+    /// `check` is a `const fn`, so it can do neither. Use this function wherever validations should
+    /// be reported, as the `TryFrom<UnguardedF64>` conversion and the rest of the crate do. Without
+    /// the `hooks` and `validation-stats` features, it is the same as `check`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the value is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64, UnguardedF64};
+    ///
+    /// assert_eq!(UnguardedF64::new(1.0).check_reported(), GuardedF64::new(1.0));
+    /// assert_eq!((UnguardedF64::new(1.0) / 0.0).check_reported(), Err(FloatError::Infinity));
+    /// ```
+    pub fn check_reported(self) -> Result<GuardedF64, FloatError> {
+        GuardedF64::new_reported(self.0)
+    }
+
+    /// Checks if the `UnguardedF64` value is valid, clamping infinities to the finite range.
+    ///
+    /// Positive infinity is mapped to `f64::MAX` and negative infinity to `f64::MIN`, so only a
+    /// NaN value is rejected. This is useful when overflow should saturate rather than propagate
+    /// an error, such as when rendering.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `GuardedF64` if the value is finite or infinite, otherwise returns an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::NaN` if the value is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF64, FloatError, GuardedF64};
+    ///
+    /// let value = UnguardedF64::new(1.0);
+    /// assert_eq!(value.check_clamped(), GuardedF64::new(1.0));
+    ///
+    /// let overflow = UnguardedF64::new(f64::MAX) * 2.0;
+    /// assert_eq!(overflow.check_clamped(), Ok(GuardedF64::MAX));
+    ///
+    /// let underflow = UnguardedF64::new(f64::MIN) * 2.0;
+    /// assert_eq!(underflow.check_clamped(), Ok(GuardedF64::MIN));
+    ///
+    /// let invalid = UnguardedF64::new(f64::NAN);
+    /// assert_eq!(invalid.check_clamped(), Err(FloatError::NaN));
+    /// ```
+    pub const fn check_clamped(self) -> Result<GuardedF64, FloatError> {
+        if self.0.is_nan() {
+            Err(FloatError::NaN)
+        } else {
+            Ok(GuardedF64(self.0.clamp(f64::MIN, f64::MAX)))
+        }
+    }
+
+    /// Checks if the `UnguardedF64` value is valid (finite) and satisfies `predicate`, in one pass.
+    ///
+    /// This is useful when the result of a computation must also be within a domain, such as a
+    /// probability that must be in `[0, 1]`. The predicate is only called for finite values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `GuardedF64` if the value is finite and `predicate` returns `true` for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the value is NaN or infinite, or `error` if `predicate` returns
+    /// `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF64, FloatError, GuardedF64};
+    ///
+    /// let is_probability = |p: f64| (0.0..=1.0).contains(&p);
+    ///
+    /// let value = UnguardedF64::new(0.25);
+    /// assert_eq!(value.check_where(is_probability, FloatError::NaN), GuardedF64::new(0.25));
+    ///
+    /// let value = UnguardedF64::new(1.5);
+    /// assert_eq!(value.check_where(is_probability, FloatError::NaN), Err(FloatError::NaN));
+    ///
+    /// let value = UnguardedF64::new(f64::INFINITY);
+    /// assert_eq!(value.check_where(is_probability, FloatError::NaN), Err(FloatError::Infinity));
+    /// ```
+    pub fn check_where(
+        self,
+        predicate: impl FnOnce(f64) -> bool,
+        error: FloatError,
+    ) -> Result<GuardedF64, FloatError> {
+        let result = self.check().and_then(|value| {
+            if predicate(value.0) {
+                Ok(value)
+            } else {
+                Err(error)
+            }
+        });
+
+        #[cfg(any(feature = "hooks", feature = "validation-stats"))]
+        crate::report::validation(result.err(), self.0);
+
+        result
+    }
 }
 
 impl TryFrom<UnguardedF64> for GuardedF64 {
@@ -63,7 +175,7 @@ impl TryFrom<UnguardedF64> for GuardedF64 {
     /// assert_eq!(GuardedF64::try_from(inf_value), Err(FloatError::Infinity));
     /// ```
     fn try_from(value: UnguardedF64) -> Result<Self, Self::Error> {
-        value.check()
+        value.check_reported()
     }
 }
 
@@ -88,6 +200,24 @@ impl From<GuardedF64> for UnguardedF64 {
     }
 }
 
+impl From<&GuardedF64> for UnguardedF64 {
+    /// Converts a reference to a `GuardedF64` into an `UnguardedF64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF64, GuardedF64};
+    ///
+    /// let checked = GuardedF64::new(3.14).unwrap();
+    /// assert_eq!(UnguardedF64::from(&checked).check(), GuardedF64::new(3.14));
+    /// ```
+    fn from(value: &GuardedF64) -> Self {
+        Self(value.0)
+    }
+}
+
+bool_conversion!((GuardedF64, UnguardedF64) as f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,6 +226,22 @@ mod tests {
     use proptest::prelude::*;
 
     proptest! {
+        #[test]
+        fn test_bool_helpers(a in valid_f64(), mask: bool) {
+            let guarded = GuardedF64::new(a).unwrap();
+            prop_assert_eq!(guarded.mask(mask).get().to_bits(), (a * f64::from(u8::from(mask))).to_bits());
+            prop_assert_eq!((guarded * mask).check(), Ok(guarded.mask(mask)));
+            prop_assert_eq!(guarded.indicator(|v| v > 0.0), GuardedF64::from(a > 0.0));
+            prop_assert_eq!(UnguardedF64::new(a).indicator(|v| v > 0.0).check(), Ok(GuardedF64::from_bool(a > 0.0)));
+        }
+
+        #[test]
+        fn test_bool_helpers_keep_invalid(a in invalid_f64(), mask: bool) {
+            let unguarded = UnguardedF64::new(a);
+            prop_assert!(unguarded.indicator(|_| true).check().is_err());
+            prop_assert!(unguarded.mask(mask).check().is_err());
+        }
+
         #[test]
         fn test_from_valid(a in valid_f64()) {
             let checked_a = UnguardedF64::new(a);
@@ -119,6 +265,37 @@ mod tests {
             prop_assert_eq!(f64::try_from(checked_a), Err(float_error));
         }
 
+        #[test]
+        fn test_check_where(a in any::<f64>(), bound in valid_f64()) {
+            let result = UnguardedF64::new(a).check_where(|v| v <= bound, FloatError::Underflow);
+            let expected = match GuardedF64::new(a) {
+                Ok(_) if a > bound => Err(FloatError::Underflow),
+                other => other,
+            };
+            prop_assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn test_check_clamped(a in any::<f64>()) {
+            let expected = if a.is_nan() {
+                Err(FloatError::NaN)
+            } else if a == f64::INFINITY {
+                Ok(GuardedF64::MAX)
+            } else if a == f64::NEG_INFINITY {
+                Ok(GuardedF64::MIN)
+            } else {
+                GuardedF64::new(a)
+            };
+
+            prop_assert_eq!(UnguardedF64::new(a).check_clamped(), expected);
+        }
+
+        #[test]
+        fn test_check_clamped_invalid(a in invalid_f64()) {
+            let clamped = UnguardedF64::new(a).check_clamped();
+            prop_assert_eq!(clamped.is_err(), a.is_nan());
+        }
+
         #[test]
         fn test_from_guarded(guarded in valid_f64()) {
             let guarded_f64 = GuardedF64::new(guarded).unwrap();
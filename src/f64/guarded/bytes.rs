@@ -0,0 +1,165 @@
+use super::GuardedF64;
+use crate::FloatError;
+
+impl GuardedF64 {
+    /// Returns the memory representation of the value as a byte array in little-endian order.
+    ///
+    /// This is the same encoding that `bincode` and `postcard` use for `f64` values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let value = GuardedF64::new(1.0).unwrap();
+    /// assert_eq!(value.to_le_bytes(), [0, 0, 0, 0, 0, 0, 0xf0, 0x3f]);
+    /// ```
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the memory representation of the value as a byte array in big-endian order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let value = GuardedF64::new(1.0).unwrap();
+    /// assert_eq!(value.to_be_bytes(), [0x3f, 0xf0, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns a 64-bit hash of the value that is the same on every platform, in every process, and
+    /// in every version of this crate.
+    ///
+    /// `-0.0` and `0.0` compare equal, so they hash to the same value, unlike hashes of `to_bits()`.
+    /// The bits of the value are mixed with the finalizer of `SplitMix64`, a bijection, so distinct
+    /// values other than the two zeros never collide. This makes the hash suitable for cache keys
+    /// that are persisted or shared between machines, which the `Hash` trait does not guarantee.
+    ///
+    /// Changing the hash of any value is a breaking change, and will only happen in a new major
+    /// version. `GuardedF32::stable_hash64` hashes the same number to the same value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let value = GuardedF64::new(1.0).unwrap();
+    /// assert_eq!(value.stable_hash64(), 0x88d7_c35b_bbf3_eded);
+    ///
+    /// let zero = GuardedF64::new(0.0).unwrap();
+    /// assert_eq!((-zero).stable_hash64(), zero.stable_hash64());
+    /// ```
+    #[must_use]
+    pub const fn stable_hash64(self) -> u64 {
+        // Adding zero turns `-0.0` into `0.0`, and leaves every other value unchanged.
+        let mut z = (self.0 + 0.0).to_bits().wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Creates a `GuardedF64` from its representation as a byte array in little-endian order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the bytes encode NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0xf0, 0x3f];
+    /// assert_eq!(GuardedF64::from_le_bytes(bytes), GuardedF64::new(1.0));
+    ///
+    /// let bytes = f64::NAN.to_le_bytes();
+    /// assert_eq!(GuardedF64::from_le_bytes(bytes), Err(FloatError::NaN));
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Result<Self, FloatError> {
+        Self::new(f64::from_le_bytes(bytes))
+    }
+
+    /// Creates a `GuardedF64` from its representation as a byte array in big-endian order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the bytes encode NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// let bytes = [0x3f, 0xf0, 0, 0, 0, 0, 0, 0];
+    /// assert_eq!(GuardedF64::from_be_bytes(bytes), GuardedF64::new(1.0));
+    ///
+    /// let bytes = f64::INFINITY.to_be_bytes();
+    /// assert_eq!(GuardedF64::from_be_bytes(bytes), Err(FloatError::Infinity));
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Result<Self, FloatError> {
+        Self::new(f64::from_be_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_stable_hash_values() {
+        assert_eq!(GuardedF64(0.0).stable_hash64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(GuardedF64(-0.0).stable_hash64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(GuardedF64(0.1).stable_hash64(), 0xf823_e4ed_2fb1_2a7d);
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f64()) {
+            let value = GuardedF64::new(a).unwrap();
+            prop_assert_eq!(GuardedF64::from_le_bytes(value.to_le_bytes()), Ok(value));
+            prop_assert_eq!(GuardedF64::from_be_bytes(value.to_be_bytes()), Ok(value));
+        }
+
+        #[test]
+        fn test_stable_hash(a in valid_f64(), b in valid_f64()) {
+            let (a, b) = (GuardedF64::new(a).unwrap(), GuardedF64::new(b).unwrap());
+            prop_assert_eq!(a.stable_hash64() == b.stable_hash64(), a == b);
+        }
+
+        #[test]
+        fn test_invalid_bytes(a in invalid_f64()) {
+            let expected = GuardedF64::new(a);
+            prop_assert_eq!(GuardedF64::from_le_bytes(a.to_le_bytes()), expected);
+            prop_assert_eq!(GuardedF64::from_be_bytes(a.to_be_bytes()), expected);
+        }
+
+        #[test]
+        #[cfg(feature = "serde")]
+        fn test_binary_formats(a in valid_f64()) {
+            let value = GuardedF64::new(a).unwrap();
+            let bincode = bincode::serialize(&value).unwrap();
+            let postcard = postcard::to_allocvec(&value).unwrap();
+
+            prop_assert_eq!(&bincode[..], &value.to_le_bytes()[..]);
+            prop_assert_eq!(&postcard[..], &value.to_le_bytes()[..]);
+            prop_assert_eq!(bincode::deserialize::<GuardedF64>(&bincode).unwrap(), value);
+            prop_assert_eq!(postcard::from_bytes::<GuardedF64>(&postcard).unwrap(), value);
+        }
+
+        #[test]
+        #[cfg(feature = "serde")]
+        fn test_binary_formats_reject_invalid(a in invalid_f64()) {
+            prop_assert!(bincode::deserialize::<GuardedF64>(&a.to_le_bytes()).is_err());
+            prop_assert!(postcard::from_bytes::<GuardedF64>(&a.to_le_bytes()).is_err());
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use super::GuardedF64;
+use crate::UnguardedF64;
+use crate::parse::ParseError;
+
+impl GuardedF64 {
+    /// Formats the value as a fixed-width, bit-exact dump of its fields, like
+    /// `+/+0003/8000000000000/0x4028000000000000`, for golden files in regression tests.
+    ///
+    /// The fields are separated by slashes: the sign, `+` or `-`; the unbiased exponent, which is
+    /// `-1023` for zeros and subnormals, as in `exponent_unbiased`; the 52-bit mantissa field in
+    /// hexadecimal; and the whole bit pattern in hexadecimal. Decimal formatting hides differences
+    /// in the last bit, which this format makes visible, and every value has the same width, so
+    /// golden files diff cleanly line by line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let value = GuardedF64::new(12.0).unwrap();
+    /// assert_eq!(value.to_canonical_string(), "+/+0003/8000000000000/0x4028000000000000");
+    ///
+    /// let value = GuardedF64::new(-0.1).unwrap();
+    /// assert_eq!(value.to_canonical_string(), "-/-0004/999999999999a/0xbfb999999999999a");
+    ///
+    /// let value = GuardedF64::new(-0.0).unwrap();
+    /// assert_eq!(value.to_canonical_string(), "-/-1023/0000000000000/0x8000000000000000");
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn to_canonical_string(self) -> String {
+        canonical(self.0.to_bits())
+    }
+
+    /// Parses a `GuardedF64` from the format written by `to_canonical_string`.
+    ///
+    /// The bit pattern in the last field is authoritative, and the other fields must agree with
+    /// it, so a golden file that was edited by hand cannot silently mean something else. The
+    /// format is strict: hexadecimal digits are lowercase, and every field has its full width.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not in the canonical format or its fields
+    /// disagree, or `ParseError::Invalid` if the bit pattern is NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use floatguard::parse::ParseError;
+    ///
+    /// let value = GuardedF64::from_canonical_string("+/+0003/8000000000000/0x4028000000000000");
+    /// assert_eq!(value, GuardedF64::new(12.0).map_err(ParseError::Invalid));
+    ///
+    /// let value = GuardedF64::from_canonical_string("+/+0004/8000000000000/0x4028000000000000");
+    /// assert_eq!(value, Err(ParseError::Malformed));
+    ///
+    /// let value = GuardedF64::from_canonical_string("+/+1024/0000000000000/0x7ff0000000000000");
+    /// assert_eq!(value, Err(ParseError::Invalid(FloatError::Infinity)));
+    /// ```
+    pub fn from_canonical_string(s: &str) -> Result<Self, ParseError> {
+        let bits = s
+            .rsplit_once('/')
+            .and_then(|(_, bits)| bits.strip_prefix("0x"))
+            .and_then(|bits| u64::from_str_radix(bits, 16).ok())
+            .filter(|bits| canonical(*bits) == s)
+            .ok_or(ParseError::Malformed)?;
+
+        UnguardedF64::new(f64::from_bits(bits))
+            .check_reported()
+            .map_err(ParseError::Invalid)
+    }
+}
+
+/// Formats the fields of an `f64` bit pattern in the canonical format.
+fn canonical(bits: u64) -> String {
+    let sign = if bits >> 63 == 0 { '+' } else { '-' };
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let exponent = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let mantissa = bits & ((1 << 52) - 1);
+    format!("{sign}/{exponent:+05}/{mantissa:013x}/{bits:#018x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_extremes() {
+        assert_eq!(
+            GuardedF64::MAX.to_canonical_string(),
+            "+/+1023/fffffffffffff/0x7fefffffffffffff"
+        );
+        assert_eq!(
+            GuardedF64(5e-324).to_canonical_string(),
+            "+/-1023/0000000000001/0x0000000000000001"
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_canonical() {
+        for s in [
+            "",
+            "0x4028000000000000",
+            "+/+3/8000000000000/0x4028000000000000",
+            "+/+0003/8000000000000/0x4028000000000000 ",
+            "+/+0003/8000000000000/0X4028000000000000",
+            "+/+0003/8000000000000/0x4028000000000000/0x4028000000000000",
+            "-/+0003/8000000000000/0x4028000000000000",
+            "+/+0003/8000000000000/0x+028000000000000",
+        ] {
+            assert_eq!(
+                GuardedF64::from_canonical_string(s),
+                Err(ParseError::Malformed),
+                "{s}"
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f64()) {
+            let canonical = GuardedF64(a).to_canonical_string();
+            prop_assert_eq!(canonical.len(), 40);
+            prop_assert_eq!(
+                GuardedF64::from_canonical_string(&canonical).map(|v| v.0.to_bits()),
+                Ok(a.to_bits())
+            );
+        }
+    }
+}
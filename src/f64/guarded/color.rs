@@ -0,0 +1,121 @@
+use super::GuardedF64;
+use crate::{FloatError, UnguardedF64};
+
+/// The largest encoded sRGB value on the linear segment of the transfer function.
+const SRGB_THRESHOLD: f64 = 0.040_45;
+
+/// The largest linear value on the linear segment of the transfer function, `SRGB_THRESHOLD / 12.92`.
+const LINEAR_THRESHOLD: f64 = 0.003_130_8;
+
+impl GuardedF64 {
+    /// Converts an sRGB-encoded color component in the range [0, 1] to linear light, with the
+    /// piecewise transfer function of IEC 61966-2-1.
+    ///
+    /// The result is clamped to [0, 1], so rounding at the ends of the range cannot push it out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is outside of the range [0, 1].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// let white = GuardedF64::new(1.0).unwrap();
+    /// assert_eq!(white.srgb_to_linear(), GuardedF64::new(1.0));
+    ///
+    /// let gray = GuardedF64::new(0.5).unwrap().srgb_to_linear().unwrap();
+    /// assert!((gray.get() - 0.214).abs() < 1e-3);
+    ///
+    /// assert_eq!(GuardedF64::new(1.5).unwrap().srgb_to_linear(), Err(FloatError::Domain));
+    /// ```
+    pub fn srgb_to_linear(self) -> Result<Self, FloatError> {
+        let value = UnguardedF64::from(self)
+            .check_where(|value| (0.0..=1.0).contains(&value), FloatError::Domain)?
+            .0;
+
+        let linear = if value <= SRGB_THRESHOLD {
+            value / 12.92
+        } else {
+            // This is `((value + 0.055) / 1.055)^2.4`, rearranged so that 1 maps to exactly 1.
+            ((value - 1.0) / 1.055 + 1.0).powf(2.4)
+        };
+        Ok(Self(linear.clamp(0.0, 1.0)))
+    }
+
+    /// Converts a linear-light color component in the range [0, 1] to sRGB encoding, with the
+    /// piecewise transfer function of IEC 61966-2-1. This is the inverse of `srgb_to_linear`.
+    ///
+    /// The result is clamped to [0, 1], so rounding at the ends of the range cannot push it out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is outside of the range [0, 1].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// let black = GuardedF64::new(0.0).unwrap();
+    /// assert_eq!(black.linear_to_srgb(), GuardedF64::new(0.0));
+    ///
+    /// let gray = GuardedF64::new(0.214).unwrap().linear_to_srgb().unwrap();
+    /// assert!((gray.get() - 0.5).abs() < 1e-3);
+    ///
+    /// assert_eq!(GuardedF64::new(-0.1).unwrap().linear_to_srgb(), Err(FloatError::Domain));
+    /// ```
+    pub fn linear_to_srgb(self) -> Result<Self, FloatError> {
+        let value = UnguardedF64::from(self)
+            .check_where(|value| (0.0..=1.0).contains(&value), FloatError::Domain)?
+            .0;
+
+        let srgb = if value <= LINEAR_THRESHOLD {
+            value * 12.92
+        } else {
+            // This is `1.055 * value^(1 / 2.4) - 0.055`, rearranged so that 1 maps to exactly 1.
+            (value.powf(1.0 / 2.4) - 1.0).mul_add(1.055, 1.0)
+        };
+        Ok(Self(srgb.clamp(0.0, 1.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_endpoints() {
+        for value in [0.0, 1.0] {
+            let value = GuardedF64(value);
+            assert_eq!(value.srgb_to_linear(), Ok(value));
+            assert_eq!(value.linear_to_srgb(), Ok(value));
+        }
+
+        let threshold = GuardedF64(SRGB_THRESHOLD).srgb_to_linear().unwrap();
+        assert!((threshold.0 - LINEAR_THRESHOLD).abs() < 1e-8);
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in 0.0..=1.0f64) {
+            let value = GuardedF64(a);
+            let linear = value.srgb_to_linear().unwrap();
+            prop_assert!((0.0..=1.0).contains(&linear.0));
+            prop_assert!((linear.linear_to_srgb().unwrap().0 - a).abs() < 1e-6);
+        }
+
+        #[test]
+        fn test_domain(a in valid_f64()) {
+            let value = GuardedF64(a);
+            let expected = if (0.0..=1.0).contains(&a) { Ok(()) } else { Err(FloatError::Domain) };
+            prop_assert_eq!(value.srgb_to_linear().map(|_| ()), expected);
+            prop_assert_eq!(value.linear_to_srgb().map(|_| ()), expected);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use super::GuardedF64;
-use crate::FloatError;
+use crate::macros::ops_nonzero::nonzero_conversion;
+use crate::{FloatError, GuardedF32};
+use std::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroU8, NonZeroU16, NonZeroU32};
 
 impl TryFrom<f64> for GuardedF64 {
     type Error = FloatError;
@@ -29,7 +31,7 @@ impl TryFrom<f64> for GuardedF64 {
     /// assert!(inf_value.is_err());
     /// ```
     fn try_from(value: f64) -> Result<Self, Self::Error> {
-        Self::new(value)
+        Self::new_reported(value)
     }
 }
 
@@ -66,10 +68,85 @@ impl From<GuardedF64> for f64 {
     }
 }
 
+impl GuardedF64 {
+    /// Converts the value to a `GuardedF32`, clamping values outside of the `f32` range to
+    /// `GuardedF32::MIN` or `GuardedF32::MAX`.
+    ///
+    /// Values within the `f32` range are rounded to the nearest `f32`, as with `as f32`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF32, GuardedF64};
+    ///
+    /// let value = GuardedF64::new(1.5).unwrap();
+    /// assert_eq!(value.to_f32_saturating(), 1.5f32);
+    ///
+    /// assert_eq!(GuardedF64::MAX.to_f32_saturating(), GuardedF32::MAX);
+    /// assert_eq!(GuardedF64::MIN.to_f32_saturating(), GuardedF32::MIN);
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn to_f32_saturating(self) -> GuardedF32 {
+        let value = self.0 as f32;
+        if value.is_finite() {
+            GuardedF32(value)
+        } else if value.is_sign_positive() {
+            GuardedF32::MAX
+        } else {
+            GuardedF32::MIN
+        }
+    }
+
+    /// Converts the value to a `GuardedF32`, failing if it is outside of the `f32` range.
+    ///
+    /// Values within the `f32` range are rounded to the nearest `f32`, as with `as f32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the value is too large in magnitude to represent as an
+    /// `f32`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// let value = GuardedF64::new(1.5).unwrap();
+    /// assert_eq!(value.to_f32_checked(), Ok(1.5f32.try_into().unwrap()));
+    ///
+    /// assert_eq!(GuardedF64::MAX.to_f32_checked(), Err(FloatError::Infinity));
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn to_f32_checked(self) -> Result<GuardedF32, FloatError> {
+        GuardedF32::new(self.0 as f32)
+    }
+}
+
+nonzero_conversion!(
+    impl From<...(NonZeroI8, NonZeroI16, NonZeroI32, NonZeroU8, NonZeroU16, NonZeroU32)> for GuardedF64 as f64;
+    r"
+        Converts a non-zero integer to `GuardedF64`. Every value of the integer type is exactly
+        representable, so the conversion cannot fail.
+
+        # Example
+
+        ```rust
+        use floatguard::GuardedF64;
+        use std::num::NonZeroU16;
+
+        let value = NonZeroU16::new(42).unwrap();
+        assert_eq!(GuardedF64::from(value), GuardedF64::new(42.0).unwrap());
+        ```
+    "
+);
+
+#[cfg(not(feature = "strict-encapsulation"))]
 impl std::ops::Deref for GuardedF64 {
     type Target = f64;
 
-    /// Dereferences `GuardedF64` to its inner `f64` value.
+    /// Dereferences `GuardedF64` to its inner `f64` value. Unavailable when the `strict-encapsulation`
+    /// feature is enabled; use `GuardedF64::get` instead.
     ///
     /// # Returns
     ///
@@ -90,7 +167,7 @@ impl std::ops::Deref for GuardedF64 {
 
 #[cfg(test)]
 mod tests {
-    #![allow(clippy::float_cmp)]
+    #![allow(clippy::float_cmp, clippy::cast_possible_truncation)]
 
     use super::*;
     use crate::f64::tests::{invalid_f64, valid_f64};
@@ -101,7 +178,7 @@ mod tests {
         fn test_from_valid(a in valid_f64()) {
             prop_assert_eq!(GuardedF64::new(a), Ok(GuardedF64(a)));
             prop_assert_eq!(GuardedF64::new(a).map(f64::from), Ok(a));
-            prop_assert_eq!(*GuardedF64::new(a).unwrap(), a);
+            prop_assert_eq!(GuardedF64::new(a).unwrap().get(), a);
 
             prop_assert_eq!(GuardedF64::try_from(a), Ok(GuardedF64(a)));
         }
@@ -119,5 +196,28 @@ mod tests {
 
             prop_assert_eq!(GuardedF64::try_from(a), Err(float_error));
         }
+
+        #[test]
+        fn test_to_f32(a in valid_f64()) {
+            let value = GuardedF64::new(a).unwrap();
+            let saturated = value.to_f32_saturating();
+
+            if a.abs() <= f64::from(f32::MAX) {
+                prop_assert_eq!(saturated, a as f32);
+                prop_assert_eq!(value.to_f32_checked(), Ok(saturated));
+            } else {
+                prop_assert_eq!(saturated, f32::MAX.copysign(a as f32));
+            }
+
+            if (a as f32).is_infinite() {
+                prop_assert_eq!(value.to_f32_checked(), Err(FloatError::Infinity));
+            }
+        }
+
+        #[test]
+        fn test_from_nonzero(a in any::<NonZeroI16>(), b in any::<NonZeroU16>()) {
+            prop_assert_eq!(GuardedF64::from(a), GuardedF64(f64::from(a.get())));
+            prop_assert_eq!(GuardedF64::from(b), GuardedF64(f64::from(b.get())));
+        }
     }
 }
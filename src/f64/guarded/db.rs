@@ -0,0 +1,123 @@
+use super::GuardedF64;
+use crate::{FloatError, UnguardedF64};
+
+/// The offset between dBm, decibels relative to a milliwatt, and decibels relative to a watt.
+const DBM_OFFSET: f64 = 30.0;
+
+impl GuardedF64 {
+    /// Converts a power ratio to decibels, `10 * log10(self)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is zero or negative, where the logarithm is undefined.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// assert_eq!(GuardedF64::new(100.0).unwrap().to_db(), GuardedF64::new(20.0));
+    /// assert_eq!(GuardedF64::new(0.0).unwrap().to_db(), Err(FloatError::Domain));
+    /// ```
+    pub fn to_db(self) -> Result<Self, FloatError> {
+        let ratio =
+            UnguardedF64::from(self).check_where(|ratio| ratio > 0.0, FloatError::Domain)?;
+        Ok(Self(10.0 * ratio.0.log10()))
+    }
+
+    /// Converts decibels to a power ratio, `10^(self / 10)`. This is the inverse of `to_db`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the ratio is too large to represent, or
+    /// `FloatError::Underflow` if it is too small and would round to zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// assert_eq!(GuardedF64::new(-30.0).unwrap().from_db(), GuardedF64::new(0.001));
+    /// assert_eq!(GuardedF64::new(4000.0).unwrap().from_db(), Err(FloatError::Infinity));
+    /// assert_eq!(GuardedF64::new(-4000.0).unwrap().from_db(), Err(FloatError::Underflow));
+    /// ```
+    pub fn from_db(self) -> Result<Self, FloatError> {
+        power_of_ten(self.0 / 10.0)
+    }
+
+    /// Converts a power in dBm, decibels relative to a milliwatt, to watts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the power is too large to represent, or
+    /// `FloatError::Underflow` if it is too small and would round to zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// assert_eq!(GuardedF64::new(30.0).unwrap().dbm_to_watts(), GuardedF64::new(1.0));
+    /// assert_eq!(GuardedF64::new(0.0).unwrap().dbm_to_watts(), GuardedF64::new(0.001));
+    /// ```
+    pub fn dbm_to_watts(self) -> Result<Self, FloatError> {
+        power_of_ten((self.0 - DBM_OFFSET) / 10.0)
+    }
+
+    /// Converts a power in watts to dBm, decibels relative to a milliwatt. This is the inverse of
+    /// `dbm_to_watts`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is zero or negative, where the logarithm is undefined.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// assert_eq!(GuardedF64::new(0.001).unwrap().watts_to_dbm(), GuardedF64::new(0.0));
+    /// assert_eq!(GuardedF64::new(-1.0).unwrap().watts_to_dbm(), Err(FloatError::Domain));
+    /// ```
+    pub fn watts_to_dbm(self) -> Result<Self, FloatError> {
+        self.to_db().map(|db| Self(db.0 + DBM_OFFSET))
+    }
+}
+
+/// Returns `10^exponent`, reporting a result that rounds to zero as `FloatError::Underflow`.
+fn power_of_ten(exponent: f64) -> Result<GuardedF64, FloatError> {
+    let value = UnguardedF64::new(10f64.powf(exponent)).check_reported()?;
+    if value == 0.0 {
+        return Err(FloatError::Underflow);
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in -300.0..300.0f64) {
+            let db = GuardedF64(a);
+            let ratio = db.from_db().unwrap();
+            prop_assert!((ratio.to_db().unwrap().0 - a).abs() <= 1e-12 * a.abs().max(1.0));
+
+            let watts = db.dbm_to_watts().unwrap();
+            prop_assert!((watts.watts_to_dbm().unwrap().0 - a).abs() <= 1e-12 * a.abs().max(1.0));
+        }
+
+        #[test]
+        fn test_domain(a in valid_f64()) {
+            let value = GuardedF64(a);
+            prop_assert_eq!(value.to_db().is_ok(), a > 0.0);
+            prop_assert_eq!(value.watts_to_dbm().is_ok(), a > 0.0);
+            if let Ok(ratio) = value.from_db() {
+                prop_assert!(ratio > 0.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use super::GuardedF64;
+use crate::parse::format_hex;
+use std::fmt::{self, Display};
+
+impl GuardedF64 {
+    /// Formats the value in decimal notation, rounded to `sig_digits` significant digits.
+    ///
+    /// The value is rounded correctly, to the nearest representable decimal, and never uses
+    /// exponent notation, so very large or very small values produce long strings. Trailing zeros
+    /// are kept because they are significant. A `sig_digits` of zero is treated as one. Because the
+    /// value is always finite, the result can never be `NaN` or `inf`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let value = GuardedF64::new(1234.5678).unwrap();
+    /// assert_eq!(value.format_sig(3), "1230");
+    /// assert_eq!(value.format_sig(6), "1234.57");
+    ///
+    /// let value = GuardedF64::new(0.000_123_45).unwrap();
+    /// assert_eq!(value.format_sig(2), "0.00012");
+    ///
+    /// let value = GuardedF64::new(-9.996).unwrap();
+    /// assert_eq!(value.format_sig(3), "-10.0");
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn format_sig(self, sig_digits: u8) -> String {
+        let precision = usize::from(sig_digits.max(1)) - 1;
+        let scientific = format!("{:.precision$e}", self.0);
+
+        // Exponent formatting of a finite value always has the shape `-d.ddde-x`.
+        let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+        let exponent = exponent.parse::<isize>().unwrap_or(0);
+        let (sign, mantissa) = mantissa
+            .strip_prefix('-')
+            .map_or(("", mantissa), |mantissa| ("-", mantissa));
+        let digits = mantissa.replace('.', "");
+
+        // The number of digits before the decimal point, which may be zero or negative.
+        let whole = exponent + 1;
+        match usize::try_from(whole) {
+            Ok(whole) if whole >= digits.len() => {
+                format!("{sign}{digits}{}", "0".repeat(whole - digits.len()))
+            }
+            Ok(whole) if whole > 0 => format!("{sign}{}.{}", &digits[..whole], &digits[whole..]),
+            _ => format!("{sign}0.{}{digits}", "0".repeat(whole.unsigned_abs())),
+        }
+    }
+
+    /// Formats the value in decimal notation with exactly `decimals` digits after the decimal
+    /// point, rounded correctly. Because the value is always finite, the result can never be `NaN`
+    /// or `inf`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let value = GuardedF64::new(2.0 / 3.0).unwrap();
+    /// assert_eq!(value.format_fixed(2), "0.67");
+    /// assert_eq!(value.format_fixed(0), "1");
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn format_fixed(self, decimals: u8) -> String {
+        self.format_decimal_fixed(decimals).to_string()
+    }
+
+    /// Returns a value that displays like `format_fixed(scale)`, with exactly `scale` digits after
+    /// the decimal point, without allocating.
+    ///
+    /// The digits are written straight into the formatter, so this can be used with `write!` in
+    /// logging and embedded code where the formatting path must not allocate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use std::fmt::Write;
+    ///
+    /// let value = GuardedF64::new(-3.14159).unwrap();
+    /// let mut line = String::with_capacity(32);
+    /// write!(line, "reading={}", value.format_decimal_fixed(3)).unwrap();
+    /// assert_eq!(line, "reading=-3.142");
+    /// ```
+    #[must_use = "method returns a value to display and does not mutate the original value"]
+    pub fn format_decimal_fixed(self, scale: u8) -> impl Display + Copy {
+        FixedDecimal {
+            value: self.0,
+            scale: usize::from(scale),
+        }
+    }
+
+    /// Formats the value as a hexadecimal floating-point number in the style of C's `%a`, like
+    /// `0x1.8p+3`.
+    ///
+    /// Unlike decimal formatting, hex-floats describe the binary value exactly, so the result can be
+    /// parsed back with `from_hex_str` to the same bits on every platform. Trailing zeros of the
+    /// fraction are omitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let value = GuardedF64::new(12.0).unwrap();
+    /// assert_eq!(value.to_hex_string(), "0x1.8p+3");
+    /// assert_eq!(GuardedF64::from_hex_str(&value.to_hex_string()), Ok(value));
+    ///
+    /// assert_eq!(GuardedF64::new(-1.0).unwrap().to_hex_string(), "-0x1p+0");
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn to_hex_string(self) -> String {
+        format_hex(self.0)
+    }
+}
+
+/// Displays a value with a fixed number of digits after the decimal point.
+#[derive(Clone, Copy)]
+struct FixedDecimal {
+    value: f64,
+    scale: usize,
+}
+
+impl Display for FixedDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*}", self.scale, self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_format_sig_round_trip(a in valid_f64()) {
+            let value = GuardedF64::new(a).unwrap();
+            prop_assert_eq!(value.format_sig(17).parse::<f64>().unwrap(), a);
+        }
+
+        #[test]
+        fn test_format_sig_precision(a in valid_f64(), sig_digits in 1u8..17) {
+            let formatted = GuardedF64::new(a).unwrap().format_sig(sig_digits);
+            let rounded = format!("{:.*e}", usize::from(sig_digits) - 1, a);
+
+            prop_assert!(!formatted.contains('e'));
+            prop_assert_eq!(formatted.parse::<f64>().unwrap(), rounded.parse::<f64>().unwrap());
+        }
+
+        #[test]
+        fn test_to_hex_string_round_trip(a in valid_f64()) {
+            let value = GuardedF64::new(a).unwrap();
+            let hex = value.to_hex_string();
+            prop_assert_eq!(GuardedF64::from_hex_str(&hex).map(|v| v.0.to_bits()), Ok(a.to_bits()));
+        }
+
+        #[test]
+        fn test_format_fixed(a in valid_f64(), decimals in 0u8..20) {
+            let value = GuardedF64::new(a).unwrap();
+            prop_assert_eq!(value.format_fixed(decimals), format!("{:.*}", usize::from(decimals), a));
+        }
+    }
+}
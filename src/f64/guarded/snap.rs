@@ -0,0 +1,141 @@
+use super::GuardedF64;
+use crate::FloatError;
+
+/// The magnitude from which every `f64` is an integer, so rounding cannot change it.
+const INTEGRAL: f64 = 1.0 / f64::EPSILON;
+
+impl GuardedF64 {
+    /// Rounds the value to the nearest multiple of `step`, with halfway cases rounded away from
+    /// zero. The sign of `step` is ignored.
+    ///
+    /// The naive `(x / step).round() * step` leaves errors like `0.30000000000000004` when `step`
+    /// is a decimal fraction, and overflows when `step` is tiny. When `step` is the reciprocal of an
+    /// integer, such as `0.1` or `0.25`, the multiple is computed by dividing by that integer
+    /// instead, so the result is the `f64` closest to the decimal multiple. When the value is so
+    /// much larger than `step` that it has no fractional steps left to round, it is returned
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if `step` is zero, or `FloatError::Infinity` if the
+    /// nearest multiple is too large to represent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// let value = GuardedF64::new(0.34).unwrap();
+    /// assert_eq!(value.quantize(GuardedF64::new(0.1).unwrap()), GuardedF64::new(0.3));
+    /// assert_eq!(value.quantize(GuardedF64::new(0.25).unwrap()), GuardedF64::new(0.25));
+    /// assert_eq!(value.quantize(GuardedF64::new(5.0).unwrap()), GuardedF64::new(0.0));
+    ///
+    /// assert_eq!(value.quantize(GuardedF64::new(0.0).unwrap()), Err(FloatError::DivisionByZero));
+    /// let step = (GuardedF64::MAX * 0.6).check().unwrap();
+    /// assert_eq!(GuardedF64::MAX.quantize(step), Err(FloatError::Infinity));
+    /// ```
+    pub fn quantize(self, step: Self) -> Result<Self, FloatError> {
+        let step = step.0.abs();
+        if step == 0.0 {
+            return Err(FloatError::DivisionByZero);
+        }
+
+        let divisor = 1.0 / step;
+        if divisor.is_finite() && divisor.fract() == 0.0 {
+            return Ok(snap(self, divisor));
+        }
+
+        let steps = self.0 / step;
+        if steps.abs() >= INTEGRAL {
+            return Ok(self);
+        }
+        Self::new(steps.round() * step)
+    }
+
+    /// Rounds the value to `decimals` digits after the decimal point, with halfway cases rounded
+    /// away from zero.
+    ///
+    /// The result is the `f64` closest to the rounded decimal, so it formats with at most
+    /// `decimals` digits. Like any decimal, halfway cases are judged by the binary value, so
+    /// `2.675`, which is stored as `2.67499999...`, rounds down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let value = GuardedF64::new(1.005_1).unwrap();
+    /// assert_eq!(value.round_to_decimals(2), GuardedF64::new(1.01).unwrap());
+    /// assert_eq!(value.round_to_decimals(0), GuardedF64::new(1.0).unwrap());
+    /// assert_eq!(GuardedF64::new(-0.125).unwrap().round_to_decimals(2).to_string(), "-0.13");
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn round_to_decimals(self, decimals: u8) -> Self {
+        snap(self, 10f64.powi(i32::from(decimals)))
+    }
+}
+
+/// Rounds `value` to the nearest multiple of `1 / divisor`, returning it unchanged if the scaled
+/// value has no fractional part left to round.
+fn snap(value: GuardedF64, divisor: f64) -> GuardedF64 {
+    let scaled = value.0 * divisor;
+    if scaled.abs() < INTEGRAL {
+        // Rounding does not increase the magnitude of `scaled`, so the result stays finite.
+        GuardedF64(scaled.round() / divisor)
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_decimal_steps() {
+        for (value, step, expected) in [(0.7, 0.1, 0.7), (1.15, 0.05, 1.15), (-2.26, 0.01, -2.26)] {
+            assert_eq!(
+                GuardedF64(value).quantize(GuardedF64(step)),
+                Ok(GuardedF64(expected))
+            );
+        }
+        assert_eq!(
+            GuardedF64(0.3).quantize(GuardedF64(-0.1)),
+            Ok(GuardedF64(0.3))
+        );
+    }
+
+    #[test]
+    fn test_extreme_steps() {
+        let tiny = GuardedF64(5e-324);
+        assert_eq!(GuardedF64::MAX.quantize(tiny), Ok(GuardedF64::MAX));
+        assert_eq!(
+            GuardedF64(1e-300).quantize(GuardedF64::MAX),
+            Ok(GuardedF64(0.0))
+        );
+        assert_eq!(GuardedF64(0.5).round_to_decimals(u8::MAX), GuardedF64(0.5));
+        assert_eq!(GuardedF64::MAX.round_to_decimals(3), GuardedF64::MAX);
+    }
+
+    proptest! {
+        #[test]
+        fn test_quantize_is_nearest_multiple(a in -1e6..1e6f64, step in 1e-3..1e3f64) {
+            let snapped = GuardedF64(a).quantize(GuardedF64(step)).unwrap();
+            let steps = snapped.0 / step;
+            prop_assert!((steps - steps.round()).abs() <= 1e-6 * steps.abs().max(1.0));
+            prop_assert!((snapped.0 - a).abs() <= step / 2.0 * (1.0 + 1e-9));
+        }
+
+        #[test]
+        fn test_round_to_decimals(a in valid_f64(), decimals in 0u8..20) {
+            let rounded = GuardedF64(a).round_to_decimals(decimals);
+            let formatted = format!("{:.*}", usize::from(decimals), a).parse::<f64>().unwrap();
+            let tolerance = a.abs().mul_add(f64::EPSILON, 0.5 / 10f64.powi(i32::from(decimals)));
+            prop_assert!((rounded.0 - formatted).abs() <= tolerance);
+        }
+    }
+}
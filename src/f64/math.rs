@@ -52,6 +52,101 @@ math!(
     }
 );
 
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Returns a number that represents the sign of `self`, like `signum`, but returns `0.0` for
+        both `0.0` and `-0.0` instead of `1.0` and `-1.0`. `GuardedF64::signum_or_zero` returns a
+        `GuardedF64` type because any value that is not NaN or infinite is guaranteed to return a
+        valid value.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+
+        let neg = GuardedF64::new(-3.5_f64).unwrap();
+        assert_eq!(neg.signum_or_zero(), -1.0);
+
+        let zero = GuardedF64::new(-0.0_f64).unwrap();
+        assert_eq!(zero.signum(), -1.0);
+        assert_eq!(zero.signum_or_zero(), 0.0);
+
+        assert!(UnguardedF64::new(f64::NAN).signum_or_zero().check().is_err());
+        ```
+    "
+    const fn signum_or_zero(value: f64) -> Self {
+        if value == 0.0 {
+            Self(0.0)
+        } else {
+            Self(value.signum())
+        }
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Returns `true` if `self` is greater than zero. Unlike `is_sign_positive`, this is `false`
+        for `0.0`, and for NaN.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+
+        assert!(GuardedF64::new(1e-300_f64).unwrap().is_positive_strict());
+        assert!(!GuardedF64::new(0.0_f64).unwrap().is_positive_strict());
+        assert!(!UnguardedF64::new(f64::NAN).is_positive_strict());
+        ```
+    "
+    const fn is_positive_strict(value: f64) -> bool {
+        value > 0.0
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Returns `true` if `self` is less than zero. Unlike `is_sign_negative`, this is `false` for
+        `-0.0`, and for NaN.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+
+        assert!(GuardedF64::new(-1e-300_f64).unwrap().is_negative_strict());
+        assert!(!GuardedF64::new(-0.0_f64).unwrap().is_negative_strict());
+        assert!(!UnguardedF64::new(-f64::NAN).is_negative_strict());
+        ```
+    "
+    const fn is_negative_strict(value: f64) -> bool {
+        value < 0.0
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Negates `self` if `condition` is `true`, without branching on `condition`. `GuardedF64::flip_sign_if`
+        returns a `GuardedF64` type because negation cannot produce NaN or infinity.
+
+        # Examples
+
+        ```rust
+        use floatguard::GuardedF64;
+
+        let value = GuardedF64::new(2.5_f64).unwrap();
+        assert_eq!(value.flip_sign_if(true), -2.5);
+        assert_eq!(value.flip_sign_if(false), 2.5);
+        ```
+    "
+    const fn flip_sign_if(value: f64, condition: bool) -> Self {
+        Self(f64::from_bits(value.to_bits() ^ ((condition as u64) << 63)))
+    }
+);
+
 math!(
     (GuardedF64, UnguardedF64)
     r"
@@ -658,6 +753,89 @@ math!(
     }
 );
 
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Computes the quotient and remainder of dividing `self` by `rhs` in one call.
+
+        Like integer `/` and `%`, the quotient is truncated toward zero and the remainder has the
+        sign of `self`, so `quotient * rhs + remainder` equals `self` up to rounding. The remainder
+        is exact, and the quotient is computed from it, so the two are always consistent.
+
+        # Examples
+
+        ```rust
+        use floatguard::{FloatError, GuardedF64};
+
+        let minutes = GuardedF64::new(-135.5).unwrap();
+        let (hours, rest) = minutes.div_rem(60.0);
+        assert_eq!(hours.check(), GuardedF64::new(-2.0));
+        assert_eq!(rest.check(), GuardedF64::new(-15.5));
+
+        let (quotient, remainder) = minutes.div_rem(0.0);
+        assert_eq!(quotient.check(), Err(FloatError::NaN));
+        assert_eq!(remainder.check(), Err(FloatError::NaN));
+        ```
+    "
+    fn div_rem(base: f64, rhs: impl Into<UnguardedF64>) -> (UnguardedF64, UnguardedF64) {
+        let lhs = UnguardedF64::new(base);
+        let rhs = rhs.into();
+        let remainder = lhs % rhs;
+
+        // `lhs - remainder` is a multiple of `rhs`, so the division only needs rounding to remove
+        // the error of the subtraction.
+        let quotient = UnguardedF64::new(((lhs - remainder) / rhs).0.round());
+        (quotient, remainder)
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Converts radians to degrees.
+
+        See: [`f64::to_degrees`]
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+
+        let angle = GuardedF64::PI;
+        let abs_difference = (angle.to_degrees() - 180.0).abs().check().unwrap();
+        assert!(abs_difference < 1e-10);
+
+        assert!(GuardedF64::MAX.to_degrees().check().is_err());
+        ```
+    "
+    const fn to_degrees(value: f64) -> UnguardedF64 {
+        UnguardedF64(value.to_degrees())
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Converts degrees to radians. `GuardedF64::to_radians` returns a `GuardedF64` type because the
+        conversion only makes a value smaller, so it is guaranteed to return a valid value.
+
+        See: [`f64::to_radians`]
+
+        # Examples
+
+        ```rust
+        use floatguard::GuardedF64;
+
+        let angle = GuardedF64::new(180.0).unwrap();
+        let abs_difference = (angle.to_radians() - GuardedF64::PI).abs().check().unwrap();
+        assert!(abs_difference < 1e-10);
+        ```
+    "
+    const fn to_radians(value: f64) -> Self {
+        Self(value.to_radians())
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use crate::{GuardedF64, UnguardedF64};
@@ -683,6 +861,26 @@ mod tests {
             prop_assert_eq!(UnguardedF64::new(a).signum().check(), expected);
         }
 
+        #[test]
+        fn test_sign_helpers(a in any::<f64>(), condition in any::<bool>()) {
+            let unguarded = UnguardedF64::new(a);
+            let signum = if a == 0.0 { GuardedF64::new(0.0) } else { GuardedF64::new(a.signum()) };
+            let flipped = if condition { -a } else { a };
+
+            prop_assert_eq!(unguarded.signum_or_zero().check(), signum);
+            prop_assert_eq!(unguarded.is_positive_strict(), a > 0.0);
+            prop_assert_eq!(unguarded.is_negative_strict(), a < 0.0);
+            prop_assert_eq!(unguarded.flip_sign_if(condition).0.to_bits(), flipped.to_bits());
+
+            if a.is_finite() {
+                let guarded = GuardedF64::new(a).unwrap();
+                prop_assert_eq!(Ok(guarded.signum_or_zero()), signum);
+                prop_assert_eq!(guarded.is_positive_strict(), a > 0.0);
+                prop_assert_eq!(guarded.is_negative_strict(), a < 0.0);
+                prop_assert_eq!(guarded.flip_sign_if(condition).0.to_bits(), flipped.to_bits());
+            }
+        }
+
         #[test]
         fn test_sqrt_valid(a in any::<f64>()) {
             let expected = GuardedF64::new(a.sqrt());
@@ -908,5 +1106,23 @@ mod tests {
             prop_assert_eq!(sin.check(), expected_sin);
             prop_assert_eq!(cos.check(), expected_cos);
         }
+
+        #[test]
+        fn test_to_degrees_valid(a in any::<f64>()) {
+            let expected = GuardedF64::new(a.to_degrees());
+            if a.is_finite() {
+                prop_assert_eq!(GuardedF64::new(a).unwrap().to_degrees().check(), expected);
+            }
+            prop_assert_eq!(UnguardedF64::new(a).to_degrees().check(), expected);
+        }
+
+        #[test]
+        fn test_to_radians_valid(a in any::<f64>()) {
+            let expected = GuardedF64::new(a.to_radians());
+            if a.is_finite() {
+                prop_assert_eq!(Ok(GuardedF64::new(a).unwrap().to_radians()), expected);
+            }
+            prop_assert_eq!(UnguardedF64::new(a).to_radians().check(), expected);
+        }
     }
 }
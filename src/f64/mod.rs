@@ -1,29 +1,55 @@
+mod activation;
+#[cfg(feature = "chrono")]
+mod chrono;
 mod consts;
 mod convert;
 mod guarded;
 mod math;
 mod ops_binary;
 mod ops_unary;
+mod parse;
+mod pow2;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod seconds;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "special")]
+mod special;
+#[cfg(feature = "time")]
+mod time;
 mod unguarded;
+#[cfg(feature = "uom")]
+mod uom;
+#[cfg(feature = "utoipa")]
+mod utoipa;
 
 pub use guarded::GuardedF64;
+#[cfg(feature = "serde")]
+pub use serde::LenientF64;
+#[cfg(feature = "serde_json")]
+pub use serde_json::JsonValueError;
 pub use unguarded::UnguardedF64;
 
 #[cfg(test)]
-mod tests {
+pub mod tests {
+    use crate::fixtures::{INVALID_F64, VALID_F64};
     use proptest::prelude::*;
 
-    const INVALID_VALUES: &[f64; 3] = &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
-
     pub fn valid_f64() -> impl Strategy<Value = f64> {
-        // Avoid NaN, ±∞, and subnormals.
-        // This gives good coverage while staying in safe computation territory.
-        (f64::MIN..=f64::MAX).prop_filter("Reject NaN and infinities", |v| {
-            v.is_finite() && !v.is_nan()
-        })
+        // Mostly random finite values, with the edge cases of the fixture corpus mixed in.
+        prop_oneof![
+            9 => (f64::MIN..=f64::MAX).prop_filter("Reject NaN and infinities", |v| {
+                v.is_finite() && !v.is_nan()
+            }),
+            1 => prop::sample::select(&VALID_F64[..]),
+        ]
     }
 
     pub fn invalid_f64() -> impl Strategy<Value = f64> {
-        prop::sample::select(INVALID_VALUES)
+        prop::sample::select(&INVALID_F64[..])
     }
 }
@@ -1,9 +1,14 @@
 use super::{GuardedF64, UnguardedF64};
 use crate::macros::ops_binary::binary_operation;
-use std::ops::{Add, Div, Mul, Rem, Sub};
+use crate::macros::ops_nonzero::nonzero_division;
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+use std::ops::{Add, Div, DivAssign, Mul, Rem, Sub};
 
 binary_operation!(
-    impl Add for ...(GuardedF64, UnguardedF64) {
+    impl Add for ...(GuardedF64, UnguardedF64) and ...(i8, i16, i32, u8, u16, u32) {
         r"
             Adds two `GuardedF64` values or a `GuardedF64` and a `f64`.
 
@@ -26,7 +31,7 @@ binary_operation!(
 );
 
 binary_operation!(
-    impl Sub for ...(GuardedF64, UnguardedF64) {
+    impl Sub for ...(GuardedF64, UnguardedF64) and ...(i8, i16, i32, u8, u16, u32) {
         r"
             Subtracts one `GuardedF64` value from another or a `f64` from a `GuardedF64`.
 
@@ -49,7 +54,7 @@ binary_operation!(
 );
 
 binary_operation!(
-    impl Mul for ...(GuardedF64, UnguardedF64) {
+    impl Mul for ...(GuardedF64, UnguardedF64) and ...(i8, i16, i32, u8, u16, u32) {
         r"
             Multiplies two `GuardedF64` values or a `GuardedF64` and a `f64`.
 
@@ -72,7 +77,7 @@ binary_operation!(
 );
 
 binary_operation!(
-    impl Div for ...(GuardedF64, UnguardedF64) {
+    impl Div for ...(GuardedF64, UnguardedF64) and ...(i8, i16, i32, u8, u16, u32) {
         r"
             Divides one `GuardedF64` value by another or a `f64` by a `GuardedF64`.
 
@@ -117,7 +122,7 @@ binary_operation!(
 );
 
 binary_operation!(
-    impl Rem for ...(GuardedF64, UnguardedF64) {
+    impl Rem for ...(GuardedF64, UnguardedF64) and ...(i8, i16, i32, u8, u16, u32) {
         r"
             Computes the remainder of division between two `GuardedF64` values or a `GuardedF64` and
             a `f64`.
@@ -164,12 +169,37 @@ binary_operation!(
     }
 );
 
+nonzero_division!(
+    impl Div<...(NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize)> for (GuardedF64, UnguardedF64) as f64;
+    r"
+        Divides a `GuardedF64` or `UnguardedF64` value by a non-zero integer.
+
+        The divisor has a magnitude of at least one, so dividing a `GuardedF64` cannot overflow or
+        produce NaN, and the result is a `GuardedF64` that does not need to be checked.
+
+        # Example
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+        use std::num::{NonZeroI32, NonZeroU64};
+
+        let value = GuardedF64::new(6.0).unwrap();
+        assert_eq!(value / NonZeroI32::new(-3).unwrap(), GuardedF64::new(-2.0).unwrap());
+        assert_eq!(GuardedF64::MAX / NonZeroU64::new(1).unwrap(), GuardedF64::MAX);
+
+        let value = UnguardedF64::new(f64::INFINITY);
+        assert!((value / NonZeroU64::new(2).unwrap()).check().is_err());
+        ```
+    "
+);
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::op_ref)]
 
     use crate::{GuardedF64, UnguardedF64};
     use proptest::prelude::*;
+    use std::num::{NonZeroI64, NonZeroU128};
 
     proptest! {
         #[test]
@@ -438,5 +468,36 @@ mod tests {
             prop_assert_eq!((&a % unguarded_b).check(), expected);
             prop_assert_eq!((&a % &unguarded_b).check(), expected);
         }
+
+        #[test]
+        #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+        fn test_division_by_nonzero(a in any::<f64>(), b in any::<NonZeroI64>(), c in any::<NonZeroU128>()) {
+            let unguarded_a = UnguardedF64::new(a);
+            prop_assert_eq!((unguarded_a / b).check(), GuardedF64::new(a / b.get() as f64));
+            prop_assert_eq!((&unguarded_a / &c).check(), GuardedF64::new(a / c.get() as f64));
+
+            if a.is_finite() {
+                let mut guarded_a = GuardedF64::new(a).unwrap();
+                prop_assert_eq!(guarded_a / b, GuardedF64::new(a / b.get() as f64).unwrap());
+                prop_assert_eq!(&guarded_a / &c, GuardedF64::new(a / c.get() as f64).unwrap());
+
+                guarded_a /= b;
+                prop_assert_eq!(guarded_a, GuardedF64::new(a / b.get() as f64).unwrap());
+            }
+        }
+
+        #[test]
+        fn test_integer_operands(a in any::<f64>(), b in any::<i32>(), c in any::<u8>()) {
+            let unguarded_a = UnguardedF64::new(a);
+            prop_assert_eq!((unguarded_a * b).check(), GuardedF64::new(a * f64::from(b)));
+            prop_assert_eq!((&c - unguarded_a).check(), GuardedF64::new(f64::from(c) - a));
+
+            if a.is_finite() {
+                let guarded_a = GuardedF64::new(a).unwrap();
+                prop_assert_eq!((guarded_a + &b).check(), GuardedF64::new(a + f64::from(b)));
+                prop_assert_eq!((b / guarded_a).check(), (f64::from(b) / guarded_a).check());
+                prop_assert_eq!((c % &guarded_a).check(), GuardedF64::new(f64::from(c) % a));
+            }
+        }
     }
 }
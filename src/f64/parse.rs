@@ -0,0 +1,297 @@
+use super::{GuardedF64, UnguardedF64};
+use crate::parse::{
+    Encoding, Exactness, Format, ParseError, ParseOptions, PercentOptions, detect,
+    parse_hex_prefix, percent_to_decimal,
+};
+use std::str::FromStr;
+
+impl GuardedF64 {
+    /// Parses a `GuardedF64` from a string, rejecting numbers whose decimal exponent is beyond the
+    /// limit in `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::ExponentOutOfRange` if the decimal exponent is beyond the limit,
+    /// `ParseError::Malformed` if the string is not a number, or `ParseError::Invalid` if it parses
+    /// to NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use floatguard::parse::{ParseError, ParseOptions};
+    ///
+    /// let options = ParseOptions::default().with_max_exponent(308);
+    /// assert_eq!(GuardedF64::parse_with("-2.5e3", options), GuardedF64::new(-2500.0).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF64::parse_with("1e-400", options), Err(ParseError::ExponentOutOfRange { exponent: -400 }));
+    /// assert_eq!(GuardedF64::parse_with("1.8e308", options), Err(ParseError::Invalid(FloatError::Infinity)));
+    /// assert_eq!(GuardedF64::parse_with("twelve", options), Err(ParseError::Malformed));
+    /// ```
+    pub fn parse_with(s: &str, options: ParseOptions) -> Result<Self, ParseError> {
+        options
+            .parse(s, |s| s.parse::<f64>().ok())
+            .and_then(|value| {
+                UnguardedF64::new(value)
+                    .check_reported()
+                    .map_err(ParseError::Invalid)
+            })
+    }
+
+    /// Parses a percentage, like `12.5%`, as the fraction it stands for, like `0.125`.
+    ///
+    /// The percent sign may come before or after the number, and be separated from it by
+    /// whitespace. Both decimal points and decimal commas are accepted: when a comma and a point
+    /// both appear, the last one is the decimal separator and the other one groups thousands, a
+    /// single comma is a decimal comma, and repeated commas group thousands. The fraction is
+    /// rounded once, from the decimal digits, so `0.1%` parses to the closest `f64` to `0.001`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a percentage, or `ParseError::Invalid`
+    /// if the fraction is too large to be finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use floatguard::parse::ParseError;
+    ///
+    /// assert_eq!(GuardedF64::parse_percent("12.5%"), GuardedF64::new(0.125).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF64::parse_percent("-1.234,5 %"), GuardedF64::new(-12.345).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF64::parse_percent("12.5"), Err(ParseError::Malformed));
+    /// ```
+    pub fn parse_percent(s: &str) -> Result<Self, ParseError> {
+        Self::parse_percent_with(s, PercentOptions::default())
+    }
+
+    /// Parses a percentage like `parse_percent`, and checks that the fraction is within the range
+    /// in `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a percentage, `ParseError::Invalid` if
+    /// the fraction is too large to be finite, or `ParseError::Invalid(FloatError::Domain)` if it
+    /// is outside of the range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use floatguard::parse::{ParseError, PercentOptions};
+    ///
+    /// let options = PercentOptions::default().with_range(0.0, 1.0);
+    /// assert_eq!(GuardedF64::parse_percent_with("% 75", options), GuardedF64::new(0.75).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF64::parse_percent_with("-5%", options), Err(ParseError::Invalid(FloatError::Domain)));
+    /// ```
+    pub fn parse_percent_with(s: &str, options: PercentOptions) -> Result<Self, ParseError> {
+        let value = percent_to_decimal(s)
+            .and_then(|decimal| decimal.parse::<f64>().ok())
+            .ok_or(ParseError::Malformed)?;
+        let value = UnguardedF64::new(value)
+            .check_reported()
+            .map_err(ParseError::Invalid)?;
+
+        options.check(value.0)?;
+        Ok(value)
+    }
+
+    /// Parses a `GuardedF64` from a decimal number, such as a database `NUMERIC` value, and reports
+    /// whether it was rounded to fit, and by how much, so precision loss can be logged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a number, or `ParseError::Invalid` if it
+    /// parses to NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use floatguard::parse::{Exactness, ParseError};
+    ///
+    /// let (value, exactness) = GuardedF64::from_numeric_str("-1234.5000").unwrap();
+    /// assert_eq!(value, -1234.5);
+    /// assert_eq!(exactness, Exactness::Exact);
+    ///
+    /// let (_, exactness) = GuardedF64::from_numeric_str("0.1").unwrap();
+    /// assert_eq!(exactness, Exactness::Rounded { error: GuardedF64::new(-5.551115123125783e-18).unwrap() });
+    ///
+    /// assert_eq!(GuardedF64::from_numeric_str("12,5"), Err(ParseError::Malformed));
+    /// ```
+    pub fn from_numeric_str(s: &str) -> Result<(Self, Exactness), ParseError> {
+        let value = s.parse::<Self>()?;
+        Ok((value, Exactness::of(s, value.0)))
+    }
+
+    /// Parses a `GuardedF64` from a hexadecimal floating-point number, like `0x1.8p3`, as written
+    /// by `to_hex_string`.
+    ///
+    /// The number has an optional sign, a `0x` prefix, hexadecimal digits with an optional point,
+    /// and an optional binary exponent. Numbers with more significant digits than fit in an `f64`
+    /// are rounded to the nearest value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a hex-float, or `ParseError::Invalid` if
+    /// the number is too large to be finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use floatguard::parse::ParseError;
+    ///
+    /// assert_eq!(GuardedF64::from_hex_str("-0x1.8p3"), GuardedF64::new(-12.0).map_err(ParseError::Invalid));
+    /// assert_eq!(GuardedF64::from_hex_str("0x1p99999"), Err(ParseError::Invalid(FloatError::Infinity)));
+    /// assert_eq!(GuardedF64::from_hex_str("12.0"), Err(ParseError::Malformed));
+    /// ```
+    pub fn from_hex_str(s: &str) -> Result<Self, ParseError> {
+        let value = match parse_hex_prefix(s.as_bytes(), Format::F64) {
+            Some((bits, consumed)) if consumed == s.len() => f64::from_bits(bits),
+            _ => return Err(ParseError::Malformed),
+        };
+
+        UnguardedF64::new(value)
+            .check_reported()
+            .map_err(ParseError::Invalid)
+    }
+
+    /// Parses a `GuardedF64` from the start of `bytes`, detecting whether it is written as a
+    /// decimal number, a hex-float like `0x1.8p3`, or a little-endian binary `f64`, and returns it
+    /// with the number of bytes consumed.
+    ///
+    /// Bytes that start like a number, after an optional sign, are parsed as text up to the end of
+    /// the number, so a value can be read from the front of a larger buffer. Anything else is read
+    /// as the 8 bytes of a binary `f64`. Hex-floats with more significant digits than fit are
+    /// rounded to the nearest value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if binary input is shorter than 8 bytes, or
+    /// `ParseError::Invalid` if the number is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use floatguard::parse::ParseError;
+    ///
+    /// let twelve = GuardedF64::new(12.0).unwrap();
+    /// assert_eq!(GuardedF64::from_bytes_auto(b"0x1.8p3 rest"), Ok((twelve, 7)));
+    /// assert_eq!(GuardedF64::from_bytes_auto(b"1.2e1,"), Ok((twelve, 5)));
+    /// assert_eq!(GuardedF64::from_bytes_auto(&12f64.to_le_bytes()), Ok((twelve, 8)));
+    ///
+    /// assert_eq!(GuardedF64::from_bytes_auto(b"1e999"), Err(ParseError::Invalid(FloatError::Infinity)));
+    /// assert_eq!(GuardedF64::from_bytes_auto(b"abc"), Err(ParseError::Malformed));
+    /// ```
+    pub fn from_bytes_auto(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let (value, consumed) = match detect(bytes) {
+            Encoding::Hex => parse_hex_prefix(bytes, Format::F64)
+                .map(|(bits, consumed)| (f64::from_bits(bits), consumed))
+                .ok_or(ParseError::Malformed)?,
+            Encoding::Decimal(len) => bytes
+                .get(..len)
+                .and_then(|digits| std::str::from_utf8(digits).ok())
+                .and_then(|s| s.parse::<f64>().ok())
+                .map(|value| (value, len))
+                .ok_or(ParseError::Malformed)?,
+            Encoding::Binary => bytes
+                .first_chunk()
+                .map(|chunk| (f64::from_le_bytes(*chunk), 8))
+                .ok_or(ParseError::Malformed)?,
+        };
+
+        UnguardedF64::new(value)
+            .check_reported()
+            .map(|value| (value, consumed))
+            .map_err(ParseError::Invalid)
+    }
+}
+
+impl FromStr for GuardedF64 {
+    type Err = ParseError;
+
+    /// Parses a `GuardedF64` from a string, without a limit on the exponent.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Malformed` if the string is not a number, or `ParseError::Invalid` if it
+    /// parses to NaN or infinity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use floatguard::parse::ParseError;
+    ///
+    /// assert_eq!("1.5".parse::<GuardedF64>(), GuardedF64::new(1.5).map_err(ParseError::Invalid));
+    /// assert_eq!("1e400".parse::<GuardedF64>(), Err(ParseError::Invalid(FloatError::Infinity)));
+    /// assert_eq!("NaN".parse::<GuardedF64>(), Err(ParseError::Invalid(FloatError::NaN)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, ParseOptions::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_from_bytes_auto_errors() {
+        assert_eq!(
+            GuardedF64::from_bytes_auto(&f64::NAN.to_le_bytes()),
+            Err(ParseError::Invalid(FloatError::NaN))
+        );
+        assert_eq!(
+            GuardedF64::from_bytes_auto(b"-0x1p99999"),
+            Err(ParseError::Invalid(FloatError::Infinity))
+        );
+        assert_eq!(
+            GuardedF64::from_bytes_auto(b"x"),
+            Err(ParseError::Malformed)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f64()) {
+            let options = ParseOptions::default().with_max_exponent(324);
+            prop_assert_eq!(a.to_string().parse::<GuardedF64>(), Ok(GuardedF64(a)));
+            prop_assert_eq!(GuardedF64::parse_with(&format!("{a:e}"), options), Ok(GuardedF64(a)));
+        }
+
+        #[test]
+        fn test_exponent_limit(exponent in -400i64..400, limit in 0u32..400) {
+            let options = ParseOptions::default().with_max_exponent(limit);
+            let result = GuardedF64::parse_with(&format!("1e{exponent}"), options);
+
+            if exponent.unsigned_abs() > u64::from(limit) {
+                prop_assert_eq!(result, Err(ParseError::ExponentOutOfRange { exponent }));
+            } else if exponent > 308 {
+                prop_assert_eq!(result, Err(ParseError::Invalid(FloatError::Infinity)));
+            } else {
+                prop_assert!(result.is_ok());
+            }
+        }
+
+        #[test]
+        fn test_from_bytes_auto_round_trip(a in valid_f64()) {
+            let text = format!("{a};{a:e}");
+            let (first, consumed) = GuardedF64::from_bytes_auto(text.as_bytes()).unwrap();
+            prop_assert_eq!(first, GuardedF64(a));
+            prop_assert_eq!(text.as_bytes()[consumed], b';');
+            prop_assert_eq!(
+                GuardedF64::from_bytes_auto(&text.as_bytes()[consumed + 1..]),
+                Ok((GuardedF64(a), text.len() - consumed - 1))
+            );
+
+            let bytes = a.to_le_bytes();
+            prop_assume!(detect(&bytes) == Encoding::Binary);
+            prop_assert_eq!(GuardedF64::from_bytes_auto(&bytes), Ok((GuardedF64(a), 8)));
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use super::{GuardedF64, UnguardedF64};
+use crate::FloatError;
+
+/// The mask of the exponent field of an `f64`.
+const EXPONENT_MASK: u64 = 0x7ff << 52;
+
+/// Builds `2^n` for an `n` in the normal range, `-1022..=1023`.
+#[inline]
+const fn pow2(n: i64) -> f64 {
+    #[allow(clippy::cast_sign_loss)]
+    f64::from_bits(((n + 1023) as u64) << 52)
+}
+
+/// Multiplies `value` by `2^n`, rounding only if the result is subnormal.
+///
+/// For normal values whose result is also normal, this only rewrites the exponent field.
+#[inline]
+const fn scale(value: f64, n: i64) -> f64 {
+    let bits = value.to_bits();
+    #[allow(clippy::cast_possible_wrap)]
+    let exponent = ((bits & EXPONENT_MASK) >> 52) as i64;
+
+    if exponent == 0x7ff || value == 0.0 {
+        // NaN, infinity, and zero are unchanged by scaling.
+        return value;
+    }
+
+    if exponent == 0 {
+        // Subnormal values are normalized first, which is exact.
+        return if n <= -1022 {
+            0.0 * value
+        } else if n < 0 {
+            value * pow2(n)
+        } else {
+            scale(value * pow2(54), n - 54)
+        };
+    }
+
+    let scaled = exponent.saturating_add(n);
+    if scaled >= 0x7ff {
+        return if value.is_sign_negative() {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        };
+    }
+
+    if scaled <= 0 {
+        // Move to the smallest normal exponent exactly, then round once into the subnormal range.
+        let shift = scaled - 1;
+        let smallest = f64::from_bits((bits & !EXPONENT_MASK) | (1 << 52));
+        return if shift < -60 {
+            0.0 * value
+        } else {
+            smallest * pow2(shift)
+        };
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    f64::from_bits((bits & !EXPONENT_MASK) | ((scaled as u64) << 52))
+}
+
+impl GuardedF64 {
+    /// Returns the exponent field of `self`, minus its bias, by reading the bits directly.
+    ///
+    /// For normal values, this is `floor(log2(|self|))`. Zero and subnormal values share the
+    /// smallest exponent field, so they all return `-1023`; use `ilog2_checked` for an exact
+    /// result for subnormal values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// assert_eq!(GuardedF64::new(10.0).unwrap().exponent_unbiased(), 3);
+    /// assert_eq!(GuardedF64::new(-0.75).unwrap().exponent_unbiased(), -1);
+    /// assert_eq!(GuardedF64::new(0.0).unwrap().exponent_unbiased(), -1023);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn exponent_unbiased(self) -> i32 {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let exponent = ((self.0.to_bits() & EXPONENT_MASK) >> 52) as i32;
+        exponent - 1023
+    }
+
+    /// Returns `floor(log2(self))` as an integer, computed exactly from the bits of `self` instead
+    /// of rounding the result of `log2`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `self` is zero or negative, where the logarithm is undefined.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// assert_eq!(GuardedF64::new(1024.0).unwrap().ilog2_checked(), Ok(10));
+    /// assert_eq!(GuardedF64::new(0.3).unwrap().ilog2_checked(), Ok(-2));
+    /// assert_eq!(GuardedF64::new(f64::from_bits(1)).unwrap().ilog2_checked(), Ok(-1074));
+    /// assert_eq!(GuardedF64::new(0.0).unwrap().ilog2_checked(), Err(FloatError::Domain));
+    /// ```
+    #[inline]
+    pub const fn ilog2_checked(self) -> Result<i32, FloatError> {
+        if self.0 <= 0.0 {
+            return Err(FloatError::Domain);
+        }
+
+        let exponent = self.exponent_unbiased();
+        if exponent > -1023 {
+            return Ok(exponent);
+        }
+
+        // A subnormal value is its bits times `2^-1074`.
+        #[allow(clippy::cast_possible_wrap)]
+        let highest_bit = self.0.to_bits().ilog2() as i32;
+        Ok(-1074 + highest_bit)
+    }
+
+    /// Multiplies `self` by `2^n` by adjusting its exponent directly.
+    ///
+    /// The result is exact unless it is subnormal, in which case it is rounded once, like `self *
+    /// 2^n`. Results that are too large to represent are infinite, so the result must be checked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// let value = GuardedF64::new(3.0).unwrap();
+    /// assert_eq!(value.mul_pow2(4).check(), GuardedF64::new(48.0));
+    /// assert_eq!(value.mul_pow2(1024).check(), Err(FloatError::Infinity));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original value"]
+    #[inline]
+    pub const fn mul_pow2(self, n: i32) -> UnguardedF64 {
+        UnguardedF64(scale(self.0, n as i64))
+    }
+
+    /// Divides `self` by `2^n` by adjusting its exponent directly. See `mul_pow2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// let value = GuardedF64::new(3.0).unwrap();
+    /// assert_eq!(value.div_pow2(2).check(), GuardedF64::new(0.75));
+    /// assert_eq!(value.div_pow2(2000).check(), GuardedF64::new(0.0));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original value"]
+    #[inline]
+    pub const fn div_pow2(self, n: i32) -> UnguardedF64 {
+        UnguardedF64(scale(self.0, -(n as i64)))
+    }
+}
+
+impl UnguardedF64 {
+    /// Multiplies `self` by `2^n` by adjusting its exponent directly. NaN and infinite values are
+    /// returned unchanged. See `GuardedF64::mul_pow2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64, UnguardedF64};
+    ///
+    /// let value = UnguardedF64::new(-3.0);
+    /// assert_eq!(value.mul_pow2(-1).check(), GuardedF64::new(-1.5));
+    ///
+    /// let invalid = UnguardedF64::new(f64::NAN);
+    /// assert_eq!(invalid.mul_pow2(2).check(), Err(FloatError::NaN));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original value"]
+    #[inline]
+    pub const fn mul_pow2(self, n: i32) -> Self {
+        Self(scale(self.0, n as i64))
+    }
+
+    /// Divides `self` by `2^n` by adjusting its exponent directly. NaN and infinite values are
+    /// returned unchanged. See `GuardedF64::mul_pow2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, UnguardedF64};
+    ///
+    /// let value = UnguardedF64::new(-3.0);
+    /// assert_eq!(value.div_pow2(-1).check(), GuardedF64::new(-6.0));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original value"]
+    #[inline]
+    pub const fn div_pow2(self, n: i32) -> Self {
+        Self(scale(self.0, -(n as i64)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_ilog2(a in valid_f64()) {
+            let value = GuardedF64(a);
+            match value.ilog2_checked() {
+                Ok(exponent) => {
+                    prop_assert!(a > 0.0);
+                    prop_assert!(value.div_pow2(exponent).0 >= 1.0);
+                    prop_assert!(value.div_pow2(exponent).0 < 2.0);
+                    if a >= f64::MIN_POSITIVE {
+                        prop_assert_eq!(value.exponent_unbiased(), exponent);
+                        prop_assert_eq!((-value).exponent_unbiased(), exponent);
+                    }
+                }
+                Err(error) => {
+                    prop_assert!(a <= 0.0);
+                    prop_assert_eq!(error, FloatError::Domain);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extremes() {
+        let max = GuardedF64::MAX;
+        assert_eq!(max.mul_pow2(i32::MAX).0, f64::INFINITY);
+        assert_eq!((-max).div_pow2(i32::MIN).0, f64::NEG_INFINITY);
+        assert_eq!(max.div_pow2(i32::MAX).0.to_bits(), 0.0f64.to_bits());
+        assert_eq!((-max).mul_pow2(i32::MIN).0.to_bits(), (-0.0f64).to_bits());
+
+        let smallest = GuardedF64(f64::from_bits(1));
+        assert_eq!(smallest.mul_pow2(1074).0, 1.0);
+        assert_eq!(smallest.div_pow2(1).0, 0.0);
+        assert_eq!(GuardedF64(1.0).div_pow2(1074).0, smallest.0);
+        assert_eq!(GuardedF64(1.5).div_pow2(1074).0, 2.0 * smallest.0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_multiplication(a in valid_f64(), n in -1022i32..=1023) {
+            let expected = a * 2f64.powi(n);
+            prop_assert_eq!(GuardedF64(a).mul_pow2(n).0.to_bits(), expected.to_bits());
+            prop_assert_eq!(UnguardedF64(a).div_pow2(-n).0.to_bits(), expected.to_bits());
+        }
+
+        #[test]
+        fn test_subnormal_round_trip(bits in 1u64..(1 << 52), n in 0i32..1000) {
+            let value = GuardedF64(f64::from_bits(bits));
+            prop_assert_eq!(value.mul_pow2(n).div_pow2(n).0.to_bits(), bits);
+        }
+
+        #[test]
+        fn test_invalid_unchanged(a in invalid_f64(), n in any::<i32>()) {
+            prop_assert_eq!(UnguardedF64(a).mul_pow2(n).0.to_bits(), a.to_bits());
+        }
+    }
+}
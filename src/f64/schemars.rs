@@ -0,0 +1,42 @@
+//! This module implements `JsonSchema` for `GuardedF64`.
+//!
+//! The schema describes a JSON number with the `double` format. JSON cannot represent NaN or
+//! infinities in the first place, and `GuardedF64` rejects them when it is deserialized.
+use super::GuardedF64;
+use ::schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use std::borrow::Cow;
+
+impl JsonSchema for GuardedF64 {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "GuardedF64".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "floatguard::GuardedF64".into()
+    }
+
+    /// Describes a finite `f64` value.
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "format": "double",
+            "description": "A finite double-precision number. NaN and infinite values are rejected."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema() {
+        let schema = ::schemars::schema_for!(GuardedF64);
+        assert_eq!(schema.get("type"), Some(&"number".into()));
+        assert_eq!(schema.get("format"), Some(&"double".into()));
+    }
+}
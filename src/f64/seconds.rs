@@ -0,0 +1,58 @@
+//! This module splits a number of seconds into the whole seconds and nanoseconds that the `chrono`
+//! and `time` crates store durations and timestamps as.
+use super::GuardedF64;
+use crate::FloatError;
+
+/// The first `f64` above the range of `i64`, which is exactly `2^63`.
+const I64_END: f64 = 9_223_372_036_854_775_808.0;
+
+/// Splits `seconds` into whole seconds, rounded toward negative infinity, and the nanoseconds after
+/// them, rounded to the nearest nanosecond.
+///
+/// # Errors
+///
+/// Returns `FloatError::Inexact` if the whole seconds do not fit in an `i64`.
+pub fn split_seconds(seconds: GuardedF64) -> Result<(i64, u32), FloatError> {
+    let mut whole = seconds.0.floor();
+    // The fraction is exact, because `floor` only clears bits of the value.
+    let mut nanos = ((seconds.0 - whole) * 1e9).round();
+    if nanos >= 1e9 {
+        whole += 1.0;
+        nanos -= 1e9;
+    }
+
+    if !(-I64_END..I64_END).contains(&whole) {
+        return Err(FloatError::Inexact);
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Ok((whole as i64, nanos as u32))
+}
+
+/// Combines whole seconds and a number of nanoseconds into a number of seconds.
+#[allow(clippy::cast_precision_loss)]
+pub fn join_seconds(whole: i64, nanos: impl Into<f64>) -> GuardedF64 {
+    // Both parts are bounded, so the sum is always finite.
+    GuardedF64(nanos.into().mul_add(1e-9, whole as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_seconds() {
+        assert_eq!(split_seconds(GuardedF64(1.5)), Ok((1, 500_000_000)));
+        assert_eq!(split_seconds(GuardedF64(-1.25)), Ok((-2, 750_000_000)));
+        assert_eq!(split_seconds(GuardedF64(0.999_999_999_9)), Ok((1, 0)));
+        assert_eq!(split_seconds(GuardedF64(-I64_END)), Ok((i64::MIN, 0)));
+        assert_eq!(split_seconds(GuardedF64(I64_END)), Err(FloatError::Inexact));
+        assert_eq!(split_seconds(GuardedF64::MIN), Err(FloatError::Inexact));
+    }
+
+    #[test]
+    fn test_join_seconds() {
+        assert_eq!(join_seconds(-1, -250_000_000), GuardedF64(-1.25));
+        assert_eq!(join_seconds(i64::MAX, 999_999_999u32), GuardedF64(I64_END));
+    }
+}
@@ -0,0 +1,254 @@
+//! This module implements `Serialize` and `Deserialize` for `GuardedF64` and `UnguardedF64`.
+//!
+//! `GuardedF64` values are validated when they are deserialized, so a NaN or infinite input is
+//! rejected with an error. `UnguardedF64` values are passed through as-is, and human-readable
+//! formats may also encode them as the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`, which is
+//! how producers that cannot write NaN or infinite numbers commonly encode them.
+use super::{GuardedF64, UnguardedF64};
+use ::serde::de::{DeserializeSeed, Error, Unexpected, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for GuardedF64 {
+    /// Serializes the inner `f64` value.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GuardedF64 {
+    /// Deserializes a `f64` value and validates that it is finite.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a number, or if it is NaN or infinite.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = f64::deserialize(deserializer)?;
+        Self::new(value).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for UnguardedF64 {
+    /// Serializes the inner `f64` value, which may be NaN or infinite.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UnguardedF64 {
+    /// Deserializes a `f64` value without validating it.
+    ///
+    /// Human-readable formats also accept the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, UnguardedVisitor::default())
+    }
+}
+
+/// A configurable deserializer for `UnguardedF64` fields that come from lenient producers.
+///
+/// Like the `Deserialize` implementation of `UnguardedF64`, it accepts the strings `"NaN"`,
+/// `"Infinity"`, and `"-Infinity"` in human-readable formats. It can additionally read `null` as
+/// NaN, for producers that write missing or invalid values as `null`. Use it as a `DeserializeSeed`,
+/// or opt a single field in with `deserialize_null_as_nan`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, LenientF64, UnguardedF64};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Reading {
+///     #[serde(deserialize_with = "LenientF64::deserialize_null_as_nan")]
+///     value: UnguardedF64,
+/// }
+///
+/// let reading: Reading = serde_json::from_str(r#"{ "value": null }"#).unwrap();
+/// assert_eq!(reading.value.check(), Err(FloatError::NaN));
+///
+/// let reading: Reading = serde_json::from_str(r#"{ "value": "-Infinity" }"#).unwrap();
+/// assert_eq!(reading.value.check(), Err(FloatError::Infinity));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LenientF64 {
+    null_as_nan: bool,
+}
+
+impl LenientF64 {
+    /// Returns the deserializer with reading `null` as NaN turned on or off.
+    #[must_use]
+    pub const fn with_null_as_nan(self, null_as_nan: bool) -> Self {
+        Self { null_as_nan }
+    }
+
+    /// Returns whether `null` is read as NaN.
+    #[must_use]
+    pub const fn null_as_nan(&self) -> bool {
+        self.null_as_nan
+    }
+
+    /// Deserializes an `UnguardedF64`, reading `null` as NaN. Intended for
+    /// `#[serde(deserialize_with = "LenientF64::deserialize_null_as_nan")]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a number, `null`, or one of the special strings.
+    pub fn deserialize_null_as_nan<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UnguardedF64, D::Error> {
+        Self::default()
+            .with_null_as_nan(true)
+            .deserialize(deserializer)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for LenientF64 {
+    type Value = UnguardedF64;
+
+    /// Deserializes an `UnguardedF64` with these options.
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<UnguardedF64, D::Error> {
+        deserialize_lenient(
+            deserializer,
+            UnguardedVisitor {
+                null_as_nan: self.null_as_nan,
+            },
+        )
+    }
+}
+
+/// Parses the strings that lenient producers write in place of NaN or infinite numbers.
+fn parse_special(value: &str) -> Option<f64> {
+    match value {
+        "NaN" => Some(f64::NAN),
+        "Infinity" => Some(f64::INFINITY),
+        "-Infinity" => Some(f64::NEG_INFINITY),
+        _ => None,
+    }
+}
+
+/// Deserializes an `UnguardedF64` with `visitor`.
+///
+/// Only human-readable formats are asked for a self-described value, because binary formats like
+/// `bincode` cannot describe their values and encode NaN and infinite numbers natively anyway.
+fn deserialize_lenient<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    visitor: UnguardedVisitor,
+) -> Result<UnguardedF64, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_f64(visitor)
+    }
+}
+
+/// Visits a number or one of the special strings, and optionally `null`.
+#[derive(Default)]
+struct UnguardedVisitor {
+    null_as_nan: bool,
+}
+
+impl Visitor<'_> for UnguardedVisitor {
+    type Value = UnguardedF64;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a number or one of \"NaN\", \"Infinity\", or \"-Infinity\"")?;
+        if self.null_as_nan {
+            formatter.write_str(", or null")?;
+        }
+        Ok(())
+    }
+
+    fn visit_f64<E: Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(UnguardedF64(value))
+    }
+    #[allow(clippy::cast_precision_loss)]
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(UnguardedF64(value as f64))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(UnguardedF64(value as f64))
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_special(value)
+            .map(UnguardedF64)
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        if self.null_as_nan {
+            Ok(UnguardedF64(f64::NAN))
+        } else {
+            Err(E::invalid_type(Unexpected::Unit, &self))
+        }
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        self.visit_unit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f64()) {
+            let guarded = GuardedF64::new(a).unwrap();
+            let json = serde_json::to_string(&guarded).unwrap();
+
+            prop_assert_eq!(serde_json::from_str::<GuardedF64>(&json).unwrap(), guarded);
+            prop_assert_eq!(serde_json::from_str::<UnguardedF64>(&json).unwrap().check(), Ok(guarded));
+        }
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(serde_json::from_str::<GuardedF64>("null").is_err());
+        assert!(serde_json::from_str::<GuardedF64>("\"1.0\"").is_err());
+        assert!(serde_json::from_str::<GuardedF64>("1e999").is_err());
+        assert!(serde_json::from_str::<GuardedF64>("\"NaN\"").is_err());
+        assert!(serde_json::from_str::<GuardedF64>("\"Infinity\"").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_special_strings() {
+        let parse = |json| serde_json::from_str::<UnguardedF64>(json).map(|value| value.0);
+
+        assert!(parse("\"NaN\"").unwrap().is_nan());
+        assert_eq!(parse("\"Infinity\"").unwrap(), f64::INFINITY);
+        assert_eq!(parse("\"-Infinity\"").unwrap(), f64::NEG_INFINITY);
+        assert_eq!(parse("-3").unwrap(), -3.0);
+        assert!(parse("\"nan\"").is_err());
+        assert!(parse("null").is_err());
+
+        let lenient = LenientF64::default().with_null_as_nan(true);
+        let value = lenient.deserialize(&mut serde_json::Deserializer::from_str("null"));
+        assert!(value.unwrap().0.is_nan());
+
+        let strict = LenientF64::default();
+        assert!(!strict.null_as_nan());
+        assert!(
+            strict
+                .deserialize(&mut serde_json::Deserializer::from_str("null"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_binary_formats_are_unchanged() {
+        let bytes = bincode::serialize(&f64::NEG_INFINITY).unwrap();
+        let value: UnguardedF64 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value.0, f64::NEG_INFINITY);
+
+        let bytes = postcard::to_allocvec(&f64::NAN).unwrap();
+        let value: UnguardedF64 = postcard::from_bytes(&bytes).unwrap();
+        assert!(value.0.is_nan());
+    }
+}
@@ -0,0 +1,141 @@
+//! This module implements conversions between `GuardedF64` and `serde_json::Value`.
+//!
+//! Every finite `f64` is a valid JSON number, so converting a guarded value into a `Value` cannot
+//! fail. Extracting one is strict: the `Value` must be a number, and strings like `"NaN"` or
+//! `"1.5"` are rejected rather than parsed.
+use super::GuardedF64;
+use crate::FloatError;
+use ::serde_json::{Number, Value};
+
+/// An error occurred while extracting a `GuardedF64` from a `serde_json::Value`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonValueError {
+    /// Indicates that the value is not a number.
+    TypeMismatch {
+        /// The kind of value that was found instead: `"null"`, `"boolean"`, `"string"`,
+        /// `"array"`, or `"object"`.
+        found: &'static str,
+    },
+
+    /// Indicates that the number is not finite, which can happen when `serde_json` stores numbers
+    /// with arbitrary precision.
+    Invalid(FloatError),
+}
+
+impl std::error::Error for JsonValueError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid(error) => Some(error),
+            Self::TypeMismatch { .. } => None,
+        }
+    }
+}
+
+/// Implements the `Display` trait for the `JsonValueError` enum, providing a user-friendly
+/// description of the error.
+impl std::fmt::Display for JsonValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeMismatch { found } => write!(f, "Expected a number, found {found}"),
+            Self::Invalid(error) => write!(f, "The number is not a valid value: {error}"),
+        }
+    }
+}
+
+impl From<GuardedF64> for Value {
+    /// Converts the value into a `Value::Number`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use serde_json::{json, Value};
+    ///
+    /// let value = GuardedF64::new(2.5).unwrap();
+    /// assert_eq!(Value::from(value), json!(2.5));
+    /// assert_eq!(json!({ "value": value }), json!({ "value": 2.5 }));
+    /// ```
+    fn from(value: GuardedF64) -> Self {
+        // Only NaN and infinities have no `Number`, so the fallback is never taken.
+        Number::from_f64(value.0).map_or(Self::Null, Self::Number)
+    }
+}
+
+impl GuardedF64 {
+    /// Extracts a `GuardedF64` from a `serde_json::Value`, which must be a finite number.
+    ///
+    /// Integers are converted to the nearest `f64`, like `Value::as_f64` does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonValueError::TypeMismatch` if the value is not a number, or
+    /// `JsonValueError::Invalid` if the number is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, JsonValueError};
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(GuardedF64::from_json_value(&json!(-0.5)), Ok(GuardedF64::new(-0.5).unwrap()));
+    /// assert_eq!(GuardedF64::from_json_value(&json!(3)), Ok(GuardedF64::new(3.0).unwrap()));
+    ///
+    /// assert_eq!(
+    ///     GuardedF64::from_json_value(&json!("NaN")),
+    ///     Err(JsonValueError::TypeMismatch { found: "string" })
+    /// );
+    /// assert_eq!(
+    ///     GuardedF64::from_json_value(&json!(null)),
+    ///     Err(JsonValueError::TypeMismatch { found: "null" })
+    /// );
+    /// ```
+    pub fn from_json_value(value: &Value) -> Result<Self, JsonValueError> {
+        let number = match value {
+            Value::Number(number) => number,
+            Value::Null => return Err(JsonValueError::TypeMismatch { found: "null" }),
+            Value::Bool(_) => return Err(JsonValueError::TypeMismatch { found: "boolean" }),
+            Value::String(_) => return Err(JsonValueError::TypeMismatch { found: "string" }),
+            Value::Array(_) => return Err(JsonValueError::TypeMismatch { found: "array" }),
+            Value::Object(_) => return Err(JsonValueError::TypeMismatch { found: "object" }),
+        };
+
+        // `as_f64` only fails for arbitrary-precision numbers that are too large for an `f64`.
+        Self::new(number.as_f64().unwrap_or(f64::INFINITY)).map_err(JsonValueError::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use ::serde_json::json;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_type_mismatch() {
+        for (value, found) in [
+            (json!(null), "null"),
+            (json!(true), "boolean"),
+            (json!("1.5"), "string"),
+            (json!([1.5]), "array"),
+            (json!({ "value": 1.5 }), "object"),
+        ] {
+            assert_eq!(
+                GuardedF64::from_json_value(&value),
+                Err(JsonValueError::TypeMismatch { found })
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f64()) {
+            let value = Value::from(GuardedF64(a));
+            prop_assert!(value.is_number());
+            prop_assert_eq!(
+                GuardedF64::from_json_value(&value).map(|v| v.0.to_bits()),
+                Ok(a.to_bits())
+            );
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use super::{GuardedF64, UnguardedF64};
+use crate::macros::math::math;
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Computes the error function of `self`. `GuardedF64::erf` returns a `GuardedF64` type because
+        the result is always in the range [-1, 1].
+
+        See: [`libm::erf`]
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+
+        let x = GuardedF64::new(0.5_f64).unwrap();
+        assert!((x.erf() - 0.520_499_877_813_046_5).abs().check().unwrap() < 1e-15);
+
+        let invalid = UnguardedF64::new(f64::NAN);
+        assert!(invalid.erf().check().is_err());
+        ```
+    "
+    fn erf(value: f64) -> Self {
+        Self(libm::erf(value))
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Computes the complementary error function of `self`, `1 - erf(self)`, without the loss of
+        precision of the subtraction for large values. `GuardedF64::erfc` returns a `GuardedF64`
+        type because the result is always in the range [0, 2].
+
+        See: [`libm::erfc`]
+
+        # Examples
+
+        ```rust
+        use floatguard::GuardedF64;
+
+        let x = GuardedF64::new(10.0_f64).unwrap();
+        assert!(x.erfc() > 0.0);
+        assert!((x.erfc() - 2.088_487_583_762_545e-45).abs().check().unwrap() < 1e-58);
+        ```
+    "
+    fn erfc(value: f64) -> Self {
+        Self(libm::erfc(value))
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Computes the gamma function of `self`. The result is invalid at zero and the negative
+        integers, where the function has poles, and overflows for arguments above roughly `171.6`.
+
+        See: [`libm::tgamma`]
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, FloatError};
+
+        let five = GuardedF64::new(5.0_f64).unwrap();
+        assert_eq!(five.gamma().check(), GuardedF64::new(24.0));
+
+        let pole = GuardedF64::new(-2.0_f64).unwrap();
+        assert!(pole.gamma().check().is_err());
+
+        let large = GuardedF64::new(172.0_f64).unwrap();
+        assert_eq!(large.gamma().check(), Err(FloatError::Infinity));
+        ```
+    "
+    fn gamma(value: f64) -> UnguardedF64 {
+        UnguardedF64(libm::tgamma(value))
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Computes the natural logarithm of the absolute value of the gamma function of `self`. This
+        stays finite for arguments where `gamma` overflows, but is invalid at the poles of the gamma
+        function.
+
+        See: [`libm::lgamma`]
+
+        # Examples
+
+        ```rust
+        use floatguard::GuardedF64;
+
+        let large = GuardedF64::new(200.0_f64).unwrap();
+        assert!(large.gamma().check().is_err());
+        assert!((large.lgamma() - 857.933_669_825_857_5).abs().check().unwrap() < 1e-9);
+
+        let pole = GuardedF64::new(0.0_f64).unwrap();
+        assert!(pole.lgamma().check().is_err());
+        ```
+    "
+    fn lgamma(value: f64) -> UnguardedF64 {
+        UnguardedF64(libm::lgamma(value))
+    }
+);
+
+math!(
+    (GuardedF64, UnguardedF64)
+    r"
+        Computes the beta function of `self` and `other`, `gamma(self) * gamma(other) / gamma(self +
+        other)`.
+
+        The result is computed from the logarithms of the gamma functions, so it stays finite when
+        the individual gamma functions overflow.
+
+        # Examples
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+
+        let a = GuardedF64::new(2.0_f64).unwrap();
+        let b = GuardedF64::new(3.0_f64).unwrap();
+        assert!((a.beta(b) - 1.0 / 12.0).abs().check().unwrap() < 1e-15);
+
+        let large = GuardedF64::new(200.0_f64).unwrap();
+        assert!(large.beta(large).check().unwrap() > 0.0);
+
+        let invalid = UnguardedF64::new(f64::NAN);
+        assert!(a.beta(invalid).check().is_err());
+        ```
+    "
+    fn beta(base: f64, other: impl Into<UnguardedF64>) -> UnguardedF64 {
+        let UnguardedF64(other) = other.into();
+        let (ln_a, sign_a) = libm::lgamma_r(base);
+        let (ln_b, sign_b) = libm::lgamma_r(other);
+        let (ln_sum, sign_sum) = libm::lgamma_r(base + other);
+
+        let magnitude = (ln_a + ln_b - ln_sum).exp();
+        UnguardedF64(if sign_a * sign_b * sign_sum < 0 {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_erf_bounded(a in valid_f64()) {
+            let value = GuardedF64::new(a).unwrap();
+            prop_assert!((-1.0..=1.0).contains(&value.erf().0));
+            prop_assert!((0.0..=2.0).contains(&value.erfc().0));
+            prop_assert_eq!(UnguardedF64::new(a).erf().check(), Ok(value.erf()));
+        }
+
+        #[test]
+        fn test_invalid_propagates(a in invalid_f64(), b in valid_f64()) {
+            let invalid = UnguardedF64::new(a);
+            let valid = GuardedF64::new(b).unwrap();
+
+            prop_assert!(invalid.gamma().check().is_err());
+            prop_assert!(invalid.lgamma().check().is_err());
+            prop_assert!(valid.beta(invalid).check().is_err());
+        }
+
+        #[test]
+        fn test_gamma_recurrence(a in 0.1..100.0f64) {
+            let value = GuardedF64::new(a).unwrap();
+            let next = (value + 1.0).check().unwrap().gamma().check().unwrap();
+            let expected = (value.gamma() * a).check().unwrap();
+            prop_assert!((next - expected).abs().check().unwrap() <= expected.0 * 1e-12);
+        }
+
+        #[test]
+        fn test_beta_matches_gamma(a in 0.1..20.0f64, b in 0.1..20.0f64) {
+            let (a, b) = (GuardedF64::new(a).unwrap(), GuardedF64::new(b).unwrap());
+            let expected = (a.gamma() * b.gamma() / (a + b).gamma()).check().unwrap();
+            let beta = a.beta(b).check().unwrap();
+            prop_assert!((beta - expected).abs().check().unwrap() <= expected.0 * 1e-12);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! This module implements conversions between `GuardedF64` seconds and the `time` durations and
+//! timestamps.
+//!
+//! Every `Duration` and `OffsetDateTime` converts to a finite number of seconds, so those
+//! conversions cannot fail. Converting back rounds to the nearest nanosecond, and reports values
+//! outside of the range of the `time` type as `FloatError::Inexact`.
+use super::GuardedF64;
+use super::seconds::{join_seconds, split_seconds};
+use crate::FloatError;
+use ::time::{Duration, OffsetDateTime};
+
+impl From<Duration> for GuardedF64 {
+    /// Converts a `Duration` into a number of seconds.
+    fn from(duration: Duration) -> Self {
+        join_seconds(duration.whole_seconds(), duration.subsec_nanoseconds())
+    }
+}
+
+impl TryFrom<GuardedF64> for Duration {
+    type Error = FloatError;
+
+    /// Converts a number of seconds into a `Duration`, rounded to the nearest nanosecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Inexact` if the duration is outside of the range of `Duration`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use time::Duration;
+    ///
+    /// let seconds = GuardedF64::new(-1.5).unwrap();
+    /// assert_eq!(Duration::try_from(seconds), Ok(Duration::milliseconds(-1500)));
+    /// assert_eq!(GuardedF64::from(Duration::milliseconds(-1500)), seconds);
+    ///
+    /// assert_eq!(Duration::try_from(GuardedF64::MAX), Err(FloatError::Inexact));
+    /// ```
+    fn try_from(seconds: GuardedF64) -> Result<Self, Self::Error> {
+        let (whole, nanos) = split_seconds(seconds)?;
+        // `split_seconds` keeps the nanoseconds below one second, which always fits in an `i32`.
+        let nanos = i32::try_from(nanos).map_err(|_| FloatError::Inexact)?;
+        Ok(Self::new(whole, nanos))
+    }
+}
+
+impl GuardedF64 {
+    /// Converts an `OffsetDateTime` into fractional seconds since the Unix epoch.
+    ///
+    /// Near the present, an `f64` resolves timestamps to about a quarter of a microsecond.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use time::OffsetDateTime;
+    ///
+    /// let time = OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_250_000_000).unwrap();
+    /// assert_eq!(
+    ///     GuardedF64::from_offset_datetime(time),
+    ///     GuardedF64::new(1_700_000_000.25).unwrap()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_offset_datetime(time: OffsetDateTime) -> Self {
+        join_seconds(time.unix_timestamp(), time.nanosecond())
+    }
+
+    /// Converts fractional seconds since the Unix epoch into a UTC `OffsetDateTime`, rounded to the
+    /// nearest nanosecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Inexact` if the timestamp is outside of the range of `OffsetDateTime`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use time::OffsetDateTime;
+    ///
+    /// let seconds = GuardedF64::new(-0.5).unwrap();
+    /// assert_eq!(
+    ///     seconds.to_offset_datetime(),
+    ///     Ok(OffsetDateTime::from_unix_timestamp_nanos(-500_000_000).unwrap())
+    /// );
+    ///
+    /// assert_eq!(GuardedF64::new(1e18).unwrap().to_offset_datetime(), Err(FloatError::Inexact));
+    /// ```
+    pub fn to_offset_datetime(self) -> Result<OffsetDateTime, FloatError> {
+        let (whole, nanos) = split_seconds(self)?;
+        let nanos = i128::from(whole) * 1_000_000_000 + i128::from(nanos);
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|_| FloatError::Inexact)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_duration_round_trip(secs in -1_000_000i64..1_000_000, nanos in -999_999_999i32..1_000_000_000) {
+            let duration = Duration::new(secs, nanos);
+            prop_assert_eq!(Duration::try_from(GuardedF64::from(duration)), Ok(duration));
+        }
+
+        #[test]
+        fn test_offset_datetime_round_trip(micros in -1_000_000_000_000i128..1_000_000_000_000) {
+            let time = OffsetDateTime::from_unix_timestamp_nanos(micros * 1000).unwrap();
+            prop_assert_eq!(GuardedF64::from_offset_datetime(time).to_offset_datetime(), Ok(time));
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        let min = Duration::seconds(i64::MIN);
+        assert_eq!(Duration::try_from(GuardedF64::from(min)), Ok(min));
+        assert_eq!(
+            GuardedF64::MIN.to_offset_datetime(),
+            Err(FloatError::Inexact)
+        );
+    }
+}
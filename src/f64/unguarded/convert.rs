@@ -1,6 +1,7 @@
 use crate::FloatError;
 
 use super::UnguardedF64;
+use crate::macros::ops_binary::integer_conversion;
 
 /// Implementing the ability to convert `GuardedF64` to `f64` safely.
 ///
@@ -33,7 +34,7 @@ impl TryFrom<UnguardedF64> for f64 {
     /// assert_eq!(f64::try_from(inf_value), Err(FloatError::Infinity));
     /// ```
     fn try_from(value: UnguardedF64) -> Result<Self, Self::Error> {
-        value.check().map(Self::from)
+        value.check_reported().map(Self::from)
     }
 }
 
@@ -58,6 +59,58 @@ impl From<f64> for UnguardedF64 {
     }
 }
 
+impl From<&f64> for UnguardedF64 {
+    /// Converts a reference to an `f64` into an `UnguardedF64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF64, GuardedF64};
+    ///
+    /// let value = 3.14;
+    /// assert_eq!(UnguardedF64::from(&value).check(), GuardedF64::new(3.14));
+    /// ```
+    fn from(value: &f64) -> Self {
+        Self::new(*value)
+    }
+}
+
+impl From<&Self> for UnguardedF64 {
+    /// Copies the `UnguardedF64` behind a reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{UnguardedF64, GuardedF64};
+    ///
+    /// let value = UnguardedF64::new(3.14);
+    /// assert_eq!(UnguardedF64::from(&value).check(), GuardedF64::new(3.14));
+    /// ```
+    fn from(value: &Self) -> Self {
+        *value
+    }
+}
+
+integer_conversion!(
+    impl From<...(i8, i16, i32, u8, u16, u32)> for UnguardedF64 as f64;
+    r"
+        Converts an integer to `UnguardedF64`. Every value of the integer type is exactly representable,
+        so integers can be used in arithmetic with guarded and unguarded values without rounding.
+
+        # Example
+
+        ```rust
+        use floatguard::{GuardedF64, UnguardedF64};
+
+        assert_eq!(UnguardedF64::from(-3i32).check(), GuardedF64::new(-3.0));
+
+        let value = GuardedF64::new(1.5).unwrap();
+        assert_eq!((value * 2i32).check(), GuardedF64::new(3.0));
+        assert_eq!((4u8 - value).check(), GuardedF64::new(2.5));
+        ```
+    "
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
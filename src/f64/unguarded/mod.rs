@@ -1,5 +1,6 @@
 mod convert;
 mod ops_assign;
+mod sum;
 
 /// Represents a checked floating-point number that ensures it is neither NaN nor infinite.
 ///
@@ -16,6 +17,7 @@ mod ops_assign;
 /// assert_eq!((unchecked_f64 - f64::INFINITY).check(), Err(FloatError::Infinity));
 /// ```
 #[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
 pub struct UnguardedF64(pub(crate) f64);
 
 impl UnguardedF64 {
@@ -28,6 +30,21 @@ impl UnguardedF64 {
     pub const fn new(value: f64) -> Self {
         Self(value)
     }
+
+    /// Returns the inner `f64` value without checking it, so it may be NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::UnguardedF64;
+    ///
+    /// let value = UnguardedF64::new(1.0) / 0.0;
+    /// assert_eq!(value.get(), f64::INFINITY);
+    /// ```
+    #[must_use]
+    pub const fn get(self) -> f64 {
+        self.0
+    }
 }
 
 impl std::fmt::Display for UnguardedF64 {
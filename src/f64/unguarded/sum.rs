@@ -0,0 +1,41 @@
+use super::UnguardedF64;
+use std::iter::Sum;
+
+impl<T: Into<Self>> Sum<T> for UnguardedF64 {
+    /// Sums guarded or unguarded values, deferring the check to the end like the `+` operator.
+    ///
+    /// An empty iterator sums to zero. With the `rayon` feature, this also implements parallel
+    /// sums, which may add the values in a different order on every run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64, UnguardedF64};
+    ///
+    /// let values = [1.0, 2.0, 3.5].map(|v| GuardedF64::new(v).unwrap());
+    /// assert_eq!(values.iter().sum::<UnguardedF64>().check(), GuardedF64::new(6.5));
+    ///
+    /// let values = [UnguardedF64::new(1.0), UnguardedF64::new(f64::NAN)];
+    /// assert_eq!(values.into_iter().sum::<UnguardedF64>().check(), Err(FloatError::NaN));
+    /// ```
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::new(0.0), |total, value| total + value.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GuardedF64;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_matches_fold(values in prop::collection::vec(valid_f64(), 0..32)) {
+            let guarded: Vec<_> = values.iter().map(|&v| GuardedF64::new(v).unwrap()).collect();
+            let expected = values.iter().fold(UnguardedF64::new(0.0), |total, &v| total + v);
+            prop_assert_eq!(guarded.iter().sum::<UnguardedF64>().check(), expected.check());
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! This module implements conversions between `GuardedF64` and `uom` quantities in the SI system,
+//! like `Length<f64>` or `Velocity<f64>`.
+//!
+//! A `uom` quantity stores its value in the base units of its dimension, so these conversions are
+//! in base units too: metres for a length, metres per second for a velocity. Every guarded value
+//! makes a valid quantity, but arithmetic on quantities is not checked, so extracting a value is
+//! where it is validated. To work in another unit, convert with the `uom` API before or after, as
+//! in `GuardedF64::new(length.get::<kilometer>())`.
+use super::GuardedF64;
+use crate::FloatError;
+use ::uom::si::{Dimension, Quantity, Units};
+use core::marker::PhantomData;
+
+impl<D, U> TryFrom<Quantity<D, U, f64>> for GuardedF64
+where
+    D: Dimension + ?Sized,
+    U: Units<f64> + ?Sized,
+{
+    type Error = FloatError;
+
+    /// Extracts the value of a quantity in its base units.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the value is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use uom::si::f64::{Length, Time, Velocity};
+    /// use uom::si::length::kilometer;
+    /// use uom::si::time::second;
+    ///
+    /// let distance = Length::new::<kilometer>(1.5);
+    /// assert_eq!(GuardedF64::try_from(distance), GuardedF64::new(1500.0));
+    ///
+    /// let speed: Velocity = distance / Time::new::<second>(0.0);
+    /// assert_eq!(GuardedF64::try_from(speed), Err(FloatError::Infinity));
+    /// ```
+    fn try_from(quantity: Quantity<D, U, f64>) -> Result<Self, Self::Error> {
+        Self::new(quantity.value)
+    }
+}
+
+impl<D, U> From<GuardedF64> for Quantity<D, U, f64>
+where
+    D: Dimension + ?Sized,
+    U: Units<f64> + ?Sized,
+{
+    /// Converts a value in base units into a quantity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use uom::si::f64::Length;
+    /// use uom::si::length::{centimeter, meter};
+    ///
+    /// let length = Length::from(GuardedF64::new(2.5).unwrap());
+    /// assert_eq!(length, Length::new::<meter>(2.5));
+    /// assert_eq!(length.get::<centimeter>(), 250.0);
+    /// ```
+    fn from(value: GuardedF64) -> Self {
+        Self {
+            dimension: PhantomData,
+            units: PhantomData,
+            value: value.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use ::uom::si::f64::{Area, Length};
+    use ::uom::si::length::meter;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f64()) {
+            let length = Length::from(GuardedF64(a));
+            prop_assert_eq!(length, Length::new::<meter>(a));
+            prop_assert_eq!(GuardedF64::try_from(length).map(|v| v.0.to_bits()), Ok(a.to_bits()));
+        }
+
+        #[test]
+        fn test_invalid_quantity(invalid in invalid_f64()) {
+            let area: Area = Length::new::<meter>(invalid) * Length::new::<meter>(1.0);
+            prop_assert_eq!(GuardedF64::try_from(area), GuardedF64::new(invalid));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! This module implements `ToSchema` for `GuardedF64`.
+//!
+//! The schema describes a JSON number with the `double` format. JSON cannot represent NaN or
+//! infinities in the first place, and `GuardedF64` rejects them when it is deserialized.
+use super::GuardedF64;
+use ::utoipa::openapi::RefOr;
+use ::utoipa::openapi::schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type};
+use ::utoipa::{PartialSchema, ToSchema};
+
+impl PartialSchema for GuardedF64 {
+    /// Describes a finite `f64` value.
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::Number)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Double)))
+            .description(Some(
+                "A finite double-precision number. NaN and infinite values are rejected.",
+            ))
+            .into()
+    }
+}
+
+impl ToSchema for GuardedF64 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema() {
+        let schema = serde_json::to_value(GuardedF64::schema()).unwrap();
+        assert_eq!(schema["type"], "number");
+        assert_eq!(schema["format"], "double");
+        assert_eq!(GuardedF64::name(), "GuardedF64");
+    }
+}
@@ -0,0 +1,142 @@
+//! Financial helpers for guarded values.
+//!
+//! Interest rates are only meaningful above `-1`, where the growth factor `1 + rate` is positive, so
+//! every helper reports a rate of `-1` or less as `FloatError::Domain`. Growth over many periods
+//! overflows easily, so compounding is computed in log space whenever the growth factor alone would
+//! overflow or underflow, and only a result that is itself too large is reported as
+//! `FloatError::Infinity`.
+use crate::decay::scale_by_exp;
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// Computes `principal * (1 + rate)^periods`, the value of `principal` after `periods` periods of
+/// compound interest at `rate` per period.
+///
+/// The growth factor is computed as `exp(periods * ln_1p(rate))`, which stays accurate for the tiny
+/// rates of short periods, where `1 + rate` would round away most of the rate. A fractional
+/// `periods` compounds continuously within the last period, and a negative `periods` discounts a
+/// future value back to the present.
+///
+/// # Errors
+///
+/// Returns `FloatError::Domain` if `rate` is `-1` or less, or `FloatError::Infinity` if the result
+/// is too large to represent.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::finance::compound;
+///
+/// let principal = GuardedF64::new(1000.0).unwrap();
+/// let rate = GuardedF64::new(0.05).unwrap();
+/// let periods = GuardedF64::new(10.0).unwrap();
+///
+/// let value = compound(principal, rate, periods).unwrap();
+/// assert!((value - 1628.894_626_777_442).abs().check().unwrap() < 1e-9);
+///
+/// assert_eq!(compound(principal, rate, GuardedF64::MAX), Err(FloatError::Infinity));
+/// assert_eq!(compound(principal, -GuardedF64::new(1.0).unwrap(), periods), Err(FloatError::Domain));
+/// ```
+pub fn compound(
+    principal: GuardedF64,
+    rate: GuardedF64,
+    periods: GuardedF64,
+) -> Result<GuardedF64, FloatError> {
+    let rate = UnguardedF64::from(rate).check_where(|rate| rate > -1.0, FloatError::Domain)?;
+    scale_by_exp(principal, UnguardedF64::new(periods.0 * rate.0.ln_1p()))
+}
+
+/// Computes the net present value of `cashflows` at a discount `rate` per period.
+///
+/// The first cash flow is at period zero, so it is not discounted, and each following cash flow is
+/// one period later than the one before it. The sum is evaluated with Horner's scheme, from the last
+/// cash flow to the first, which divides by `1 + rate` once per period instead of raising it to a
+/// power.
+///
+/// # Errors
+///
+/// Returns `FloatError::Domain` if `rate` is `-1` or less, or `FloatError::Infinity` if the result,
+/// or a partial sum of the later cash flows, is too large to represent.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::finance::npv;
+///
+/// let rate = GuardedF64::new(0.1).unwrap();
+/// let cashflows = [-1000.0, 500.0, 500.0, 500.0].map(|v| GuardedF64::new(v).unwrap());
+///
+/// let value = npv(rate, &cashflows).unwrap();
+/// assert!((value - 243.425_995_492_111).abs().check().unwrap() < 1e-9);
+///
+/// assert_eq!(npv(rate, &[]), Ok(GuardedF64::new(0.0).unwrap()));
+/// assert_eq!(npv(GuardedF64::new(-1.5).unwrap(), &cashflows), Err(FloatError::Domain));
+/// ```
+pub fn npv(rate: GuardedF64, cashflows: &[GuardedF64]) -> Result<GuardedF64, FloatError> {
+    let rate = UnguardedF64::from(rate).check_where(|rate| rate > -1.0, FloatError::Domain)?;
+    let factor = 1.0 + rate.0;
+
+    cashflows
+        .iter()
+        .rev()
+        .try_fold(GuardedF64(0.0), |acc, &cashflow| {
+            (acc / factor + cashflow).check_reported()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_extremes() {
+        let one = GuardedF64(1.0);
+        let tiny = GuardedF64(1e-300);
+        let periods = GuardedF64(1100.0);
+
+        // The growth factor overflows, but the tiny principal absorbs it.
+        let value = compound(tiny, one, periods).unwrap();
+        let expected = 1100f64.mul_add(2f64.ln(), 1e-300f64.ln()).exp();
+        assert!(((value - expected) / expected).abs().check().unwrap() < 1e-9);
+
+        assert_eq!(compound(one, one, -GuardedF64::MAX), Ok(GuardedF64(0.0)));
+        assert_eq!(
+            compound(GuardedF64(0.0), one, GuardedF64::MAX),
+            Ok(GuardedF64(0.0))
+        );
+        assert_eq!(
+            compound(one, GuardedF64(-1.0), one),
+            Err(FloatError::Domain)
+        );
+
+        let rate = GuardedF64(-0.999);
+        let cashflows = [one, one, GuardedF64::MAX];
+        assert_eq!(npv(rate, &cashflows), Err(FloatError::Infinity));
+        assert_eq!(npv(GuardedF64::MAX, &cashflows), Ok(GuardedF64(1.0)));
+    }
+
+    proptest! {
+        #[test]
+        fn test_compound_matches_powf(principal in -1e6..1e6f64, rate in -0.5..1.0f64, periods in 0u8..100) {
+            let value = compound(GuardedF64(principal), GuardedF64(rate), GuardedF64(f64::from(periods))).unwrap();
+            let expected = principal * (1.0 + rate).powi(i32::from(periods));
+            prop_assert!((value.0 - expected).abs() <= expected.abs().mul_add(1e-12, 1e-300));
+        }
+
+        #[test]
+        fn test_npv_matches_sum(rate in -0.5..1.0f64, cashflows in prop::collection::vec(-1e6..1e6f64, 0..32)) {
+            let guarded = cashflows.iter().map(|&v| GuardedF64(v)).collect::<Vec<_>>();
+            let value = npv(GuardedF64(rate), &guarded).unwrap();
+
+            let mut expected = 0.0;
+            let mut scale = 0.0;
+            for (cashflow, discount) in cashflows.iter().zip(std::iter::successors(Some(1.0), |d| Some(d / (1.0 + rate)))) {
+                expected += cashflow * discount;
+                scale += (cashflow * discount).abs();
+            }
+            prop_assert!((value.0 - expected).abs() <= scale.mul_add(1e-12, 1e-300));
+        }
+    }
+}
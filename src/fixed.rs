@@ -0,0 +1,278 @@
+//! This module provides the `GuardedFixed64` type, a Q32.32 fixed-point number for code that mixes
+//! fixed-point and floating-point math.
+//!
+//! Fixed-point values can never be NaN or infinite, so the conversion from floating point is where
+//! invalid values are rejected: converting a value that is too large reports
+//! `FloatError::Infinity`, and `FixedRounding::Exact` reports `FloatError::Inexact` for values that
+//! need more than 32 fractional bits.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// The scale of a Q32.32 value, `2^32`.
+const SCALE: f64 = 4_294_967_296.0;
+
+/// How a conversion handles values that cannot be represented exactly, such as values that need
+/// more than 32 fractional bits in a `GuardedFixed64`, or ratios passed to `GuardedF64::from_ratio`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FixedRounding {
+    /// Rounds to the nearest representable value. Ties are rounded away from zero by conversions to
+    /// `GuardedFixed64`, like `f64::round`, and to even by conversions to floats, like IEEE 754
+    /// arithmetic.
+    #[default]
+    Nearest,
+
+    /// Returns `FloatError::Inexact` instead of rounding.
+    Exact,
+}
+
+/// A signed Q32.32 fixed-point number, with 32 integer bits and 32 fractional bits.
+///
+/// Arithmetic is exact for addition and subtraction and rounded to the nearest value for
+/// multiplication and division. Every operation returns a `Result`, reporting
+/// `FloatError::Infinity` when the result leaves the representable range and
+/// `FloatError::DivisionByZero` when dividing by zero.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64, GuardedFixed64};
+///
+/// let a = GuardedFixed64::try_from(GuardedF64::new(1.5).unwrap()).unwrap();
+/// let b = GuardedFixed64::from(2);
+/// let product = (a * b).unwrap();
+/// assert_eq!(GuardedF64::from(product), 3.0);
+///
+/// assert_eq!(a / GuardedFixed64::ZERO, Err(FloatError::DivisionByZero));
+/// assert_eq!(GuardedFixed64::MAX + a, Err(FloatError::Infinity));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GuardedFixed64(i64);
+
+impl GuardedFixed64 {
+    /// The number of fractional bits.
+    pub const FRACTIONAL_BITS: u32 = 32;
+
+    /// Zero.
+    pub const ZERO: Self = Self(0);
+
+    /// One.
+    pub const ONE: Self = Self(1 << Self::FRACTIONAL_BITS);
+
+    /// The smallest representable value, `-2^31`.
+    pub const MIN: Self = Self(i64::MIN);
+
+    /// The largest representable value, `2^31 - 2^-32`.
+    pub const MAX: Self = Self(i64::MAX);
+
+    /// Creates a `GuardedFixed64` from its raw Q32.32 representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedFixed64;
+    ///
+    /// assert_eq!(GuardedFixed64::from_bits(1 << 32), GuardedFixed64::ONE);
+    /// ```
+    #[must_use]
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw Q32.32 representation of the value.
+    #[must_use]
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Converts a floating-point value to fixed point.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `value` if it is NaN or infinite, `FloatError::Infinity` if it is
+    /// outside of the representable range, or `FloatError::Inexact` if `rounding` is
+    /// `FixedRounding::Exact` and the value needs more than 32 fractional bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FixedRounding, FloatError, GuardedFixed64};
+    ///
+    /// let third = GuardedFixed64::from_f64(1.0 / 3.0, FixedRounding::Nearest).unwrap();
+    /// assert_eq!(third.to_bits(), 1_431_655_765);
+    ///
+    /// assert_eq!(GuardedFixed64::from_f64(1.0 / 3.0, FixedRounding::Exact), Err(FloatError::Inexact));
+    /// assert_eq!(GuardedFixed64::from_f64(3e9, FixedRounding::Nearest), Err(FloatError::Infinity));
+    /// assert_eq!(GuardedFixed64::from_f64(f64::NAN, FixedRounding::Nearest), Err(FloatError::NaN));
+    /// ```
+    pub fn from_f64(
+        value: impl Into<UnguardedF64>,
+        rounding: FixedRounding,
+    ) -> Result<Self, FloatError> {
+        // Scaling by a power of two is exact for every value that can be in range.
+        let scaled = value.into().check_reported()?.0 * SCALE;
+        let rounded = scaled.round();
+
+        if !(-SCALE * SCALE / 2.0..SCALE * SCALE / 2.0).contains(&rounded) {
+            return Err(FloatError::Infinity);
+        }
+
+        if rounding == FixedRounding::Exact && scaled.fract() != 0.0 {
+            return Err(FloatError::Inexact);
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(Self(rounded as i64))
+    }
+}
+
+impl From<i32> for GuardedFixed64 {
+    /// Converts an integer to fixed point, which is always exact.
+    fn from(value: i32) -> Self {
+        Self(i64::from(value) << Self::FRACTIONAL_BITS)
+    }
+}
+
+impl TryFrom<GuardedF64> for GuardedFixed64 {
+    type Error = FloatError;
+
+    /// Converts a `GuardedF64` to fixed point, rounding to the nearest representable value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the value is outside of the representable range.
+    fn try_from(value: GuardedF64) -> Result<Self, Self::Error> {
+        Self::from_f64(value, FixedRounding::Nearest)
+    }
+}
+
+impl From<GuardedFixed64> for GuardedF64 {
+    /// Converts a fixed-point value to a `GuardedF64`. Values with more than 53 significant bits are
+    /// rounded to the nearest `f64`.
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: GuardedFixed64) -> Self {
+        Self(value.0 as f64 / SCALE)
+    }
+}
+
+/// Narrows a wide intermediate result back to Q32.32.
+fn narrow(value: i128) -> Result<GuardedFixed64, FloatError> {
+    i64::try_from(value)
+        .map(GuardedFixed64)
+        .map_err(|_| FloatError::Infinity)
+}
+
+impl Add for GuardedFixed64 {
+    type Output = Result<Self, FloatError>;
+
+    /// Adds two fixed-point values.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or(FloatError::Infinity)
+    }
+}
+
+impl Sub for GuardedFixed64 {
+    type Output = Result<Self, FloatError>;
+
+    /// Subtracts two fixed-point values.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or(FloatError::Infinity)
+    }
+}
+
+impl Mul for GuardedFixed64 {
+    type Output = Result<Self, FloatError>;
+
+    /// Multiplies two fixed-point values, rounding to the nearest value with ties rounded up.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let product = i128::from(self.0) * i128::from(rhs.0);
+        narrow((product + (1 << (Self::FRACTIONAL_BITS - 1))) >> Self::FRACTIONAL_BITS)
+    }
+}
+
+impl Div for GuardedFixed64 {
+    type Output = Result<Self, FloatError>;
+
+    /// Divides two fixed-point values, rounding to the nearest value with ties rounded away from
+    /// zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.0 == 0 {
+            return Err(FloatError::DivisionByZero);
+        }
+
+        let numerator = i128::from(self.0) << Self::FRACTIONAL_BITS;
+        let denominator = i128::from(rhs.0);
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+
+        if 2 * remainder.abs() >= denominator.abs() {
+            narrow(quotient + numerator.signum() * denominator.signum())
+        } else {
+            narrow(quotient)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use proptest::prelude::*;
+
+    fn fixed() -> impl Strategy<Value = GuardedFixed64> {
+        any::<i64>().prop_map(GuardedFixed64::from_bits)
+    }
+
+    #[test]
+    fn test_range() {
+        let min = GuardedFixed64::from_f64(-2_147_483_648.0, FixedRounding::Exact);
+        assert_eq!(min, Ok(GuardedFixed64::MIN));
+
+        let max = GuardedFixed64::from_f64(2_147_483_648.0, FixedRounding::Nearest);
+        assert_eq!(max, Err(FloatError::Infinity));
+
+        let tiny = GuardedFixed64::from_f64(f64::MIN_POSITIVE, FixedRounding::Nearest);
+        assert_eq!(tiny, Ok(GuardedFixed64::ZERO));
+
+        assert_eq!(
+            GuardedFixed64::MIN / GuardedFixed64::from(-1),
+            Err(FloatError::Infinity)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(bits in -(1i64 << 53)..(1i64 << 53)) {
+            let value = GuardedFixed64::from_bits(bits);
+            let float = GuardedF64::from(value);
+            prop_assert_eq!(GuardedFixed64::from_f64(float, FixedRounding::Exact), Ok(value));
+        }
+
+        #[test]
+        fn test_arithmetic_matches_wide(a in fixed(), b in fixed()) {
+            let (wide_a, wide_b) = (i128::from(a.0), i128::from(b.0));
+
+            prop_assert_eq!(a + b, narrow(wide_a + wide_b));
+            prop_assert_eq!(a - b, narrow(wide_a - wide_b));
+
+            // Multiplication is within half of the smallest step of the exact product.
+            if let Ok(product) = a * b {
+                let error = (i128::from(product.0) << 32) - wide_a * wide_b;
+                prop_assert!(error.abs() <= 1 << 31);
+            }
+        }
+
+        #[test]
+        fn test_div_inverts_mul(a in -(1i64 << 40)..(1i64 << 40), b in 1i32..1000) {
+            let a = GuardedFixed64::from_bits(a);
+            let b = GuardedFixed64::from(b);
+            prop_assert_eq!((a * b).and_then(|product| product / b), Ok(a));
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Canonical edge-case values for testing code that uses guarded values.
+//!
+//! The crate's own property tests mix these values into their random inputs, and they are exposed
+//! behind the `fixtures` feature so that downstream crates can run their integration tests against
+//! the same corpus. The `VALID_*` arrays only contain finite values, which `GuardedF64::new` and
+//! `GuardedF32::new` accept, and the `INVALID_*` arrays only contain NaN and infinities, which
+//! they reject.
+//!
+//! # Example
+//!
+//! ```rust
+//! use floatguard::GuardedF64;
+//! use floatguard::fixtures::{INVALID_F64, VALID_F64};
+//!
+//! for value in VALID_F64 {
+//!     assert!(GuardedF64::new(value).is_ok());
+//! }
+//! for value in INVALID_F64 {
+//!     assert!(GuardedF64::new(value).is_err());
+//! }
+//! ```
+
+/// The largest `f64` whose exponential is finite.
+pub const EXP_MAX_F64: f64 = 709.782_712_893_384;
+
+/// The smallest `f64` whose exponential is not zero.
+pub const EXP_MIN_F64: f64 = -745.133_219_101_941_1;
+
+/// The largest `f64` whose square is finite.
+pub const SQRT_MAX_F64: f64 = 1.340_780_792_994_259_6e154;
+
+/// The largest `f32` whose exponential is finite.
+pub const EXP_MAX_F32: f32 = 88.722_83;
+
+/// The smallest `f32` whose exponential is not zero.
+pub const EXP_MIN_F32: f32 = -103.972_08;
+
+/// The largest `f32` whose square is finite.
+pub const SQRT_MAX_F32: f32 = 1.844_674_3e19;
+
+/// Finite `f64` values at the edges of the representable range and of common functions.
+pub const VALID_F64: [f64; 22] = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.0,
+    1.0 + f64::EPSILON,
+    1.0 - f64::EPSILON / 2.0,
+    f64::EPSILON,
+    // The smallest positive subnormal value.
+    f64::from_bits(1),
+    -f64::from_bits(1),
+    // The largest subnormal value.
+    f64::from_bits(0x000f_ffff_ffff_ffff),
+    f64::MIN_POSITIVE,
+    -f64::MIN_POSITIVE,
+    f64::MAX,
+    f64::MIN,
+    // The largest integer below which every integer is exactly representable.
+    9_007_199_254_740_992.0,
+    EXP_MAX_F64,
+    // The smallest value whose exponential overflows.
+    709.782_712_893_384_1,
+    EXP_MIN_F64,
+    SQRT_MAX_F64,
+    -SQRT_MAX_F64,
+    std::f64::consts::PI,
+    std::f64::consts::FRAC_PI_2,
+];
+
+/// NaN and infinite `f64` values, including NaNs with a sign bit, a payload, or the signaling bit.
+pub const INVALID_F64: [f64; 7] = [
+    f64::NAN,
+    -f64::NAN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    // A signaling NaN, with the quiet bit clear.
+    f64::from_bits(0x7ff0_0000_0000_0001),
+    // A quiet NaN with every payload bit set.
+    f64::from_bits(0x7fff_ffff_ffff_ffff),
+    // A negative quiet NaN with a payload.
+    f64::from_bits(0xfff8_0000_0000_0001),
+];
+
+/// Finite `f32` values at the edges of the representable range and of common functions.
+pub const VALID_F32: [f32; 22] = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.0,
+    1.0 + f32::EPSILON,
+    1.0 - f32::EPSILON / 2.0,
+    f32::EPSILON,
+    // The smallest positive subnormal value.
+    f32::from_bits(1),
+    -f32::from_bits(1),
+    // The largest subnormal value.
+    f32::from_bits(0x007f_ffff),
+    f32::MIN_POSITIVE,
+    -f32::MIN_POSITIVE,
+    f32::MAX,
+    f32::MIN,
+    // The largest integer below which every integer is exactly representable.
+    16_777_216.0,
+    EXP_MAX_F32,
+    // The smallest value whose exponential overflows.
+    88.722_84,
+    EXP_MIN_F32,
+    SQRT_MAX_F32,
+    -SQRT_MAX_F32,
+    std::f32::consts::PI,
+    std::f32::consts::FRAC_PI_2,
+];
+
+/// NaN and infinite `f32` values, including NaNs with a sign bit, a payload, or the signaling bit.
+pub const INVALID_F32: [f32; 7] = [
+    f32::NAN,
+    -f32::NAN,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    // A signaling NaN, with the quiet bit clear.
+    f32::from_bits(0x7f80_0001),
+    // A quiet NaN with every payload bit set.
+    f32::from_bits(0x7fff_ffff),
+    // A negative quiet NaN with a payload.
+    f32::from_bits(0xffc0_0001),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GuardedF32, GuardedF64};
+
+    #[test]
+    fn test_corpus() {
+        assert!(VALID_F64.iter().all(|&v| GuardedF64::new(v).is_ok()));
+        assert!(INVALID_F64.iter().all(|&v| GuardedF64::new(v).is_err()));
+        assert!(VALID_F32.iter().all(|&v| GuardedF32::new(v).is_ok()));
+        assert!(INVALID_F32.iter().all(|&v| GuardedF32::new(v).is_err()));
+    }
+
+    #[test]
+    fn test_thresholds() {
+        assert!(EXP_MAX_F64.exp().is_finite());
+        assert!(VALID_F64[16].exp().is_infinite());
+        assert!(EXP_MIN_F64.exp() > 0.0);
+        assert!(f64::from_bits(EXP_MIN_F64.to_bits() + 1).exp() == 0.0);
+        assert!((SQRT_MAX_F64 * SQRT_MAX_F64).is_finite());
+        assert!((f64::from_bits(SQRT_MAX_F64.to_bits() + 1).powi(2)).is_infinite());
+
+        assert!(EXP_MAX_F32.exp().is_finite());
+        assert!(VALID_F32[16].exp().is_infinite());
+        assert!(EXP_MIN_F32.exp() > 0.0);
+        assert!(f32::from_bits(EXP_MIN_F32.to_bits() + 1).exp() == 0.0);
+        assert!((SQRT_MAX_F32 * SQRT_MAX_F32).is_finite());
+        assert!((f32::from_bits(SQRT_MAX_F32.to_bits() + 1).powi(2)).is_infinite());
+
+        assert!(VALID_F64[16] == f64::from_bits(EXP_MAX_F64.to_bits() + 1));
+        assert!(VALID_F32[16] == f32::from_bits(EXP_MAX_F32.to_bits() + 1));
+    }
+}
@@ -0,0 +1,196 @@
+use super::GeoError;
+use crate::GuardedF64;
+
+/// Converts an angle in degrees, minutes, and seconds to decimal degrees.
+///
+/// The sign of the angle is the sign of `degrees`, including `-0.0`, so angles between `0` and
+/// `-1` degrees can be written as `-0.0` degrees and a positive number of minutes. `minutes` and
+/// `seconds` must both be in `[0, 60)`. The result is not checked against the bounds of a latitude
+/// or longitude; pass it to `check_latitude` or `check_longitude` for that.
+///
+/// # Errors
+///
+/// Returns `GeoError::MinutesOutOfRange` or `GeoError::SecondsOutOfRange` if `minutes` or `seconds`
+/// are not in `[0, 60)`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::geo::{GeoError, dms_to_decimal};
+///
+/// let [d, m, s] = [-33.0, 52.0, 30.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(dms_to_decimal(d, m, s), Ok(GuardedF64::new(-33.875).unwrap()));
+///
+/// let [d, m, s] = [-0.0, 30.0, 0.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(dms_to_decimal(d, m, s), Ok(GuardedF64::new(-0.5).unwrap()));
+///
+/// let [d, m, s] = [12.0, 60.0, 0.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(dms_to_decimal(d, m, s), Err(GeoError::MinutesOutOfRange { minutes: m }));
+/// ```
+pub fn dms_to_decimal(
+    degrees: GuardedF64,
+    minutes: GuardedF64,
+    seconds: GuardedF64,
+) -> Result<GuardedF64, GeoError> {
+    if !(0.0..60.0).contains(&minutes.0) {
+        return Err(GeoError::MinutesOutOfRange { minutes });
+    }
+    if !(0.0..60.0).contains(&seconds.0) {
+        return Err(GeoError::SecondsOutOfRange { seconds });
+    }
+
+    // The fraction is less than one degree, so adding it to a finite angle cannot overflow.
+    let fraction = (minutes.0 + seconds.0 / 60.0) / 60.0;
+    let magnitude = degrees.0.abs() + fraction;
+    Ok(GuardedF64(if degrees.0.is_sign_negative() {
+        -magnitude
+    } else {
+        magnitude
+    }))
+}
+
+/// Splits an angle in decimal degrees into whole degrees, whole minutes, and seconds.
+///
+/// The sign of the angle is carried by the degrees, which are `-0.0` for angles between `0` and
+/// `-1` degrees, so the result converts back with `dms_to_decimal`. The minutes are always a whole
+/// number in `[0, 60)`, and the seconds are always in `[0, 60)`, even when rounding would have
+/// carried them into the next minute.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::geo::decimal_to_dms;
+///
+/// let (d, m, s) = decimal_to_dms(GuardedF64::new(-33.875).unwrap());
+/// assert_eq!((d, m, s), (GuardedF64::new(-33.0).unwrap(), GuardedF64::new(52.0).unwrap(), GuardedF64::new(30.0).unwrap()));
+///
+/// let (d, m, _) = decimal_to_dms(GuardedF64::new(-0.5).unwrap());
+/// assert!(d.get().is_sign_negative());
+/// assert_eq!(m, GuardedF64::new(30.0).unwrap());
+/// ```
+#[must_use = "function returns a new value and does not mutate the original value"]
+pub fn decimal_to_dms(angle: GuardedF64) -> (GuardedF64, GuardedF64, GuardedF64) {
+    let magnitude = angle.0.abs();
+    let degrees = magnitude.floor();
+
+    // The fraction is less than one, but scaling it can round up to a full minute or second, so
+    // both are kept below `60`.
+    let minutes = (magnitude - degrees) * 60.0;
+    let whole_minutes = minutes.floor().min(59.0);
+    let seconds = ((minutes - whole_minutes) * 60.0).min(60f64.next_down());
+
+    let degrees = if angle.0.is_sign_negative() {
+        -degrees
+    } else {
+        degrees
+    };
+    (
+        GuardedF64(degrees),
+        GuardedF64(whole_minutes),
+        GuardedF64(seconds),
+    )
+}
+
+/// Checks that an angle in decimal degrees is a valid latitude, in `[-90, 90]`.
+///
+/// # Errors
+///
+/// Returns `GeoError::LatitudeOutOfRange` if the angle is beyond either pole.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::geo::{GeoError, check_latitude};
+///
+/// let degrees = GuardedF64::new(-90.0).unwrap();
+/// assert_eq!(check_latitude(degrees), Ok(degrees));
+///
+/// let degrees = GuardedF64::new(91.0).unwrap();
+/// assert_eq!(check_latitude(degrees), Err(GeoError::LatitudeOutOfRange { degrees }));
+/// ```
+pub fn check_latitude(degrees: GuardedF64) -> Result<GuardedF64, GeoError> {
+    if (-90.0..=90.0).contains(&degrees.0) {
+        Ok(degrees)
+    } else {
+        Err(GeoError::LatitudeOutOfRange { degrees })
+    }
+}
+
+/// Checks that an angle in decimal degrees is a valid longitude, in `[-180, 180]`.
+///
+/// Both `-180` and `180` are accepted, since data sources disagree on which one to use for the
+/// antimeridian.
+///
+/// # Errors
+///
+/// Returns `GeoError::LongitudeOutOfRange` if the angle is beyond the antimeridian.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::geo::{GeoError, check_longitude};
+///
+/// let degrees = GuardedF64::new(180.0).unwrap();
+/// assert_eq!(check_longitude(degrees), Ok(degrees));
+///
+/// let degrees = GuardedF64::new(-180.5).unwrap();
+/// assert_eq!(check_longitude(degrees), Err(GeoError::LongitudeOutOfRange { degrees }));
+/// ```
+pub fn check_longitude(degrees: GuardedF64) -> Result<GuardedF64, GeoError> {
+    if (-180.0..=180.0).contains(&degrees.0) {
+        Ok(degrees)
+    } else {
+        Err(GeoError::LongitudeOutOfRange { degrees })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_rejects_out_of_range_components() {
+        let (zero, sixty) = (GuardedF64(0.0), GuardedF64(60.0));
+        let negative = GuardedF64(-1.0);
+        assert_eq!(
+            dms_to_decimal(zero, negative, zero),
+            Err(GeoError::MinutesOutOfRange { minutes: negative })
+        );
+        assert_eq!(
+            dms_to_decimal(zero, zero, sixty),
+            Err(GeoError::SecondsOutOfRange { seconds: sixty })
+        );
+        assert_eq!(
+            dms_to_decimal(GuardedF64::MAX, GuardedF64(59.0), zero),
+            Ok(GuardedF64::MAX)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_components_in_range(angle in valid_f64()) {
+            let (degrees, minutes, seconds) = decimal_to_dms(GuardedF64(angle));
+            prop_assert_eq!(degrees.0.is_sign_negative(), angle.is_sign_negative());
+            prop_assert_eq!(degrees.0.fract(), 0.0);
+            prop_assert_eq!(minutes.0.fract(), 0.0);
+            prop_assert!((0.0..60.0).contains(&minutes.0));
+            prop_assert!((0.0..60.0).contains(&seconds.0));
+        }
+
+        #[test]
+        fn test_round_trip(angle in -180.0..=180.0f64) {
+            let (degrees, minutes, seconds) = decimal_to_dms(GuardedF64(angle));
+            let decimal = dms_to_decimal(degrees, minutes, seconds).unwrap();
+            prop_assert!((decimal.0 - angle).abs() <= 1e-12);
+            prop_assert_eq!(check_longitude(decimal), Ok(decimal));
+        }
+    }
+}
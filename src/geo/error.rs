@@ -0,0 +1,58 @@
+use crate::GuardedF64;
+
+/// An error occurred while converting or validating a geographic coordinate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoError {
+    /// Indicates that the minutes of an angle are not in `[0, 60)`.
+    MinutesOutOfRange {
+        /// The offending minutes.
+        minutes: GuardedF64,
+    },
+
+    /// Indicates that the seconds of an angle are not in `[0, 60)`.
+    SecondsOutOfRange {
+        /// The offending seconds.
+        seconds: GuardedF64,
+    },
+
+    /// Indicates that a latitude is not in `[-90, 90]`.
+    LatitudeOutOfRange {
+        /// The offending latitude, in decimal degrees.
+        degrees: GuardedF64,
+    },
+
+    /// Indicates that a longitude is not in `[-180, 180]`.
+    LongitudeOutOfRange {
+        /// The offending longitude, in decimal degrees.
+        degrees: GuardedF64,
+    },
+}
+
+impl std::error::Error for GeoError {}
+
+/// Implements the `Display` trait for the `GeoError` enum, providing a user-friendly description of
+/// the error.
+impl std::fmt::Display for GeoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MinutesOutOfRange { minutes } => {
+                write!(f, "The minutes {minutes} are not between 0 and 60")
+            }
+            Self::SecondsOutOfRange { seconds } => {
+                write!(f, "The seconds {seconds} are not between 0 and 60")
+            }
+            Self::LatitudeOutOfRange { degrees } => {
+                write!(
+                    f,
+                    "The latitude {degrees} is not between -90 and 90 degrees"
+                )
+            }
+            Self::LongitudeOutOfRange { degrees } => {
+                write!(
+                    f,
+                    "The longitude {degrees} is not between -180 and 180 degrees"
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Conversions and validation for geographic coordinates.
+//!
+//! Coordinates from EXIF metadata, GPS receivers, and hand-entered data are often written in
+//! degrees, minutes, and seconds, and just as often arrive with minutes of `60` or latitudes past
+//! the poles. `dms_to_decimal` rejects components that are out of range instead of silently
+//! folding them into the result, and `decimal_to_dms` splits decimal degrees back into components
+//! that are always in range. Since every component is guarded, neither conversion can overflow.
+//! `check_latitude` and `check_longitude` validate the bounds of a decimal coordinate, for the
+//! callers that know which one they have.
+mod dms;
+mod error;
+
+pub use dms::{check_latitude, check_longitude, decimal_to_dms, dms_to_decimal};
+pub use error::GeoError;
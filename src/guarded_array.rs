@@ -0,0 +1,62 @@
+//! This module provides the `guarded_array!` macro, which builds arrays of `GuardedF64` values that
+//! are validated at compile time.
+use crate::GuardedF64;
+
+/// Creates an array of `GuardedF64` values from `f64` expressions, validated at compile time.
+///
+/// The array is built in a `const` block, so every value must be a constant expression, and a value
+/// that is not finite fails to compile instead of panicking at runtime. This makes the macro
+/// suitable for coefficient and lookup tables, including `const` and `static` items.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{guarded_array, GuardedF64};
+///
+/// const COEFFICIENTS: [GuardedF64; 3] = guarded_array![1.0, 2.5, 3.75];
+/// assert_eq!(COEFFICIENTS[1], 2.5);
+///
+/// let weights = guarded_array![0.25, 0.75];
+/// assert_eq!(weights.len(), 2);
+/// ```
+///
+/// ```rust,compile_fail
+/// use floatguard::guarded_array;
+///
+/// let invalid = guarded_array![1.0, f64::NAN];
+/// ```
+#[macro_export]
+macro_rules! guarded_array {
+    ($($value:expr),* $(,)?) => {
+        const { [$($crate::__private::guarded_f64($value)),*] }
+    };
+}
+
+/// Validates a value for `guarded_array!`, panicking if it is not finite. Not part of the public
+/// API.
+#[doc(hidden)]
+#[must_use]
+pub const fn guarded_f64(value: f64) -> GuardedF64 {
+    assert!(value.is_finite(), "`guarded_array!` values must be finite");
+    GuardedF64(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guarded_array() {
+        const VALUES: [GuardedF64; 3] = guarded_array![1.0, -2.5, f64::MAX];
+        assert_eq!(VALUES, [GuardedF64(1.0), GuardedF64(-2.5), GuardedF64::MAX]);
+
+        let empty: [GuardedF64; 0] = guarded_array![];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "`guarded_array!` values must be finite")]
+    fn test_guarded_f64_panics() {
+        let _ = guarded_f64(std::hint::black_box(f64::INFINITY));
+    }
+}
@@ -0,0 +1,57 @@
+//! This module implements conversions between `GuardedVec` and the `glam` double-precision
+//! vectors, `DVec2`, `DVec3`, and `DVec4`.
+use super::GuardedVec;
+use crate::FloatError;
+use ::glam::{DVec2, DVec3, DVec4};
+
+macro_rules! glam_conversion {
+    ($($DVec:ty => $N:literal),* $(,)?) => {
+        $(
+            impl TryFrom<$DVec> for GuardedVec<$N> {
+                type Error = FloatError;
+
+                #[doc = concat!("Validates each component of a `", stringify!($DVec), "`.")]
+                ///
+                /// # Errors
+                ///
+                /// Returns the `FloatError` of the first component that is NaN or infinite.
+                fn try_from(vector: $DVec) -> Result<Self, Self::Error> {
+                    Self::try_from(vector.to_array())
+                }
+            }
+
+            impl From<GuardedVec<$N>> for $DVec {
+                #[doc = concat!("Converts a `GuardedVec` into a `", stringify!($DVec), "`.")]
+                fn from(vector: GuardedVec<$N>) -> Self {
+                    Self::from_array(vector.into())
+                }
+            }
+        )*
+    };
+}
+
+glam_conversion!(DVec2 => 2, DVec3 => 3, DVec4 => 4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_round_trip(components in prop::array::uniform3(valid_f64())) {
+            let vector = DVec3::from_array(components);
+            let guarded = GuardedVec::try_from(vector).unwrap();
+            prop_assert_eq!(DVec3::from(guarded), vector);
+
+            let vector = DVec2::new(components[0], components[1]);
+            prop_assert_eq!(DVec2::from(GuardedVec::try_from(vector).unwrap()), vector);
+        }
+
+        #[test]
+        fn test_invalid_component(invalid in invalid_f64()) {
+            prop_assert!(GuardedVec::try_from(DVec4::new(0.0, 0.0, invalid, 0.0)).is_err());
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! This module provides the `GuardedVec` type, a fixed-size vector of guarded components.
+//!
+//! `GuardedVec` is the boundary type for interop with linear algebra crates. Behind the `nalgebra`
+//! and `glam` features, vectors from those crates can be converted into a `GuardedVec`, which
+//! validates every component, and converted back without any checks.
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+use crate::{FloatError, GuardedF64};
+
+/// A fixed-size vector whose components are all `GuardedF64` values.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64, GuardedVec};
+///
+/// let vector = GuardedVec::try_from([1.0, 2.0, 3.0]).unwrap();
+/// assert_eq!(vector[1], GuardedF64::new(2.0).unwrap());
+/// assert_eq!(<[f64; 3]>::from(vector), [1.0, 2.0, 3.0]);
+///
+/// assert_eq!(GuardedVec::try_from([1.0, f64::NAN]), Err(FloatError::NaN));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardedVec<const N: usize>([GuardedF64; N]);
+
+impl<const N: usize> GuardedVec<N> {
+    /// Creates a new `GuardedVec` from its components.
+    #[must_use]
+    pub const fn new(components: [GuardedF64; N]) -> Self {
+        Self(components)
+    }
+
+    /// Returns the components of the vector.
+    #[must_use]
+    pub const fn into_array(self) -> [GuardedF64; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> From<[GuardedF64; N]> for GuardedVec<N> {
+    /// Wraps an array of guarded components, which are already known to be valid.
+    fn from(components: [GuardedF64; N]) -> Self {
+        Self(components)
+    }
+}
+
+impl<const N: usize> TryFrom<[f64; N]> for GuardedVec<N> {
+    type Error = FloatError;
+
+    /// Validates each component of an array of `f64` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `FloatError` of the first component that is NaN or infinite.
+    fn try_from(components: [f64; N]) -> Result<Self, Self::Error> {
+        let mut guarded = [GuardedF64(0.0); N];
+        for (slot, component) in guarded.iter_mut().zip(components) {
+            *slot = GuardedF64::new_reported(component)?;
+        }
+        Ok(Self(guarded))
+    }
+}
+
+impl<const N: usize> From<GuardedVec<N>> for [GuardedF64; N] {
+    /// Unwraps the guarded components of the vector.
+    fn from(vector: GuardedVec<N>) -> Self {
+        vector.0
+    }
+}
+
+impl<const N: usize> From<GuardedVec<N>> for [f64; N] {
+    /// Unwraps the components of the vector as `f64` values.
+    fn from(vector: GuardedVec<N>) -> Self {
+        vector.0.map(|component| component.0)
+    }
+}
+
+impl<const N: usize> std::ops::Deref for GuardedVec<N> {
+    type Target = [GuardedF64; N];
+
+    /// Dereferences the vector to its array of components.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_array_round_trip(components in prop::array::uniform3(valid_f64())) {
+            let vector = GuardedVec::try_from(components).unwrap();
+            prop_assert_eq!(<[f64; 3]>::from(vector), components);
+            prop_assert_eq!(GuardedVec::from(vector.into_array()), vector);
+        }
+
+        #[test]
+        fn test_invalid_component(
+            mut components in prop::array::uniform3(valid_f64()),
+            invalid in invalid_f64(),
+            index in 0usize..3,
+        ) {
+            components[index] = invalid;
+            prop_assert_eq!(
+                GuardedVec::try_from(components),
+                Err(GuardedF64::new(invalid).unwrap_err())
+            );
+        }
+    }
+}
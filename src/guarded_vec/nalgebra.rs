@@ -0,0 +1,59 @@
+//! This module implements conversions between `GuardedVec` and `nalgebra::SVector`.
+use super::GuardedVec;
+use crate::FloatError;
+use ::nalgebra::SVector;
+
+impl<const N: usize> TryFrom<SVector<f64, N>> for GuardedVec<N> {
+    type Error = FloatError;
+
+    /// Validates each component of a `nalgebra` vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `FloatError` of the first component that is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedVec};
+    /// use nalgebra::Vector3;
+    ///
+    /// let vector = GuardedVec::try_from(Vector3::new(1.0, 2.0, 3.0)).unwrap();
+    /// assert_eq!(Vector3::from(vector), Vector3::new(1.0, 2.0, 3.0));
+    ///
+    /// let invalid = Vector3::new(1.0, f64::INFINITY, 3.0);
+    /// assert_eq!(GuardedVec::try_from(invalid), Err(FloatError::Infinity));
+    /// ```
+    fn try_from(vector: SVector<f64, N>) -> Result<Self, Self::Error> {
+        Self::try_from(<[f64; N]>::from(vector))
+    }
+}
+
+impl<const N: usize> From<GuardedVec<N>> for SVector<f64, N> {
+    /// Converts a `GuardedVec` into a `nalgebra` vector.
+    fn from(vector: GuardedVec<N>) -> Self {
+        Self::from(<[f64; N]>::from(vector))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_round_trip(components in prop::array::uniform4(valid_f64())) {
+            let vector = SVector::<f64, 4>::from(components);
+            let guarded = GuardedVec::try_from(vector).unwrap();
+            prop_assert_eq!(SVector::from(guarded), vector);
+        }
+
+        #[test]
+        fn test_invalid_component(invalid in invalid_f64()) {
+            let vector = SVector::<f64, 2>::new(0.0, invalid);
+            prop_assert!(GuardedVec::try_from(vector).is_err());
+        }
+    }
+}
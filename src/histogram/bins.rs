@@ -0,0 +1,232 @@
+use super::HistogramError;
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// Counts samples into bins bounded by guarded edges.
+///
+/// A histogram with `n + 1` edges has `n` bins. Each bin includes its lower edge and excludes its
+/// upper edge, except for the last bin, which includes both. Samples below the first edge or above
+/// the last edge are counted by `below` and `above`, and samples that are NaN or infinite are
+/// counted by `invalid`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::histogram::Histogram;
+///
+/// let edges = [0.0, 1.0, 2.0, 4.0].map(|v| GuardedF64::new(v).unwrap());
+/// let mut histogram = Histogram::new(&edges).unwrap();
+///
+/// for sample in [0.5, 1.0, 1.5, 3.0, 4.0, 5.0] {
+///     histogram.add(sample).unwrap();
+/// }
+/// assert!(histogram.add(f64::NAN).is_err());
+///
+/// assert_eq!(histogram.counts(), &[1, 2, 2]);
+/// assert_eq!((histogram.above(), histogram.invalid()), (1, 1));
+///
+/// let densities = histogram.densities().unwrap();
+/// assert_eq!(densities, [0.2, 0.4, 0.2].map(|v| GuardedF64::new(v).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    edges: Vec<GuardedF64>,
+    counts: Vec<u64>,
+    below: u64,
+    above: u64,
+    invalid: u64,
+}
+
+impl Histogram {
+    /// Creates an empty `Histogram` with the given bin edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HistogramError::TooFewEdges` if there are fewer than two edges, or
+    /// `HistogramError::NotIncreasing` with the index of the first edge that is not strictly greater
+    /// than the one before it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use floatguard::histogram::{Histogram, HistogramError};
+    ///
+    /// let edges = [0.0, 2.0, 2.0].map(|v| GuardedF64::new(v).unwrap());
+    /// assert_eq!(Histogram::new(&edges), Err(HistogramError::NotIncreasing { index: 2 }));
+    /// assert_eq!(Histogram::new(&edges[..1]), Err(HistogramError::TooFewEdges));
+    /// ```
+    pub fn new(edges: &[GuardedF64]) -> Result<Self, HistogramError> {
+        if edges.len() < 2 {
+            return Err(HistogramError::TooFewEdges);
+        }
+
+        if let Some(index) = edges.windows(2).position(|pair| pair[1] <= pair[0]) {
+            return Err(HistogramError::NotIncreasing { index: index + 1 });
+        }
+
+        Ok(Self {
+            edges: edges.to_vec(),
+            counts: vec![0; edges.len() - 1],
+            below: 0,
+            above: 0,
+            invalid: 0,
+        })
+    }
+
+    /// Adds a sample to the bin that contains it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if the sample is NaN or infinite. The sample is still counted by
+    /// `invalid`, so the error can be ignored when only the tally matters.
+    pub fn add(&mut self, sample: impl Into<UnguardedF64>) -> Result<(), FloatError> {
+        let sample = match sample.into().check_reported() {
+            Ok(sample) => sample,
+            Err(error) => {
+                self.invalid += 1;
+                return Err(error);
+            }
+        };
+
+        let last = self.edges.len() - 1;
+        if sample < self.edges[0] {
+            self.below += 1;
+        } else if sample > self.edges[last] {
+            self.above += 1;
+        } else {
+            let upper = self.edges.partition_point(|&edge| edge <= sample);
+            self.counts[upper.clamp(1, last) - 1] += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the bin edges.
+    #[must_use]
+    pub fn edges(&self) -> &[GuardedF64] {
+        &self.edges
+    }
+
+    /// Returns the number of samples in each bin.
+    #[must_use]
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Returns the number of samples that were below the first edge.
+    #[must_use]
+    pub const fn below(&self) -> u64 {
+        self.below
+    }
+
+    /// Returns the number of samples that were above the last edge.
+    #[must_use]
+    pub const fn above(&self) -> u64 {
+        self.above
+    }
+
+    /// Returns the number of samples that were NaN or infinite.
+    #[must_use]
+    pub const fn invalid(&self) -> u64 {
+        self.invalid
+    }
+
+    /// Returns the number of samples in all of the bins, excluding samples that were out of range
+    /// or invalid.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the density of each bin, which is the fraction of the binned samples that it holds
+    /// divided by its width, so that the densities integrate to one over the edges.
+    ///
+    /// If no samples have been binned, every density is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if a bin is so narrow that its density is too large to
+    /// represent.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn densities(&self) -> Result<Vec<GuardedF64>, FloatError> {
+        let total = self.total();
+        if total == 0 {
+            return Ok(vec![GuardedF64(0.0); self.counts.len()]);
+        }
+
+        self.counts
+            .iter()
+            .zip(self.edges.windows(2))
+            .map(|(&count, pair)| {
+                let fraction = count as f64 / total as f64;
+
+                // The width between extreme edges can overflow, but half of it cannot.
+                let width = pair[1].0 - pair[0].0;
+                if width.is_finite() {
+                    UnguardedF64(fraction / width).check_reported()
+                } else {
+                    UnguardedF64((fraction * 0.5) / pair[1].0.mul_add(0.5, -pair[0].0 * 0.5))
+                        .check_reported()
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn histogram() -> impl Strategy<Value = Histogram> {
+        prop::collection::vec(valid_f64().prop_map(|v| GuardedF64::new(v).unwrap()), 2..16)
+            .prop_filter_map("Reject fewer than two distinct edges", |mut edges| {
+                edges.sort();
+                edges.dedup();
+                Histogram::new(&edges).ok()
+            })
+    }
+
+    #[test]
+    fn test_extreme_edges() {
+        let mut histogram = Histogram::new(&[GuardedF64::MIN, GuardedF64::MAX]).unwrap();
+        histogram.add(0.0).unwrap();
+        assert_eq!(histogram.add(f64::INFINITY), Err(FloatError::Infinity));
+
+        let density = histogram.densities().unwrap()[0];
+        assert!((density.0 * f64::MAX).mul_add(2.0, -1.0).abs() < 1e-12);
+
+        let tiny = GuardedF64::new(f64::from_bits(1)).unwrap();
+        let mut histogram = Histogram::new(&[GuardedF64(0.0), tiny]).unwrap();
+        histogram.add(0.0).unwrap();
+        assert_eq!(histogram.densities(), Err(FloatError::Infinity));
+    }
+
+    proptest! {
+        #[test]
+        fn test_every_sample_is_counted(
+            mut histogram in histogram(),
+            samples in prop::collection::vec(any::<f64>(), 0..64),
+        ) {
+            for &sample in &samples {
+                prop_assert_eq!(histogram.add(sample).is_ok(), sample.is_finite());
+            }
+
+            let counted = histogram.total() + histogram.below() + histogram.above() + histogram.invalid();
+            prop_assert_eq!(counted, samples.len() as u64);
+        }
+
+        #[test]
+        fn test_edges_fall_in_bins(mut histogram in histogram()) {
+            for edge in histogram.edges().to_vec() {
+                histogram.add(edge).unwrap();
+            }
+
+            let counts = histogram.counts();
+            prop_assert_eq!(counts[counts.len() - 1], 2);
+            prop_assert!(counts[..counts.len() - 1].iter().all(|&count| count == 1));
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/// An error occurred while building a `Histogram`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistogramError {
+    /// Indicates that fewer than two edges were given, so there are no bins.
+    TooFewEdges,
+
+    /// Indicates that the edge at `index` is not strictly greater than the one before it.
+    NotIncreasing {
+        /// The index of the offending edge.
+        index: usize,
+    },
+}
+
+impl std::error::Error for HistogramError {}
+
+/// Implements the `Display` trait for the `HistogramError` enum, providing a user-friendly
+/// description of the error.
+impl std::fmt::Display for HistogramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooFewEdges => write!(f, "The histogram needs at least two bin edges"),
+            Self::NotIncreasing { index } => write!(
+                f,
+                "The bin edge at index {index} is not greater than the one before it"
+            ),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Histograms over guarded bin edges.
+//!
+//! A `Histogram` validates its bin edges once, when it is built, so adding a sample only has to
+//! find the bin that contains it. Samples that are NaN or infinite are tallied separately from
+//! samples that fall outside of the edges, so neither can silently distort the bin counts.
+mod bins;
+mod error;
+
+pub use bins::Histogram;
+pub use error::HistogramError;
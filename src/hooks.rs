@@ -0,0 +1,108 @@
+//! A global hook that is invoked when a floating-point value fails validation.
+//!
+//! Every validation in the crate reports to the hook, including the checks inside math, statistics,
+//! slice, and root-finding functions, except for the `const fn`s `GuardedF64::new`,
+//! `UnguardedF64::check`, `check_clamped`, and the byte constructors, which cannot call a function
+//! pointer. Their non-`const` counterparts, `GuardedF64::new_reported` and
+//! `UnguardedF64::check_reported`, do report.
+//!
+//! This module is only available when the `hooks` feature is enabled. Without it, validation does
+//! not check for a hook at all, so there is no runtime cost.
+use crate::FloatError;
+use std::sync::{PoisonError, RwLock};
+
+/// A function that is called with the error and the raw value when validation fails.
+///
+/// `f32` values are widened to `f64` before they are passed to the hook.
+pub type InvalidHook = fn(&FloatError, f64);
+
+static HOOK: RwLock<Option<InvalidHook>> = RwLock::new(None);
+
+/// Sets the global hook that is invoked when a value fails validation, replacing any previous hook.
+///
+/// The hook is called on the thread that performed the validation, for every failure except those
+/// of the `const fn`s listed in the module documentation. It is intended for instrumentation, such
+/// as counting failures in a metrics system.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, UnguardedF64};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static FAILURES: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn count_failure(_error: &FloatError, _value: f64) {
+///     FAILURES.fetch_add(1, Ordering::Relaxed);
+/// }
+///
+/// floatguard::set_invalid_hook(count_failure);
+///
+/// assert!((UnguardedF64::new(1.0) / 0.0).check_reported().is_err());
+/// assert_eq!(FAILURES.load(Ordering::Relaxed), 1);
+///
+/// floatguard::clear_invalid_hook();
+/// ```
+pub fn set_invalid_hook(hook: InvalidHook) {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(hook);
+}
+
+/// Removes the global invalid-value hook, returning it if one was set.
+pub fn clear_invalid_hook() -> Option<InvalidHook> {
+    HOOK.write().unwrap_or_else(PoisonError::into_inner).take()
+}
+
+/// Reports a failed validation to the global hook, if one is set.
+pub fn report_invalid(error: FloatError, value: f64) {
+    let hook = *HOOK.read().unwrap_or_else(PoisonError::into_inner);
+    if let Some(hook) = hook {
+        hook(&error, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GuardedF32, GuardedF64, UnguardedF32, UnguardedF64};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static REPORTED: RefCell<Vec<(FloatError, u64)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    // The signature is fixed by `InvalidHook`.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn record(error: &FloatError, value: f64) {
+        REPORTED.with_borrow_mut(|reported| reported.push((*error, value.to_bits())));
+    }
+
+    #[test]
+    fn test_hook_reports_failures() {
+        set_invalid_hook(record);
+
+        assert!(GuardedF64::new_reported(1.0).is_ok());
+        assert!(GuardedF64::new_reported(f64::NAN).is_err());
+        assert!(UnguardedF64::new(f64::INFINITY).check_reported().is_err());
+        assert!(GuardedF32::try_from(f32::NEG_INFINITY).is_err());
+        assert!(UnguardedF32::new(f32::NAN).check_reported().is_err());
+
+        // Validation inside the rest of the crate is reported too.
+        assert!(crate::slice::sum_checked(&[GuardedF64::MAX, GuardedF64::MAX]).is_err());
+
+        // The `const` validating functions are never reported.
+        assert!(GuardedF64::new(f64::NAN).is_err());
+        assert!(UnguardedF64::new(f64::INFINITY).check().is_err());
+        assert!(UnguardedF32::new(f32::NAN).check_clamped().is_err());
+
+        let reported = REPORTED.take();
+        assert_eq!(reported.len(), 5);
+        assert_eq!(reported[0].0, FloatError::NaN);
+        assert_eq!(reported[1], (FloatError::Infinity, f64::INFINITY.to_bits()));
+        assert_eq!(
+            reported[2],
+            (FloatError::Infinity, f64::NEG_INFINITY.to_bits())
+        );
+        assert_eq!(reported[3].0, FloatError::NaN);
+        assert_eq!(reported[4], (FloatError::Infinity, f64::INFINITY.to_bits()));
+    }
+}
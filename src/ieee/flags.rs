@@ -0,0 +1,253 @@
+use super::IeeeException;
+use crate::FloatError;
+use std::arch::asm;
+
+/// Access to the SSE control and status register, `MXCSR`.
+#[cfg(target_arch = "x86_64")]
+mod status {
+    use super::{IeeeException, asm};
+
+    pub type Register = u32;
+
+    /// The exception flags, in the order of `IeeeException::ALL`. The denormal flag is not an IEEE
+    /// exception, so it is left alone.
+    pub const BITS: [Register; 5] = [1 << 0, 1 << 2, 1 << 3, 1 << 4, 1 << 5];
+
+    pub fn read() -> Register {
+        let mut value: Register = 0;
+        // SAFETY: `stmxcsr` only stores the register to the given address, which is a valid `u32`.
+        unsafe { asm!("stmxcsr [{}]", in(reg) &raw mut value, options(nostack, preserves_flags)) };
+        value
+    }
+
+    pub fn write(value: Register) {
+        // SAFETY: Only the sticky exception flags are changed from the value that was read, which
+        // does not affect rounding or exception masking.
+        unsafe {
+            asm!("ldmxcsr [{}]", in(reg) &raw const value, options(nostack, preserves_flags));
+        }
+    }
+
+    const _: () = assert!(BITS.len() == IeeeException::ALL.len());
+}
+
+/// Access to the floating-point status register, `FPSR`.
+#[cfg(target_arch = "aarch64")]
+mod status {
+    use super::{IeeeException, asm};
+
+    pub type Register = u64;
+
+    /// The cumulative exception flags, in the order of `IeeeException::ALL`. The input denormal
+    /// flag is not an IEEE exception, so it is left alone.
+    pub const BITS: [Register; 5] = [1 << 0, 1 << 1, 1 << 2, 1 << 3, 1 << 4];
+
+    pub fn read() -> Register {
+        let value: Register;
+        // SAFETY: Reading `FPSR` has no side effects.
+        unsafe { asm!("mrs {}, fpsr", out(reg) value, options(nomem, nostack, preserves_flags)) };
+        value
+    }
+
+    pub fn write(value: Register) {
+        // SAFETY: Only the cumulative exception flags are changed from the value that was read.
+        unsafe { asm!("msr fpsr, {}", in(reg) value, options(nomem, nostack, preserves_flags)) };
+    }
+
+    const _: () = assert!(BITS.len() == IeeeException::ALL.len());
+}
+
+/// The set of IEEE 754 exceptions that were signaled by the hardware.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FpFlags, IeeeException};
+///
+/// let flags = FpFlags::default();
+/// assert!(flags.is_empty());
+/// assert!(!flags.contains(IeeeException::Overflow));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FpFlags(u8);
+
+impl FpFlags {
+    /// Returns whether `exception` was signaled.
+    #[must_use]
+    pub const fn contains(self, exception: IeeeException) -> bool {
+        self.0 & (1 << exception as u8) != 0
+    }
+
+    /// Returns whether no exception was signaled.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the exceptions that were signaled, in the order of `IeeeException::ALL`.
+    pub fn iter(self) -> impl Iterator<Item = IeeeException> {
+        IeeeException::ALL
+            .iter()
+            .copied()
+            .filter(move |exception| self.contains(*exception))
+    }
+
+    /// Converts the exception flags of a status register.
+    fn from_register(register: status::Register) -> Self {
+        let mut flags = 0;
+        for (index, bit) in status::BITS.iter().enumerate() {
+            if register & bit != 0 {
+                flags |= 1 << index;
+            }
+        }
+        Self(flags)
+    }
+}
+
+impl FloatError {
+    /// Returns a suggestion of what caused the error, narrowed down by the IEEE 754 exceptions that
+    /// were signaled while computing the invalid value.
+    ///
+    /// An infinity may come from a division by zero or from an overflow, and a NaN may come from an
+    /// invalid operation on finite values or from an earlier division by zero. The flags recorded by
+    /// `with_fp_flags` tell these apart, so the hint can name the operation that went wrong first.
+    /// Without a matching flag, this returns the same hint as `hint`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, UnguardedF64, with_fp_flags};
+    /// use std::hint::black_box;
+    ///
+    /// let (value, flags) = with_fp_flags(|| {
+    ///     let length = UnguardedF64::new(black_box(0.0));
+    ///     (UnguardedF64::new(black_box(3.0)) / length - 1.0).check()
+    /// });
+    /// let error = value.unwrap_err();
+    /// assert_eq!(error, FloatError::Infinity);
+    /// assert!(error.hint_with_flags(flags).contains("divided by a value that may be zero"));
+    /// ```
+    #[must_use]
+    pub const fn hint_with_flags(self, flags: FpFlags) -> &'static str {
+        let invalid = matches!(self, Self::NaN | Self::Infinity | Self::DivisionByZero);
+        if invalid && flags.contains(IeeeException::DivisionByZero) {
+            "A finite value was divided by a value that may be zero; check the divisors"
+        } else if matches!(self, Self::Infinity) && flags.contains(IeeeException::Overflow) {
+            "An intermediate value grew too large; rescale the inputs, or reorder the computation \
+             to keep intermediate values small"
+        } else if matches!(self, Self::NaN) && flags.contains(IeeeException::InvalidOperation) {
+            "An operation had no defined result, such as 0/0, infinity minus infinity, or the \
+             square root or logarithm of a negative intermediate value"
+        } else {
+            self.hint()
+        }
+    }
+}
+
+/// Runs `f`, and returns its result with the IEEE 754 exceptions that the hardware signaled while it
+/// ran.
+///
+/// The exception flags are cleared before `f` runs. Afterward, the flags that were set before are
+/// restored, together with the flags that `f` set, so code outside of the closure observes the same
+/// sticky flags as if it had not been called.
+///
+/// Rust does not model the floating-point environment, so the compiler is free to evaluate
+/// constant expressions at compile time or to move arithmetic on values it already knows out of
+/// `f`. Pass the inputs of the operations that should be observed through `std::hint::black_box`.
+/// Only the current thread is affected, and only operations in SIMD registers, which is how
+/// `f32` and `f64` math is performed on `x86_64` and `aarch64`, are observed.
+///
+/// This function is only available when the `fp-flags` feature is enabled on `x86_64` or
+/// `aarch64`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{IeeeException, with_fp_flags};
+/// use std::hint::black_box;
+///
+/// let (value, flags) = with_fp_flags(|| black_box(f64::MIN_POSITIVE) / 3.0);
+/// assert!(value > 0.0);
+/// assert!(flags.contains(IeeeException::Underflow));
+/// assert!(flags.contains(IeeeException::Inexact));
+///
+/// let (_, flags) = with_fp_flags(|| black_box(1.5) + 1.0);
+/// assert!(flags.is_empty());
+/// ```
+pub fn with_fp_flags<T>(f: impl FnOnce() -> T) -> (T, FpFlags) {
+    let mask = status::BITS.iter().fold(0, |mask, bit| mask | bit);
+
+    let saved = status::read();
+    status::write(saved & !mask);
+
+    let result = std::hint::black_box(f());
+
+    let raised = status::read() & mask;
+    status::write(saved | raised);
+
+    (result, FpFlags::from_register(raised))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    fn raised(operation: fn() -> f64) -> Vec<IeeeException> {
+        with_fp_flags(operation).1.iter().collect()
+    }
+
+    #[test]
+    fn test_detects_exceptions() {
+        use IeeeException::{DivisionByZero, Inexact, InvalidOperation, Overflow, Underflow};
+
+        assert_eq!(
+            raised(|| black_box(0.0) / black_box(0.0)),
+            [InvalidOperation]
+        );
+        assert_eq!(raised(|| black_box(1.0) / black_box(0.0)), [DivisionByZero]);
+        assert_eq!(raised(|| black_box(f64::MAX) * 2.0), [Overflow, Inexact]);
+        assert_eq!(
+            raised(|| black_box(f64::MIN_POSITIVE) / 3.0),
+            [Underflow, Inexact]
+        );
+        assert_eq!(raised(|| black_box(1.0) / 3.0), [Inexact]);
+        assert_eq!(raised(|| black_box(1.5) + 1.0), []);
+    }
+
+    #[test]
+    fn test_hint_with_flags() {
+        let hint = |operation: fn() -> f64| {
+            let (value, flags) = with_fp_flags(operation);
+            let error = crate::UnguardedF64::new(value).check().unwrap_err();
+            (error.hint_with_flags(flags), error.hint())
+        };
+
+        let (hint_with_flags, _) =
+            hint(|| black_box(1.0) / black_box(0.0) - black_box(f64::INFINITY));
+        assert!(hint_with_flags.contains("divided by a value that may be zero"));
+
+        let (hint_with_flags, _) = hint(|| black_box(f64::MAX) * 2.0);
+        assert!(hint_with_flags.contains("grew too large"));
+
+        let (hint_with_flags, _) = hint(|| black_box(-1.0f64).sqrt());
+        assert!(hint_with_flags.contains("no defined result"));
+
+        let (hint_with_flags, hint) = hint(|| black_box(f64::NAN));
+        assert_eq!(hint_with_flags, hint);
+    }
+
+    #[test]
+    fn test_restores_outer_flags() {
+        let ((_, inner), outer) = with_fp_flags(|| {
+            black_box(black_box(1.0) / black_box(0.0));
+            with_fp_flags(|| black_box(1.0) / 3.0)
+        });
+
+        assert_eq!(inner.iter().collect::<Vec<_>>(), [IeeeException::Inexact]);
+        assert_eq!(
+            outer.iter().collect::<Vec<_>>(),
+            [IeeeException::DivisionByZero, IeeeException::Inexact]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! The IEEE 754 exceptions that floating-point errors correspond to.
+//!
+//! `FloatError::ieee_class` maps an error reported by this crate to the exception that the hardware
+//! would have signaled for it. With the `fp-flags` feature, `with_fp_flags` reads the exceptions
+//! that the hardware actually signaled while running a closure, which also detects the underflow
+//! and inexact results that validation alone cannot see. The status registers are only read on
+//! `x86_64` and `aarch64`, so on other targets the feature adds nothing.
+#[cfg(all(
+    feature = "fp-flags",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod flags;
+
+#[cfg(all(
+    feature = "fp-flags",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+pub use flags::{FpFlags, with_fp_flags};
+
+/// An exception defined by IEEE 754.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IeeeException {
+    /// An operation had no meaningful result, such as `0.0 / 0.0` or the square root of a negative
+    /// number. The default result is NaN.
+    InvalidOperation,
+
+    /// An operation on finite operands produced an exact infinity, such as `1.0 / 0.0`.
+    DivisionByZero,
+
+    /// A result was too large in magnitude to represent, and was rounded to infinity.
+    Overflow,
+
+    /// A non-zero result was too small in magnitude to represent as a normal number.
+    Underflow,
+
+    /// A result had to be rounded.
+    Inexact,
+}
+
+impl IeeeException {
+    /// Every variant of `IeeeException`, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::InvalidOperation,
+        Self::DivisionByZero,
+        Self::Overflow,
+        Self::Underflow,
+        Self::Inexact,
+    ];
+}
+
+/// Implements the `Display` trait for the `IeeeException` enum, providing a user-friendly
+/// description of the exception.
+impl std::fmt::Display for IeeeException {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidOperation => write!(f, "invalid operation"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::Overflow => write!(f, "overflow"),
+            Self::Underflow => write!(f, "underflow"),
+            Self::Inexact => write!(f, "inexact"),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{FloatError, GuardedF64, GuardedVec, UnguardedF64};
+
+/// Returns the area of the triangle with the vertices `a`, `b`, and `c`.
+///
+/// The area of a degenerate triangle, whose vertices are collinear, is zero.
+///
+/// # Errors
+///
+/// Returns `FloatError::Infinity` if the area is too large to represent.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{GuardedF64, GuardedVec};
+/// use floatguard::interp::triangle_area;
+///
+/// let [a, b, c] = [[0.0, 0.0], [4.0, 0.0], [0.0, 3.0]].map(|p| GuardedVec::try_from(p).unwrap());
+/// assert_eq!(triangle_area(a, b, c), GuardedF64::new(6.0));
+/// assert_eq!(triangle_area(a, c, b), GuardedF64::new(6.0));
+/// ```
+pub fn triangle_area(
+    a: GuardedVec<2>,
+    b: GuardedVec<2>,
+    c: GuardedVec<2>,
+) -> Result<GuardedF64, FloatError> {
+    let (lhs, rhs) = cross_terms(a, b, c);
+    (UnguardedF64(lhs - rhs).abs() * 0.5).check_reported()
+}
+
+/// Returns the barycentric coordinates of `p` in the triangle with the vertices `a`, `b`, and `c`.
+///
+/// The coordinates are the weights of the vertices that sum to one and reproduce `p`, so a value
+/// defined at each vertex is interpolated at `p` by weighting it the same way. All three weights
+/// are in `[0, 1]` when `p` is inside the triangle.
+///
+/// A degenerate triangle, whose vertices are collinear, has no barycentric coordinates. Since the
+/// test for collinearity is made in floating point, a triangle is considered degenerate when its
+/// orientation is within the rounding error of the computation, as well as when it is exactly zero.
+///
+/// # Errors
+///
+/// Returns `FloatError::Domain` if the triangle is degenerate, or `FloatError::Infinity` if a
+/// weight or an intermediate value is too large to represent.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64, GuardedVec};
+/// use floatguard::interp::barycentric;
+///
+/// let [a, b, c] = [[0.0, 0.0], [4.0, 0.0], [0.0, 4.0]].map(|p| GuardedVec::try_from(p).unwrap());
+/// let p = GuardedVec::try_from([1.0, 2.0]).unwrap();
+/// let weights = barycentric(p, a, b, c).unwrap();
+/// assert_eq!(weights.map(f64::from), [0.25, 0.25, 0.5]);
+///
+/// // Interpolate a value given at each vertex.
+/// let values = [10.0, 20.0, 30.0];
+/// let value: f64 = weights.iter().zip(values).map(|(w, v)| w.get() * v).sum();
+/// assert_eq!(value, 22.5);
+///
+/// let [a, b, c] = [[0.0, 0.0], [1.0, 1.0], [3.0, 3.0]].map(|p| GuardedVec::try_from(p).unwrap());
+/// assert_eq!(barycentric(p, a, b, c), Err(FloatError::Domain));
+/// ```
+pub fn barycentric(
+    p: GuardedVec<2>,
+    a: GuardedVec<2>,
+    b: GuardedVec<2>,
+    c: GuardedVec<2>,
+) -> Result<[GuardedF64; 3], FloatError> {
+    let (lhs, rhs) = cross_terms(a, b, c);
+    let determinant = UnguardedF64(lhs - rhs).check_reported()?;
+
+    // The orientation error bound of Shewchuk's `orient2d`, below which the sign of the
+    // determinant, and so whether the triangle is degenerate at all, cannot be trusted.
+    let bound = 3.0 * f64::EPSILON * (lhs.abs() + rhs.abs());
+    if determinant.0.abs() <= bound {
+        return Err(FloatError::Domain);
+    }
+
+    let (lhs, rhs) = cross_terms(a, p, c);
+    let weight_b = (UnguardedF64(lhs - rhs) / determinant).check_reported()?;
+    let (lhs, rhs) = cross_terms(a, b, p);
+    let weight_c = (UnguardedF64(lhs - rhs) / determinant).check_reported()?;
+    let weight_a = (1.0 - weight_b - weight_c).check_reported()?;
+
+    Ok([weight_a, weight_b, weight_c])
+}
+
+/// Returns the two products whose difference is twice the signed area of the triangle `a`, `b`,
+/// `c`, which is positive when the vertices are in counterclockwise order.
+fn cross_terms(a: GuardedVec<2>, b: GuardedVec<2>, c: GuardedVec<2>) -> (f64, f64) {
+    let [ax, ay] = a.into_array().map(f64::from);
+    let [bx, by] = b.into_array().map(f64::from);
+    let [cx, cy] = c.into_array().map(f64::from);
+    ((bx - ax) * (cy - ay), (cx - ax) * (by - ay))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn point() -> impl Strategy<Value = GuardedVec<2>> {
+        prop::array::uniform2(-1e6..1e6f64).prop_map(|p| GuardedVec::try_from(p).unwrap())
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let [a, b, c] =
+            [[0.1, 0.1], [0.2, 0.2], [0.3, 0.3]].map(|p| GuardedVec::try_from(p).unwrap());
+        assert_eq!(triangle_area(a, b, c).map(|area| area.0 < 1e-17), Ok(true));
+        assert_eq!(barycentric(a, a, b, c), Err(FloatError::Domain));
+        assert_eq!(barycentric(a, a, a, a), Err(FloatError::Domain));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let [a, b, c] =
+            [[-1e300, 0.0], [1e300, 0.0], [0.0, 1e300]].map(|p| GuardedVec::try_from(p).unwrap());
+        assert_eq!(triangle_area(a, b, c), Err(FloatError::Infinity));
+        assert_eq!(barycentric(a, a, b, c), Err(FloatError::Infinity));
+
+        let [a, b, c] =
+            [[0.0, 0.0], [1.0, 0.0], [0.0, 1e-300]].map(|p| GuardedVec::try_from(p).unwrap());
+        let p = GuardedVec::try_from([0.0, 1e10]).unwrap();
+        assert_eq!(barycentric(p, a, b, c), Err(FloatError::Infinity));
+    }
+
+    proptest! {
+        #[test]
+        fn test_reconstructs_point(p in point(), a in point(), b in point(), c in point()) {
+            let Ok(weights) = barycentric(p, a, b, c) else {
+                return Ok(());
+            };
+            let area = triangle_area(a, b, c).unwrap();
+            prop_assume!(area.0 > 1e-3);
+
+            let sum: f64 = weights.iter().map(|w| w.0).sum();
+            prop_assert!((sum - 1.0).abs() < 1e-9);
+            for i in 0..2 {
+                let reconstructed: f64 = weights.iter().zip([a, b, c]).map(|(w, v)| w.0 * v[i].0).sum();
+                let scale = [p, a, b, c].iter().map(|v| v[i].0.abs()).fold(1.0, f64::max);
+                let tolerance = 1e-6 * scale * weights.iter().map(|w| w.0.abs()).sum::<f64>();
+                prop_assert!((reconstructed - p[i].0).abs() <= tolerance);
+            }
+        }
+
+        #[test]
+        fn test_vertices(a in point(), b in point(), c in point()) {
+            if let Ok(weights) = barycentric(a, a, b, c) {
+                prop_assert_eq!(weights, [GuardedF64(1.0), GuardedF64(0.0), GuardedF64(0.0)]);
+            }
+        }
+    }
+}
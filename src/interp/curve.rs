@@ -0,0 +1,237 @@
+use crate::{FloatError, GuardedF64, GuardedVec};
+
+/// Evaluates a cubic Bézier curve with the control points `p0` through `p3` at `t`.
+///
+/// The curve starts at `p0` when `t` is zero and ends at `p3` when `t` is one. Within that range,
+/// each point of the curve is a weighted average of the control points, so it cannot overflow.
+/// Other values of `t` extrapolate the curve.
+///
+/// The components are computed without intermediate checks, and the result is validated once.
+///
+/// # Errors
+///
+/// Returns the `FloatError` of the first component that is NaN or infinite, which can only happen
+/// when `t` is outside of [0, 1].
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{GuardedF64, GuardedVec};
+/// use floatguard::interp::cubic_bezier;
+///
+/// let [p0, p1, p2, p3] = [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]
+///     .map(|p| GuardedVec::try_from(p).unwrap());
+///
+/// let half = GuardedF64::new(0.5).unwrap();
+/// let point = cubic_bezier(p0, p1, p2, p3, half).unwrap();
+/// assert_eq!(<[f64; 2]>::from(point), [0.5, 0.75]);
+/// ```
+pub fn cubic_bezier<const N: usize>(
+    p0: GuardedVec<N>,
+    p1: GuardedVec<N>,
+    p2: GuardedVec<N>,
+    p3: GuardedVec<N>,
+    t: GuardedF64,
+) -> Result<GuardedVec<N>, FloatError> {
+    let (t, s) = (t.0, 1.0 - t.0);
+    combine(
+        [p0, p1, p2, p3],
+        [s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t],
+    )
+}
+
+/// Evaluates the derivative, with respect to `t`, of the cubic Bézier curve with the control points
+/// `p0` through `p3` at `t`. This is the velocity of a point that moves along `cubic_bezier`.
+///
+/// # Errors
+///
+/// Returns the `FloatError` of the first component that is NaN or infinite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{GuardedF64, GuardedVec};
+/// use floatguard::interp::cubic_bezier_derivative;
+///
+/// let [p0, p1, p2, p3] = [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]
+///     .map(|p| GuardedVec::try_from(p).unwrap());
+///
+/// let start = GuardedF64::new(0.0).unwrap();
+/// let velocity = cubic_bezier_derivative(p0, p1, p2, p3, start).unwrap();
+/// assert_eq!(<[f64; 2]>::from(velocity), [0.0, 3.0]);
+/// ```
+pub fn cubic_bezier_derivative<const N: usize>(
+    p0: GuardedVec<N>,
+    p1: GuardedVec<N>,
+    p2: GuardedVec<N>,
+    p3: GuardedVec<N>,
+    t: GuardedF64,
+) -> Result<GuardedVec<N>, FloatError> {
+    let (t, s) = (t.0, 1.0 - t.0);
+    combine(
+        [p0, p1, p2, p3],
+        [
+            -3.0 * s * s,
+            3.0 * s * 2.0f64.mul_add(-t, s),
+            3.0 * t * 2.0f64.mul_add(s, -t),
+            3.0 * t * t,
+        ],
+    )
+}
+
+/// Evaluates the uniform Catmull-Rom spline segment between `p1` and `p2` at `t`, using `p0` and
+/// `p3` to shape the tangents.
+///
+/// The segment passes through `p1` when `t` is zero and through `p2` when `t` is one, so
+/// evaluating consecutive windows of four points traces a smooth curve through all of the points
+/// except the first and last. The result is validated once.
+///
+/// # Errors
+///
+/// Returns the `FloatError` of the first component that is NaN or infinite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{GuardedF64, GuardedVec};
+/// use floatguard::interp::catmull_rom;
+///
+/// let [p0, p1, p2, p3] = [[0.0], [1.0], [2.0], [3.0]].map(|p| GuardedVec::try_from(p).unwrap());
+///
+/// let t = GuardedF64::new(0.25).unwrap();
+/// assert_eq!(<[f64; 1]>::from(catmull_rom(p0, p1, p2, p3, t).unwrap()), [1.25]);
+/// ```
+pub fn catmull_rom<const N: usize>(
+    p0: GuardedVec<N>,
+    p1: GuardedVec<N>,
+    p2: GuardedVec<N>,
+    p3: GuardedVec<N>,
+    t: GuardedF64,
+) -> Result<GuardedVec<N>, FloatError> {
+    let (t, s) = (t.0, 1.0 - t.0);
+    combine(
+        [p0, p1, p2, p3],
+        [
+            -0.5 * t * s * s,
+            0.5 * (t * t).mul_add(3.0f64.mul_add(t, -5.0), 2.0),
+            0.5 * t * t.mul_add((-3.0f64).mul_add(t, 4.0), 1.0),
+            -0.5 * t * t * s,
+        ],
+    )
+}
+
+/// Evaluates the derivative, with respect to `t`, of the uniform Catmull-Rom spline segment
+/// between `p1` and `p2` at `t`. See `catmull_rom`.
+///
+/// # Errors
+///
+/// Returns the `FloatError` of the first component that is NaN or infinite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{GuardedF64, GuardedVec};
+/// use floatguard::interp::catmull_rom_derivative;
+///
+/// let [p0, p1, p2, p3] = [[0.0], [1.0], [2.0], [3.0]].map(|p| GuardedVec::try_from(p).unwrap());
+///
+/// let t = GuardedF64::new(0.25).unwrap();
+/// assert_eq!(<[f64; 1]>::from(catmull_rom_derivative(p0, p1, p2, p3, t).unwrap()), [1.0]);
+/// ```
+pub fn catmull_rom_derivative<const N: usize>(
+    p0: GuardedVec<N>,
+    p1: GuardedVec<N>,
+    p2: GuardedVec<N>,
+    p3: GuardedVec<N>,
+    t: GuardedF64,
+) -> Result<GuardedVec<N>, FloatError> {
+    let t = t.0;
+    combine(
+        [p0, p1, p2, p3],
+        [
+            0.5 * t.mul_add((-3.0f64).mul_add(t, 4.0), -1.0),
+            0.5 * t * 9.0f64.mul_add(t, -10.0),
+            0.5 * t.mul_add((-9.0f64).mul_add(t, 8.0), 1.0),
+            0.5 * t * 3.0f64.mul_add(t, -2.0),
+        ],
+    )
+}
+
+/// Returns the weighted sum of four points, validating the components only once, at the end.
+fn combine<const N: usize>(
+    points: [GuardedVec<N>; 4],
+    weights: [f64; 4],
+) -> Result<GuardedVec<N>, FloatError> {
+    let mut components = [0.0; N];
+    for (i, component) in components.iter_mut().enumerate() {
+        *component = points
+            .iter()
+            .zip(weights)
+            .fold(0.0, |sum, (point, weight)| weight.mul_add(point[i].0, sum));
+    }
+    GuardedVec::try_from(components)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use proptest::prelude::*;
+
+    type Curve = fn(
+        GuardedVec<2>,
+        GuardedVec<2>,
+        GuardedVec<2>,
+        GuardedVec<2>,
+        GuardedF64,
+    ) -> Result<GuardedVec<2>, FloatError>;
+
+    fn point() -> impl Strategy<Value = GuardedVec<2>> {
+        prop::array::uniform2(-1e6..1e6f64).prop_map(|p| GuardedVec::try_from(p).unwrap())
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = GuardedVec::new([GuardedF64::MAX]);
+        let min = GuardedVec::new([GuardedF64::MIN]);
+        assert_eq!(
+            cubic_bezier(max, max, max, max, GuardedF64(1.0)).map(|p| p[0]),
+            Ok(GuardedF64::MAX)
+        );
+        assert_eq!(
+            cubic_bezier_derivative(min, max, max, max, GuardedF64(0.0)),
+            Err(FloatError::Infinity)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_endpoints(p0 in point(), p1 in point(), p2 in point(), p3 in point()) {
+            let (zero, one) = (GuardedF64(0.0), GuardedF64(1.0));
+            prop_assert_eq!(cubic_bezier(p0, p1, p2, p3, zero), Ok(p0));
+            prop_assert_eq!(cubic_bezier(p0, p1, p2, p3, one), Ok(p3));
+            prop_assert_eq!(catmull_rom(p0, p1, p2, p3, zero), Ok(p1));
+            prop_assert_eq!(catmull_rom(p0, p1, p2, p3, one), Ok(p2));
+        }
+
+        #[test]
+        fn test_derivatives(p0 in point(), p1 in point(), p2 in point(), p3 in point(), t in 0.01..0.99f64) {
+            let h = 1e-6;
+            let curves: [(Curve, Curve); 2] = [
+                (cubic_bezier::<2>, cubic_bezier_derivative::<2>),
+                (catmull_rom::<2>, catmull_rom_derivative::<2>),
+            ];
+
+            for (curve, derivative) in curves {
+                let before: GuardedVec<2> = curve(p0, p1, p2, p3, GuardedF64(t - h)).unwrap();
+                let after: GuardedVec<2> = curve(p0, p1, p2, p3, GuardedF64(t + h)).unwrap();
+                let slope: GuardedVec<2> = derivative(p0, p1, p2, p3, GuardedF64(t)).unwrap();
+                for i in 0..2 {
+                    let estimate = (after[i].0 - before[i].0) / (2.0 * h);
+                    prop_assert!((estimate - slope[i].0).abs() <= 1e-3 * slope[i].0.abs().max(1e3));
+                }
+            }
+        }
+    }
+}
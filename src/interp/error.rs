@@ -0,0 +1,45 @@
+/// An error occurred while building or evaluating an `InterpTable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpError {
+    /// Indicates that the x and y slices have different lengths.
+    LengthMismatch {
+        /// The number of x values.
+        x_len: usize,
+
+        /// The number of y values.
+        y_len: usize,
+    },
+
+    /// Indicates that the table has fewer than two points, so there is nothing to interpolate.
+    TooFewPoints,
+
+    /// Indicates that the x value at `index` is not strictly greater than the one before it.
+    NotIncreasing {
+        /// The index of the offending x value.
+        index: usize,
+    },
+
+    /// Indicates that the table was evaluated outside of its x range with
+    /// `Extrapolation::Error`.
+    OutOfRange,
+}
+
+impl std::error::Error for InterpError {}
+
+/// Implements the `Display` trait for the `InterpError` enum, providing a user-friendly
+/// description of the error.
+impl std::fmt::Display for InterpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LengthMismatch { x_len, y_len } => {
+                write!(f, "The table has {x_len} x values but {y_len} y values")
+            }
+            Self::TooFewPoints => write!(f, "The table needs at least two points"),
+            Self::NotIncreasing { index } => write!(
+                f,
+                "The x value at index {index} is not greater than the one before it"
+            ),
+            Self::OutOfRange => write!(f, "The value is outside of the range of the table"),
+        }
+    }
+}
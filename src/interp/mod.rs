@@ -0,0 +1,22 @@
+//! Lookup tables with linear interpolation between guarded points.
+//!
+//! An `InterpTable` validates its breakpoints once, when it is built, so evaluating it never has to
+//! account for unsorted or duplicate x values. Since every interpolated value lies between two
+//! guarded y values, evaluation always produces a `GuardedF64`.
+//!
+//! The module also evaluates cubic Bézier curves and Catmull-Rom splines, and their derivatives,
+//! over `GuardedVec` points. Each evaluation computes the result without intermediate checks and
+//! validates it once.
+//!
+//! For triangle meshes, `barycentric` computes the weights that interpolate values given at the
+//! vertices of a triangle, and reports degenerate triangles as `FloatError::Domain` instead of
+//! dividing by zero. `triangle_area` computes the area of a triangle.
+mod barycentric;
+mod curve;
+mod error;
+mod table;
+
+pub use barycentric::{barycentric, triangle_area};
+pub use curve::{catmull_rom, catmull_rom_derivative, cubic_bezier, cubic_bezier_derivative};
+pub use error::InterpError;
+pub use table::{Extrapolation, InterpTable};
@@ -0,0 +1,207 @@
+use super::InterpError;
+use crate::GuardedF64;
+
+/// How an `InterpTable` handles inputs outside of its x range.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Extrapolation {
+    /// Returns the y value of the nearest end of the table.
+    #[default]
+    Clamp,
+
+    /// Returns `InterpError::OutOfRange`.
+    Error,
+}
+
+/// A lookup table that linearly interpolates between guarded points.
+///
+/// The x values must be strictly increasing, which is validated when the table is built. Inputs
+/// outside of the x range are handled according to the table's `Extrapolation`, which defaults to
+/// `Extrapolation::Clamp`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::interp::{Extrapolation, InterpError, InterpTable};
+///
+/// let xs = [0.0, 10.0, 20.0].map(|v| GuardedF64::new(v).unwrap());
+/// let ys = [0.0, 100.0, 150.0].map(|v| GuardedF64::new(v).unwrap());
+/// let table = InterpTable::new(&xs, &ys).unwrap();
+///
+/// assert_eq!(table.eval(GuardedF64::new(5.0).unwrap()), Ok(GuardedF64::new(50.0).unwrap()));
+/// assert_eq!(table.eval(GuardedF64::new(15.0).unwrap()), Ok(GuardedF64::new(125.0).unwrap()));
+/// assert_eq!(table.eval(GuardedF64::new(30.0).unwrap()), Ok(GuardedF64::new(150.0).unwrap()));
+///
+/// let table = table.with_extrapolation(Extrapolation::Error);
+/// assert_eq!(table.eval(GuardedF64::new(30.0).unwrap()), Err(InterpError::OutOfRange));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterpTable {
+    xs: Vec<GuardedF64>,
+    ys: Vec<GuardedF64>,
+    extrapolation: Extrapolation,
+}
+
+impl InterpTable {
+    /// Creates a new `InterpTable` from matching slices of x and y values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InterpError::LengthMismatch` if the slices have different lengths,
+    /// `InterpError::TooFewPoints` if there are fewer than two points, or
+    /// `InterpError::NotIncreasing` with the index of the first x value that is not strictly greater
+    /// than the one before it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use floatguard::interp::{InterpError, InterpTable};
+    ///
+    /// let xs = [0.0, 2.0, 1.0].map(|v| GuardedF64::new(v).unwrap());
+    /// let ys = [0.0, 1.0, 2.0].map(|v| GuardedF64::new(v).unwrap());
+    /// assert_eq!(InterpTable::new(&xs, &ys), Err(InterpError::NotIncreasing { index: 2 }));
+    ///
+    /// assert_eq!(
+    ///     InterpTable::new(&xs[..2], &ys),
+    ///     Err(InterpError::LengthMismatch { x_len: 2, y_len: 3 })
+    /// );
+    /// ```
+    pub fn new(xs: &[GuardedF64], ys: &[GuardedF64]) -> Result<Self, InterpError> {
+        if xs.len() != ys.len() {
+            return Err(InterpError::LengthMismatch {
+                x_len: xs.len(),
+                y_len: ys.len(),
+            });
+        }
+
+        if xs.len() < 2 {
+            return Err(InterpError::TooFewPoints);
+        }
+
+        if let Some(index) = xs.windows(2).position(|pair| pair[1] <= pair[0]) {
+            return Err(InterpError::NotIncreasing { index: index + 1 });
+        }
+
+        Ok(Self {
+            xs: xs.to_vec(),
+            ys: ys.to_vec(),
+            extrapolation: Extrapolation::default(),
+        })
+    }
+
+    /// Returns the table with its extrapolation behavior replaced.
+    #[must_use]
+    pub fn with_extrapolation(self, extrapolation: Extrapolation) -> Self {
+        Self {
+            extrapolation,
+            ..self
+        }
+    }
+
+    /// Returns how the table handles inputs outside of its x range.
+    #[must_use]
+    pub const fn extrapolation(&self) -> Extrapolation {
+        self.extrapolation
+    }
+
+    /// Evaluates the table at `x`, interpolating linearly between the surrounding points.
+    ///
+    /// The interpolated value is clamped between the y values of the surrounding points, so it is
+    /// always finite and never overshoots due to rounding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InterpError::OutOfRange` if `x` is outside of the table's x range and the table uses
+    /// `Extrapolation::Error`.
+    pub fn eval(&self, x: GuardedF64) -> Result<GuardedF64, InterpError> {
+        let last = self.xs.len() - 1;
+        if x < self.xs[0] || x > self.xs[last] {
+            return match self.extrapolation {
+                Extrapolation::Clamp if x < self.xs[0] => Ok(self.ys[0]),
+                Extrapolation::Clamp => Ok(self.ys[last]),
+                Extrapolation::Error => Err(InterpError::OutOfRange),
+            };
+        }
+
+        let upper = self.xs.partition_point(|&xi| xi <= x).clamp(1, last);
+        let (x0, x1) = (self.xs[upper - 1].0, self.xs[upper].0);
+        let (y0, y1) = (self.ys[upper - 1].0, self.ys[upper].0);
+
+        // The span between extreme breakpoints can overflow, but half of it cannot.
+        let span = x1 - x0;
+        let t = if span.is_finite() {
+            (x.0 - x0) / span
+        } else {
+            x.0.mul_add(0.5, -x0 * 0.5) / x1.mul_add(0.5, -x0 * 0.5)
+        };
+
+        let y = y0.mul_add(1.0 - t, t * y1);
+        Ok(GuardedF64(y.clamp(y0.min(y1), y0.max(y1))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn table() -> impl Strategy<Value = InterpTable> {
+        let point = (valid_f64(), valid_f64())
+            .prop_map(|(x, y)| (GuardedF64::new(x).unwrap(), GuardedF64::new(y).unwrap()));
+
+        prop::collection::vec(point, 2..16).prop_filter_map(
+            "Reject tables with fewer than two distinct x values",
+            |mut points| {
+                points.sort_by_key(|point| point.0);
+                points.dedup_by_key(|point| point.0);
+                let (xs, ys): (Vec<_>, Vec<_>) = points.into_iter().unzip();
+                InterpTable::new(&xs, &ys).ok()
+            },
+        )
+    }
+
+    #[test]
+    fn test_too_few_points() {
+        let point = [GuardedF64(1.0)];
+        assert_eq!(
+            InterpTable::new(&point, &point),
+            Err(InterpError::TooFewPoints)
+        );
+        assert_eq!(InterpTable::new(&[], &[]), Err(InterpError::TooFewPoints));
+    }
+
+    #[test]
+    fn test_extreme_span() {
+        let xs = [GuardedF64::MIN, GuardedF64::MAX];
+        let ys = [GuardedF64::MAX, GuardedF64::MIN];
+        let table = InterpTable::new(&xs, &ys).unwrap();
+
+        assert_eq!(table.eval(GuardedF64(0.0)), Ok(GuardedF64(0.0)));
+        assert_eq!(table.eval(GuardedF64::MIN), Ok(GuardedF64::MAX));
+        assert_eq!(table.eval(GuardedF64::MAX), Ok(GuardedF64::MIN));
+    }
+
+    proptest! {
+        #[test]
+        fn test_eval_hits_breakpoints(table in table()) {
+            for (x, y) in table.xs.iter().zip(&table.ys) {
+                prop_assert_eq!(table.eval(*x), Ok(*y));
+            }
+        }
+
+        #[test]
+        fn test_eval_is_bounded(table in table(), x in valid_f64()) {
+            let x = GuardedF64::new(x).unwrap();
+            let y = table.eval(x).unwrap();
+            let min = table.ys.iter().min().unwrap();
+            let max = table.ys.iter().max().unwrap();
+            prop_assert!(*min <= y && y <= *max);
+
+            let in_range = *table.xs.first().unwrap() <= x && x <= *table.xs.last().unwrap();
+            let table = table.with_extrapolation(Extrapolation::Error);
+            prop_assert_eq!(table.eval(x).is_ok(), in_range);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! This module provides the `LabeledError` type, which attaches a short label to a `FloatError`
+//! without allocating.
+use crate::FloatError;
+use std::fmt;
+
+/// A `FloatError` with a label of up to `N` bytes, stored inline.
+///
+/// `FloatError` is a plain enum, so it says what went wrong but not where. Attaching a `String`
+/// would require an allocator, which embedded targets often do not have, so `LabeledError` copies
+/// the label into a fixed-size buffer instead. It is `Copy`, can be built in a `const` context, and
+/// labels that do not fit are truncated at a character boundary.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, LabeledError, UnguardedF64};
+///
+/// let error = (UnguardedF64::new(-1.0).sqrt())
+///     .check()
+///     .map_err(|error| error.with_label("wheel speed"))
+///     .unwrap_err();
+/// assert_eq!(error.error(), FloatError::NaN);
+/// assert_eq!(error.label(), "wheel speed");
+///
+/// let error = LabeledError::<4>::new(FloatError::Infinity, "temperature");
+/// assert_eq!(error.label(), "temp");
+/// assert!(error.is_truncated());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LabeledError<const N: usize = 32> {
+    error: FloatError,
+    label: [u8; N],
+    len: usize,
+    truncated: bool,
+}
+
+impl<const N: usize> LabeledError<N> {
+    /// Creates a `LabeledError`, truncating the label to the longest prefix that fits in `N` bytes
+    /// and ends at a character boundary.
+    #[must_use]
+    // Every index is below `len`, which is at most `N` and the length of the label, or checked
+    // against the length of the label first.
+    #[allow(clippy::indexing_slicing)]
+    pub const fn new(error: FloatError, label: &str) -> Self {
+        let bytes = label.as_bytes();
+        let mut len = if bytes.len() < N { bytes.len() } else { N };
+        // Continuation bytes have the form 0b10xx_xxxx, so a character cannot start at one.
+        while len < bytes.len() && len > 0 && bytes[len] & 0xc0 == 0x80 {
+            len -= 1;
+        }
+
+        let mut buffer = [0; N];
+        let mut i = 0;
+        while i < len {
+            buffer[i] = bytes[i];
+            i += 1;
+        }
+
+        Self {
+            error,
+            label: buffer,
+            len,
+            truncated: len < bytes.len(),
+        }
+    }
+
+    /// Returns the error without its label.
+    #[must_use]
+    pub const fn error(self) -> FloatError {
+        self.error
+    }
+
+    /// Returns the label, which may have been truncated.
+    #[must_use]
+    pub const fn label(&self) -> &str {
+        let label = match self.label.split_at_checked(self.len) {
+            Some((label, _)) => label,
+            None => &[],
+        };
+        match std::str::from_utf8(label) {
+            Ok(label) => label,
+            // `new` only copies whole characters, so the label is always valid UTF-8.
+            Err(_) => "",
+        }
+    }
+
+    /// Returns `true` if the label did not fit in `N` bytes and was truncated.
+    #[must_use]
+    pub const fn is_truncated(self) -> bool {
+        self.truncated
+    }
+}
+
+impl FloatError {
+    /// Attaches a label of up to 32 bytes to the error. See `LabeledError`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::FloatError;
+    ///
+    /// let error = FloatError::Domain.with_label("log(x)");
+    /// assert_eq!(error.to_string(), "log(x): The input is outside of the domain of the operation");
+    /// ```
+    #[must_use]
+    pub const fn with_label(self, label: &str) -> LabeledError {
+        LabeledError::new(self, label)
+    }
+}
+
+impl<const N: usize> From<LabeledError<N>> for FloatError {
+    fn from(error: LabeledError<N>) -> Self {
+        error.error
+    }
+}
+
+impl<const N: usize> fmt::Debug for LabeledError<N> {
+    /// Formats the label as a string, instead of as the bytes of the buffer.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LabeledError")
+            .field("error", &self.error)
+            .field("label", &self.label())
+            .field("truncated", &self.truncated)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<const N: usize> std::error::Error for LabeledError<N> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Implements the `Display` trait for `LabeledError`, prefixing the description of the error with
+/// its label.
+impl<const N: usize> fmt::Display for LabeledError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.label(), self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_is_a_single_byte() {
+        assert_eq!(size_of::<FloatError>(), 1);
+    }
+
+    #[test]
+    fn test_truncates_at_character_boundary() {
+        let error = LabeledError::<5>::new(FloatError::NaN, "ab€cd");
+        assert_eq!(error.label(), "ab€");
+        assert!(error.is_truncated());
+
+        let error = LabeledError::<4>::new(FloatError::NaN, "ab€cd");
+        assert_eq!(error.label(), "ab");
+
+        let error = LabeledError::<0>::new(FloatError::NaN, "label");
+        assert_eq!(error.label(), "");
+        assert!(error.is_truncated());
+    }
+
+    #[test]
+    fn test_label_that_fits() {
+        const ERROR: LabeledError<8> = LabeledError::new(FloatError::Underflow, "mass");
+        assert_eq!(ERROR.label(), "mass");
+        assert!(!ERROR.is_truncated());
+        assert_eq!(FloatError::from(ERROR), FloatError::Underflow);
+        assert_eq!(
+            format!("{ERROR:?}"),
+            "LabeledError { error: Underflow, label: \"mass\", truncated: false, .. }"
+        );
+    }
+}
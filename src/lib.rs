@@ -1,10 +1,128 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery)]
+#![cfg_attr(
+    all(floatguard_no_panic, not(test)),
+    deny(
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented,
+        clippy::unreachable,
+        clippy::unwrap_used
+    )
+)]
 
+mod approx;
+mod assert;
+mod cancellation;
+#[cfg(feature = "constants")]
+pub mod constants;
+pub mod decay;
+#[cfg(feature = "derive")]
+mod derive;
+pub mod ease;
+pub mod eft;
+#[cfg_attr(all(floatguard_no_panic, not(test)), deny(clippy::indexing_slicing))]
 mod error;
+mod exact_int;
+pub mod expr;
+#[cfg_attr(all(floatguard_no_panic, not(test)), deny(clippy::indexing_slicing))]
 mod f32;
+#[cfg_attr(all(floatguard_no_panic, not(test)), deny(clippy::indexing_slicing))]
 mod f64;
+pub mod finance;
+mod fixed;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+pub mod geo;
+mod guarded_array;
+mod guarded_vec;
+pub mod histogram;
+#[cfg(feature = "hooks")]
+mod hooks;
+mod ieee;
+pub mod interp;
+#[cfg_attr(all(floatguard_no_panic, not(test)), deny(clippy::indexing_slicing))]
+mod labeled_error;
+pub mod linalg;
+mod log_prob;
 pub(crate) mod macros;
+mod mat;
+pub mod metrics;
+pub mod parse;
+mod pid;
+pub mod pipeline;
+pub mod poly;
+mod quat;
+mod ratio;
+#[cfg_attr(all(floatguard_no_panic, not(test)), deny(clippy::indexing_slicing))]
+mod raw;
+#[cfg(any(feature = "hooks", feature = "validation-stats"))]
+mod report;
+pub mod roots;
+pub mod scope;
+pub mod slice;
+pub mod space;
+pub mod stats;
+pub mod trig;
+mod unit;
+#[cfg(feature = "validation-stats")]
+mod validation_stats;
+mod wide;
 
+pub use approx::ApproxConfig;
+pub use cancellation::CancellationInfo;
+#[cfg(feature = "derive")]
+pub use derive::GuardedNewtype;
 pub use error::Error as FloatError;
+pub use exact_int::ExactInt;
 pub use f32::{GuardedF32, UnguardedF32};
+#[cfg(feature = "serde_json")]
+pub use f64::JsonValueError;
+#[cfg(feature = "serde")]
+pub use f64::LenientF64;
 pub use f64::{GuardedF64, UnguardedF64};
+pub use fixed::{FixedRounding, GuardedFixed64};
+pub use guarded_vec::GuardedVec;
+#[cfg(feature = "hooks")]
+pub use hooks::{InvalidHook, clear_invalid_hook, set_invalid_hook};
+pub use ieee::IeeeException;
+#[cfg(all(
+    feature = "fp-flags",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+pub use ieee::{FpFlags, with_fp_flags};
+pub use labeled_error::LabeledError;
+pub use log_prob::LogProb;
+pub use mat::{GuardedMat, UnguardedMat};
+pub use pid::Pid;
+pub use quat::{GuardedQuat, UnguardedQuat};
+pub use raw::RawF64;
+pub use unit::UnitF64;
+#[cfg(feature = "validation-stats")]
+pub use validation_stats::{ValidationStats, stats_reset, stats_snapshot};
+pub use wide::WideF64;
+
+/// Deprecated alias of [`GuardedF64`], from before the checked types were renamed.
+#[deprecated(note = "renamed to `GuardedF64`")]
+pub type CheckedF64 = GuardedF64;
+
+/// Deprecated alias of [`UnguardedF64`], from before the checked types were renamed.
+#[deprecated(note = "renamed to `UnguardedF64`")]
+pub type UncheckedF64 = UnguardedF64;
+
+/// Deprecated alias of [`GuardedF32`], from before the checked types were renamed.
+#[deprecated(note = "renamed to `GuardedF32`")]
+pub type CheckedF32 = GuardedF32;
+
+/// Deprecated alias of [`UnguardedF32`], from before the checked types were renamed.
+#[deprecated(note = "renamed to `UnguardedF32`")]
+pub type UncheckedF32 = UnguardedF32;
+
+/// Re-exports used by the code generated by `floatguard` macros. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::assert::Validate;
+    pub use crate::guarded_array::guarded_f64;
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
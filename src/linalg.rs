@@ -0,0 +1,196 @@
+//! Determinants and linear solves for small systems of guarded values.
+//!
+//! The solvers use Cramer's rule, which is exact enough for the well-conditioned 2x2 and 3x3
+//! systems that show up in geometry code and avoids any allocation. A singular matrix is reported
+//! as `FloatError::DivisionByZero` rather than producing infinities or NaN.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// Computes the determinant of a 2x2 matrix, given in row-major order.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::linalg::det2;
+///
+/// let matrix = [[1.0, 2.0], [3.0, 4.0]].map(|row| row.map(|v| GuardedF64::new(v).unwrap()));
+/// assert_eq!(det2(matrix).check(), GuardedF64::new(-2.0));
+/// ```
+#[must_use = "function returns a new value and does not mutate the original value"]
+pub fn det2(matrix: [[GuardedF64; 2]; 2]) -> UnguardedF64 {
+    let [[a, b], [c, d]] = matrix;
+    a * d - b * c
+}
+
+/// Computes the determinant of a 3x3 matrix, given in row-major order.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::linalg::det3;
+///
+/// let matrix = [[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 1.0]]
+///     .map(|row| row.map(|v| GuardedF64::new(v).unwrap()));
+/// assert_eq!(det3(matrix).check(), GuardedF64::new(0.0));
+/// ```
+#[must_use = "function returns a new value and does not mutate the original value"]
+#[allow(clippy::many_single_char_names)]
+pub fn det3(matrix: [[GuardedF64; 3]; 3]) -> UnguardedF64 {
+    let [[a, b, c], [d, e, f], [g, h, i]] = matrix;
+    a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g)
+}
+
+/// Solves the 2x2 linear system `matrix * x = rhs` for `x`.
+///
+/// # Errors
+///
+/// Returns `FloatError::DivisionByZero` if the determinant of `matrix` is zero, or another
+/// `FloatError` if the determinant or any component of the solution is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::linalg::solve2;
+///
+/// let matrix = [[2.0, 1.0], [1.0, 3.0]].map(|row| row.map(|v| GuardedF64::new(v).unwrap()));
+/// let rhs = [3.0, 5.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(solve2(matrix, rhs), Ok([0.8, 1.4].map(|v| GuardedF64::new(v).unwrap())));
+///
+/// let singular = [[1.0, 2.0], [2.0, 4.0]].map(|row| row.map(|v| GuardedF64::new(v).unwrap()));
+/// assert_eq!(solve2(singular, rhs), Err(FloatError::DivisionByZero));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn solve2(
+    matrix: [[GuardedF64; 2]; 2],
+    rhs: [GuardedF64; 2],
+) -> Result<[GuardedF64; 2], FloatError> {
+    let det = nonzero_determinant(det2(matrix))?;
+    let [[a, b], [c, d]] = matrix;
+    let [x, y] = rhs;
+
+    Ok([
+        (det2([[x, b], [y, d]]) / det).check_reported()?,
+        (det2([[a, x], [c, y]]) / det).check_reported()?,
+    ])
+}
+
+/// Solves the 3x3 linear system `matrix * x = rhs` for `x`.
+///
+/// # Errors
+///
+/// Returns `FloatError::DivisionByZero` if the determinant of `matrix` is zero, or another
+/// `FloatError` if the determinant or any component of the solution is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::linalg::solve3;
+///
+/// let matrix = [[1.0, 1.0, 1.0], [0.0, 2.0, 5.0], [2.0, 5.0, -1.0]]
+///     .map(|row| row.map(|v| GuardedF64::new(v).unwrap()));
+/// let rhs = [6.0, -4.0, 27.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(solve3(matrix, rhs), Ok([5.0, 3.0, -2.0].map(|v| GuardedF64::new(v).unwrap())));
+///
+/// let singular = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]
+///     .map(|row| row.map(|v| GuardedF64::new(v).unwrap()));
+/// assert_eq!(solve3(singular, rhs), Err(FloatError::DivisionByZero));
+/// ```
+pub fn solve3(
+    matrix: [[GuardedF64; 3]; 3],
+    rhs: [GuardedF64; 3],
+) -> Result<[GuardedF64; 3], FloatError> {
+    let det = nonzero_determinant(det3(matrix))?;
+
+    let mut solution = [GuardedF64(0.0); 3];
+    for (column, value) in solution.iter_mut().enumerate() {
+        let mut replaced = matrix;
+        for (row, b) in replaced.iter_mut().zip(rhs) {
+            row[column] = b;
+        }
+        *value = (det3(replaced) / det).check_reported()?;
+    }
+
+    Ok(solution)
+}
+
+/// Validates a determinant, rejecting zero as `FloatError::DivisionByZero`.
+pub(crate) fn nonzero_determinant(det: UnguardedF64) -> Result<GuardedF64, FloatError> {
+    let det = det.check_reported()?;
+    if det == 0.0 {
+        Err(FloatError::DivisionByZero)
+    } else {
+        Ok(det)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ApproxConfig;
+    use proptest::prelude::*;
+
+    fn small_f64() -> impl Strategy<Value = GuardedF64> {
+        (-100.0..100.0f64).prop_map(|v| GuardedF64::new(v).unwrap())
+    }
+
+    fn assert_residual<const N: usize>(
+        matrix: [[GuardedF64; N]; N],
+        rhs: [GuardedF64; N],
+        solution: [GuardedF64; N],
+    ) {
+        let config = ApproxConfig::epsilon(GuardedF64::new(1e-6).unwrap());
+        for (row, b) in matrix.iter().zip(rhs) {
+            let mut sum = UnguardedF64::new(0.0);
+            for (a, x) in row.iter().zip(solution) {
+                sum += a * x;
+            }
+            crate::assert_guarded_approx_eq!(sum.check().unwrap(), b, config);
+        }
+    }
+
+    #[test]
+    fn test_overflowing_determinant() {
+        let matrix = [
+            [GuardedF64::MAX, GuardedF64::MIN],
+            [GuardedF64::MAX, GuardedF64::MAX],
+        ];
+        let rhs = [GuardedF64(1.0), GuardedF64(1.0)];
+        assert_eq!(solve2(matrix, rhs), Err(FloatError::Infinity));
+    }
+
+    proptest! {
+        #[test]
+        fn test_solve2_residual(
+            matrix in prop::array::uniform2(prop::array::uniform2(small_f64())),
+            rhs in prop::array::uniform2(small_f64()),
+        ) {
+            prop_assume!(det2(matrix).check().unwrap().abs() > 1.0);
+            assert_residual(matrix, rhs, solve2(matrix, rhs).unwrap());
+        }
+
+        #[test]
+        fn test_solve3_residual(
+            matrix in prop::array::uniform3(prop::array::uniform3(small_f64())),
+            rhs in prop::array::uniform3(small_f64()),
+        ) {
+            prop_assume!(det3(matrix).check().unwrap().abs() > 1.0);
+            assert_residual(matrix, rhs, solve3(matrix, rhs).unwrap());
+        }
+
+        #[test]
+        fn test_solve_singular(
+            row in prop::array::uniform3(small_f64()),
+            other in prop::array::uniform3(small_f64()),
+            rhs in prop::array::uniform3(small_f64()),
+        ) {
+            let matrix = [[row[0], row[1]], [row[0], row[1]]];
+            prop_assert_eq!(solve2(matrix, [rhs[0], rhs[1]]), Err(FloatError::DivisionByZero));
+
+            let matrix = [other, row, row];
+            prop_assert_eq!(solve3(matrix, rhs), Err(FloatError::DivisionByZero));
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! This module provides the `LogProb` type, which stores probabilities in log space.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+use std::ops::{Add, AddAssign, Div, Mul};
+
+/// A probability (or any other positive quantity) stored as its natural logarithm.
+///
+/// Products of many small probabilities underflow to zero in linear space long before their
+/// logarithms leave the finite range. `LogProb` keeps the logarithm in a `GuardedF64`, so `*` and
+/// `/` become addition and subtraction of logarithms, and `+` is computed with the `logsumexp`
+/// identity, `ln(a + b) = max + ln(1 + exp(min - max))`.
+///
+/// Because `ln(0)` is negative infinity, a probability of exactly zero cannot be represented, and
+/// converting a `LogProb` back to linear space reports an error if the result would underflow.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64, LogProb};
+///
+/// let p = LogProb::from_linear(1e-200).unwrap();
+/// let joint = (p * p).unwrap();
+///
+/// assert_eq!(joint.to_linear(), Err(FloatError::Underflow));
+/// assert!((joint.log() - 2.0 * (1e-200f64).ln()).abs().check().unwrap() < 1e-9);
+///
+/// let half = LogProb::from_linear(0.5).unwrap();
+/// assert!((half + half).log().abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LogProb(GuardedF64);
+
+impl LogProb {
+    /// A probability of one, whose logarithm is zero.
+    pub const ONE: Self = Self(GuardedF64(0.0));
+
+    /// Creates a `LogProb` from a natural logarithm.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, LogProb};
+    ///
+    /// let log = GuardedF64::new(-2.0).unwrap();
+    /// assert_eq!(LogProb::from_log(log).log(), log);
+    /// ```
+    #[must_use]
+    pub const fn from_log(log: GuardedF64) -> Self {
+        Self(log)
+    }
+
+    /// Creates a `LogProb` from a value in linear space.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::NaN` if `value` is NaN, `FloatError::Domain` if `value` is negative,
+    /// `FloatError::Underflow` if `value` is zero, or `FloatError::Infinity` if `value` is infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, LogProb};
+    ///
+    /// assert_eq!(LogProb::from_linear(1.0), Ok(LogProb::ONE));
+    /// assert_eq!(LogProb::from_linear(0.0), Err(FloatError::Underflow));
+    /// assert_eq!(LogProb::from_linear(-0.5), Err(FloatError::Domain));
+    /// ```
+    pub fn from_linear(value: impl Into<UnguardedF64>) -> Result<Self, FloatError> {
+        let value = value.into().check_reported()?;
+        if value == 0.0 {
+            return Err(FloatError::Underflow);
+        }
+
+        value.ln_checked().map(Self)
+    }
+
+    /// Returns the natural logarithm of the value.
+    #[must_use]
+    pub const fn log(self) -> GuardedF64 {
+        self.0
+    }
+
+    /// Converts the value back to linear space.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Underflow` if the value is too small to represent in linear space, or
+    /// `FloatError::Infinity` if it is too large.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64, LogProb};
+    ///
+    /// let half = LogProb::from_linear(0.5).unwrap();
+    /// assert!((half.to_linear().unwrap() - 0.5).abs().check().unwrap() < 1e-12);
+    ///
+    /// let tiny = LogProb::from_log(GuardedF64::new(-1000.0).unwrap());
+    /// assert_eq!(tiny.to_linear(), Err(FloatError::Underflow));
+    /// ```
+    pub fn to_linear(self) -> Result<GuardedF64, FloatError> {
+        let value = self.0.exp().check_reported()?;
+        if value == 0.0 {
+            Err(FloatError::Underflow)
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl Mul for LogProb {
+    type Output = Result<Self, FloatError>;
+
+    /// Multiplies two values by adding their logarithms.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64, LogProb};
+    ///
+    /// let a = LogProb::from_linear(0.5).unwrap();
+    /// let b = LogProb::from_linear(0.25).unwrap();
+    /// assert!(((a * b).unwrap().to_linear().unwrap() - 0.125).abs().check().unwrap() < 1e-12);
+    ///
+    /// let min = LogProb::from_log(GuardedF64::MIN);
+    /// assert_eq!(min * min, Err(FloatError::Infinity));
+    /// ```
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        (self.0 + rhs.0).check_reported().map(Self)
+    }
+}
+
+impl Div for LogProb {
+    type Output = Result<Self, FloatError>;
+
+    /// Divides two values by subtracting their logarithms.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::LogProb;
+    ///
+    /// let a = LogProb::from_linear(0.5).unwrap();
+    /// let b = LogProb::from_linear(0.25).unwrap();
+    /// assert!(((a / b).unwrap().to_linear().unwrap() - 2.0).abs().check().unwrap() < 1e-12);
+    /// ```
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        (self.0 - rhs.0).check_reported().map(Self)
+    }
+}
+
+impl Add for LogProb {
+    type Output = Self;
+
+    /// Adds two values in linear space with the `logsumexp` identity. The result is always finite,
+    /// because it is at most `ln(2)` larger than the larger of the two logarithms.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::LogProb;
+    ///
+    /// let a = LogProb::from_linear(0.5).unwrap();
+    /// let b = LogProb::from_linear(0.25).unwrap();
+    /// assert!(((a + b).to_linear().unwrap() - 0.75).abs().check().unwrap() < 1e-12);
+    /// ```
+    fn add(self, rhs: Self) -> Self::Output {
+        let (max, min) = if self >= rhs {
+            (self.0.0, rhs.0.0)
+        } else {
+            (rhs.0.0, self.0.0)
+        };
+        let sum = max + (min - max).exp().ln_1p();
+
+        // Rounding can only push the sum past the largest finite value when `max` is already there.
+        Self(GuardedF64(if sum.is_finite() { sum } else { max }))
+    }
+}
+
+impl AddAssign for LogProb {
+    /// Adds another value to this one in linear space. See [`LogProb::add`].
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn log_prob() -> impl Strategy<Value = LogProb> {
+        valid_f64().prop_map(|v| LogProb::from_log(GuardedF64::new(v).unwrap()))
+    }
+
+    #[test]
+    fn test_add_extremes() {
+        let max = LogProb::from_log(GuardedF64::MAX);
+        let min = LogProb::from_log(GuardedF64::MIN);
+
+        assert_eq!(max + max, max);
+        assert_eq!(max + min, max);
+        assert_eq!(min + min, min);
+    }
+
+    proptest! {
+        #[test]
+        fn test_add_is_bounded(a in log_prob(), b in log_prob()) {
+            let sum = a + b;
+            prop_assert!(sum >= a.max(b));
+            prop_assert!(sum.log().0 <= a.max(b).log().0 + std::f64::consts::LN_2 + 1e-12);
+            prop_assert_eq!(sum, b + a);
+        }
+
+        #[test]
+        fn test_linear_round_trip(p in 1e-300..1e300f64) {
+            let value = LogProb::from_linear(p).unwrap().to_linear().unwrap();
+            prop_assert!((value.0 - p).abs() <= p * 1e-12);
+        }
+
+        #[test]
+        fn test_mul_div_round_trip(a in -1e300..1e300f64, b in -1e300..1e300f64) {
+            let a = LogProb::from_log(GuardedF64::new(a).unwrap());
+            let b = LogProb::from_log(GuardedF64::new(b).unwrap());
+
+            let product = (a * b).unwrap();
+            let quotient = (product / b).unwrap();
+            prop_assert!((quotient.log() - a.log()).abs().check().unwrap() <= 1e-12 * (a.log().abs().0 + b.log().abs().0));
+        }
+    }
+}
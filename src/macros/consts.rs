@@ -10,7 +10,34 @@
 /// - `$t`: The type of the constant (e.g., `f64`, `u32`, etc.).
 /// - `$value`: The value of the constant, which can be a direct reference to an `f64` constant or a specific value.
 /// - `$doc`: A documentation string that describes the constant and its purpose.
+///
+/// With `= derived(...)`, the value is a raw float expression over other constants, such as
+/// `std::f64::consts::PI / 180.0`, instead of a decimal literal that could be mistyped. It is
+/// folded and validated at compile time, like the free-standing form.
+///
+/// The free-standing form defines a free-standing guarded constant, such as those in the `constants` module.
+/// The value is validated at compile time, so a non-finite value fails the build instead of producing
+/// an invalid guarded value.
 macro_rules! copy_const_value {
+    (
+        ( $( $T:ty ),* )
+        $doc:literal
+        $name:ident : $name_t:ident = derived($value:expr)
+    ) => {
+        $(
+            impl $T {
+                #[doc = $doc]
+                // `f32` constants are derived from their `f64` counterparts, to round only once.
+                #[allow(clippy::cast_possible_truncation)]
+                pub const $name: $name_t = {
+                    let value = $value;
+                    assert!(value.is_finite(), concat!("`", stringify!($name), "` is not a finite value"));
+                    $name_t(value)
+                };
+            }
+        )*
+    };
+
     (
         ( $( $T:ty ),* )
         $doc:literal
@@ -23,6 +50,17 @@ macro_rules! copy_const_value {
             }
         )*
     };
+
+    (
+        $doc:literal
+        pub const $name:ident : $T:ident = $value:expr
+    ) => {
+        #[doc = $doc]
+        pub const $name: $T = match $T::new($value) {
+            Ok(value) => value,
+            Err(_) => panic!(concat!("`", stringify!($name), "` is not a finite value")),
+        };
+    };
 }
 
 pub(crate) use copy_const_value;
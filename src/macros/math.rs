@@ -35,6 +35,24 @@ macro_rules! math {
         )*
     };
 
+    (
+        ($( $T:ty ),*)
+        $doc:literal
+        const fn $name:ident ($base:ident : $base_ty:ty, $operand:ident : $operand_ty:ty ) -> $ret:ty $implementation:block
+    ) => {
+        $(
+            impl $T {
+                #[doc = $doc]
+                #[must_use = "method returns a new instance and does not mutate the original value"]
+                #[inline(always)]
+                pub const fn $name(self, $operand: $operand_ty) -> $ret {
+                    let $base: $base_ty = self.0;
+                    $implementation
+                }
+            }
+        )*
+    };
+
     (
         ($( $T:ty ),*)
         $doc:literal
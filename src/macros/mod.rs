@@ -2,4 +2,6 @@ pub mod consts;
 pub mod math;
 pub mod ops_assign;
 pub mod ops_binary;
+pub mod ops_bool;
+pub mod ops_nonzero;
 pub mod ops_unary;
@@ -3,36 +3,40 @@
 /// This macro generates implementations for various combinations of `GuardedF64`, `f64`, and `UnguardedF64`
 /// types, allowing for flexible arithmetic operations while ensuring that the results are checked for validity.
 ///
+/// To keep the amount of generated code small, the guarded and unguarded types (and references to them) accept any
+/// right-hand side that converts into the unguarded type through a single generic implementation. Only a primitive
+/// left-hand side, which cannot be implemented generically, is expanded into one implementation per combination.
+///
+/// Integer types listed after `and` can also be used as the left-hand side, and are converted to the primitive
+/// floating-point type with `From`. Only integer types that convert losslessly should be listed, so that every other
+/// integer type is a compile error rather than a silent rounding. Integers on the right-hand side are accepted through
+/// `integer_conversion`.
+///
 /// # Arguments
 ///
 /// * `$op_trait` - The trait representing the binary operation (e.g., `Add`, `Sub`, etc.).
 /// * `$op_method` - The method name for the operation (e.g., `add`, `sub`, etc.).
+/// * `$int` - The integer types that can be used as the left-hand side.
 /// * `$implementation` - A block of code that defines how the operation is performed.
 /// * `$doc` - A documentation string that describes the operation and its usage.
 macro_rules! binary_operation {
     (
-        impl $op_trait:ident for ...($TGuarded:ty, $TUnguarded:ty) {
+        impl $op_trait:ident for ...($TGuarded:ty, $TUnguarded:ty) $(and ...($($int:ty),*))? {
             $doc:literal
             fn $op_method:ident ($lhs:ident : $lhs_internal:ty, $rhs:ident : $rhs_internal:ty) -> $ret:ty
             $implementation:block
         }
     ) => {
         binary_operation!(
-            $op_trait :: $op_method
+            impl $op_trait :: $op_method for $TGuarded => $TUnguarded;
             $doc
-            fn ($lhs: $TGuarded, $rhs: $rhs_internal) -> $ret {
-                let $lhs: $lhs_internal = $lhs.0;
-                $implementation
-            }
+            fn ($lhs: $lhs_internal, $rhs: $rhs_internal) -> $ret $implementation
         );
 
         binary_operation!(
-            $op_trait :: $op_method
+            impl $op_trait :: $op_method for $TUnguarded => $TUnguarded;
             $doc
-            fn ($lhs: $TUnguarded, $rhs: $rhs_internal) -> $ret {
-                let $lhs: $lhs_internal = $lhs.0;
-                $implementation
-            }
+            fn ($lhs: $lhs_internal, $rhs: $rhs_internal) -> $ret $implementation
         );
 
         binary_operation!(
@@ -53,42 +57,65 @@ macro_rules! binary_operation {
             }
         );
 
-        binary_operation!(
-            $op_trait :: $op_method
-            $doc
-            fn ($lhs: $TGuarded, $rhs: $TGuarded) -> $ret {
-                let $lhs: $lhs_internal = $lhs.0;
-                let $rhs: $rhs_internal = $rhs.0;
-                $implementation
-            }
-        );
+        $($(
+            binary_operation!(
+                $op_trait :: $op_method
+                $doc
+                fn ($lhs: $int as $lhs_internal, $rhs: $TGuarded) -> $ret {
+                    let $rhs: $rhs_internal = $rhs.0;
+                    $implementation
+                }
+            );
+
+            binary_operation!(
+                $op_trait :: $op_method
+                $doc
+                fn ($lhs: $int as $lhs_internal, $rhs: $TUnguarded) -> $ret {
+                    let $rhs: $rhs_internal = $rhs.0;
+                    $implementation
+                }
+            );
+        )*)?
+    };
 
-        binary_operation!(
-            $op_trait :: $op_method
-            $doc
-            fn ($lhs: $TGuarded, $rhs: $TUnguarded) -> $ret {
-                let $lhs: $lhs_internal = $lhs.0;
-                let $rhs: $rhs_internal = $rhs.0;
+    (
+        impl $op_trait:ident :: $op_method:ident for $LHS:ty => $TUnguarded:ty;
+        $doc:literal
+        fn ($lhs:ident : $lhs_internal:ty, $rhs:ident : $rhs_internal:ty) -> $ret:ty $implementation:block
+    ) => {
+        impl<T: Into<$TUnguarded>> $op_trait<T> for $LHS {
+            type Output = $ret;
+
+            #[doc = $doc]
+            #[inline(always)]
+            fn $op_method(self, $rhs: T) -> Self::Output {
+                let $lhs: $lhs_internal = self.0;
+                let $rhs: $rhs_internal = <T as Into<$TUnguarded>>::into($rhs).0;
                 $implementation
             }
-        );
+        }
 
-        binary_operation!(
-            $op_trait :: $op_method
-            $doc
-            fn ($lhs: $TUnguarded, $rhs: $TGuarded) -> $ret {
-                let $lhs: $lhs_internal = $lhs.0;
-                let $rhs: $rhs_internal = $rhs.0;
-                $implementation
+        impl<T: Into<$TUnguarded>> $op_trait<T> for &$LHS {
+            type Output = $ret;
+
+            #[doc = $doc]
+            #[inline(always)]
+            fn $op_method(self, $rhs: T) -> Self::Output {
+                (*self).$op_method($rhs)
             }
-        );
+        }
+    };
 
+    (
+        $op_trait:ident :: $op_method:ident
+        $doc:literal
+        fn ($lhs:ident : $int:ty as $lhs_internal:ty, $rhs:ident : $RHS:ty) -> $ret:ty $implementation:block
+    ) => {
         binary_operation!(
             $op_trait :: $op_method
             $doc
-            fn ($lhs: $TUnguarded, $rhs: $TUnguarded) -> $ret {
-                let $lhs: $lhs_internal = $lhs.0;
-                let $rhs: $rhs_internal = $rhs.0;
+            fn ($lhs: $int, $rhs: $RHS) -> $ret {
+                let $lhs = <$lhs_internal>::from($lhs);
                 $implementation
             }
         );
@@ -143,3 +170,42 @@ macro_rules! binary_operation {
 }
 
 pub(crate) use binary_operation;
+
+/// Defines lossless conversions from primitive integers into an unguarded floating-point type, so
+/// that integers can be used as the right-hand side of the operators defined by `binary_operation`.
+///
+/// Only integer types whose every value is exactly representable by `$float` should be listed, so
+/// that the conversion is both infallible and exact.
+///
+/// # Arguments
+///
+/// * `$TUnguarded` - The unguarded type to convert into.
+/// * `$float` - The primitive floating-point type wrapped by `$TUnguarded`.
+/// * `$int` - The integer types to implement the conversion for.
+/// * `$doc` - A documentation string that describes the conversion and its usage.
+macro_rules! integer_conversion {
+    (
+        impl From<...($($int:ty),*)> for $TUnguarded:ty as $float:ty;
+        $doc:literal
+    ) => {
+        $(
+            impl From<$int> for $TUnguarded {
+                #[doc = $doc]
+                #[inline(always)]
+                fn from(value: $int) -> Self {
+                    Self(<$float>::from(value))
+                }
+            }
+
+            impl From<&$int> for $TUnguarded {
+                #[doc = $doc]
+                #[inline(always)]
+                fn from(value: &$int) -> Self {
+                    Self(<$float>::from(*value))
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use integer_conversion;
@@ -0,0 +1,92 @@
+/// Defines conversions from `bool` and boolean masking for guarded and unguarded floating-point
+/// types.
+///
+/// Branchless numeric code often turns a condition into `0.0` or `1.0` and multiplies by it. These
+/// helpers are trivial, but writing them out by hand for each type is recurring friction, so this
+/// macro defines `from_bool`, `indicator`, `mask`, and `From<bool>` for every type at once. With
+/// `From<bool>`, a `bool` can also be used directly as an operand, like `value * mask`.
+///
+/// # Arguments
+///
+/// * `$T` - The types to implement the helpers for.
+/// * `$float` - The primitive floating-point type wrapped by each `$T`.
+macro_rules! bool_conversion {
+    (($($T:ident),*) as $float:ty) => {
+        $(
+            impl $T {
+                /// Converts a `bool` to `1.0` if it is `true`, or `0.0` if it is `false`.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                #[doc = concat!("use floatguard::", stringify!($T), ";")]
+                ///
+                #[doc = concat!("assert_eq!(", stringify!($T), "::from_bool(true).get(), 1.0);")]
+                #[doc = concat!("assert_eq!(", stringify!($T), "::from_bool(false).get(), 0.0);")]
+                /// ```
+                #[must_use]
+                #[inline(always)]
+                pub const fn from_bool(value: bool) -> Self {
+                    Self(if value { 1.0 } else { 0.0 })
+                }
+
+                /// Returns `1.0` if `predicate` holds for the value, or `0.0` if it does not.
+                ///
+                /// The predicate is only called for finite values, so an invalid value stays
+                /// invalid instead of being hidden behind a `0.0` or `1.0`.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                #[doc = concat!("use floatguard::", stringify!($T), ";")]
+                ///
+                #[doc = concat!("let value = ", stringify!($T), "::from_bool(true);")]
+                /// assert_eq!(value.indicator(|v| v > 0.5).get(), 1.0);
+                /// assert_eq!(value.indicator(|v| v > 2.0).get(), 0.0);
+                /// ```
+                #[must_use = "method returns a new value and does not mutate the original value"]
+                #[inline(always)]
+                pub fn indicator(self, predicate: impl FnOnce($float) -> bool) -> Self {
+                    if self.0.is_finite() {
+                        Self::from_bool(predicate(self.0))
+                    } else {
+                        self
+                    }
+                }
+
+                /// Masks the value, returning it unchanged if `keep` is `true`, or multiplying it
+                /// by `0.0` if it is `false`.
+                ///
+                /// This is the same as multiplying by `keep`, which converts to the unguarded type,
+                /// but keeps the type of `self`. Because it is multiplication, a masked negative
+                /// value is `-0.0`, and a masked invalid value is still invalid.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                #[doc = concat!("use floatguard::", stringify!($T), ";")]
+                ///
+                #[doc = concat!("let value = ", stringify!($T), "::from_bool(true);")]
+                /// assert_eq!(value.mask(true).get(), 1.0);
+                /// assert_eq!(value.mask(false).get(), 0.0);
+                /// assert_eq!((value * false).get(), value.mask(false).get());
+                /// ```
+                #[must_use = "method returns a new value and does not mutate the original value"]
+                #[inline(always)]
+                pub const fn mask(self, keep: bool) -> Self {
+                    Self(self.0 * Self::from_bool(keep).0)
+                }
+            }
+
+            impl From<bool> for $T {
+                /// Converts a `bool` to `1.0` or `0.0`, like `from_bool`.
+                #[inline(always)]
+                fn from(value: bool) -> Self {
+                    Self::from_bool(value)
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use bool_conversion;
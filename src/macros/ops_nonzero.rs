@@ -0,0 +1,107 @@
+/// Defines division of a guarded and an unguarded floating-point type by `NonZero*` integers.
+///
+/// An integer divisor that is known to be non-zero has a magnitude of at least one, so dividing a
+/// finite value by it can neither overflow nor produce NaN. Dividing a guarded value therefore
+/// returns a guarded value directly, without going through the unguarded type.
+///
+/// # Arguments
+///
+/// * `$TGuarded` - The guarded type, which keeps its type when divided.
+/// * `$TUnguarded` - The unguarded type, which keeps its type when divided.
+/// * `$float` - The primitive floating-point type that the divisor is converted to.
+/// * `$NonZero` - The `NonZero*` integer types to implement division for.
+/// * `$doc` - A documentation string that describes the operation and its usage.
+macro_rules! nonzero_division {
+    (
+        impl Div<...($($NonZero:ty),*)> for ($TGuarded:ty, $TUnguarded:ty) as $float:ty;
+        $doc:literal
+    ) => {
+        $(
+            nonzero_division!(@impl $TGuarded, $NonZero, $float, $doc);
+            nonzero_division!(@impl $TUnguarded, $NonZero, $float, $doc);
+        )*
+    };
+
+    (@impl $T:ty, $NonZero:ty, $float:ty, $doc:literal) => {
+        impl Div<$NonZero> for $T {
+            type Output = $T;
+
+            #[doc = $doc]
+            #[inline(always)]
+            #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+            fn div(self, rhs: $NonZero) -> Self::Output {
+                Self(self.0 / rhs.get() as $float)
+            }
+        }
+
+        impl Div<&$NonZero> for $T {
+            type Output = $T;
+
+            #[doc = $doc]
+            #[inline(always)]
+            fn div(self, rhs: &$NonZero) -> Self::Output {
+                self / *rhs
+            }
+        }
+
+        impl Div<$NonZero> for &$T {
+            type Output = $T;
+
+            #[doc = $doc]
+            #[inline(always)]
+            fn div(self, rhs: $NonZero) -> Self::Output {
+                *self / rhs
+            }
+        }
+
+        impl Div<&$NonZero> for &$T {
+            type Output = $T;
+
+            #[doc = $doc]
+            #[inline(always)]
+            fn div(self, rhs: &$NonZero) -> Self::Output {
+                *self / *rhs
+            }
+        }
+
+        impl DivAssign<$NonZero> for $T {
+            #[doc = $doc]
+            #[allow(clippy::inline_always)]
+            #[inline(always)]
+            fn div_assign(&mut self, rhs: $NonZero) {
+                *self = *self / rhs;
+            }
+        }
+    };
+}
+
+pub(crate) use nonzero_division;
+
+/// Defines lossless conversions from `NonZero*` integers into a guarded floating-point type.
+///
+/// Only integer types whose every value is exactly representable by `$float` should be listed, so
+/// that the conversion is both infallible and exact.
+///
+/// # Arguments
+///
+/// * `$TGuarded` - The guarded type to convert into.
+/// * `$float` - The primitive floating-point type wrapped by `$TGuarded`.
+/// * `$NonZero` - The `NonZero*` integer types to implement the conversion for.
+/// * `$doc` - A documentation string that describes the conversion and its usage.
+macro_rules! nonzero_conversion {
+    (
+        impl From<...($($NonZero:ty),*)> for $TGuarded:ty as $float:ty;
+        $doc:literal
+    ) => {
+        $(
+            impl From<$NonZero> for $TGuarded {
+                #[doc = $doc]
+                fn from(value: $NonZero) -> Self {
+                    Self(<$float>::from(value.get()))
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use nonzero_conversion;
@@ -0,0 +1,564 @@
+//! This module provides the `GuardedMat` and `UnguardedMat` types for small, fixed-size matrices.
+//!
+//! Like the scalar and quaternion types, operations on a `GuardedMat` produce an `UnguardedMat`,
+//! which is validated once with `check` at the end of the computation, so a chain of products does
+//! not pay for a check of every entry at every step. The arithmetic is fused with `mul_add` where
+//! possible, which is both faster and more accurate. The 2x2, 3x3, and 4x4 matrices can also be
+//! inverted, and a singular matrix is reported as `FloatError::DivisionByZero`, like the solvers in
+//! `linalg`.
+use crate::linalg::nonzero_determinant;
+use crate::{FloatError, GuardedF64, GuardedVec, UnguardedF64};
+use std::ops::Mul;
+
+/// A square matrix whose entries are all `GuardedF64` values, stored in row-major order.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedMat;
+///
+/// let rotate = GuardedMat::try_from([[0.0, -1.0], [1.0, 0.0]]).unwrap();
+/// let scale = GuardedMat::try_from([[2.0, 0.0], [0.0, 3.0]]).unwrap();
+///
+/// let transform = (rotate * scale).check().unwrap();
+/// assert_eq!(<[[f64; 2]; 2]>::from(transform), [[0.0, -3.0], [2.0, 0.0]]);
+/// assert_eq!((transform.inverse().unwrap() * transform).check(), Ok(GuardedMat::IDENTITY));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardedMat<const N: usize>([[GuardedF64; N]; N]);
+
+/// A square matrix whose entries are `UnguardedF64` values, produced by operations on a
+/// `GuardedMat` and validated with `check`.
+#[derive(Debug, Clone, Copy)]
+pub struct UnguardedMat<const N: usize>([[UnguardedF64; N]; N]);
+
+impl<const N: usize> GuardedMat<N> {
+    /// The identity matrix.
+    pub const IDENTITY: Self = {
+        let mut rows = [[GuardedF64(0.0); N]; N];
+        let mut index = 0;
+        while index < N {
+            rows[index][index] = GuardedF64(1.0);
+            index += 1;
+        }
+        Self(rows)
+    };
+
+    /// Creates a new `GuardedMat` from its rows.
+    #[must_use]
+    pub const fn new(rows: [[GuardedF64; N]; N]) -> Self {
+        Self(rows)
+    }
+
+    /// Returns the rows of the matrix.
+    #[must_use]
+    pub const fn into_array(self) -> [[GuardedF64; N]; N] {
+        self.0
+    }
+
+    /// Swaps the rows and columns of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedMat;
+    ///
+    /// let matrix = GuardedMat::try_from([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+    /// assert_eq!(<[[f64; 2]; 2]>::from(matrix.transpose()), [[1.0, 3.0], [2.0, 4.0]]);
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn transpose(self) -> Self {
+        Self(std::array::from_fn(|row| {
+            std::array::from_fn(|column| self.0[column][row])
+        }))
+    }
+
+    /// Multiplies the matrix by a column vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `FloatError` of the first component of the result that is NaN or infinite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedMat, GuardedVec};
+    ///
+    /// let matrix = GuardedMat::try_from([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+    /// let vector = GuardedVec::try_from([1.0, -1.0]).unwrap();
+    /// assert_eq!(matrix.transform(vector), GuardedVec::try_from([-1.0, -1.0]));
+    ///
+    /// let large = GuardedVec::try_from([f64::MAX, f64::MAX]).unwrap();
+    /// assert_eq!(matrix.transform(large), Err(FloatError::Infinity));
+    /// ```
+    pub fn transform(self, vector: GuardedVec<N>) -> Result<GuardedVec<N>, FloatError> {
+        let vector = vector.into_array().map(|component| component.0);
+
+        let mut transformed = [GuardedF64(0.0); N];
+        for (component, row) in transformed.iter_mut().zip(self.raw()) {
+            *component = UnguardedF64(dot(row, vector)).check_reported()?;
+        }
+        Ok(GuardedVec::new(transformed))
+    }
+
+    /// Returns the entries as raw `f64` values.
+    fn raw(self) -> [[f64; N]; N] {
+        self.0.map(|row| row.map(|entry| entry.0))
+    }
+}
+
+impl GuardedMat<2> {
+    /// Computes the determinant of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, GuardedMat};
+    ///
+    /// let matrix = GuardedMat::try_from([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+    /// assert_eq!(matrix.determinant().check(), GuardedF64::new(-2.0));
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn determinant(self) -> UnguardedF64 {
+        let [[a, b], [c, d]] = self.raw();
+        UnguardedF64(a.mul_add(d, -b * c))
+    }
+
+    /// Computes the inverse of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if the matrix is singular, or another `FloatError` if
+    /// the determinant or any entry of the inverse is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedMat};
+    ///
+    /// let matrix = GuardedMat::try_from([[4.0, 7.0], [2.0, 6.0]]).unwrap();
+    /// let inverse = GuardedMat::try_from([[0.6, -0.7], [-0.2, 0.4]]);
+    /// assert_eq!(matrix.inverse(), inverse);
+    ///
+    /// let singular = GuardedMat::try_from([[1.0, 2.0], [2.0, 4.0]]).unwrap();
+    /// assert_eq!(singular.inverse(), Err(FloatError::DivisionByZero));
+    /// ```
+    pub fn inverse(self) -> Result<Self, FloatError> {
+        let [[a, b], [c, d]] = self.raw();
+        invert([[d, -b], [-c, a]], self.determinant())
+    }
+}
+
+impl GuardedMat<3> {
+    /// Computes the determinant of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, GuardedMat};
+    ///
+    /// let matrix = GuardedMat::try_from([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 1.0]]).unwrap();
+    /// assert_eq!(matrix.determinant().check(), GuardedF64::new(0.0));
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn determinant(self) -> UnguardedF64 {
+        let [first, ..] = self.raw();
+        UnguardedF64(dot(first, self.cofactors()[0]))
+    }
+
+    /// Computes the inverse of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if the matrix is singular, or another `FloatError` if
+    /// the determinant or any entry of the inverse is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedMat};
+    ///
+    /// let matrix = GuardedMat::try_from([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]).unwrap();
+    /// let inverse = [[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]];
+    /// assert_eq!(matrix.inverse(), GuardedMat::try_from(inverse));
+    ///
+    /// let singular = GuardedMat::try_from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+    /// assert_eq!(singular.unwrap().inverse(), Err(FloatError::DivisionByZero));
+    /// ```
+    pub fn inverse(self) -> Result<Self, FloatError> {
+        let [first, ..] = self.raw();
+        let cofactors = self.cofactors();
+        let determinant = UnguardedF64(dot(first, cofactors[0]));
+
+        // The adjugate is the transpose of the matrix of cofactors.
+        invert(Self::transpose_raw(cofactors), determinant)
+    }
+
+    /// Computes the matrix of cofactors, in which the first row expands the determinant.
+    #[allow(clippy::many_single_char_names)]
+    fn cofactors(self) -> [[f64; 3]; 3] {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.raw();
+        let minor = |a: f64, b: f64, c: f64, d: f64| a.mul_add(d, -b * c);
+        [
+            [minor(e, f, h, i), -minor(d, f, g, i), minor(d, e, g, h)],
+            [-minor(b, c, h, i), minor(a, c, g, i), -minor(a, b, g, h)],
+            [minor(b, c, e, f), -minor(a, c, d, f), minor(a, b, d, e)],
+        ]
+    }
+
+    /// Swaps the rows and columns of a raw matrix.
+    #[allow(clippy::many_single_char_names)]
+    const fn transpose_raw(matrix: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+        let [[a, b, c], [d, e, f], [g, h, i]] = matrix;
+        [[a, d, g], [b, e, h], [c, f, i]]
+    }
+}
+
+impl GuardedMat<4> {
+    /// Computes the determinant of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, GuardedMat};
+    ///
+    /// let matrix = GuardedMat::try_from([
+    ///     [2.0, 0.0, 0.0, 1.0],
+    ///     [0.0, 3.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 4.0, 0.0],
+    ///     [1.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// assert_eq!(matrix.unwrap().determinant().check(), GuardedF64::new(12.0));
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn determinant(self) -> UnguardedF64 {
+        self.minors().determinant()
+    }
+
+    /// Computes the inverse of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if the matrix is singular, or another `FloatError` if
+    /// the determinant or any entry of the inverse is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedMat};
+    ///
+    /// let translate = GuardedMat::try_from([
+    ///     [1.0, 0.0, 0.0, 2.0],
+    ///     [0.0, 1.0, 0.0, -3.0],
+    ///     [0.0, 0.0, 1.0, 4.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// let inverse = GuardedMat::try_from([
+    ///     [1.0, 0.0, 0.0, -2.0],
+    ///     [0.0, 1.0, 0.0, 3.0],
+    ///     [0.0, 0.0, 1.0, -4.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// assert_eq!(translate.unwrap().inverse(), inverse);
+    ///
+    /// let flatten = GuardedMat::try_from([
+    ///     [1.0, 0.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// assert_eq!(flatten.unwrap().inverse(), Err(FloatError::DivisionByZero));
+    /// ```
+    pub fn inverse(self) -> Result<Self, FloatError> {
+        let m = self.raw();
+        let Minors { s, c } = self.minors();
+
+        // Each entry of the adjugate expands a 3x3 cofactor along the 2x2 minors of the other half.
+        let adjugate = [
+            [
+                dot([m[1][1], -m[1][2], m[1][3]], [c[5], c[4], c[3]]),
+                dot([-m[0][1], m[0][2], -m[0][3]], [c[5], c[4], c[3]]),
+                dot([m[3][1], -m[3][2], m[3][3]], [s[5], s[4], s[3]]),
+                dot([-m[2][1], m[2][2], -m[2][3]], [s[5], s[4], s[3]]),
+            ],
+            [
+                dot([-m[1][0], m[1][2], -m[1][3]], [c[5], c[2], c[1]]),
+                dot([m[0][0], -m[0][2], m[0][3]], [c[5], c[2], c[1]]),
+                dot([-m[3][0], m[3][2], -m[3][3]], [s[5], s[2], s[1]]),
+                dot([m[2][0], -m[2][2], m[2][3]], [s[5], s[2], s[1]]),
+            ],
+            [
+                dot([m[1][0], -m[1][1], m[1][3]], [c[4], c[2], c[0]]),
+                dot([-m[0][0], m[0][1], -m[0][3]], [c[4], c[2], c[0]]),
+                dot([m[3][0], -m[3][1], m[3][3]], [s[4], s[2], s[0]]),
+                dot([-m[2][0], m[2][1], -m[2][3]], [s[4], s[2], s[0]]),
+            ],
+            [
+                dot([-m[1][0], m[1][1], -m[1][2]], [c[3], c[1], c[0]]),
+                dot([m[0][0], -m[0][1], m[0][2]], [c[3], c[1], c[0]]),
+                dot([-m[3][0], m[3][1], -m[3][2]], [s[3], s[1], s[0]]),
+                dot([m[2][0], -m[2][1], m[2][2]], [s[3], s[1], s[0]]),
+            ],
+        ];
+
+        invert(adjugate, Minors { s, c }.determinant())
+    }
+
+    /// Computes the 2x2 minors of the top two rows, and of the bottom two rows.
+    fn minors(self) -> Minors {
+        let [top, upper, lower, bottom] = self.raw();
+        let minor = |rows: [[f64; 4]; 2], i: usize, j: usize| {
+            let [first, second] = rows;
+            first[i].mul_add(second[j], -second[i] * first[j])
+        };
+
+        let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        Minors {
+            s: pairs.map(|(i, j)| minor([top, upper], i, j)),
+            c: pairs.map(|(i, j)| minor([lower, bottom], i, j)),
+        }
+    }
+}
+
+/// The 2x2 minors of a 4x4 matrix, from which its determinant and adjugate are computed.
+struct Minors {
+    /// The minors of the top two rows, for the column pairs (0, 1), (0, 2), (0, 3), (1, 2), (1, 3),
+    /// and (2, 3).
+    s: [f64; 6],
+
+    /// The minors of the bottom two rows, for the same column pairs.
+    c: [f64; 6],
+}
+
+impl Minors {
+    /// Computes the determinant by pairing each minor of the top rows with the complementary minor
+    /// of the bottom rows.
+    fn determinant(&self) -> UnguardedF64 {
+        let Self { s, c } = self;
+        let top = dot([s[0], -s[1], s[2]], [c[5], c[4], c[3]]);
+        UnguardedF64(top + dot([s[3], -s[4], s[5]], [c[2], c[1], c[0]]))
+    }
+}
+
+impl<const N: usize> UnguardedMat<N> {
+    /// Creates a new `UnguardedMat` from its rows.
+    #[must_use]
+    pub const fn new(rows: [[UnguardedF64; N]; N]) -> Self {
+        Self(rows)
+    }
+
+    /// Returns the rows of the matrix.
+    #[must_use]
+    pub const fn into_array(self) -> [[UnguardedF64; N]; N] {
+        self.0
+    }
+
+    /// Checks that every entry is valid (finite).
+    ///
+    /// # Errors
+    ///
+    /// Returns the `FloatError` of the first entry, in row-major order, that is NaN or infinite.
+    pub fn check(self) -> Result<GuardedMat<N>, FloatError> {
+        let mut rows = [[GuardedF64(0.0); N]; N];
+        for (row, unguarded) in rows.iter_mut().zip(self.0) {
+            for (entry, unguarded) in row.iter_mut().zip(unguarded) {
+                *entry = unguarded.check_reported()?;
+            }
+        }
+        Ok(GuardedMat(rows))
+    }
+}
+
+impl<const N: usize> From<GuardedMat<N>> for UnguardedMat<N> {
+    /// Converts a `GuardedMat` into an `UnguardedMat`.
+    fn from(value: GuardedMat<N>) -> Self {
+        Self(value.0.map(|row| row.map(UnguardedF64::from)))
+    }
+}
+
+impl<const N: usize> From<[[GuardedF64; N]; N]> for GuardedMat<N> {
+    /// Wraps an array of guarded rows, which are already known to be valid.
+    fn from(rows: [[GuardedF64; N]; N]) -> Self {
+        Self(rows)
+    }
+}
+
+impl<const N: usize> TryFrom<[[f64; N]; N]> for GuardedMat<N> {
+    type Error = FloatError;
+
+    /// Validates each entry of an array of `f64` rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `FloatError` of the first entry, in row-major order, that is NaN or infinite.
+    fn try_from(rows: [[f64; N]; N]) -> Result<Self, Self::Error> {
+        UnguardedMat(rows.map(|row| row.map(UnguardedF64))).check()
+    }
+}
+
+impl<const N: usize> From<GuardedMat<N>> for [[f64; N]; N] {
+    /// Unwraps the entries of the matrix as `f64` values.
+    fn from(matrix: GuardedMat<N>) -> Self {
+        matrix.raw()
+    }
+}
+
+impl<const N: usize> std::ops::Deref for GuardedMat<N> {
+    type Target = [[GuardedF64; N]; N];
+
+    /// Dereferences the matrix to its array of rows.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize, T: Into<Self>> Mul<T> for UnguardedMat<N> {
+    type Output = Self;
+
+    /// Computes the matrix product, without checking the entries.
+    fn mul(self, rhs: T) -> Self::Output {
+        let lhs = self.0.map(|row| row.map(|entry| entry.0));
+        let rhs = rhs.into().0.map(|row| row.map(|entry| entry.0));
+
+        Self(lhs.map(|row| {
+            std::array::from_fn(|column| UnguardedF64(dot(row, rhs.map(|rhs| rhs[column]))))
+        }))
+    }
+}
+
+impl<const N: usize, T: Into<UnguardedMat<N>>> Mul<T> for GuardedMat<N> {
+    type Output = UnguardedMat<N>;
+
+    /// Computes the matrix product, which composes the transformations of the matrices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedMat};
+    ///
+    /// let shear = GuardedMat::try_from([[1.0, 1.0], [0.0, 1.0]]).unwrap();
+    /// let product = (shear * shear * shear).check().unwrap();
+    /// assert_eq!(<[[f64; 2]; 2]>::from(product), [[1.0, 3.0], [0.0, 1.0]]);
+    ///
+    /// let large = GuardedMat::try_from([[f64::MAX, 0.0], [0.0, 1.0]]).unwrap();
+    /// assert_eq!((large * large).check(), Err(FloatError::Infinity));
+    /// ```
+    fn mul(self, rhs: T) -> Self::Output {
+        UnguardedMat::from(self) * rhs
+    }
+}
+
+/// Computes the dot product of two rows, fusing each multiplication with the running sum.
+fn dot<const N: usize>(a: [f64; N], b: [f64; N]) -> f64 {
+    a.into_iter()
+        .zip(b)
+        .fold(0.0, |sum, (x, y)| x.mul_add(y, sum))
+}
+
+/// Divides the adjugate of a matrix by its determinant, and checks the entries of the inverse.
+fn invert<const N: usize>(
+    adjugate: [[f64; N]; N],
+    determinant: UnguardedF64,
+) -> Result<GuardedMat<N>, FloatError> {
+    let determinant = nonzero_determinant(determinant)?.0;
+    UnguardedMat(adjugate.map(|row| row.map(|entry| UnguardedF64(entry / determinant)))).check()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Generates matrices that are diagonally dominant, and therefore well-conditioned.
+    fn invertible<const N: usize>() -> impl Strategy<Value = GuardedMat<N>> {
+        prop::array::uniform::<_, N>(prop::array::uniform::<_, N>(-1.0..1.0f64)).prop_map(
+            |mut rows| {
+                for (index, row) in rows.iter_mut().enumerate() {
+                    row[index] += 2.0 * f64::from(u8::try_from(N).unwrap());
+                }
+                GuardedMat::try_from(rows).unwrap()
+            },
+        )
+    }
+
+    fn assert_identity<const N: usize>(matrix: UnguardedMat<N>) {
+        let matrix = matrix.check().unwrap();
+        for (row, expected) in matrix.iter().zip(*GuardedMat::<N>::IDENTITY) {
+            for (entry, expected) in row.iter().zip(expected) {
+                assert!((entry.0 - expected.0).abs() < 1e-12, "{matrix:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_singular_and_overflow() {
+        assert_eq!(
+            GuardedMat::<4>::try_from([[1.0; 4]; 4]).unwrap().inverse(),
+            Err(FloatError::DivisionByZero)
+        );
+        assert_eq!(
+            GuardedMat::try_from([[f64::MAX, f64::MAX], [-f64::MAX, f64::MAX]])
+                .unwrap()
+                .inverse(),
+            Err(FloatError::Infinity)
+        );
+        assert_eq!(
+            GuardedMat::try_from([[1e-310, 0.0], [0.0, 1.0]])
+                .unwrap()
+                .inverse(),
+            Err(FloatError::Infinity)
+        );
+
+        // The determinant underflows to zero, so the matrix is treated as singular.
+        assert_eq!(
+            GuardedMat::try_from([[1e-300, 0.0], [0.0, 1e-300]])
+                .unwrap()
+                .inverse(),
+            Err(FloatError::DivisionByZero)
+        );
+        assert_eq!(
+            GuardedMat::try_from([[1.0, f64::NAN], [0.0, 1.0]]),
+            Err(FloatError::NaN)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_inverse_2(matrix in invertible::<2>()) {
+            let inverse = matrix.inverse().unwrap();
+            assert_identity(matrix * inverse);
+            assert_identity(inverse * matrix);
+        }
+
+        #[test]
+        fn test_inverse_3(matrix in invertible::<3>()) {
+            let inverse = matrix.inverse().unwrap();
+            assert_identity(matrix * inverse);
+            assert_identity(inverse * matrix);
+        }
+
+        #[test]
+        fn test_inverse_4(matrix in invertible::<4>()) {
+            let inverse = matrix.inverse().unwrap();
+            assert_identity(matrix * inverse);
+            assert_identity(inverse * matrix);
+        }
+
+        #[test]
+        fn test_determinant_of_product(a in invertible::<4>(), b in invertible::<4>()) {
+            let product = (a * b).check().unwrap();
+            let expected = a.determinant().0 * b.determinant().0;
+            prop_assert!((product.determinant().0 - expected).abs() <= expected.abs() * 1e-12);
+        }
+
+        #[test]
+        fn test_transform_matches_product(matrix in invertible::<3>(), vector in prop::array::uniform3(-1e3..1e3f64)) {
+            let transformed = matrix.transform(GuardedVec::try_from(vector).unwrap()).unwrap();
+            let column = GuardedMat::try_from(vector.map(|v| [v, 0.0, 0.0])).unwrap();
+            let product = (matrix * column).check().unwrap();
+            for (component, row) in transformed.iter().zip(product.iter()) {
+                prop_assert_eq!(*component, row[0]);
+            }
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Helpers for exporting guarded values as Prometheus metrics.
+//!
+//! Prometheus accepts `NaN` as a sample value, so a NaN that reaches an exporter is passed along to
+//! every dashboard and alert that reads the metric. The helpers in this module only ever render
+//! finite values: guarded values are always finite, and unguarded values that are not are replaced
+//! with a fallback. Values are formatted the way the official Go client formats them, so exported
+//! samples are byte-for-byte identical to those of other exporters.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+use std::fmt;
+
+impl GuardedF64 {
+    /// Formats the value as a Prometheus sample value.
+    ///
+    /// The value is written with the fewest digits that parse back to the same `f64`, in exponent
+    /// notation when the decimal exponent is less than `-4` or at least `6`, matching Go's
+    /// `strconv.FormatFloat(value, 'g', -1, 64)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    ///
+    /// assert_eq!(GuardedF64::new(0.25).unwrap().to_prometheus_value(), "0.25");
+    /// assert_eq!(GuardedF64::new(123_456.0).unwrap().to_prometheus_value(), "123456");
+    /// assert_eq!(GuardedF64::new(1_500_000.0).unwrap().to_prometheus_value(), "1.5e+06");
+    /// assert_eq!(GuardedF64::new(-0.000_02).unwrap().to_prometheus_value(), "-2e-05");
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn to_prometheus_value(self) -> String {
+        SampleValue(self.0).to_string()
+    }
+}
+
+impl UnguardedF64 {
+    /// Formats the value as a Prometheus sample value, or formats `fallback` instead if the value is
+    /// NaN or infinite. See `GuardedF64::to_prometheus_value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, UnguardedF64};
+    ///
+    /// let fallback = GuardedF64::new(-1.0).unwrap();
+    /// assert_eq!(UnguardedF64::new(2.5).to_prometheus_value(fallback), "2.5");
+    /// assert_eq!((UnguardedF64::new(0.0) / 0.0).to_prometheus_value(fallback), "-1");
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn to_prometheus_value(self, fallback: GuardedF64) -> String {
+        self.check_reported()
+            .unwrap_or(fallback)
+            .to_prometheus_value()
+    }
+}
+
+/// A Prometheus counter: a value that starts at zero and only increases.
+///
+/// `Display` formats the value like `GuardedF64::to_prometheus_value`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::metrics::Counter;
+///
+/// let mut requests = Counter::new();
+/// requests.inc().unwrap();
+/// requests.inc_by(GuardedF64::new(2.0).unwrap()).unwrap();
+/// assert_eq!(requests.to_string(), "3");
+///
+/// assert_eq!(requests.inc_by(GuardedF64::new(-1.0).unwrap()), Err(FloatError::Domain));
+/// assert_eq!(requests.get(), GuardedF64::new(3.0).unwrap());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Counter(GuardedF64);
+
+impl Counter {
+    /// Creates a counter with a value of zero.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(GuardedF64(0.0))
+    }
+
+    /// Returns the current value of the counter.
+    #[must_use]
+    pub const fn get(self) -> GuardedF64 {
+        self.0
+    }
+
+    /// Increases the counter by one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the result is too large to represent, leaving the counter
+    /// unchanged.
+    pub fn inc(&mut self) -> Result<(), FloatError> {
+        self.inc_by(GuardedF64(1.0))
+    }
+
+    /// Increases the counter by `amount`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `amount` is negative, since counters cannot decrease, or
+    /// `FloatError::Infinity` if the result is too large to represent. The counter is unchanged in
+    /// either case.
+    pub fn inc_by(&mut self, amount: GuardedF64) -> Result<(), FloatError> {
+        if amount.0 < 0.0 {
+            return Err(FloatError::Domain);
+        }
+        self.0 = (self.0 + amount).check_reported()?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Counter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        SampleValue(self.0.0).fmt(f)
+    }
+}
+
+/// A Prometheus gauge: a value that can be set, increased, and decreased.
+///
+/// `Display` formats the value like `GuardedF64::to_prometheus_value`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::metrics::Gauge;
+///
+/// let mut temperature = Gauge::new(GuardedF64::new(20.5).unwrap());
+/// temperature.add(GuardedF64::new(-1.5).unwrap()).unwrap();
+/// assert_eq!(temperature.to_string(), "19");
+///
+/// assert_eq!(temperature.add(GuardedF64::MAX), Ok(()));
+/// assert_eq!(temperature.add(GuardedF64::MAX), Err(FloatError::Infinity));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Gauge(GuardedF64);
+
+impl Gauge {
+    /// Creates a gauge with the given value.
+    #[must_use]
+    pub const fn new(value: GuardedF64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the current value of the gauge.
+    #[must_use]
+    pub const fn get(self) -> GuardedF64 {
+        self.0
+    }
+
+    /// Sets the value of the gauge.
+    pub const fn set(&mut self, value: GuardedF64) {
+        self.0 = value;
+    }
+
+    /// Adds `amount`, which may be negative, to the gauge.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the result is too large to represent, leaving the gauge
+    /// unchanged.
+    pub fn add(&mut self, amount: GuardedF64) -> Result<(), FloatError> {
+        self.0 = (self.0 + amount).check_reported()?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Gauge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        SampleValue(self.0.0).fmt(f)
+    }
+}
+
+/// Displays a finite value in the format of Go's `strconv.FormatFloat(value, 'g', -1, 64)`.
+#[derive(Clone, Copy)]
+struct SampleValue(f64);
+
+impl fmt::Display for SampleValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Exponent formatting writes the shortest round-trip digits, with the shape `-d.ddde-x`.
+        let scientific = format!("{:e}", self.0);
+        let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+        let exponent = exponent.parse::<i32>().unwrap_or(0);
+
+        if (-4..6).contains(&exponent) {
+            write!(f, "{}", self.0)
+        } else {
+            let sign = if exponent < 0 { '-' } else { '+' };
+            write!(f, "{mantissa}e{sign}{:02}", exponent.unsigned_abs())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_prometheus_value_format() {
+        let cases = [
+            (0.0, "0"),
+            (-0.0, "-0"),
+            (100_000.0, "100000"),
+            (1_000_000.0, "1e+06"),
+            (0.0001, "0.0001"),
+            (0.000_012_5, "1.25e-05"),
+            (f64::MAX, "1.7976931348623157e+308"),
+            (5e-324, "5e-324"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(GuardedF64(value).to_prometheus_value(), expected);
+        }
+    }
+
+    #[test]
+    fn test_counter_overflow() {
+        let mut counter = Counter::new();
+        counter.inc_by(GuardedF64::MAX).unwrap();
+        assert_eq!(counter.inc_by(GuardedF64::MAX), Err(FloatError::Infinity));
+        assert_eq!(counter.get(), GuardedF64::MAX);
+    }
+
+    proptest! {
+        #[test]
+        fn test_prometheus_value_round_trip(a in valid_f64()) {
+            let formatted = GuardedF64(a).to_prometheus_value();
+            prop_assert!(!formatted.contains("NaN") && !formatted.contains("inf"));
+            prop_assert_eq!(formatted.parse::<f64>().unwrap().to_bits(), a.to_bits());
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Detection of the encoding of a number at the start of a byte slice.
+
+/// The encoding of a number at the start of a byte slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// A hex-float, like `0x1.8p3`.
+    Hex,
+
+    /// A decimal number of the given length in bytes, like `-12.5e3`.
+    Decimal(usize),
+
+    /// Anything else, which is read as a little-endian binary float.
+    Binary,
+}
+
+/// Detects the encoding of the number at the start of `bytes`.
+///
+/// Text is recognized by its leading characters: an optional sign followed by `0x` and a hex digit
+/// or point, a decimal digit, or a point and a decimal digit. Bytes that do not start like a
+/// number are assumed to be binary.
+pub fn detect(bytes: &[u8]) -> Encoding {
+    let unsigned = bytes
+        .strip_prefix(b"+")
+        .or_else(|| bytes.strip_prefix(b"-"));
+    let unsigned = unsigned.unwrap_or(bytes);
+
+    if matches!(unsigned, [b'0', b'x' | b'X', next, ..] if next.is_ascii_hexdigit() || *next == b'.')
+    {
+        return Encoding::Hex;
+    }
+
+    decimal_prefix(bytes).map_or(Encoding::Binary, Encoding::Decimal)
+}
+
+/// Returns the length of the decimal number at the start of `bytes`, or `None` if there is none.
+///
+/// The number has an optional sign, digits with an optional point, and an optional exponent like
+/// `e-3`. An exponent marker that is not followed by digits is not part of the number.
+fn decimal_prefix(bytes: &[u8]) -> Option<usize> {
+    let digits_from = |start: usize| {
+        bytes[start.min(bytes.len())..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    };
+
+    let mut index = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let whole = digits_from(index);
+    index += whole;
+
+    let mut fraction = 0;
+    if bytes.get(index) == Some(&b'.') {
+        fraction = digits_from(index + 1);
+        if whole + fraction > 0 {
+            index += 1 + fraction;
+        }
+    }
+
+    if whole + fraction == 0 {
+        return None;
+    }
+
+    if matches!(bytes.get(index), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(index + 1), Some(b'+' | b'-')));
+        let exponent = digits_from(index + 1 + sign);
+        if exponent > 0 {
+            index += 1 + sign + exponent;
+        }
+    }
+
+    Some(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(detect(b"0x1.8p3"), Encoding::Hex);
+        assert_eq!(detect(b"-0X.8"), Encoding::Hex);
+        assert_eq!(detect(b"0xg"), Encoding::Decimal(1));
+        assert_eq!(detect(b"12.5e3,"), Encoding::Decimal(6));
+        assert_eq!(detect(b"-.5e"), Encoding::Decimal(3));
+        assert_eq!(detect(b"1.e+2x"), Encoding::Decimal(5));
+        assert_eq!(detect(b"+."), Encoding::Binary);
+        assert_eq!(detect(&1.5f64.to_le_bytes()), Encoding::Binary);
+        assert_eq!(detect(b""), Encoding::Binary);
+    }
+}
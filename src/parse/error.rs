@@ -0,0 +1,43 @@
+use crate::FloatError;
+
+/// An error occurred while parsing a guarded value from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// Indicates that the string is not a number.
+    Malformed,
+
+    /// Indicates that the decimal exponent of the number is beyond the limit in the
+    /// `ParseOptions`.
+    ExponentOutOfRange {
+        /// The decimal exponent of the number, as if it were written in normalized scientific
+        /// notation. This saturates for exponents that do not fit in an `i64`.
+        exponent: i64,
+    },
+
+    /// Indicates that the string is a number, but parses to NaN or infinity.
+    Invalid(FloatError),
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid(error) => Some(error),
+            Self::Malformed | Self::ExponentOutOfRange { .. } => None,
+        }
+    }
+}
+
+/// Implements the `Display` trait for the `ParseError` enum, providing a user-friendly description
+/// of the error.
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Malformed => write!(f, "The string is not a valid number"),
+            Self::ExponentOutOfRange { exponent } => write!(
+                f,
+                "The decimal exponent {exponent} is beyond the allowed limit"
+            ),
+            Self::Invalid(error) => write!(f, "The parsed value is invalid: {error}"),
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::GuardedF64;
+use std::cmp::Ordering;
+
+/// Whether a decimal number was converted to a float without rounding, as reported by
+/// `from_numeric_str`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::parse::Exactness;
+///
+/// let (value, exactness) = GuardedF64::from_numeric_str("2.375").unwrap();
+/// assert_eq!(value, 2.375);
+/// assert_eq!(exactness, Exactness::Exact);
+///
+/// let (value, exactness) = GuardedF64::from_numeric_str("0.1").unwrap();
+/// assert_eq!(value, 0.1);
+/// assert_eq!(exactness, Exactness::Rounded { error: GuardedF64::new(-5.551115123125783e-18).unwrap() });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exactness {
+    /// The float has exactly the value of the decimal number.
+    Exact,
+
+    /// The decimal number was rounded to the nearest float.
+    Rounded {
+        /// The decimal number minus the float, itself rounded to the nearest `f64`. This is zero if
+        /// the difference is too small to represent, as for numbers that underflow to zero.
+        error: GuardedF64,
+    },
+}
+
+impl Exactness {
+    /// Returns `true` if the conversion was exact.
+    #[must_use]
+    pub const fn is_exact(&self) -> bool {
+        matches!(self, Self::Exact)
+    }
+
+    /// Compares the decimal number in `s` to `value`, the finite float that it was parsed to.
+    pub(crate) fn of(s: &str, value: f64) -> Self {
+        let Some(decimal) = Decimal::parse(s) else {
+            return Self::Exact;
+        };
+
+        if decimal.digits.is_empty() {
+            return Self::Exact;
+        }
+
+        // The number underflowed, so the error is the number itself.
+        if value == 0.0 {
+            let error = s.parse::<f64>().unwrap_or(value);
+            return Self::Rounded {
+                error: GuardedF64(error),
+            };
+        }
+
+        // With enough digits, formatting is exact, and no finite `f64` has more than 767
+        // significant digits.
+        let Some(exact) = Decimal::parse(&format!("{:.767e}", value.abs())) else {
+            return Self::Exact;
+        };
+
+        let (difference, below) = match decimal.cmp_magnitude(&exact) {
+            Ordering::Equal => return Self::Exact,
+            Ordering::Less => (exact.abs_diff(&decimal), true),
+            Ordering::Greater => (decimal.abs_diff(&exact), false),
+        };
+
+        let error = difference.to_f64();
+        let negative = below != value.is_sign_negative();
+        Self::Rounded {
+            error: GuardedF64(if negative { -error } else { error }),
+        }
+    }
+}
+
+/// The magnitude of a decimal number, as its significant digits without leading or trailing zeros
+/// and the exponent of the last digit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Decimal {
+    digits: Vec<u8>,
+    exponent: i64,
+}
+
+impl Decimal {
+    /// Parses the magnitude of a decimal number, or returns `None` if `s` is not one.
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let (mantissa, exponent) = s.split_once(['e', 'E']).unwrap_or((s, "0"));
+        let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        let mut digits = whole
+            .bytes()
+            .chain(fraction.bytes())
+            .map(|b| b.is_ascii_digit().then(|| b - b'0'))
+            .collect::<Option<Vec<_>>>()?;
+
+        // Exponents that do not fit in an `i64` only appear in numbers that are zero, or that
+        // overflow or underflow.
+        let exponent = exponent.parse::<i64>().unwrap_or_else(|_| {
+            if exponent.starts_with('-') {
+                i64::MIN
+            } else {
+                i64::MAX
+            }
+        });
+
+        let trailing = digits.iter().rev().take_while(|&&digit| digit == 0).count();
+        digits.truncate(digits.len() - trailing);
+        let leading = digits.iter().take_while(|&&digit| digit == 0).count();
+        digits.drain(..leading);
+
+        let exponent = if digits.is_empty() {
+            0
+        } else {
+            exponent
+                .saturating_sub(i64::try_from(fraction.len()).ok()?)
+                .saturating_add(i64::try_from(trailing).ok()?)
+        };
+
+        Some(Self { digits, exponent })
+    }
+
+    /// Returns the digits of both numbers, scaled to the same exponent and padded to the same
+    /// length.
+    fn align(&self, other: &Self) -> (Vec<u8>, Vec<u8>, i64) {
+        let exponent = self.exponent.min(other.exponent);
+        let scale = |decimal: &Self| {
+            let zeros =
+                usize::try_from(decimal.exponent.saturating_sub(exponent)).unwrap_or(usize::MAX);
+            let mut digits = decimal.digits.clone();
+            digits.resize(digits.len().saturating_add(zeros), 0);
+            digits
+        };
+
+        let (mut left, mut right) = (scale(self), scale(other));
+        let len = left.len().max(right.len());
+        for digits in [&mut left, &mut right] {
+            digits.splice(..0, std::iter::repeat_n(0, len - digits.len()));
+        }
+
+        (left, right, exponent)
+    }
+
+    /// Compares the magnitudes of two numbers.
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+
+        let (left, right, _) = self.align(other);
+        left.cmp(&right)
+    }
+
+    /// Returns `self - other`, where `self` is the larger number.
+    fn abs_diff(&self, other: &Self) -> Self {
+        let (mut digits, right, exponent) = self.align(other);
+
+        let mut borrow = 0;
+        for (digit, subtrahend) in digits.iter_mut().zip(right).rev() {
+            let subtrahend = subtrahend + borrow;
+            borrow = u8::from(*digit < subtrahend);
+            *digit = *digit + 10 * borrow - subtrahend;
+        }
+
+        Self { digits, exponent }
+    }
+
+    /// Returns the number rounded to the nearest `f64`.
+    fn to_f64(&self) -> f64 {
+        let digits = self
+            .digits
+            .iter()
+            .map(|&digit| char::from(b'0' + digit))
+            .collect::<String>();
+
+        format!("{digits}e{}", self.exponent).parse().unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn rounded(error: f64) -> Exactness {
+        Exactness::Rounded {
+            error: GuardedF64(error),
+        }
+    }
+
+    #[test]
+    fn test_of() {
+        assert_eq!(Exactness::of("-0.00", -0.0), Exactness::Exact);
+        assert_eq!(
+            Exactness::of("0e99999999999999999999", 0.0),
+            Exactness::Exact
+        );
+        assert_eq!(Exactness::of("123.5000", 123.5), Exactness::Exact);
+        assert_eq!(Exactness::of("+1.5E3", 1500.0), Exactness::Exact);
+        assert_eq!(
+            Exactness::of("-0.1", -0.1),
+            rounded(5.551_115_123_125_783e-18)
+        );
+        assert_eq!(
+            Exactness::of("9007199254740993", 9_007_199_254_740_992.0),
+            rounded(1.0)
+        );
+        assert_eq!(Exactness::of("1e-400", 0.0), rounded(0.0));
+        assert_eq!(Exactness::of("-1e-50", -0.0), rounded(-1e-50));
+    }
+
+    #[test]
+    fn test_decimal() {
+        let decimal = Decimal::parse("0012.3400e-1").unwrap();
+        assert_eq!(decimal.digits, [1, 2, 3, 4]);
+        assert_eq!(decimal.exponent, -3);
+        assert_eq!(Decimal::parse("1x"), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_exact_digits_are_exact(a in valid_f64()) {
+            prop_assert_eq!(Exactness::of(&format!("{a:.767e}"), a), Exactness::Exact);
+        }
+
+        #[test]
+        fn test_error_is_within_half_ulp(mantissa in any::<u64>(), exponent in -330i32..280) {
+            let s = format!("{mantissa}e{exponent}");
+            let value = s.parse::<f64>().unwrap();
+
+            if let Exactness::Rounded { error } = Exactness::of(&s, value) {
+                let ulp = f64::from_bits(value.abs().to_bits() + 1) - value.abs();
+                prop_assert!(error.0.abs() <= ulp / 2.0);
+            }
+
+            let exact = mantissa == 0 || 64 - mantissa.leading_zeros() - mantissa.trailing_zeros() <= 53;
+            if exponent == 0 {
+                prop_assert_eq!(Exactness::of(&s, value).is_exact(), exact);
+            }
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! Parsing of hexadecimal floating-point numbers, like `0x1.8p3`.
+//!
+//! Hex-floats describe the binary value of a float exactly, so a number with at most as many
+//! significant bits as the target type is parsed without rounding. Longer numbers are rounded to the
+//! nearest value, with ties rounded to even, like decimal parsing.
+
+/// The layout of an IEEE 754 binary floating-point type.
+#[derive(Debug, Clone, Copy)]
+pub struct Format {
+    /// The total number of bits.
+    bits: u32,
+
+    /// The number of significant bits, including the implicit leading bit.
+    significand_bits: u32,
+
+    /// The largest unbiased exponent of a finite value, which is also the exponent bias.
+    max_exponent: i64,
+}
+
+impl Format {
+    /// The layout of `f64`.
+    pub const F64: Self = Self {
+        bits: 64,
+        significand_bits: 53,
+        max_exponent: 1023,
+    };
+
+    /// The layout of `f32`.
+    pub const F32: Self = Self {
+        bits: 32,
+        significand_bits: 24,
+        max_exponent: 127,
+    };
+
+    /// Converts the bits of a value in this format to an `f64`, which is always exact.
+    pub fn widen(self, bits: u64) -> f64 {
+        if self.bits == 32 {
+            #[allow(clippy::cast_possible_truncation)]
+            f64::from(f32::from_bits(bits as u32))
+        } else {
+            f64::from_bits(bits)
+        }
+    }
+}
+
+/// Formats `value` as an exact hex-float in the style of C's `%a`, like `0x1.8p+3`.
+///
+/// Normal values have a leading `1`, subnormal values have a leading `0` and an exponent of
+/// `-1022`, and trailing zeros of the fraction are omitted. Non-finite values are formatted as
+/// `NaN`, `inf`, or `-inf`.
+pub fn format_hex(value: f64) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+
+    let bits = value.to_bits();
+    let sign = if value.is_sign_negative() { "-" } else { "" };
+    let biased = (bits >> 52) & 0x7ff;
+    let fraction = bits & ((1 << 52) - 1);
+
+    let (leading, exponent) = match (biased, fraction) {
+        (0, 0) => (0, 0),
+        (0, _) => (0, -1022),
+        #[allow(clippy::cast_possible_wrap)]
+        _ => (1, biased as i64 - 1023),
+    };
+
+    let digits = format!("{fraction:013x}");
+    let digits = digits.trim_end_matches('0');
+    let point = if digits.is_empty() { "" } else { "." };
+    format!("{sign}0x{leading}{point}{digits}p{exponent:+}")
+}
+
+/// Returns the value of an ASCII hexadecimal digit.
+fn hex_digit(byte: u8) -> Option<u64> {
+    char::from(byte).to_digit(16).map(u64::from)
+}
+
+/// Parses the hex-float at the start of `bytes`, and returns the bits of the nearest value in
+/// `format` with the number of bytes consumed.
+///
+/// The number has an optional sign, a `0x` or `0X` prefix, hexadecimal digits with an optional
+/// point, and an optional binary exponent like `p-3`. Returns `None` if `bytes` does not start with
+/// a hex-float. A value too large for `format` returns the bits of infinity.
+pub fn parse_hex_prefix(bytes: &[u8], format: Format) -> Option<(u64, usize)> {
+    let mut index = 0;
+    let negative = match bytes.first() {
+        Some(b'-') => {
+            index += 1;
+            true
+        }
+        Some(b'+') => {
+            index += 1;
+            false
+        }
+        _ => false,
+    };
+
+    if !matches!(bytes.get(index..index + 2), Some(b"0x" | b"0X")) {
+        return None;
+    }
+    index += 2;
+
+    let mut mantissa = 0u64;
+    let mut sticky = false;
+    let mut exponent = 0i64;
+    let mut digits = 0;
+    let mut fraction = false;
+
+    loop {
+        match bytes.get(index) {
+            Some(b'.') if !fraction => fraction = true,
+            Some(&byte) => {
+                let Some(digit) = hex_digit(byte) else { break };
+                digits += 1;
+
+                // Digits beyond the 60 bits that fit in the mantissa only affect rounding.
+                if mantissa >> 60 == 0 {
+                    mantissa = (mantissa << 4) | digit;
+                    if fraction {
+                        exponent = exponent.saturating_sub(4);
+                    }
+                } else {
+                    sticky |= digit != 0;
+                    if !fraction {
+                        exponent = exponent.saturating_add(4);
+                    }
+                }
+            }
+            None => break,
+        }
+        index += 1;
+    }
+
+    if digits == 0 {
+        return None;
+    }
+
+    if let Some((binary_exponent, length)) = parse_binary_exponent(&bytes[index..]) {
+        exponent = exponent.saturating_add(binary_exponent);
+        index += length;
+    }
+
+    Some((compose(negative, mantissa, sticky, exponent, format), index))
+}
+
+/// Parses an exponent like `p-3` at the start of `bytes`, and returns it with the number of bytes
+/// consumed. Exponents too large to matter saturate.
+fn parse_binary_exponent(bytes: &[u8]) -> Option<(i64, usize)> {
+    if !matches!(bytes.first(), Some(b'p' | b'P')) {
+        return None;
+    }
+
+    let mut index = 1;
+    let negative = match bytes.get(index) {
+        Some(b'-') => {
+            index += 1;
+            true
+        }
+        Some(b'+') => {
+            index += 1;
+            false
+        }
+        _ => false,
+    };
+
+    let start = index;
+    let mut exponent = 0i64;
+    while let Some(digit) = bytes.get(index).filter(|byte| byte.is_ascii_digit()) {
+        exponent = exponent
+            .saturating_mul(10)
+            .saturating_add(i64::from(digit - b'0'));
+        index += 1;
+    }
+
+    if index == start {
+        return None;
+    }
+
+    // Far beyond the range of any format, but small enough that adding it cannot overflow.
+    let exponent = exponent.min(1 << 40);
+    Some((if negative { -exponent } else { exponent }, index))
+}
+
+/// Returns the bits of the value in `format` nearest to `mantissa * 2^exponent`, where `sticky`
+/// indicates that non-zero bits were dropped below the mantissa.
+pub fn compose(negative: bool, mantissa: u64, sticky: bool, exponent: i64, format: Format) -> u64 {
+    let Format {
+        bits,
+        significand_bits,
+        max_exponent,
+    } = format;
+    let fraction_bits = significand_bits - 1;
+    let sign = u64::from(negative) << (bits - 1);
+    #[allow(clippy::cast_sign_loss)]
+    let infinity = sign | (((max_exponent * 2 + 1) as u64) << fraction_bits);
+
+    if mantissa == 0 {
+        return sign;
+    }
+
+    // Normalize to `mantissa * 2^exponent` with the mantissa in `[2^63, 2^64)`, so that the value is
+    // `1.xxx * 2^unbiased`.
+    let leading_zeros = mantissa.leading_zeros();
+    let mantissa = u128::from(mantissa << leading_zeros);
+    let mut unbiased = exponent
+        .saturating_sub(i64::from(leading_zeros))
+        .saturating_add(63);
+
+    if unbiased > max_exponent {
+        return infinity;
+    }
+
+    // Subnormal values have fewer significant bits the smaller they are.
+    let min_exponent = 1 - max_exponent;
+    let kept_bits = if unbiased >= min_exponent {
+        i64::from(significand_bits)
+    } else {
+        i64::from(significand_bits) - (min_exponent - unbiased)
+    };
+
+    let shift = 64 - kept_bits;
+    if shift > 127 {
+        return sign;
+    }
+
+    let mut kept = mantissa >> shift;
+    let remainder = mantissa & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || remainder == half && (sticky || kept & 1 == 1) {
+        kept += 1;
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let bits = if unbiased >= min_exponent {
+        if kept == 1 << significand_bits {
+            kept >>= 1;
+            unbiased += 1;
+            if unbiased > max_exponent {
+                return infinity;
+            }
+        }
+
+        (((unbiased + max_exponent) as u64) << fraction_bits)
+            | (kept as u64 & ((1 << fraction_bits) - 1))
+    } else {
+        // Rounding up to the smallest normal value sets the lowest exponent bit, as it should.
+        kept as u64
+    };
+
+    sign | bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn parse_f64(s: &str) -> Option<(f64, usize)> {
+        parse_hex_prefix(s.as_bytes(), Format::F64).map(|(bits, len)| (f64::from_bits(bits), len))
+    }
+
+    #[test]
+    fn test_format_hex() {
+        assert_eq!(format_hex(12.0), "0x1.8p+3");
+        assert_eq!(format_hex(-0.1), "-0x1.999999999999ap-4");
+        assert_eq!(format_hex(0.0), "0x0p+0");
+        assert_eq!(format_hex(-0.0), "-0x0p+0");
+        assert_eq!(format_hex(f64::from_bits(1)), "0x0.0000000000001p-1022");
+        assert_eq!(format_hex(f64::MAX), "0x1.fffffffffffffp+1023");
+        assert_eq!(format_hex(f64::NEG_INFINITY), "-inf");
+    }
+
+    #[test]
+    fn test_parse_hex_prefix() {
+        assert_eq!(parse_f64("0x1.8p3"), Some((12.0, 7)));
+        assert_eq!(parse_f64("-0X.8P+1;"), Some((-1.0, 8)));
+        assert_eq!(parse_f64("0x10p"), Some((16.0, 4)));
+        assert_eq!(parse_f64("0x.p1"), None);
+        assert_eq!(parse_f64("1.0"), None);
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(parse_f64("0x1p-1074"), Some((f64::from_bits(1), 9)));
+        assert_eq!(parse_f64("0x1p-1075").map(|v| v.0.to_bits()), Some(0));
+        assert_eq!(
+            parse_f64("0x1.000000001p-1075").map(|v| v.0.to_bits()),
+            Some(1)
+        );
+        assert_eq!(parse_f64("0x3p-1075").map(|v| v.0.to_bits()), Some(2));
+        assert_eq!(
+            parse_f64("0x1.fffffffffffff7p1023").map(|v| v.0),
+            Some(f64::MAX)
+        );
+        assert_eq!(
+            parse_f64("0x1.fffffffffffff8p1023").map(|v| v.0),
+            Some(f64::INFINITY)
+        );
+        assert_eq!(parse_f64("0x1.00000000000008p0").map(|v| v.0), Some(1.0));
+        assert_eq!(
+            parse_f64("0x1.000000000000080000000001p0").map(|v| v.0),
+            Some(1.0 + f64::EPSILON)
+        );
+        assert_eq!(
+            parse_hex_prefix(b"0x1.000001p0", Format::F32).map(|(bits, _)| bits),
+            Some(u64::from(1f32.to_bits()))
+        );
+        assert_eq!(
+            parse_hex_prefix(b"0x1p-149", Format::F32).map(|(bits, _)| bits),
+            Some(1)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in valid_f64()) {
+            let hex = format_hex(a);
+            prop_assert_eq!(parse_f64(&hex), Some((a, hex.len())));
+        }
+
+        #[test]
+        fn test_round_trip_subnormal(bits in 0..1u64 << 52, negative: bool) {
+            let a = f64::from_bits(bits | u64::from(negative) << 63);
+            let hex = format_hex(a);
+            prop_assert_eq!(parse_f64(&hex).map(|v| v.0.to_bits()), Some(a.to_bits()));
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Options for parsing guarded values from strings.
+//!
+//! `GuardedF64` and `GuardedF32` implement `FromStr`, which rejects strings that parse to NaN or
+//! infinity. `parse_with` additionally rejects numbers whose decimal exponent is beyond a limit,
+//! with a dedicated error, so out-of-range input such as `1e400` can be told apart from a
+//! legitimate value that overflows during a later computation. `from_numeric_str` reports, with
+//! an `Exactness`, whether a decimal number such as a database `NUMERIC` was rounded.
+//! `parse_percent` reads percentages from spreadsheets, like `12.5%` or `12,5 %`, as the fraction
+//! they stand for, and `PercentOptions` restricts the fraction to a range.
+mod detect;
+mod error;
+mod exact;
+mod hex;
+mod percent;
+
+pub(crate) use detect::{Encoding, detect};
+pub use error::ParseError;
+pub use exact::Exactness;
+pub(crate) use hex::{Format, compose, format_hex, parse_hex_prefix};
+pub use percent::PercentOptions;
+pub(crate) use percent::percent_to_decimal;
+
+/// Options that control how `parse_with` validates a number.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::parse::{ParseError, ParseOptions};
+///
+/// let options = ParseOptions::default().with_max_exponent(308);
+///
+/// assert_eq!(GuardedF64::parse_with("1.5e308", options), GuardedF64::new(1.5e308).map_err(ParseError::Invalid));
+/// assert_eq!(
+///     GuardedF64::parse_with("15e308", options),
+///     Err(ParseError::ExponentOutOfRange { exponent: 309 })
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    max_exponent: Option<u32>,
+}
+
+impl ParseOptions {
+    /// Returns the options with the largest allowed magnitude of the decimal exponent replaced.
+    ///
+    /// The exponent is measured as if the number were written in normalized scientific notation,
+    /// so `12345e305` and `0.001e312` both have an exponent of 309.
+    #[must_use]
+    pub const fn with_max_exponent(self, max_exponent: u32) -> Self {
+        Self {
+            max_exponent: Some(max_exponent),
+        }
+    }
+
+    /// Returns the largest allowed magnitude of the decimal exponent, if there is a limit.
+    #[must_use]
+    pub const fn max_exponent(&self) -> Option<u32> {
+        self.max_exponent
+    }
+
+    /// Parses `s` with `parse` after validating its decimal exponent.
+    pub(crate) fn parse<T>(
+        self,
+        s: &str,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<T, ParseError> {
+        match (self.max_exponent, decimal_exponent(s)) {
+            (Some(limit), Some(exponent)) if exponent.unsigned_abs() > u64::from(limit) => {
+                Err(ParseError::ExponentOutOfRange { exponent })
+            }
+            _ => parse(s).ok_or(ParseError::Malformed),
+        }
+    }
+}
+
+/// Returns the exponent of the first significant digit of a decimal number, or `None` if `s` is
+/// not a decimal number or is zero.
+fn decimal_exponent(s: &str) -> Option<i64> {
+    let s = s.strip_prefix(['+', '-']).unwrap_or(s);
+    let (mantissa, exponent) = s.split_once(['e', 'E']).unwrap_or((s, "0"));
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() && fraction.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+
+    let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+    if digits.is_empty() || !is_digits(digits) {
+        return None;
+    }
+
+    // Exponents that do not fit in an `i64` are far beyond any limit.
+    let exponent = exponent.parse::<i64>().unwrap_or_else(|_| {
+        if exponent.starts_with('-') {
+            i64::MIN
+        } else {
+            i64::MAX
+        }
+    });
+
+    let leading = if let Some(index) = whole.bytes().position(|b| b != b'0') {
+        i64::try_from(whole.len() - index - 1).ok()?
+    } else {
+        let index = fraction.bytes().position(|b| b != b'0')?;
+        -i64::try_from(index + 1).ok()?
+    };
+
+    Some(exponent.saturating_add(leading))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_decimal_exponent() {
+        assert_eq!(decimal_exponent("12345e305"), Some(309));
+        assert_eq!(decimal_exponent("0.001e312"), Some(309));
+        assert_eq!(decimal_exponent("-.5"), Some(-1));
+        assert_eq!(decimal_exponent("+7."), Some(0));
+        assert_eq!(decimal_exponent("1e-99999999999999999999"), Some(i64::MIN));
+        assert_eq!(decimal_exponent("0.000"), None);
+        assert_eq!(decimal_exponent("."), None);
+        assert_eq!(decimal_exponent("1e"), None);
+        assert_eq!(decimal_exponent("inf"), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_scientific(a in any::<f64>().prop_filter("Reject zero and non-finite values", |a| a.is_finite() && *a != 0.0)) {
+            let scientific = format!("{a:e}");
+            let (_, exponent) = scientific.split_once('e').unwrap();
+            prop_assert_eq!(decimal_exponent(&scientific), exponent.parse().ok());
+            prop_assert_eq!(decimal_exponent(&a.to_string()), exponent.parse().ok());
+        }
+    }
+}
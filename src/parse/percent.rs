@@ -0,0 +1,143 @@
+use super::ParseError;
+use crate::FloatError;
+
+/// Options that control how `parse_percent_with` validates a percentage.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::parse::{ParseError, PercentOptions};
+///
+/// let options = PercentOptions::default().with_range(0.0, 1.0);
+///
+/// assert_eq!(GuardedF64::parse_percent_with("100%", options), GuardedF64::new(1.0).map_err(ParseError::Invalid));
+/// assert_eq!(GuardedF64::parse_percent_with("120%", options), Err(ParseError::Invalid(FloatError::Domain)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PercentOptions {
+    range: Option<(f64, f64)>,
+}
+
+impl PercentOptions {
+    /// Returns the options with the allowed range of the fractional value replaced.
+    ///
+    /// The bounds are inclusive and apply to the fraction, not the percentage, so `(0.0, 1.0)`
+    /// allows `0%` through `100%`. No value is within a range that has a NaN bound.
+    #[must_use]
+    pub const fn with_range(self, min: f64, max: f64) -> Self {
+        Self {
+            range: Some((min, max)),
+        }
+    }
+
+    /// Returns the allowed range of the fractional value, if there is one.
+    #[must_use]
+    pub const fn range(&self) -> Option<(f64, f64)> {
+        self.range
+    }
+
+    /// Checks that the fractional value is within the allowed range.
+    pub(crate) fn check(self, value: f64) -> Result<(), ParseError> {
+        match self.range {
+            Some((min, max)) if !(min..=max).contains(&value) => {
+                Err(ParseError::Invalid(FloatError::Domain))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Rewrites a percentage, like `12,5 %`, as the decimal number of the fraction it stands for, like
+/// `12.5e-2`, or returns `None` if `s` is not a percentage.
+///
+/// The percent sign may come before or after the number, separated by whitespace. When both a
+/// comma and a point appear, the last one is the decimal separator and the other one groups
+/// thousands; a lone comma is a decimal comma, and repeated commas group thousands. Exponents,
+/// infinities, and NaN are not accepted.
+pub fn percent_to_decimal(s: &str) -> Option<String> {
+    let s = s.trim();
+    let number = s.strip_suffix('%').or_else(|| s.strip_prefix('%'))?.trim();
+    let (sign, digits) = number.strip_prefix('-').map_or_else(
+        || ("", number.strip_prefix('+').unwrap_or(number)),
+        |digits| ("-", digits),
+    );
+
+    let (decimal, grouping) = match (digits.rfind('.'), digits.rfind(',')) {
+        (Some(point), Some(comma)) if comma > point => (',', '.'),
+        (None, Some(_)) if digits.matches(',').count() == 1 => (',', '.'),
+        _ => ('.', ','),
+    };
+    let (whole, fraction) = digits.rsplit_once(decimal).unwrap_or((digits, ""));
+
+    // Every group of thousands after the first has exactly three digits.
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    let groups: Vec<&str> = whole.split(grouping).collect();
+    let grouped = groups.iter().enumerate().all(|(index, group)| {
+        is_digits(group)
+            && match (index, groups.len()) {
+                (_, 1) => true,
+                (0, _) => (1..=3).contains(&group.len()),
+                _ => group.len() == 3,
+            }
+    });
+    if !grouped || !is_digits(fraction) || whole.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    Some(format!("{sign}{}.{fraction}e-2", groups.concat()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_percent_to_decimal() {
+        for (s, expected) in [
+            ("12.5%", "12.5e-2"),
+            ("12,5 %", "12.5e-2"),
+            ("%-12,5", "-12.5e-2"),
+            ("+.5%", ".5e-2"),
+            ("7.%", "7.e-2"),
+            ("1,234,567.89%", "1234567.89e-2"),
+            ("1.234.567,89%", "1234567.89e-2"),
+            ("1,234%", "1.234e-2"),
+            ("1,234,567%", "1234567.e-2"),
+            ("\u{a0}50\u{202f}%\u{a0}", "50.e-2"),
+        ] {
+            assert_eq!(percent_to_decimal(s).as_deref(), Some(expected), "{s}");
+        }
+
+        for s in [
+            "",
+            "%",
+            "12.5",
+            "12.5%%",
+            ".%",
+            "1.2.3%",
+            "1,23,456%",
+            "12,345.6,7%",
+            "1e2%",
+            "NaN%",
+            "inf%",
+            "--1%",
+            "1 000%",
+        ] {
+            assert_eq!(percent_to_decimal(s), None, "{s}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(value in -1e6..1e6f64) {
+            let decimal = percent_to_decimal(&format!("{value}%")).unwrap();
+            let parsed = decimal.parse::<f64>().unwrap();
+            prop_assert!((parsed - value / 100.0).abs() <= (value / 100.0).abs() * f64::EPSILON);
+
+            let comma = percent_to_decimal(&format!("{value}%").replace('.', ","));
+            prop_assert_eq!(comma, Some(decimal));
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! This module provides the `Pid` type, a proportional-integral-derivative controller whose state
+//! is always valid.
+use crate::decay::smoothing_alpha;
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// A proportional-integral-derivative controller.
+///
+/// Each call to `update` takes the current error, the setpoint minus the measurement, and returns
+/// the control output `kp * error + integral + kd * derivative`. The integral term accumulates
+/// `ki * error * dt`, and is clamped to the integral limit so that it cannot wind up while the
+/// output is saturated. The derivative term can be smoothed with a first-order low-pass filter,
+/// because differentiating a noisy error amplifies the noise. The first update has no previous
+/// error to differentiate, so its derivative is zero instead of a spike.
+///
+/// An update that fails leaves the controller unchanged, so a single invalid measurement cannot
+/// poison the state of later updates.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64, Pid};
+///
+/// let [kp, ki, kd] = [2.0, 0.5, 0.0].map(|v| GuardedF64::new(v).unwrap());
+/// let mut pid = Pid::new(kp, ki, kd).with_integral_limit(GuardedF64::new(1.0).unwrap());
+///
+/// let dt = GuardedF64::new(0.1).unwrap();
+/// assert_eq!(pid.update(1.0, dt), GuardedF64::new(2.05));
+///
+/// // The integral term stops growing at its limit.
+/// for _ in 0..100 {
+///     pid.update(1.0, dt).unwrap();
+/// }
+/// assert_eq!(pid.integral(), 1.0);
+///
+/// assert_eq!(pid.update(f64::NAN, dt), Err(FloatError::NaN));
+/// assert_eq!(pid.update(1.0, -dt), Err(FloatError::Domain));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pid {
+    kp: GuardedF64,
+    ki: GuardedF64,
+    kd: GuardedF64,
+    integral_limit: GuardedF64,
+    derivative_filter: GuardedF64,
+    integral: GuardedF64,
+    derivative: GuardedF64,
+    previous_error: Option<GuardedF64>,
+}
+
+impl Pid {
+    /// Creates a controller with the proportional, integral, and derivative gains `kp`, `ki`, and
+    /// `kd`, no integral limit, and no derivative filter.
+    #[must_use]
+    pub const fn new(kp: GuardedF64, ki: GuardedF64, kd: GuardedF64) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            integral_limit: GuardedF64::MAX,
+            derivative_filter: GuardedF64(0.0),
+            integral: GuardedF64(0.0),
+            derivative: GuardedF64(0.0),
+            previous_error: None,
+        }
+    }
+
+    /// Returns the controller with the integral term clamped to `[-limit, limit]`. The sign of
+    /// `limit` is ignored.
+    #[must_use]
+    pub const fn with_integral_limit(self, limit: GuardedF64) -> Self {
+        Self {
+            integral_limit: GuardedF64(limit.0.abs()),
+            ..self
+        }
+    }
+
+    /// Returns the controller with the derivative smoothed by a low-pass filter with the time
+    /// constant `tau`. A `tau` of zero or less disables the filter.
+    #[must_use]
+    pub const fn with_derivative_filter(self, tau: GuardedF64) -> Self {
+        Self {
+            derivative_filter: tau,
+            ..self
+        }
+    }
+
+    /// Returns the current integral term, which already includes the gain `ki`.
+    #[must_use]
+    pub const fn integral(&self) -> GuardedF64 {
+        self.integral
+    }
+
+    /// Returns the current, filtered derivative of the error.
+    #[must_use]
+    pub const fn derivative(&self) -> GuardedF64 {
+        self.derivative
+    }
+
+    /// Clears the integral term, the derivative, and the previous error, but keeps the gains.
+    pub const fn reset(&mut self) {
+        self.integral = GuardedF64(0.0);
+        self.derivative = GuardedF64(0.0);
+        self.previous_error = None;
+    }
+
+    /// Advances the controller by `dt` with the current `error`, and returns the control output.
+    ///
+    /// If the integral term overflows, it saturates at the integral limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `error` if it is NaN or infinite, `FloatError::Domain` if `dt` is not
+    /// strictly positive, or `FloatError::Infinity` if the derivative or the output is too large to
+    /// represent. The controller is not changed when an error is returned.
+    pub fn update(
+        &mut self,
+        error: impl Into<UnguardedF64>,
+        dt: GuardedF64,
+    ) -> Result<GuardedF64, FloatError> {
+        let error = error.into().check_reported()?;
+        UnguardedF64::from(dt).check_where(|dt| dt > 0.0, FloatError::Domain)?;
+
+        let limit = self.integral_limit;
+        let integral = (self.integral + self.ki * error * dt)
+            .check_clamped()?
+            .clamp(-limit, limit);
+
+        let derivative = match self.previous_error {
+            Some(previous) => {
+                let raw = ((error - previous) / dt).check_reported()?;
+                let alpha = if self.derivative_filter > 0.0 {
+                    smoothing_alpha(dt, self.derivative_filter)?
+                } else {
+                    GuardedF64(1.0)
+                };
+                (self.derivative + alpha * (raw - self.derivative)).check_reported()?
+            }
+            None => GuardedF64(0.0),
+        };
+
+        let output = (self.kp * error + integral + self.kd * derivative).check_reported()?;
+
+        self.integral = integral;
+        self.derivative = derivative;
+        self.previous_error = Some(error);
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_derivative() {
+        let zero = GuardedF64(0.0);
+        let dt = GuardedF64(0.5);
+        let mut pid = Pid::new(zero, zero, GuardedF64(1.0));
+
+        assert_eq!(pid.update(1.0, dt), Ok(zero));
+        assert_eq!(pid.update(2.0, dt), Ok(GuardedF64(2.0)));
+
+        let mut filtered = Pid::new(zero, zero, GuardedF64(1.0)).with_derivative_filter(dt);
+        filtered.update(1.0, dt).unwrap();
+        let output = filtered.update(2.0, dt).unwrap();
+        assert!(output > 0.0 && output < 2.0);
+
+        filtered.reset();
+        assert_eq!(filtered.update(5.0, dt), Ok(zero));
+    }
+
+    #[test]
+    fn test_errors_keep_state() {
+        let one = GuardedF64(1.0);
+        let mut pid = Pid::new(one, one, one);
+        pid.update(1.0, one).unwrap();
+        let before = pid;
+
+        assert_eq!(pid.update(f64::INFINITY, one), Err(FloatError::Infinity));
+        assert_eq!(
+            pid.update(f64::MAX, GuardedF64(f64::MIN_POSITIVE)),
+            Err(FloatError::Infinity)
+        );
+        assert_eq!(pid.update(1.0, GuardedF64(0.0)), Err(FloatError::Domain));
+        assert_eq!(pid, before);
+    }
+
+    proptest! {
+        #[test]
+        fn test_integral_is_bounded(
+            errors in prop::collection::vec(-1e300..1e300f64, 1..32),
+            limit in 0.0..1e6f64,
+            dt in 1e-3..1e3f64,
+        ) {
+            let one = GuardedF64(1.0);
+            let mut pid = Pid::new(GuardedF64(0.0), one, GuardedF64(0.0)).with_integral_limit(GuardedF64(-limit));
+            for error in errors {
+                let output = pid.update(error, GuardedF64(dt)).unwrap();
+                prop_assert_eq!(output, pid.integral());
+                prop_assert!(pid.integral().abs() <= limit);
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::FloatError;
+
+/// An error occurred while running a `Pipeline`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineError {
+    /// Indicates that the input of the pipeline was invalid.
+    Input(FloatError),
+
+    /// Indicates that a stage produced an invalid value.
+    Stage {
+        /// The zero-based index of the stage, in the order the stages were added.
+        index: usize,
+
+        /// The reason the value produced by the stage is invalid.
+        error: FloatError,
+    },
+}
+
+impl PipelineError {
+    /// Returns the reason the failing value is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::FloatError;
+    /// use floatguard::pipeline::PipelineError;
+    ///
+    /// let error = PipelineError::Stage { index: 2, error: FloatError::NaN };
+    /// assert_eq!(error.float_error(), FloatError::NaN);
+    /// ```
+    #[must_use]
+    pub const fn float_error(self) -> FloatError {
+        match self {
+            Self::Input(error) | Self::Stage { error, .. } => error,
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Input(error) | Self::Stage { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Implements the `Display` trait for the `PipelineError` enum, providing a user-friendly
+/// description of the error.
+impl std::fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Input(error) => write!(f, "The pipeline input is invalid: {error}"),
+            Self::Stage { index, error } => {
+                write!(
+                    f,
+                    "Stage {index} of the pipeline produced an invalid value: {error}"
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! A builder for multi-stage numeric transforms with a single point of failure.
+//!
+//! A `Pipeline` runs each stage on an `UnguardedF64` and checks the result, so `finish` can report
+//! which stage first produced NaN or infinity instead of only that something did. The builder uses
+//! the typestate pattern: stages cannot be added and `finish` cannot be called until the pipeline
+//! has an input.
+mod error;
+
+pub use error::PipelineError;
+
+use crate::{GuardedF64, UnguardedF64};
+use std::marker::PhantomData;
+
+/// The state of a `Pipeline` that does not have an input yet.
+#[derive(Debug, Clone, Copy)]
+pub enum Empty {}
+
+/// The state of a `Pipeline` that has an input and accepts stages.
+#[derive(Debug, Clone, Copy)]
+pub enum Loaded {}
+
+/// A sequence of transforms applied to a value, validated as a whole.
+///
+/// Once a stage produces an invalid value, the remaining stages are skipped and `finish` returns a
+/// `PipelineError` with the index of that stage. Invalid values are reported to hooks and
+/// validation statistics once, at the stage that produced them.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::pipeline::{Pipeline, PipelineError};
+///
+/// let result = Pipeline::new()
+///     .input(4.0)
+///     .stage(|x| x.sqrt())
+///     .stage(|x| x * 3.0)
+///     .finish();
+/// assert_eq!(result, GuardedF64::new(6.0).map_err(PipelineError::Input));
+///
+/// let result = Pipeline::new()
+///     .input(4.0)
+///     .stage(|x| x - 5.0)
+///     .stage(|x| x.sqrt())
+///     .stage(|x| x * 3.0)
+///     .finish();
+/// assert_eq!(result, Err(PipelineError::Stage { index: 1, error: FloatError::NaN }));
+/// ```
+///
+/// Stages cannot be added before the input:
+///
+/// ```rust,compile_fail
+/// use floatguard::pipeline::Pipeline;
+///
+/// let result = Pipeline::new().stage(|x| x * 2.0).finish();
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[must_use = "a pipeline does nothing until `finish` is called"]
+pub struct Pipeline<State> {
+    value: Result<GuardedF64, PipelineError>,
+    stages: usize,
+    state: PhantomData<State>,
+}
+
+impl Pipeline<Empty> {
+    /// Creates a pipeline without an input.
+    pub const fn new() -> Self {
+        Self {
+            value: Ok(GuardedF64(0.0)),
+            stages: 0,
+            state: PhantomData,
+        }
+    }
+
+    /// Sets the input of the pipeline, which is checked before the first stage.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::FloatError;
+    /// use floatguard::pipeline::{Pipeline, PipelineError};
+    ///
+    /// let result = Pipeline::new().input(f64::NAN).stage(|x| x + 1.0).finish();
+    /// assert_eq!(result, Err(PipelineError::Input(FloatError::NaN)));
+    /// ```
+    pub fn input(self, value: impl Into<UnguardedF64>) -> Pipeline<Loaded> {
+        Pipeline {
+            value: value.into().check_reported().map_err(PipelineError::Input),
+            stages: 0,
+            state: PhantomData,
+        }
+    }
+}
+
+impl Default for Pipeline<Empty> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pipeline<Loaded> {
+    /// Adds a stage that transforms the current value.
+    ///
+    /// The stage receives the value as an `UnguardedF64`, so it can use lazy arithmetic, and may
+    /// return any value that converts to one, such as a `GuardedF64`. It is not called if an
+    /// earlier stage failed.
+    pub fn stage<R: Into<UnguardedF64>>(mut self, stage: impl FnOnce(UnguardedF64) -> R) -> Self {
+        let index = self.stages;
+        self.stages += 1;
+        self.value = self.value.and_then(|value| {
+            stage(UnguardedF64::from(value))
+                .into()
+                .check_reported()
+                .map_err(|error| PipelineError::Stage { index, error })
+        });
+        self
+    }
+
+    /// Returns the number of stages that have been added.
+    #[must_use]
+    pub const fn stages(&self) -> usize {
+        self.stages
+    }
+
+    /// Returns the final value, or the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PipelineError::Input` if the input was invalid, or `PipelineError::Stage` with the
+    /// index of the first stage that produced an invalid value.
+    pub const fn finish(self) -> Result<GuardedF64, PipelineError> {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_skips_stages_after_failure() {
+        let mut calls = 0;
+        let result = Pipeline::new()
+            .input(1.0)
+            .stage(|x| x / 0.0)
+            .stage(|x| {
+                calls += 1;
+                x
+            })
+            .finish();
+
+        assert_eq!(
+            result,
+            Err(PipelineError::Stage {
+                index: 0,
+                error: FloatError::Infinity
+            })
+        );
+        assert_eq!(calls, 0);
+    }
+
+    #[cfg(feature = "validation-stats")]
+    #[test]
+    fn test_reports_failure_once() {
+        crate::stats_reset();
+        let result = Pipeline::new()
+            .input(1.0)
+            .stage(|x| x / 0.0)
+            .stage(|x| x * 2.0)
+            .finish();
+        assert!(result.is_err());
+
+        let stats = crate::stats_snapshot();
+        assert_eq!(stats.checks(), 2);
+        assert_eq!(stats.failures(FloatError::Infinity), 1);
+        assert_eq!(stats.total_failures(), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_direct_computation(a in valid_f64(), b in valid_f64()) {
+            let pipeline = Pipeline::new().input(a).stage(|x| x * b).stage(|x| x + 1.0);
+            prop_assert_eq!(pipeline.stages(), 2);
+
+            let expected = (UnguardedF64::new(a) * b + 1.0).check();
+            prop_assert_eq!(pipeline.finish().map_err(PipelineError::float_error), expected);
+        }
+    }
+}
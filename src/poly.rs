@@ -0,0 +1,120 @@
+//! Polynomial evaluation over guarded coefficients.
+//!
+//! Coefficients are ordered from the highest degree down to the constant term, so `[a, b, c]`
+//! represents `a * x^2 + b * x + c`. Both functions use Horner's method, which needs one
+//! multiplication and one addition per coefficient, and return an `UnguardedF64` so that the result
+//! can be checked once at the end of the evaluation.
+use crate::{GuardedF64, UnguardedF64};
+
+/// Evaluates a polynomial at `x` with Horner's method.
+///
+/// An empty slice of coefficients evaluates to zero, and a constant polynomial evaluates to its
+/// constant for any `x`, including NaN and infinities.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::poly::polyval;
+///
+/// // 2x^2 - 3x + 1
+/// let coefficients = [2.0, -3.0, 1.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(polyval(&coefficients, 3.0).check(), GuardedF64::new(10.0));
+///
+/// assert_eq!(polyval(&coefficients, 1e200).check(), Err(FloatError::Infinity));
+/// assert_eq!(polyval(&coefficients, f64::NAN).check(), Err(FloatError::NaN));
+/// ```
+#[must_use = "function returns a new value and does not mutate the original value"]
+pub fn polyval(coefficients: &[GuardedF64], x: impl Into<UnguardedF64>) -> UnguardedF64 {
+    let Some((leading, rest)) = coefficients.split_first() else {
+        return UnguardedF64::new(0.0);
+    };
+
+    let x = x.into();
+    rest.iter()
+        .fold(UnguardedF64::from(leading), |acc, coefficient| {
+            acc * x + coefficient
+        })
+}
+
+/// Evaluates a polynomial at `x` with Horner's method, using a fused multiply-add for each step.
+///
+/// Each step is rounded once instead of twice, which makes the result more accurate than
+/// [`polyval`], particularly near the roots of the polynomial. On targets without a hardware FMA
+/// instruction, this is slower than `polyval`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::poly::polyval_fma;
+///
+/// // 2x^2 - 3x + 1
+/// let coefficients = [2.0, -3.0, 1.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(polyval_fma(&coefficients, 3.0).check(), GuardedF64::new(10.0));
+/// assert_eq!(polyval_fma(&coefficients, 0.5).check(), GuardedF64::new(0.0));
+/// ```
+#[must_use = "function returns a new value and does not mutate the original value"]
+pub fn polyval_fma(coefficients: &[GuardedF64], x: impl Into<UnguardedF64>) -> UnguardedF64 {
+    let Some((leading, rest)) = coefficients.split_first() else {
+        return UnguardedF64::new(0.0);
+    };
+
+    let x = x.into().0;
+    UnguardedF64::new(
+        rest.iter()
+            .fold(leading.0, |acc, coefficient| acc.mul_add(x, coefficient.0)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use proptest::prelude::*;
+
+    fn small_f64() -> impl Strategy<Value = GuardedF64> {
+        (-100.0..100.0f64).prop_map(|v| GuardedF64::new(v).unwrap())
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(polyval(&[], 2.0).check(), GuardedF64::new(0.0));
+        assert_eq!(polyval_fma(&[], 2.0).check(), GuardedF64::new(0.0));
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_power_sum(
+            coefficients in prop::collection::vec(small_f64(), 0..8),
+            x in small_f64(),
+        ) {
+            let mut expected = UnguardedF64::new(0.0);
+            for (power, coefficient) in coefficients.iter().rev().enumerate() {
+                expected += x.powi(i32::try_from(power).unwrap()) * coefficient;
+            }
+            let expected = expected.check().unwrap();
+            let tolerance = expected.abs().0.max(1.0) * 1e-9;
+
+            for value in [polyval(&coefficients, x), polyval_fma(&coefficients, x)] {
+                prop_assert!((value - expected).abs().check().unwrap() <= tolerance);
+            }
+        }
+
+        #[test]
+        fn test_constant(constant in valid_f64(), x in any::<f64>()) {
+            let coefficients = [GuardedF64::new(constant).unwrap()];
+            prop_assert_eq!(polyval(&coefficients, x).check(), Ok(coefficients[0]));
+            prop_assert_eq!(polyval_fma(&coefficients, x).check(), Ok(coefficients[0]));
+        }
+
+        #[test]
+        fn test_invalid_x(
+            coefficients in prop::collection::vec(small_f64(), 2..8),
+            x in invalid_f64(),
+        ) {
+            prop_assert!(polyval(&coefficients, x).check().is_err());
+            prop_assert!(polyval_fma(&coefficients, x).check().is_err());
+        }
+    }
+}
@@ -0,0 +1,441 @@
+//! This module provides the `GuardedQuat` and `UnguardedQuat` types for rotation math.
+//!
+//! Like the scalar types, operations on a `GuardedQuat` produce an `UnguardedQuat`, which is
+//! validated once with `check` at the end of the computation. The operations that are notorious
+//! for producing NaN clamp their inputs to the valid domain first: `slerp` clamps the cosine of the
+//! angle between its inputs before taking `acos`, and `to_euler` clamps the sine of the pitch
+//! before taking `asin`.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+use std::ops::{Mul, Neg};
+
+/// A quaternion whose components are all `GuardedF64` values.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{GuardedF64, GuardedQuat};
+///
+/// let quarter_turn = GuardedF64::FRAC_PI_2;
+/// let zero = GuardedF64::new(0.0).unwrap();
+/// let yaw = GuardedQuat::from_euler(zero, zero, quarter_turn).check().unwrap();
+///
+/// let half_turn = (yaw * yaw).check().unwrap();
+/// let (_, _, angle) = half_turn.to_euler();
+/// assert!((angle - GuardedF64::PI).abs().check().unwrap() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardedQuat {
+    /// The scalar component.
+    pub w: GuardedF64,
+
+    /// The first vector component.
+    pub x: GuardedF64,
+
+    /// The second vector component.
+    pub y: GuardedF64,
+
+    /// The third vector component.
+    pub z: GuardedF64,
+}
+
+/// A quaternion whose components are `UnguardedF64` values, produced by operations on a
+/// `GuardedQuat` and validated with `check`.
+#[derive(Debug, Clone, Copy)]
+pub struct UnguardedQuat {
+    /// The scalar component.
+    pub w: UnguardedF64,
+
+    /// The first vector component.
+    pub x: UnguardedF64,
+
+    /// The second vector component.
+    pub y: UnguardedF64,
+
+    /// The third vector component.
+    pub z: UnguardedF64,
+}
+
+impl GuardedQuat {
+    /// The identity rotation.
+    pub const IDENTITY: Self = Self::new(
+        GuardedF64(1.0),
+        GuardedF64(0.0),
+        GuardedF64(0.0),
+        GuardedF64(0.0),
+    );
+
+    /// Creates a new `GuardedQuat` from its scalar component `w` and vector components `x`, `y`,
+    /// and `z`.
+    #[must_use]
+    pub const fn new(w: GuardedF64, x: GuardedF64, y: GuardedF64, z: GuardedF64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// Creates a rotation from Euler angles, in radians, applied as yaw about z, then pitch about
+    /// y, then roll about x.
+    ///
+    /// The components of the result are products of sines and cosines, so they are always finite,
+    /// but they are returned as an `UnguardedQuat` like the crate's other trigonometric functions.
+    #[must_use = "function returns a new value and does not mutate the original value"]
+    pub fn from_euler(roll: GuardedF64, pitch: GuardedF64, yaw: GuardedF64) -> UnguardedQuat {
+        let (sr, cr) = (roll * 0.5).sin_cos();
+        let (sp, cp) = (pitch * 0.5).sin_cos();
+        let (sy, cy) = (yaw * 0.5).sin_cos();
+
+        UnguardedQuat {
+            w: cr * cp * cy + sr * sp * sy,
+            x: sr * cp * cy - cr * sp * sy,
+            y: cr * sp * cy + sr * cp * sy,
+            z: cr * cp * sy - sr * sp * cy,
+        }
+    }
+
+    /// Converts a unit quaternion to Euler angles, in radians, as `(roll, pitch, yaw)`, using the
+    /// same convention as [`GuardedQuat::from_euler`].
+    ///
+    /// The sine of the pitch is clamped to `[-1, 1]` before taking its arcsine, so a quaternion
+    /// that is slightly off of unit length due to rounding does not produce NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, GuardedQuat};
+    ///
+    /// let [roll, pitch, yaw] = [0.1, -0.2, 0.3].map(|v| GuardedF64::new(v).unwrap());
+    /// let rotation = GuardedQuat::from_euler(roll, pitch, yaw).check().unwrap();
+    ///
+    /// let (r, p, y) = rotation.to_euler();
+    /// assert!((r - roll).abs().check().unwrap() < 1e-12);
+    /// assert!((p - pitch).abs().check().unwrap() < 1e-12);
+    /// assert!((y - yaw).abs().check().unwrap() < 1e-12);
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn to_euler(self) -> (UnguardedF64, UnguardedF64, UnguardedF64) {
+        let Self { w, x, y, z } = self;
+
+        let roll = ((w * x + y * z) * 2.0).atan2(1.0 - (x * x + y * y) * 2.0);
+        let sin_pitch = ((w * y - z * x) * 2.0).0.clamp(-1.0, 1.0);
+        let pitch = UnguardedF64(sin_pitch).asin();
+        let yaw = ((w * z + x * y) * 2.0).atan2(1.0 - (y * y + z * z) * 2.0);
+
+        (roll, pitch, yaw)
+    }
+
+    /// Returns the conjugate of the quaternion, which is the inverse rotation of a unit
+    /// quaternion.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn conjugate(self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Computes the dot product of two quaternions.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn dot(self, other: Self) -> UnguardedF64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Computes the length of the quaternion.
+    ///
+    /// The components are scaled by the largest of them before they are squared, so the length
+    /// only overflows if the result itself is too large to represent.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn norm(self) -> UnguardedF64 {
+        let scale = self.max_abs();
+        if scale == 0.0 {
+            return UnguardedF64(0.0);
+        }
+
+        scale * self.scaled_norm(scale)
+    }
+
+    /// Scales the quaternion to unit length.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if every component is zero, since the zero quaternion
+    /// has no direction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64, GuardedQuat};
+    ///
+    /// let [two, zero] = [2.0, 0.0].map(|v| GuardedF64::new(v).unwrap());
+    /// let scaled = GuardedQuat::new(two, zero, zero, zero);
+    /// assert_eq!(scaled.normalize(), Ok(GuardedQuat::IDENTITY));
+    ///
+    /// let origin = GuardedQuat::new(zero, zero, zero, zero);
+    /// assert_eq!(origin.normalize(), Err(FloatError::DivisionByZero));
+    /// ```
+    pub fn normalize(self) -> Result<Self, FloatError> {
+        let scale = self.max_abs();
+        if scale == 0.0 {
+            return Err(FloatError::DivisionByZero);
+        }
+
+        // The scaled components are at most one in magnitude and the largest of them is exactly
+        // one, so the scaled norm is in `[1, 2]` and every division below stays finite.
+        let norm = self.scaled_norm(scale);
+        let unit = |component: GuardedF64| GuardedF64(component.0 / scale.0 / norm);
+        Ok(Self::new(
+            unit(self.w),
+            unit(self.x),
+            unit(self.y),
+            unit(self.z),
+        ))
+    }
+
+    /// Spherically interpolates between two unit quaternions, taking the shorter path.
+    ///
+    /// A `t` of zero returns `self` and a `t` of one returns `other`, or its negation, which
+    /// represents the same rotation. The cosine of the angle between the quaternions is clamped to
+    /// `[-1, 1]`, so rounding cannot push it outside of the domain of `acos`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, GuardedQuat};
+    ///
+    /// let zero = GuardedF64::new(0.0).unwrap();
+    /// let start = GuardedQuat::IDENTITY;
+    /// let end = GuardedQuat::from_euler(zero, zero, GuardedF64::FRAC_PI_2).check().unwrap();
+    ///
+    /// let halfway = start.slerp(end, 0.5).check().unwrap();
+    /// let (_, _, yaw) = halfway.to_euler();
+    /// assert!((yaw - GuardedF64::FRAC_PI_4).abs().check().unwrap() < 1e-12);
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn slerp(self, other: Self, t: impl Into<UnguardedF64>) -> UnguardedQuat {
+        let t = t.into();
+        let (other, cos_theta) = match self.dot(other) {
+            dot if dot.0 < 0.0 => (-other, -dot),
+            dot => (other, dot),
+        };
+        let cos_theta = UnguardedF64(cos_theta.0.clamp(-1.0, 1.0));
+
+        // Nearly parallel quaternions have a tiny `sin(theta)`, so interpolate linearly instead.
+        if cos_theta.0 > 0.9995 {
+            let lerp =
+                UnguardedQuat::from(self).scale(1.0 - t) + UnguardedQuat::from(other).scale(t);
+            return lerp.scale(lerp.norm().recip());
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let from_self = ((1.0 - t) * theta).sin() / sin_theta;
+        let from_other = (t * theta).sin() / sin_theta;
+
+        UnguardedQuat::from(self).scale(from_self) + UnguardedQuat::from(other).scale(from_other)
+    }
+
+    /// Returns the largest magnitude of any component.
+    fn max_abs(self) -> GuardedF64 {
+        self.w
+            .abs()
+            .max(self.x.abs())
+            .max(self.y.abs())
+            .max(self.z.abs())
+    }
+
+    /// Computes the length of the quaternion after dividing every component by `scale`.
+    fn scaled_norm(self, scale: GuardedF64) -> f64 {
+        let [w, x, y, z] = [self.w, self.x, self.y, self.z].map(|component| component.0 / scale.0);
+        w.mul_add(w, x.mul_add(x, y.mul_add(y, z * z))).sqrt()
+    }
+}
+
+impl UnguardedQuat {
+    /// Checks that every component is valid (finite).
+    ///
+    /// # Errors
+    ///
+    /// Returns the `FloatError` of the first component that is NaN or infinite.
+    pub fn check(self) -> Result<GuardedQuat, FloatError> {
+        Ok(GuardedQuat::new(
+            self.w.check_reported()?,
+            self.x.check_reported()?,
+            self.y.check_reported()?,
+            self.z.check_reported()?,
+        ))
+    }
+
+    /// Checks the quaternion and scales it to unit length. See [`GuardedQuat::normalize`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError` if any component is NaN or infinite, or
+    /// `FloatError::DivisionByZero` if every component is zero.
+    pub fn normalize(self) -> Result<GuardedQuat, FloatError> {
+        self.check()?.normalize()
+    }
+
+    /// Computes the length of the quaternion.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn norm(self) -> UnguardedF64 {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Multiplies every component by `factor`.
+    fn scale(self, factor: impl Into<UnguardedF64>) -> Self {
+        let factor = factor.into();
+        Self {
+            w: self.w * factor,
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+}
+
+impl From<GuardedQuat> for UnguardedQuat {
+    /// Converts a `GuardedQuat` into an `UnguardedQuat`.
+    fn from(value: GuardedQuat) -> Self {
+        Self {
+            w: value.w.into(),
+            x: value.x.into(),
+            y: value.y.into(),
+            z: value.z.into(),
+        }
+    }
+}
+
+impl Neg for GuardedQuat {
+    type Output = Self;
+
+    /// Negates every component, which represents the same rotation for a unit quaternion.
+    fn neg(self) -> Self::Output {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl std::ops::Add for UnguardedQuat {
+    type Output = Self;
+
+    /// Adds two quaternions component-wise.
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            w: self.w + rhs.w,
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T: Into<Self>> Mul<T> for UnguardedQuat {
+    type Output = Self;
+
+    /// Computes the Hamilton product of two quaternions, which composes their rotations.
+    fn mul(self, rhs: T) -> Self::Output {
+        let Self { w, x, y, z } = self;
+        let rhs = rhs.into();
+
+        Self {
+            w: w * rhs.w - x * rhs.x - y * rhs.y - z * rhs.z,
+            x: w * rhs.x + x * rhs.w + y * rhs.z - z * rhs.y,
+            y: w * rhs.y - x * rhs.z + y * rhs.w + z * rhs.x,
+            z: w * rhs.z + x * rhs.y - y * rhs.x + z * rhs.w,
+        }
+    }
+}
+
+impl<T: Into<UnguardedQuat>> Mul<T> for GuardedQuat {
+    type Output = UnguardedQuat;
+
+    /// Computes the Hamilton product of two quaternions, which composes their rotations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, GuardedQuat};
+    ///
+    /// let [roll, pitch, yaw] = [0.4, 0.5, 0.6].map(|v| GuardedF64::new(v).unwrap());
+    /// let rotation = GuardedQuat::from_euler(roll, pitch, yaw).check().unwrap();
+    ///
+    /// let identity = (rotation * rotation.conjugate()).normalize().unwrap();
+    /// assert!((identity.w - 1.0).abs().check().unwrap() < 1e-12);
+    /// ```
+    fn mul(self, rhs: T) -> Self::Output {
+        UnguardedQuat::from(self) * rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn quat() -> impl Strategy<Value = GuardedQuat> {
+        prop::array::uniform4(valid_f64()).prop_map(|[w, x, y, z]| {
+            GuardedQuat::new(GuardedF64(w), GuardedF64(x), GuardedF64(y), GuardedF64(z))
+        })
+    }
+
+    fn rotation() -> impl Strategy<Value = GuardedQuat> {
+        prop::array::uniform3(-std::f64::consts::PI..std::f64::consts::PI).prop_map(|[r, p, y]| {
+            GuardedQuat::from_euler(GuardedF64(r), GuardedF64(p), GuardedF64(y))
+                .check()
+                .unwrap()
+        })
+    }
+
+    fn assert_unit(quat: GuardedQuat) {
+        let norm = UnguardedQuat::from(quat).norm().check().unwrap();
+        assert!((norm - 1.0).abs().check().unwrap() < 1e-12, "{quat:?}");
+    }
+
+    #[test]
+    fn test_to_euler_off_unit() {
+        let component = GuardedF64(std::f64::consts::FRAC_1_SQRT_2 * (1.0 + 1e-12));
+        let zero = GuardedF64(0.0);
+        let quat = GuardedQuat::new(component, zero, component, zero);
+
+        let (roll, pitch, yaw) = quat.to_euler();
+        assert!(roll.check().is_ok() && yaw.check().is_ok());
+        assert_eq!(pitch.check(), Ok(GuardedF64::FRAC_PI_2));
+    }
+
+    proptest! {
+        #[test]
+        fn test_normalize_is_unit(quat in quat()) {
+            match quat.normalize() {
+                Ok(unit) => assert_unit(unit),
+                Err(error) => {
+                    prop_assert_eq!(error, FloatError::DivisionByZero);
+                    prop_assert_eq!(quat.max_abs(), 0.0);
+                }
+            }
+        }
+
+        #[test]
+        fn test_product_of_rotations_is_unit(a in rotation(), b in rotation()) {
+            assert_unit((a * b).check().unwrap());
+        }
+
+        #[test]
+        fn test_euler_round_trip(
+            roll in -3.0..3.0f64,
+            pitch in -1.5..1.5f64,
+            yaw in -3.0..3.0f64,
+        ) {
+            let quat = GuardedQuat::from_euler(GuardedF64(roll), GuardedF64(pitch), GuardedF64(yaw));
+            let (r, p, y) = quat.check().unwrap().to_euler();
+
+            prop_assert!((r - roll).abs().check().unwrap() < 1e-9);
+            prop_assert!((p - pitch).abs().check().unwrap() < 1e-9);
+            prop_assert!((y - yaw).abs().check().unwrap() < 1e-9);
+        }
+
+        #[test]
+        fn test_slerp(a in rotation(), b in rotation(), t in 0.0..=1.0f64) {
+            assert_unit(a.slerp(b, t).check().unwrap());
+
+            let start = a.slerp(b, 0.0).check().unwrap();
+            prop_assert!((start.dot(a).abs() - 1.0).abs().check().unwrap() < 1e-9);
+
+            let end = a.slerp(b, 1.0).check().unwrap();
+            prop_assert!((end.dot(b).abs() - 1.0).abs().check().unwrap() < 1e-9);
+        }
+    }
+}
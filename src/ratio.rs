@@ -0,0 +1,313 @@
+//! This module provides exact conversions between guarded values and ratios of integers.
+use crate::parse::{Format, compose};
+use crate::{FixedRounding, FloatError, GuardedF32, GuardedF64};
+
+/// Splits the magnitude of `value` into an odd mantissa and a power of two, or returns `(0, 0)`
+/// for zero.
+const fn decompose(value: f64) -> (u64, i64) {
+    let bits = value.to_bits();
+    let biased = (bits >> 52) & 0x7ff;
+    let fraction = bits & ((1 << 52) - 1);
+
+    #[allow(clippy::cast_possible_wrap)]
+    let (mantissa, exponent) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased as i64 - 1075)
+    };
+
+    normalize(mantissa, exponent)
+}
+
+/// Removes the trailing zero bits of `mantissa`, adjusting `exponent` to keep the same value.
+const fn normalize(mantissa: u64, exponent: i64) -> (u64, i64) {
+    if mantissa == 0 {
+        return (0, 0);
+    }
+
+    let zeros = mantissa.trailing_zeros();
+    (mantissa >> zeros, exponent + zeros as i64)
+}
+
+/// Returns `value` as a reduced fraction, if it fits.
+fn as_ratio(value: f64) -> Result<(i128, u128), FloatError> {
+    let (mantissa, exponent) = decompose(value);
+    let (numerator, denominator) = if exponent >= 0 {
+        let bits = u64::BITS - mantissa.leading_zeros();
+        if i64::from(bits) + exponent > 127 {
+            return Err(FloatError::Inexact);
+        }
+        (i128::from(mantissa) << exponent, 1)
+    } else {
+        if exponent < -127 {
+            return Err(FloatError::Inexact);
+        }
+        (i128::from(mantissa), 1 << -exponent)
+    };
+
+    Ok((
+        if value < 0.0 { -numerator } else { numerator },
+        denominator,
+    ))
+}
+
+/// Divides `numerator` by `denominator`, and returns the bits of the nearest value in `format`,
+/// or `FloatError::Inexact` if `rounding` is `FixedRounding::Exact` and the result is rounded.
+///
+/// The result is always in the normal range of `f64`, and in the range of `f32`, because the
+/// magnitude of a ratio of 128-bit integers is between `2^-128` and `2^127`.
+fn from_ratio(
+    numerator: i128,
+    denominator: u128,
+    rounding: FixedRounding,
+    format: Format,
+) -> Result<u64, FloatError> {
+    if denominator == 0 {
+        return Err(FloatError::DivisionByZero);
+    }
+
+    if numerator == 0 {
+        return Ok(0);
+    }
+
+    let dividend = numerator.unsigned_abs();
+    let quotient = dividend / denominator;
+    let mut remainder = dividend % denominator;
+
+    // Long division, producing at least 64 significant bits of the quotient.
+    let bits = u128::BITS - quotient.leading_zeros();
+    let (mut mantissa, mut exponent, mut sticky) = if bits > 64 {
+        let shift = bits - 64;
+        #[allow(clippy::cast_possible_truncation)]
+        let mantissa = (quotient >> shift) as u64;
+        let dropped = quotient & ((1 << shift) - 1) != 0;
+        (mantissa, i64::from(shift), dropped)
+    } else {
+        #[allow(clippy::cast_possible_truncation)]
+        let mantissa = quotient as u64;
+        (mantissa, 0, false)
+    };
+
+    while mantissa >> 63 == 0 {
+        // `2 * remainder` may not fit, but `remainder < denominator` so the comparison does.
+        let bit = remainder >= denominator - remainder;
+        remainder = if bit {
+            remainder - (denominator - remainder)
+        } else {
+            remainder << 1
+        };
+        mantissa = (mantissa << 1) | u64::from(bit);
+        exponent -= 1;
+    }
+    sticky |= remainder != 0;
+
+    let bits = compose(numerator < 0, mantissa, sticky, exponent, format);
+    if rounding == FixedRounding::Exact
+        && (sticky || decompose(format.widen(bits)) != normalize(mantissa, exponent))
+    {
+        return Err(FloatError::Inexact);
+    }
+
+    Ok(bits)
+}
+
+impl GuardedF64 {
+    /// Returns the exact value as a fraction `(numerator, denominator)` in lowest terms.
+    ///
+    /// Every finite float is a ratio of an integer and a power of two, so the denominator is always
+    /// a power of two, and zero is `(0, 1)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Inexact` if the numerator or denominator does not fit, which is the case
+    /// for magnitudes of `2^127` and above, and for values with bits below `2^-127`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    ///
+    /// assert_eq!(GuardedF64::new(-0.75).unwrap().as_ratio(), Ok((-3, 4)));
+    /// assert_eq!(GuardedF64::new(0.1).unwrap().as_ratio(), Ok((3_602_879_701_896_397, 1 << 55)));
+    /// assert_eq!(GuardedF64::new(1e300).unwrap().as_ratio(), Err(FloatError::Inexact));
+    /// ```
+    pub fn as_ratio(self) -> Result<(i128, u128), FloatError> {
+        as_ratio(self.0)
+    }
+
+    /// Converts the fraction `numerator / denominator` to the nearest `GuardedF64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if `denominator` is zero, or `FloatError::Inexact` if
+    /// `rounding` is `FixedRounding::Exact` and the fraction is not exactly representable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FixedRounding, FloatError, GuardedF64};
+    ///
+    /// assert_eq!(GuardedF64::from_ratio(-3, 4, FixedRounding::Exact), GuardedF64::new(-0.75));
+    /// assert_eq!(GuardedF64::from_ratio(1, 10, FixedRounding::Nearest), GuardedF64::new(0.1));
+    /// assert_eq!(GuardedF64::from_ratio(1, 10, FixedRounding::Exact), Err(FloatError::Inexact));
+    /// assert_eq!(GuardedF64::from_ratio(1, 0, FixedRounding::Nearest), Err(FloatError::DivisionByZero));
+    /// ```
+    pub fn from_ratio(
+        numerator: i128,
+        denominator: u128,
+        rounding: FixedRounding,
+    ) -> Result<Self, FloatError> {
+        from_ratio(numerator, denominator, rounding, Format::F64)
+            .map(|bits| Self(f64::from_bits(bits)))
+    }
+}
+
+impl GuardedF32 {
+    /// Returns the exact value as a fraction `(numerator, denominator)` in lowest terms.
+    ///
+    /// Every finite float is a ratio of an integer and a power of two, so the denominator is always
+    /// a power of two, and zero is `(0, 1)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Inexact` if the numerator or denominator does not fit, which is the case
+    /// for magnitudes of `2^127` and above, and for values with bits below `2^-127`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::new(-0.75).unwrap().as_ratio(), Ok((-3, 4)));
+    /// assert_eq!(GuardedF32::new(0.1).unwrap().as_ratio(), Ok((13_421_773, 1 << 27)));
+    /// assert_eq!(GuardedF32::MAX.as_ratio(), Err(FloatError::Inexact));
+    /// ```
+    pub fn as_ratio(self) -> Result<(i128, u128), FloatError> {
+        as_ratio(f64::from(self.0))
+    }
+
+    /// Converts the fraction `numerator / denominator` to the nearest `GuardedF32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if `denominator` is zero, or `FloatError::Inexact` if
+    /// `rounding` is `FixedRounding::Exact` and the fraction is not exactly representable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FixedRounding, FloatError, GuardedF32};
+    ///
+    /// assert_eq!(GuardedF32::from_ratio(-3, 4, FixedRounding::Exact), GuardedF32::new(-0.75));
+    /// assert_eq!(GuardedF32::from_ratio(1, 10, FixedRounding::Nearest), GuardedF32::new(0.1));
+    /// assert_eq!(GuardedF32::from_ratio(1, 10, FixedRounding::Exact), Err(FloatError::Inexact));
+    /// ```
+    pub fn from_ratio(
+        numerator: i128,
+        denominator: u128,
+        rounding: FixedRounding,
+    ) -> Result<Self, FloatError> {
+        #[allow(clippy::cast_possible_truncation)]
+        from_ratio(numerator, denominator, rounding, Format::F32)
+            .map(|bits| Self(f32::from_bits(bits as u32)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f32::tests::valid_f32;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_extremes() {
+        let exact = FixedRounding::Exact;
+        assert_eq!(GuardedF64(0.0).as_ratio(), Ok((0, 1)));
+        assert_eq!(GuardedF64(-0.0).as_ratio(), Ok((0, 1)));
+        assert_eq!(GuardedF64(2f64.powi(126)).as_ratio(), Ok((1 << 126, 1)));
+        assert_eq!(
+            GuardedF64(2f64.powi(127)).as_ratio(),
+            Err(FloatError::Inexact)
+        );
+        assert_eq!(GuardedF64(2f64.powi(-127)).as_ratio(), Ok((1, 1 << 127)));
+        assert_eq!(
+            GuardedF64(2f64.powi(-128)).as_ratio(),
+            Err(FloatError::Inexact)
+        );
+
+        assert_eq!(
+            GuardedF64::from_ratio(i128::MIN, 1, exact),
+            Ok(GuardedF64(-(2f64.powi(127))))
+        );
+        assert_eq!(
+            GuardedF64::from_ratio(i128::MAX, 1, exact),
+            Err(FloatError::Inexact)
+        );
+        assert_eq!(
+            GuardedF64::from_ratio(1, u128::MAX, FixedRounding::Nearest),
+            Ok(GuardedF64(2f64.powi(-128)))
+        );
+        assert_eq!(GuardedF64::from_ratio(0, 7, exact), Ok(GuardedF64(0.0)));
+        assert_eq!(
+            GuardedF32::from_ratio(1, u128::MAX, FixedRounding::Nearest),
+            Ok(GuardedF32(f32::from_bits(1 << 21)))
+        );
+    }
+
+    #[test]
+    fn test_ties_to_even() {
+        let nearest = FixedRounding::Nearest;
+        let two_53 = 1i128 << 53;
+        assert_eq!(
+            GuardedF64::from_ratio(two_53 + 1, 1, nearest),
+            Ok(GuardedF64(2f64.powi(53)))
+        );
+        assert_eq!(
+            GuardedF64::from_ratio(two_53 + 3, 1, nearest),
+            Ok(GuardedF64(2f64.powi(53) + 4.0))
+        );
+        assert_eq!(
+            GuardedF64::from_ratio(two_53 + 1, 1, FixedRounding::Exact),
+            Err(FloatError::Inexact)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip_f64(a in valid_f64()) {
+            let value = GuardedF64(a);
+            if let Ok((numerator, denominator)) = value.as_ratio() {
+                prop_assert!(denominator.is_power_of_two());
+                prop_assert!(numerator % 2 != 0 || denominator == 1);
+                prop_assert_eq!(GuardedF64::from_ratio(numerator, denominator, FixedRounding::Exact), Ok(value));
+            }
+        }
+
+        #[test]
+        fn test_round_trip_f32(a in valid_f32()) {
+            let value = GuardedF32(a);
+            if let Ok((numerator, denominator)) = value.as_ratio() {
+                prop_assert_eq!(GuardedF32::from_ratio(numerator, denominator, FixedRounding::Exact), Ok(value));
+            }
+        }
+
+        #[test]
+        fn test_matches_division(numerator in any::<i64>(), denominator in 1..u64::MAX) {
+            #[allow(clippy::cast_precision_loss)]
+            let expected = (numerator as f64 / denominator as f64).abs();
+            let value = GuardedF64::from_ratio(numerator.into(), denominator.into(), FixedRounding::Nearest).unwrap();
+
+            // Both operands may be rounded before the division, so allow a few units in the last place.
+            prop_assert!((value.0.abs() - expected).abs() <= expected * 4.0 * f64::EPSILON);
+        }
+
+        #[test]
+        fn test_small_integers_are_exact(numerator in any::<i32>(), shift in 0u32..100) {
+            let value = GuardedF64::from_ratio(numerator.into(), 1 << shift, FixedRounding::Exact).unwrap();
+            prop_assert_eq!(value.0, f64::from(numerator) / 2f64.powi(i32::try_from(shift).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! This module provides the `RawF64` type, which transports a possibly invalid `f64` bit-for-bit.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::num::FpCategory;
+
+/// An `f64` that may be NaN or infinite, carried unchanged until it is validated.
+///
+/// `GuardedF64` is always valid and `UnguardedF64` is the pending result of a computation, while
+/// `RawF64` is for transport: values received over FFI or from a file that must be passed between
+/// layers before deciding to validate them. It has no arithmetic, so a NaN's payload and sign are
+/// never changed, and its equality and hashing compare bits, so every value equals itself.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64, RawF64};
+/// use std::num::FpCategory;
+///
+/// let raw = RawF64::from_bits(0x7ff8_0000_0000_002a);
+/// assert_eq!(raw.classify(), FpCategory::Nan);
+/// assert_eq!(raw.nan_payload(), Some(42));
+/// assert_eq!(raw.try_guard(), Err(FloatError::NaN));
+///
+/// let raw = RawF64::new(1.5);
+/// assert_eq!(raw.nan_payload(), None);
+/// assert_eq!(raw.try_guard(), GuardedF64::new(1.5));
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct RawF64(f64);
+
+impl RawF64 {
+    /// Creates a `RawF64` from any `f64`, including NaN and infinity.
+    #[must_use]
+    pub const fn new(value: f64) -> Self {
+        Self(value)
+    }
+
+    /// Creates a `RawF64` from the bits of an `f64`.
+    #[must_use]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(f64::from_bits(bits))
+    }
+
+    /// Returns the inner `f64`, which may be NaN or infinite.
+    #[must_use]
+    pub const fn get(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the bits of the inner `f64`.
+    #[must_use]
+    pub const fn to_bits(self) -> u64 {
+        self.0.to_bits()
+    }
+
+    /// Returns the floating-point category of the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::RawF64;
+    /// use std::num::FpCategory;
+    ///
+    /// assert_eq!(RawF64::new(f64::INFINITY).classify(), FpCategory::Infinite);
+    /// assert_eq!(RawF64::new(1e-310).classify(), FpCategory::Subnormal);
+    /// ```
+    #[must_use]
+    pub const fn classify(self) -> FpCategory {
+        self.0.classify()
+    }
+
+    /// Returns the payload of a NaN, the 51 fraction bits below the quiet bit, or `None` if the value
+    /// is not NaN.
+    ///
+    /// The standard `f64::NAN` has a payload of zero. Payloads are often used to record where a NaN
+    /// came from, so they are worth reporting before the value is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::RawF64;
+    ///
+    /// assert_eq!(RawF64::new(f64::NAN).nan_payload(), Some(0));
+    /// assert_eq!(RawF64::from_bits(0xfff0_0000_0000_0001).nan_payload(), Some(1));
+    /// assert_eq!(RawF64::new(f64::INFINITY).nan_payload(), None);
+    /// ```
+    #[must_use]
+    pub const fn nan_payload(self) -> Option<u64> {
+        if self.0.is_nan() {
+            Some(self.to_bits() & ((1 << 51) - 1))
+        } else {
+            None
+        }
+    }
+
+    /// Validates the value, converting it to a `GuardedF64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::NaN` or `FloatError::Infinity` if the value is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64, RawF64};
+    ///
+    /// assert_eq!(RawF64::new(2.0).try_guard(), GuardedF64::new(2.0));
+    /// assert_eq!(RawF64::new(f64::NEG_INFINITY).try_guard(), Err(FloatError::Infinity));
+    /// ```
+    pub const fn try_guard(self) -> Result<GuardedF64, FloatError> {
+        UnguardedF64::new(self.0).check()
+    }
+}
+
+impl PartialEq for RawF64 {
+    /// Compares the bits of two values, so NaNs with the same payload are equal and `0.0` and
+    /// `-0.0` are not.
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl Eq for RawF64 {}
+
+impl Hash for RawF64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
+}
+
+impl fmt::Debug for RawF64 {
+    /// Formats the value, and the payload of a NaN, since the standard formatting omits it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.nan_payload() {
+            Some(payload) => write!(
+                f,
+                "RawF64({}NaN:{payload:#x})",
+                if self.0.is_sign_negative() { "-" } else { "" }
+            ),
+            None => f.debug_tuple("RawF64").field(&self.0).finish(),
+        }
+    }
+}
+
+impl fmt::Display for RawF64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<f64> for RawF64 {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<RawF64> for f64 {
+    fn from(value: RawF64) -> Self {
+        value.0
+    }
+}
+
+impl From<GuardedF64> for RawF64 {
+    fn from(value: GuardedF64) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<UnguardedF64> for RawF64 {
+    fn from(value: UnguardedF64) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<RawF64> for UnguardedF64 {
+    /// Converts a `RawF64` into an `UnguardedF64`, to be checked later or used in a computation.
+    fn from(value: RawF64) -> Self {
+        Self(value.0)
+    }
+}
+
+impl TryFrom<RawF64> for GuardedF64 {
+    type Error = FloatError;
+
+    /// Validates a `RawF64`, like `RawF64::try_guard`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::NaN` or `FloatError::Infinity` if the value is not finite.
+    fn try_from(value: RawF64) -> Result<Self, Self::Error> {
+        value.try_guard()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_debug_shows_payload() {
+        assert_eq!(
+            format!("{:?}", RawF64::from_bits(0xfff8_0000_0000_00ff)),
+            "RawF64(-NaN:0xff)"
+        );
+        assert_eq!(format!("{:?}", RawF64::new(1.5)), "RawF64(1.5)");
+    }
+
+    #[test]
+    fn test_equality_is_bitwise() {
+        assert_eq!(RawF64::new(f64::NAN), RawF64::new(f64::NAN));
+        assert_ne!(RawF64::new(0.0), RawF64::new(-0.0));
+        assert_ne!(
+            RawF64::from_bits(0x7ff8_0000_0000_0001),
+            RawF64::new(f64::NAN)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_bits_are_preserved(bits: u64) {
+            let raw = RawF64::from_bits(bits);
+            prop_assert_eq!(RawF64::from(UnguardedF64::from(raw)).to_bits(), bits);
+            prop_assert_eq!(raw.classify(), f64::from_bits(bits).classify());
+            prop_assert_eq!(raw.try_guard(), UnguardedF64::new(f64::from_bits(bits)).check());
+            prop_assert_eq!(raw.nan_payload().is_some(), f64::from_bits(bits).is_nan());
+        }
+    }
+}
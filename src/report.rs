@@ -0,0 +1,24 @@
+//! The single point where validations are reported to the invalid-value hook and the validation
+//! counters.
+//!
+//! This module is only available when the `hooks` or `validation-stats` feature is enabled.
+//! `GuardedF64::new_reported`, and everything in the crate that validates through it, ends up here,
+//! so the hook and the counters always see the same validations.
+use crate::FloatError;
+
+/// Reports a validation of `value`, which failed with `error` if there is one.
+#[cfg_attr(not(feature = "hooks"), allow(unused_variables))]
+pub fn validation(error: Option<FloatError>, value: f64) {
+    #[cfg(feature = "validation-stats")]
+    {
+        crate::validation_stats::record_check();
+        if let Some(error) = error {
+            crate::validation_stats::record_failure(error);
+        }
+    }
+
+    #[cfg(feature = "hooks")]
+    if let Some(error) = error {
+        crate::hooks::report_invalid(error, value);
+    }
+}
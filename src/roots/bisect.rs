@@ -0,0 +1,114 @@
+use super::RootError;
+use crate::{GuardedF64, UnguardedF64};
+
+/// Finds a root of `f` between `lo` and `hi` with the bisection method.
+///
+/// The function must have opposite signs at `lo` and `hi` (or be zero at either). The bracket is
+/// halved until it is no wider than `tolerance` or cannot be split any further, so the search
+/// always terminates, and the midpoint of the final bracket is returned.
+///
+/// # Errors
+///
+/// Returns `RootError::NotBracketed` if `f` has the same sign at `lo` and `hi`, or
+/// `RootError::Function` if `f` returns NaN or infinity. Evaluating the ends of the bracket is
+/// iteration zero.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::roots::{find_root_bisect, RootError};
+///
+/// let [lo, hi, tolerance] = [0.0, 2.0, 1e-12].map(|v| GuardedF64::new(v).unwrap());
+/// let root = find_root_bisect(|x| x * x - 2.0, lo, hi, tolerance).unwrap();
+/// assert!((root - std::f64::consts::SQRT_2).abs().check().unwrap() < 1e-12);
+///
+/// assert_eq!(find_root_bisect(|x| x * x + 1.0, lo, hi, tolerance), Err(RootError::NotBracketed));
+/// ```
+pub fn find_root_bisect(
+    mut f: impl FnMut(GuardedF64) -> UnguardedF64,
+    lo: GuardedF64,
+    hi: GuardedF64,
+    tolerance: GuardedF64,
+) -> Result<GuardedF64, RootError> {
+    let mut eval = |x: GuardedF64, iteration: usize| {
+        f(x).check_reported()
+            .map_err(|error| RootError::Function { iteration, error })
+    };
+
+    let (mut lo, mut hi) = (lo.min(hi), lo.max(hi));
+    let (f_lo, f_hi) = (eval(lo, 0)?, eval(hi, 0)?);
+    if f_lo == 0.0 {
+        return Ok(lo);
+    }
+    if f_hi == 0.0 {
+        return Ok(hi);
+    }
+    if f_lo.signum() == f_hi.signum() {
+        return Err(RootError::NotBracketed);
+    }
+
+    let lo_is_negative = f_lo < 0.0;
+    let mut iteration = 0;
+    loop {
+        iteration += 1;
+        // Halving each end separately keeps the midpoint finite for any finite bracket.
+        let mid = GuardedF64(lo.0.mul_add(0.5, hi.0 * 0.5));
+        let width = hi - lo;
+        if mid <= lo || mid >= hi || width.check_reported().is_ok_and(|width| width <= tolerance) {
+            return Ok(mid.clamp(lo, hi));
+        }
+
+        let f_mid = eval(mid, iteration)?;
+        if f_mid == 0.0 {
+            return Ok(mid);
+        }
+        if (f_mid < 0.0) == lo_is_negative {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_function_errors() {
+        let lo = GuardedF64::new(-1.0).unwrap();
+        let hi = GuardedF64::new(1.0).unwrap();
+        let tolerance = GuardedF64::new(1e-9).unwrap();
+
+        assert_eq!(
+            find_root_bisect(GuardedF64::recip, lo, hi, tolerance),
+            Err(RootError::Function {
+                iteration: 1,
+                error: FloatError::Infinity
+            })
+        );
+
+        let root = find_root_bisect(
+            UnguardedF64::from,
+            GuardedF64::MIN,
+            GuardedF64::MAX,
+            tolerance,
+        );
+        assert_eq!(root, Ok(GuardedF64::new(0.0).unwrap()));
+    }
+
+    proptest! {
+        #[test]
+        fn test_finds_linear_root(root in -1e6..1e6f64, slope in 0.1..10.0f64, tolerance in 1e-9..1.0f64) {
+            let lo = GuardedF64::new(-1e7).unwrap();
+            let hi = GuardedF64::new(1e7).unwrap();
+            let tolerance = GuardedF64::new(tolerance).unwrap();
+
+            let found = find_root_bisect(|x| (x - root) * slope, hi, lo, tolerance).unwrap();
+            prop_assert!((found - root).abs().check().unwrap() <= tolerance);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::FloatError;
+
+/// An error occurred while searching for a root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootError {
+    /// Indicates that the function has the same sign at both ends of the bracket, so the bracket
+    /// is not known to contain a root.
+    NotBracketed,
+
+    /// Indicates that the function, or its derivative, returned an invalid value.
+    Function {
+        /// The iteration during which the invalid value was returned, starting at zero.
+        iteration: usize,
+
+        /// The error produced by checking the returned value.
+        error: FloatError,
+    },
+
+    /// Indicates that the derivative was zero, so Newton's method cannot take a step.
+    ZeroDerivative {
+        /// The iteration during which the derivative was zero, starting at zero.
+        iteration: usize,
+    },
+
+    /// Indicates that a step left the range of finite values.
+    Diverged {
+        /// The iteration during which the step was taken, starting at zero.
+        iteration: usize,
+    },
+
+    /// Indicates that the solver did not converge within its iteration limit.
+    NoConvergence {
+        /// The number of iterations that were performed.
+        iterations: usize,
+    },
+}
+
+impl std::error::Error for RootError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Function { error, .. } => Some(error),
+            Self::NotBracketed
+            | Self::ZeroDerivative { .. }
+            | Self::Diverged { .. }
+            | Self::NoConvergence { .. } => None,
+        }
+    }
+}
+
+/// Implements the `Display` trait for the `RootError` enum, providing a user-friendly
+/// description of the error.
+impl std::fmt::Display for RootError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotBracketed => write!(
+                f,
+                "The function has the same sign at both ends of the bracket"
+            ),
+            Self::Function { iteration, error } => {
+                write!(f, "The function failed at iteration {iteration}: {error}")
+            }
+            Self::ZeroDerivative { iteration } => {
+                write!(f, "The derivative was zero at iteration {iteration}")
+            }
+            Self::Diverged { iteration } => {
+                write!(f, "The solver diverged at iteration {iteration}")
+            }
+            Self::NoConvergence { iterations } => {
+                write!(
+                    f,
+                    "The solver did not converge after {iterations} iterations"
+                )
+            }
+        }
+    }
+}
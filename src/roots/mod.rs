@@ -0,0 +1,18 @@
+//! Root finding for functions of guarded values.
+//!
+//! The functions passed to the solvers return an `UnguardedF64`, so they can be written with plain
+//! arithmetic. Each value they return is checked as soon as the solver needs it, and a NaN or
+//! infinity is reported as a `RootError` with the iteration that produced it, instead of silently
+//! propagating through the remaining iterations.
+//!
+//! Quadratic equations have a closed-form solution, so `solve_quadratic` reports invalid
+//! coefficients with a `FloatError` instead.
+mod bisect;
+mod error;
+mod newton;
+mod quadratic;
+
+pub use bisect::find_root_bisect;
+pub use error::RootError;
+pub use newton::{NewtonOptions, find_root_newton};
+pub use quadratic::{QuadraticRoots, solve_quadratic};
@@ -0,0 +1,176 @@
+use super::RootError;
+use crate::{GuardedF64, UnguardedF64};
+
+/// Options that control when `find_root_newton` stops iterating.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::roots::NewtonOptions;
+///
+/// let options = NewtonOptions::default()
+///     .with_tolerance(GuardedF64::new(1e-6).unwrap())
+///     .with_max_iterations(20);
+/// assert_eq!(options.max_iterations(), 20);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NewtonOptions {
+    tolerance: GuardedF64,
+    max_iterations: usize,
+}
+
+impl Default for NewtonOptions {
+    /// Returns options with a tolerance of `1e-12` and a limit of 100 iterations.
+    fn default() -> Self {
+        Self {
+            tolerance: GuardedF64(1e-12),
+            max_iterations: 100,
+        }
+    }
+}
+
+impl NewtonOptions {
+    /// Returns the options with the tolerance replaced. Iteration stops once a step is no larger
+    /// than the tolerance.
+    #[must_use]
+    pub const fn with_tolerance(self, tolerance: GuardedF64) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Returns the options with the iteration limit replaced.
+    #[must_use]
+    pub const fn with_max_iterations(self, max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            ..self
+        }
+    }
+
+    /// Returns the largest step that is considered converged.
+    #[must_use]
+    pub const fn tolerance(&self) -> GuardedF64 {
+        self.tolerance
+    }
+
+    /// Returns the maximum number of iterations.
+    #[must_use]
+    pub const fn max_iterations(&self) -> usize {
+        self.max_iterations
+    }
+}
+
+/// Finds a root of `f` near `x0` with Newton's method, using `df` as the derivative of `f`.
+///
+/// Each iteration steps from `x` to `x - f(x) / df(x)`, and the search succeeds once a step is no
+/// larger than the tolerance in `options`, or `f(x)` is exactly zero.
+///
+/// # Errors
+///
+/// Returns `RootError::Function` if `f` or `df` returns NaN or infinity,
+/// `RootError::ZeroDerivative` if `df` returns zero, `RootError::Diverged` if a step is not finite,
+/// or `RootError::NoConvergence` if the iteration limit is reached first.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::roots::{find_root_newton, NewtonOptions, RootError};
+///
+/// let x0 = GuardedF64::new(1.0).unwrap();
+/// let root = find_root_newton(|x| x * x - 2.0, |x| x * 2.0, x0, NewtonOptions::default()).unwrap();
+/// assert!((root - std::f64::consts::SQRT_2).abs().check().unwrap() < 1e-12);
+///
+/// let zero = GuardedF64::new(0.0).unwrap();
+/// let result = find_root_newton(|x| x * x - 2.0, |x| x * 2.0, zero, NewtonOptions::default());
+/// assert_eq!(result, Err(RootError::ZeroDerivative { iteration: 0 }));
+/// ```
+pub fn find_root_newton(
+    mut f: impl FnMut(GuardedF64) -> UnguardedF64,
+    mut df: impl FnMut(GuardedF64) -> UnguardedF64,
+    x0: GuardedF64,
+    options: NewtonOptions,
+) -> Result<GuardedF64, RootError> {
+    let mut x = x0;
+    for iteration in 0..options.max_iterations {
+        let check = |value: UnguardedF64| {
+            value
+                .check_reported()
+                .map_err(|error| RootError::Function { iteration, error })
+        };
+
+        let fx = check(f(x))?;
+        if fx == 0.0 {
+            return Ok(x);
+        }
+
+        let dfx = check(df(x))?;
+        if dfx == 0.0 {
+            return Err(RootError::ZeroDerivative { iteration });
+        }
+
+        let step = (fx / dfx)
+            .check_reported()
+            .map_err(|_| RootError::Diverged { iteration })?;
+        x = (x - step)
+            .check_reported()
+            .map_err(|_| RootError::Diverged { iteration })?;
+
+        if step.abs() <= options.tolerance {
+            return Ok(x);
+        }
+    }
+
+    Err(RootError::NoConvergence {
+        iterations: options.max_iterations,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_failures() {
+        let x0 = GuardedF64::new(1.0).unwrap();
+        let options = NewtonOptions::default();
+
+        assert_eq!(
+            find_root_newton(|x| x - 1.0, |x| (x - 2.0).recip(), GuardedF64(2.0), options),
+            Err(RootError::Function {
+                iteration: 0,
+                error: FloatError::Infinity
+            })
+        );
+
+        // The cube root of x overshoots further on every step.
+        let cbrt = |x: GuardedF64| UnguardedF64(x.0.cbrt());
+        let dcbrt = |x: GuardedF64| UnguardedF64(x.0.cbrt().powi(2).recip() / 3.0);
+        assert_eq!(
+            find_root_newton(cbrt, dcbrt, x0, options.with_max_iterations(10)),
+            Err(RootError::NoConvergence { iterations: 10 })
+        );
+
+        let tiny = GuardedF64::new(1e-300).unwrap();
+        assert_eq!(
+            find_root_newton(
+                |_| UnguardedF64::new(1e300),
+                move |_| tiny.into(),
+                x0,
+                options
+            ),
+            Err(RootError::Diverged { iteration: 0 })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_finds_square_roots(square in 1e-6..1e6f64) {
+            let x0 = GuardedF64::new(square.max(1.0)).unwrap();
+            let root = find_root_newton(|x| x * x - square, |x| x * 2.0, x0, NewtonOptions::default()).unwrap();
+            prop_assert!((root - square.sqrt()).abs().check().unwrap() <= 1e-9 * square.sqrt());
+        }
+    }
+}
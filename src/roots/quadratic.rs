@@ -0,0 +1,169 @@
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// The real roots of a quadratic equation, as returned by `solve_quadratic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuadraticRoots {
+    /// The equation has no real roots.
+    None,
+
+    /// The equation has a single root, either because it is linear or because the discriminant is
+    /// zero.
+    One(GuardedF64),
+
+    /// The equation has two distinct roots, in ascending order.
+    Two(GuardedF64, GuardedF64),
+}
+
+/// Solves `a * x^2 + b * x + c = 0` for the real roots `x`.
+///
+/// The naive formula `(-b ± sqrt(b^2 - 4ac)) / 2a` loses most of its precision in the root where
+/// `-b` and the square root nearly cancel. This computes the larger root with the sign of `b`, so
+/// that nothing cancels, and the smaller root from the product of the roots, `c / a`. The
+/// coefficients are scaled by a power of two so that the discriminant cannot overflow, and the
+/// discriminant is computed with fused multiply-adds to keep nearly equal roots apart.
+///
+/// If `a` is zero, the equation is linear and has at most one root.
+///
+/// # Errors
+///
+/// Returns the error of the first coefficient that is NaN or infinite, `FloatError::Domain` if
+/// every coefficient is zero, so that every `x` is a root, or `FloatError::Infinity` if a root is
+/// too large to represent.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::roots::{QuadraticRoots, solve_quadratic};
+///
+/// let [one, two] = [1.0, 2.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(solve_quadratic(1.0, -3.0, 2.0), Ok(QuadraticRoots::Two(one, two)));
+/// assert_eq!(solve_quadratic(1.0, -2.0, 1.0), Ok(QuadraticRoots::One(one)));
+/// assert_eq!(solve_quadratic(1.0, 0.0, 1.0), Ok(QuadraticRoots::None));
+///
+/// // The small root is accurate even though `b * b` dwarfs `4 * a * c`.
+/// let Ok(QuadraticRoots::Two(_, small)) = solve_quadratic(1.0, 1e9, 1.0) else { panic!() };
+/// assert_eq!(small, -1e-9);
+///
+/// assert_eq!(solve_quadratic(0.0, 0.0, 0.0), Err(FloatError::Domain));
+/// assert_eq!(solve_quadratic(f64::NAN, 1.0, 1.0), Err(FloatError::NaN));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn solve_quadratic(
+    a: impl Into<UnguardedF64>,
+    b: impl Into<UnguardedF64>,
+    c: impl Into<UnguardedF64>,
+) -> Result<QuadraticRoots, FloatError> {
+    let (a, b, c) = (
+        a.into().check_reported()?.0,
+        b.into().check_reported()?.0,
+        c.into().check_reported()?.0,
+    );
+
+    if a == 0.0 {
+        return match (b == 0.0, c == 0.0) {
+            (true, true) => Err(FloatError::Domain),
+            (true, false) => Ok(QuadraticRoots::None),
+            (false, _) => UnguardedF64::new(-c / b)
+                .check_reported()
+                .map(QuadraticRoots::One),
+        };
+    }
+
+    let [a, b, c] = scale([a, b, c]);
+
+    // Kahan's discriminant: `w` is `4ac` rounded, and `error` is exactly what the rounding lost.
+    let w = 4.0 * a * c;
+    let error = (-4.0 * a).mul_add(c, w);
+    let discriminant = b.mul_add(b, -w) + error;
+
+    if discriminant < 0.0 {
+        return Ok(QuadraticRoots::None);
+    }
+    if discriminant == 0.0 {
+        return UnguardedF64::new(-0.5 * b / a)
+            .check_reported()
+            .map(QuadraticRoots::One);
+    }
+
+    let q = -0.5 * (b + discriminant.sqrt().copysign(b));
+    let first = UnguardedF64::new(q / a).check_reported()?;
+    let second = UnguardedF64::new(c / q).check_reported()?;
+    Ok(QuadraticRoots::Two(first.min(second), first.max(second)))
+}
+
+/// Scales the coefficients by the same power of two, so that the largest has a magnitude near one.
+/// This does not change the roots, and is exact unless a coefficient is negligibly small.
+fn scale(coefficients: [f64; 3]) -> [f64; 3] {
+    let largest = coefficients.iter().fold(0.0f64, |acc, v| acc.max(v.abs()));
+
+    #[allow(clippy::cast_possible_truncation)]
+    let shift = -(largest.log2().floor() as i32);
+
+    // Applying the shift in two halves keeps each factor finite for subnormal coefficients.
+    let (first, second) = (2f64.powi(shift / 2), 2f64.powi(shift - shift / 2));
+    coefficients.map(|v| v * first * second)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use proptest::prelude::*;
+
+    const fn two(first: f64, second: f64) -> QuadraticRoots {
+        QuadraticRoots::Two(GuardedF64(first), GuardedF64(second))
+    }
+
+    #[test]
+    fn test_special_cases() {
+        assert_eq!(
+            solve_quadratic(0.0, 2.0, -1.0),
+            Ok(QuadraticRoots::One(GuardedF64(0.5)))
+        );
+        assert_eq!(solve_quadratic(0.0, 0.0, 1.0), Ok(QuadraticRoots::None));
+        assert_eq!(
+            solve_quadratic(0.0, 1e-300, 1e300),
+            Err(FloatError::Infinity)
+        );
+        assert_eq!(
+            solve_quadratic(1.0, f64::INFINITY, 0.0),
+            Err(FloatError::Infinity)
+        );
+        assert_eq!(
+            solve_quadratic(1.0, 0.0, 0.0),
+            Ok(QuadraticRoots::One(GuardedF64(0.0)))
+        );
+        assert_eq!(solve_quadratic(2.0, 0.0, -8.0), Ok(two(-2.0, 2.0)));
+    }
+
+    #[test]
+    fn test_extreme_coefficients() {
+        for scale in [2f64.powi(1000), 2f64.powi(-1000), f64::MIN_POSITIVE / 4.0] {
+            assert_eq!(
+                solve_quadratic(scale, -3.0 * scale, 2.0 * scale),
+                Ok(two(1.0, 2.0))
+            );
+        }
+        assert_eq!(solve_quadratic(1e-310, 1.0, 1.0), Err(FloatError::Infinity));
+    }
+
+    proptest! {
+        #[test]
+        fn test_recovers_roots(first in -1e3..1e3f64, gap in 1.0..1e3f64, a in 1e-6..1e6f64, negate in any::<bool>()) {
+            let second = first + gap;
+            let a = if negate { -a } else { a };
+            let (b, c) = (-a * (first + second), a * first * second);
+
+            let roots = solve_quadratic(a, b, c);
+            let Ok(QuadraticRoots::Two(low, high)) = roots else {
+                return Err(TestCaseError::fail(format!("expected two roots, got {roots:?}")));
+            };
+
+            let scale = first.abs().max(second.abs()).max(1.0);
+            prop_assert!((low.0 - first).abs() <= 1e-9 * scale);
+            prop_assert!((high.0 - second).abs() <= 1e-9 * scale);
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! Deferred checking with a compile-time guarantee that the check is not forgotten.
+//!
+//! `UnguardedF64` already defers checking to the end of a computation, but nothing stops an
+//! unguarded value from being stored or returned without ever being checked. Inside `scope`,
+//! values are `Scoped`, which support the same infallible arithmetic but are tied to the lifetime
+//! of the closure, so the only way to get a value out is to return it and have it checked.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// An invariant lifetime marker, so a `Scoped<'s>` cannot be coerced to any other lifetime.
+type Invariant<'s> = PhantomData<fn(&'s ()) -> &'s ()>;
+
+/// A handle to a `scope`, used to bring values into it.
+#[derive(Clone, Copy)]
+pub struct Scope<'s> {
+    lifetime: Invariant<'s>,
+}
+
+impl<'s> Scope<'s> {
+    /// Brings a value into the scope.
+    ///
+    /// The value is not checked here, so invalid inputs are reported when the scope ends, like
+    /// invalid intermediate results.
+    #[must_use]
+    pub fn value(self, value: impl Into<UnguardedF64>) -> Scoped<'s> {
+        Scoped {
+            value: value.into(),
+            lifetime: PhantomData,
+        }
+    }
+}
+
+impl fmt::Debug for Scope<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Scope")
+    }
+}
+
+/// A value inside a `scope`, whose arithmetic never fails and which cannot leave the scope
+/// unchecked.
+///
+/// Arithmetic is supported with other `Scoped` values from the same scope and with anything that
+/// converts to `UnguardedF64`, along with a few common math functions.
+#[derive(Clone, Copy)]
+pub struct Scoped<'s> {
+    value: UnguardedF64,
+    lifetime: Invariant<'s>,
+}
+
+impl Scoped<'_> {
+    /// Applies a reusable operation on scoped values, such as a closure shared by several scopes.
+    ///
+    /// The operation receives the `Scoped` value rather than the `UnguardedF64` inside it, so it
+    /// cannot smuggle the value out of the scope unchecked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use floatguard::scope::scope;
+    ///
+    /// let hypot = scope(|s| s.value(3.0).map(|v| v * v + 16.0).sqrt());
+    /// assert_eq!(hypot, GuardedF64::new(5.0));
+    /// ```
+    ///
+    /// The operation cannot move the value out of the scope:
+    ///
+    /// ```rust,compile_fail
+    /// use floatguard::scope::scope;
+    ///
+    /// let mut leaked = None;
+    /// let _ = scope(|s| {
+    ///     s.value(1.0).map(|v| {
+    ///         leaked = Some(v / 0.0);
+    ///         v
+    ///     })
+    /// });
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn map(self, operation: impl FnOnce(Self) -> Self) -> Self {
+        operation(self)
+    }
+
+    /// Raises the value to an integer power, like `UnguardedF64::powi`.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn powi(self, power: i32) -> Self {
+        Self {
+            value: self.value.powi(power),
+            lifetime: PhantomData,
+        }
+    }
+}
+
+macro_rules! scoped_math {
+    ($( $name:ident ),* $(,)?) => {
+        impl Scoped<'_> {
+            $(
+                #[doc = concat!("Computes `", stringify!($name), "` of the value, like `UnguardedF64::", stringify!($name), "`.")]
+                #[must_use = "method returns a new value and does not mutate the original value"]
+                pub fn $name(self) -> Self {
+                    Self {
+                        value: self.value.$name(),
+                        lifetime: PhantomData,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+scoped_math!(abs, sqrt, exp, ln, sin, cos, tan);
+
+impl fmt::Debug for Scoped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Scoped").field(&self.value.0).finish()
+    }
+}
+
+/// Runs `body` with a scope for deferred arithmetic, and checks its result once at the end.
+///
+/// The `Scoped` values created inside `body` cannot be stored outside of it or returned from it
+/// any other way, so every computation that leaves the scope has been checked.
+///
+/// # Errors
+///
+/// Returns `FloatError` if the value returned by `body` is NaN or infinite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::scope::scope;
+///
+/// let mean = scope(|s| (s.value(1.5) + 2.5 + s.value(5.0)) / 3.0);
+/// assert_eq!(mean, GuardedF64::new(3.0));
+///
+/// let ratio = scope(|s| s.value(1.0) / 0.0 * 0.0);
+/// assert_eq!(ratio, Err(FloatError::NaN));
+/// ```
+///
+/// Scoped values cannot escape without being checked:
+///
+/// ```rust,compile_fail
+/// use floatguard::scope::scope;
+///
+/// let mut escaped = None;
+/// let _ = scope(|s| {
+///     let value = s.value(1.0);
+///     escaped = Some(value);
+///     value
+/// });
+/// ```
+pub fn scope(body: impl for<'s> FnOnce(Scope<'s>) -> Scoped<'s>) -> Result<GuardedF64, FloatError> {
+    let scoped = body(Scope {
+        lifetime: PhantomData,
+    });
+    scoped.value.check_reported()
+}
+
+macro_rules! scoped_operation {
+    ($( impl $op_trait:ident, $op:ident; )*) => {
+        $(
+            impl<'s> $op_trait for Scoped<'s> {
+                type Output = Self;
+
+                #[inline]
+                fn $op(self, rhs: Self) -> Self {
+                    Self {
+                        value: self.value.$op(rhs.value),
+                        lifetime: PhantomData,
+                    }
+                }
+            }
+
+            impl<T: Into<UnguardedF64>> $op_trait<T> for Scoped<'_> {
+                type Output = Self;
+
+                #[inline]
+                fn $op(self, rhs: T) -> Self {
+                    Self {
+                        value: self.value.$op(rhs.into()),
+                        lifetime: PhantomData,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+scoped_operation! {
+    impl Add, add;
+    impl Sub, sub;
+    impl Mul, mul;
+    impl Div, div;
+    impl Rem, rem;
+}
+
+impl Neg for Scoped<'_> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            value: -self.value,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_matches_unguarded(a in valid_f64(), b in valid_f64()) {
+            let expected = ((UnguardedF64::new(a) - b) * a / b % 7.0).check();
+            prop_assert_eq!(scope(|s| (s.value(a) - s.value(b)) * a / b % 7.0), expected);
+            prop_assert_eq!(scope(|s| -s.value(a)), GuardedF64::new(-a));
+            prop_assert_eq!(
+                scope(|s| s.value(a).map(|v| v.abs().sqrt()).powi(2)),
+                UnguardedF64::new(a).abs().sqrt().powi(2).check()
+            );
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// Reinterprets a slice of `UnguardedF64` values as raw `f64` values, without copying.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnguardedF64;
+/// use floatguard::slice::as_raw_slice;
+///
+/// let values = [UnguardedF64::new(1.0), UnguardedF64::new(f64::NAN)];
+/// let raw = as_raw_slice(&values);
+/// assert_eq!(raw[0], 1.0);
+/// assert!(raw[1].is_nan());
+/// ```
+#[must_use]
+pub const fn as_raw_slice(values: &[UnguardedF64]) -> &[f64] {
+    // SAFETY: `UnguardedF64` is `repr(transparent)` over `f64`, so the two have the same layout, and
+    // every `f64` is a valid `UnguardedF64`.
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+}
+
+/// Reinterprets a mutable slice of `UnguardedF64` values as raw `f64` values, without copying.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{GuardedF64, UnguardedF64};
+/// use floatguard::slice::as_raw_slice_mut;
+///
+/// let mut values = [UnguardedF64::new(1.0)];
+/// as_raw_slice_mut(&mut values)[0] = 2.0;
+/// assert_eq!(values[0].check(), GuardedF64::new(2.0));
+/// ```
+#[must_use]
+pub const fn as_raw_slice_mut(values: &mut [UnguardedF64]) -> &mut [f64] {
+    // SAFETY: `UnguardedF64` is `repr(transparent)` over `f64`, and any `f64` written through the
+    // result is a valid `UnguardedF64`.
+    unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len()) }
+}
+
+/// Reinterprets a slice of raw `f64` values as `UnguardedF64` values, without copying, so an existing
+/// buffer can be used with the lazy API.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::slice::from_raw_slice_unguarded;
+///
+/// let buffer = [1.0, 2.0, f64::INFINITY];
+/// let values = from_raw_slice_unguarded(&buffer);
+/// assert_eq!((values[0] + values[1]).check(), GuardedF64::new(3.0));
+/// assert!(values[2].check().is_err());
+/// ```
+#[must_use]
+pub const fn from_raw_slice_unguarded(values: &[f64]) -> &[UnguardedF64] {
+    // SAFETY: `UnguardedF64` is `repr(transparent)` over `f64`, and does not require its value to be
+    // finite.
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+}
+
+/// Reinterprets a mutable slice of raw `f64` values as `UnguardedF64` values, without copying.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::UnguardedF64;
+/// use floatguard::slice::from_raw_slice_unguarded_mut;
+///
+/// let mut buffer = [1.0, 2.0];
+/// for value in from_raw_slice_unguarded_mut(&mut buffer) {
+///     *value = *value * 2.0;
+/// }
+/// assert_eq!(buffer, [2.0, 4.0]);
+/// ```
+#[must_use]
+pub const fn from_raw_slice_unguarded_mut(values: &mut [f64]) -> &mut [UnguardedF64] {
+    // SAFETY: `UnguardedF64` is `repr(transparent)` over `f64`, and does not require its value to be
+    // finite.
+    unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len()) }
+}
+
+/// Reinterprets a slice of raw `f64` values as `GuardedF64` values, without copying, after
+/// validating every value.
+///
+/// Unlike the unguarded direction, this cannot be a plain cast: a `GuardedF64` promises that its
+/// value is finite, and the rest of the crate relies on that promise, so each value is checked
+/// once, up front. There is no mutable version, because writing a NaN through the `f64` slice
+/// afterward would break the promise of the `GuardedF64` slice.
+///
+/// With the `simd` feature enabled, the values are scanned several at a time, using WebAssembly
+/// SIMD instructions on `wasm32` targets that enable `simd128`. The same error is reported either
+/// way.
+///
+/// # Errors
+///
+/// Returns the `FloatError` of the first value that is NaN or infinite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::FloatError;
+/// use floatguard::slice::from_raw_slice_guarded;
+///
+/// let buffer = [1.0, 2.0];
+/// let values = from_raw_slice_guarded(&buffer).unwrap();
+/// assert_eq!(values[1], 2.0);
+///
+/// assert_eq!(from_raw_slice_guarded(&[1.0, f64::NAN]), Err(FloatError::NaN));
+/// ```
+pub fn from_raw_slice_guarded(values: &[f64]) -> Result<&[GuardedF64], FloatError> {
+    check_all(values)?;
+
+    // SAFETY: Every value was checked to be finite above.
+    Ok(unsafe { assume_guarded(values) })
+}
+
+/// Reinterprets a slice of raw `f64` values as `GuardedF64` values, without checking them.
+///
+/// # Safety
+///
+/// Every value in `values` must be finite.
+pub const unsafe fn assume_guarded(values: &[f64]) -> &[GuardedF64] {
+    // SAFETY: `GuardedF64` is `repr(transparent)` over `f64`, so the two have the same layout, and
+    // the caller guarantees that every value is finite. The shared borrow of `values` prevents them
+    // from changing afterward.
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+}
+
+/// Checks each value in turn, stopping at the first one that is NaN or infinite.
+#[cfg(not(feature = "simd"))]
+fn check_all(values: &[f64]) -> Result<(), FloatError> {
+    for &value in values {
+        UnguardedF64(value).check_reported()?;
+    }
+    Ok(())
+}
+
+/// Scans the values several at a time, and checks the first one that is NaN or infinite.
+#[cfg(feature = "simd")]
+fn check_all(values: &[f64]) -> Result<(), FloatError> {
+    if let Some(index) = super::lanes::position_non_finite(values) {
+        // Only the invalid value is reported to hooks and statistics.
+        UnguardedF64(values[index]).check_reported()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_round_trip(values in prop::collection::vec(any::<f64>(), 0..32)) {
+            let bits = |values: &[f64]| values.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+
+            let unguarded = from_raw_slice_unguarded(&values);
+            prop_assert_eq!(unguarded.len(), values.len());
+            prop_assert_eq!(bits(as_raw_slice(unguarded)), bits(&values));
+
+            let expected = values.iter().map(|&v| GuardedF64::new(v)).collect::<Result<Vec<_>, _>>();
+            prop_assert_eq!(from_raw_slice_guarded(&values).map(<[_]>::to_vec), expected);
+        }
+
+        #[test]
+        fn test_mutation(mut values in prop::collection::vec(valid_f64(), 0..32)) {
+            let expected = values.iter().map(|v| -v).collect::<Vec<_>>();
+            for value in from_raw_slice_unguarded_mut(&mut values) {
+                *value = -*value;
+            }
+            prop_assert_eq!(&values, &expected);
+
+            let mut unguarded = values.iter().map(|&v| UnguardedF64(v)).collect::<Vec<_>>();
+            as_raw_slice_mut(&mut unguarded).copy_from_slice(&expected);
+            prop_assert_eq!(as_raw_slice(&unguarded), &expected[..]);
+        }
+    }
+}
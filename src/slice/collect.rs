@@ -0,0 +1,95 @@
+use super::SliceError;
+use crate::{GuardedF64, UnguardedF64};
+
+/// Collects an iterator of raw values into a `Vec<GuardedF64>`, validating each value and reporting
+/// the index of the first invalid one.
+///
+/// This is implemented for every iterator whose items convert into `UnguardedF64`, such as `f64`,
+/// `&f64`, and the guarded and unguarded types, so ingesting raw floats into guarded storage is a
+/// single call. Collection stops at the first invalid value.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::slice::{SliceError, TryCollectGuarded};
+///
+/// let values = [1.0, 2.0, 3.0].iter().try_collect_guarded().unwrap();
+/// assert_eq!(values, [1.0, 2.0, 3.0].map(|v| GuardedF64::new(v).unwrap()));
+///
+/// let raw = vec![1.0, 2.0, f64::NAN, f64::INFINITY];
+/// assert_eq!(
+///     raw.into_iter().try_collect_guarded(),
+///     Err(SliceError::Invalid { index: 2, error: FloatError::NaN })
+/// );
+/// ```
+pub trait TryCollectGuarded: Iterator {
+    /// Validates every item and collects them into a `Vec<GuardedF64>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SliceError::Invalid` with the index and error of the first item that is NaN or
+    /// infinite.
+    fn try_collect_guarded(self) -> Result<Vec<GuardedF64>, SliceError>;
+}
+
+impl<I> TryCollectGuarded for I
+where
+    I: Iterator,
+    I::Item: Into<UnguardedF64>,
+{
+    fn try_collect_guarded(self) -> Result<Vec<GuardedF64>, SliceError> {
+        self.enumerate()
+            .map(|(index, value)| {
+                value
+                    .into()
+                    .check_reported()
+                    .map_err(|error| SliceError::Invalid { index, error })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_matches_sequential_checks(values in prop::collection::vec(any::<f64>(), 0..32)) {
+            let expected = values
+                .iter()
+                .position(|value| !value.is_finite())
+                .map_or_else(
+                    || Ok(values.iter().map(|&v| GuardedF64(v)).collect::<Vec<_>>()),
+                    |index| Err(SliceError::Invalid {
+                        index,
+                        error: GuardedF64::new(values[index]).unwrap_err(),
+                    }),
+                );
+
+            prop_assert_eq!(&values.iter().try_collect_guarded(), &expected);
+            prop_assert_eq!(values.iter().map(|&v| UnguardedF64::new(v)).try_collect_guarded(), expected);
+        }
+    }
+
+    #[test]
+    fn test_stops_at_first_invalid() {
+        let mut seen = 0;
+        let result = [1.0, f64::INFINITY, f64::NAN]
+            .into_iter()
+            .inspect(|_| seen += 1)
+            .try_collect_guarded();
+
+        assert_eq!(
+            result,
+            Err(SliceError::Invalid {
+                index: 1,
+                error: FloatError::Infinity
+            })
+        );
+        assert_eq!(seen, 2);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::GuardedF64;
+
+/// Sorts `values` in ascending order and removes every value that is within `tolerance` of the
+/// last value that was kept, so that the values that remain are more than `tolerance` apart.
+///
+/// Each run of close values is merged into its smallest value. Values are compared to the value
+/// that was kept rather than to their neighbor, so a long chain of values that are each within
+/// `tolerance` of the next is split into several values instead of collapsing into one. A negative
+/// tolerance keeps every value, including exact duplicates.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::slice::dedup_approx;
+///
+/// let mut values = vec![1.0, 3.0, 1.05, 0.98, 3.08, 3.5]
+///     .into_iter()
+///     .map(|v| GuardedF64::new(v).unwrap())
+///     .collect::<Vec<_>>();
+/// dedup_approx(&mut values, GuardedF64::new(0.1).unwrap());
+/// assert_eq!(values, [0.98, 3.0, 3.5].map(|v| GuardedF64::new(v).unwrap()));
+/// ```
+pub fn dedup_approx(values: &mut Vec<GuardedF64>, tolerance: GuardedF64) {
+    values.sort_unstable();
+    values.dedup_by(|value, kept| {
+        (*value - *kept)
+            .check_reported()
+            .is_ok_and(|distance| distance <= tolerance)
+    });
+}
+
+/// Returns the values of `values` that are more than `tolerance` apart, in ascending order, like
+/// `dedup_approx` but without modifying the input.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::slice::unique_approx;
+///
+/// let values = [0.3, 0.1 + 0.2, 0.7].map(|v| GuardedF64::new(v).unwrap());
+/// let unique = unique_approx(&values, GuardedF64::new(1e-12).unwrap());
+/// assert_eq!(unique, [0.3, 0.7].map(|v| GuardedF64::new(v).unwrap()));
+/// ```
+#[must_use = "function returns a new vector and does not modify the input"]
+pub fn unique_approx(values: &[GuardedF64], tolerance: GuardedF64) -> Vec<GuardedF64> {
+    let mut unique = values.to_vec();
+    dedup_approx(&mut unique, tolerance);
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn guarded(values: &[f64]) -> Vec<GuardedF64> {
+        values.iter().map(|&v| GuardedF64(v)).collect()
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let mut values = guarded(&[2.0, 2.0, -0.0, 0.0]);
+        dedup_approx(&mut values, GuardedF64(-1.0));
+        assert_eq!(values.len(), 4);
+
+        dedup_approx(&mut values, GuardedF64(0.0));
+        assert_eq!(values, guarded(&[0.0, 2.0]));
+
+        let extremes = guarded(&[f64::MAX, f64::MIN]);
+        assert_eq!(
+            unique_approx(&extremes, GuardedF64(f64::MAX)),
+            guarded(&[f64::MIN, f64::MAX])
+        );
+
+        let chain = guarded(&[0.0, 0.6, 1.2, 1.8]);
+        assert_eq!(unique_approx(&chain, GuardedF64(1.0)), guarded(&[0.0, 1.2]));
+    }
+
+    proptest! {
+        #[test]
+        fn test_unique_approx(values in prop::collection::vec(valid_f64(), 0..64), tolerance in 0.0..1e300f64) {
+            let values = guarded(&values);
+            let tolerance = GuardedF64(tolerance);
+            let unique = unique_approx(&values, tolerance);
+
+            prop_assert!(unique.len() <= values.len());
+            for pair in unique.windows(2) {
+                prop_assert!(!(pair[1] - pair[0]).check().is_ok_and(|distance| distance <= tolerance));
+            }
+            for value in &values {
+                let index = unique.partition_point(|kept| kept <= value);
+                prop_assert!(index > 0);
+                prop_assert!((*value - unique[index - 1]).check().unwrap() <= tolerance);
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use super::SliceError;
+use crate::eft::{two_prod, two_sum};
+use crate::{GuardedF64, UnguardedF64};
+
+/// Computes the dot product of two slices of guarded values.
+///
+/// With the `simd` feature enabled, the products are accumulated in several independent lanes that
+/// the compiler can vectorize, which is faster for long slices but sums the products in a
+/// different order than a sequential loop, so the result may differ in the last few bits. On
+/// `wasm32` targets compiled with the `simd128` target feature, the lanes use WebAssembly SIMD
+/// instructions, and produce the same bits as on other targets.
+///
+/// # Errors
+///
+/// Returns `SliceError::LengthMismatch` if the slices have different lengths, or
+/// `SliceError::Float` if the result is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::slice::{dot, SliceError};
+///
+/// let a = [1.0, 2.0, 3.0].map(|v| GuardedF64::new(v).unwrap());
+/// let b = [4.0, -5.0, 6.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(dot(&a, &b), Ok(GuardedF64::new(12.0).unwrap()));
+///
+/// assert_eq!(dot(&a, &b[..2]), Err(SliceError::LengthMismatch { left: 3, right: 2 }));
+///
+/// let large = [GuardedF64::MAX, GuardedF64::MAX];
+/// assert_eq!(dot(&large, &large), Err(SliceError::Float(FloatError::Infinity)));
+/// ```
+pub fn dot(a: &[GuardedF64], b: &[GuardedF64]) -> Result<GuardedF64, SliceError> {
+    check_lengths(a, b)?;
+    Ok(accumulate(a, b).check_reported()?)
+}
+
+/// Computes the dot product of two slices of guarded values with compensated accumulation.
+///
+/// Uses the `Dot2` algorithm of Ogita, Rump, and Oishi, which tracks the rounding errors of every
+/// product and partial sum with error-free transformations and adds them back at the end. The
+/// result is as accurate as if it had been computed in twice the working precision and then
+/// rounded, at roughly four times the cost of [`dot`].
+///
+/// # Errors
+///
+/// Returns `SliceError::LengthMismatch` if the slices have different lengths, or
+/// `SliceError::Float` if the result or any intermediate product or partial sum is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::slice::dot_compensated;
+///
+/// let a = [1e16, 1.0, -1e16].map(|v| GuardedF64::new(v).unwrap());
+/// let b = [1.0, 1.0, 1.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(dot_compensated(&a, &b), Ok(GuardedF64::new(1.0).unwrap()));
+/// ```
+pub fn dot_compensated(a: &[GuardedF64], b: &[GuardedF64]) -> Result<GuardedF64, SliceError> {
+    check_lengths(a, b)?;
+
+    let mut sum = GuardedF64(0.0);
+    let mut compensation = UnguardedF64::new(0.0);
+    for (x, y) in a.iter().zip(b) {
+        let (product, product_error) = two_prod(*x, *y)?;
+        let (next, sum_error) = two_sum(sum, product)?;
+        sum = next;
+        compensation += product_error + sum_error;
+    }
+
+    Ok((sum + compensation).check_reported()?)
+}
+
+/// Returns `SliceError::LengthMismatch` if the slices have different lengths.
+const fn check_lengths(a: &[GuardedF64], b: &[GuardedF64]) -> Result<(), SliceError> {
+    if a.len() == b.len() {
+        Ok(())
+    } else {
+        Err(SliceError::LengthMismatch {
+            left: a.len(),
+            right: b.len(),
+        })
+    }
+}
+
+/// Sums the products of the slices sequentially.
+#[cfg(not(feature = "simd"))]
+fn accumulate(a: &[GuardedF64], b: &[GuardedF64]) -> UnguardedF64 {
+    a.iter()
+        .zip(b)
+        .fold(UnguardedF64::new(0.0), |sum, (x, y)| sum + x * y)
+}
+
+/// Sums the products of the slices in independent lanes, so that the loop can be vectorized.
+#[cfg(feature = "simd")]
+fn accumulate(a: &[GuardedF64], b: &[GuardedF64]) -> UnguardedF64 {
+    super::lanes::dot(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use proptest::prelude::*;
+
+    fn small_f64() -> impl Strategy<Value = GuardedF64> {
+        (-1e6..1e6f64).prop_map(|v| GuardedF64::new(v).unwrap())
+    }
+
+    fn pair() -> impl Strategy<Value = (Vec<GuardedF64>, Vec<GuardedF64>)> {
+        (0usize..64).prop_flat_map(|len| {
+            (
+                prop::collection::vec(small_f64(), len),
+                prop::collection::vec(small_f64(), len),
+            )
+        })
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(dot(&[], &[]), Ok(GuardedF64(0.0)));
+        assert_eq!(dot_compensated(&[], &[]), Ok(GuardedF64(0.0)));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let large = [GuardedF64::MAX; 8];
+        let error = Err(SliceError::Float(FloatError::Infinity));
+        assert_eq!(dot(&large, &large), error);
+        assert_eq!(dot_compensated(&large, &large), error);
+    }
+
+    proptest! {
+        #[test]
+        fn test_dot_matches_compensated((a, b) in pair()) {
+            let expected = dot_compensated(&a, &b).unwrap();
+            let magnitude = a.iter().zip(&b).map(|(x, y)| (x.0 * y.0).abs()).sum::<f64>();
+
+            let value = dot(&a, &b).unwrap();
+            prop_assert!((value - expected).abs().check().unwrap() <= magnitude * 1e-12);
+        }
+
+        #[test]
+        fn test_length_mismatch((a, mut longer) in pair(), extra in small_f64()) {
+            longer.push(extra);
+
+            let error = Err(SliceError::LengthMismatch { left: a.len(), right: longer.len() });
+            prop_assert_eq!(dot(&a, &longer), error);
+            prop_assert_eq!(dot_compensated(&a, &longer), error);
+        }
+    }
+}
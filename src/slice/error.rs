@@ -0,0 +1,79 @@
+use crate::FloatError;
+
+/// An error occurred while processing a slice of guarded values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    /// Indicates that two slices that must have the same length do not.
+    LengthMismatch {
+        /// The length of the left-hand slice.
+        left: usize,
+
+        /// The length of the right-hand slice.
+        right: usize,
+    },
+
+    /// Indicates that a buffer of pairs, such as interleaved complex numbers, has an odd length.
+    OddLength {
+        /// The length of the buffer.
+        len: usize,
+    },
+
+    /// Indicates that a slice that must be sorted in ascending order is not.
+    Unsorted {
+        /// The index of the first value that is less than the value before it.
+        index: usize,
+    },
+
+    /// Indicates that a value being validated is NaN or infinite.
+    Invalid {
+        /// The index of the first invalid value.
+        index: usize,
+
+        /// The error produced by checking the value.
+        error: FloatError,
+    },
+
+    /// Indicates that the result of the computation was not a valid floating-point value.
+    Float(FloatError),
+}
+
+impl From<FloatError> for SliceError {
+    /// Wraps a `FloatError` produced while checking the result of a reduction.
+    fn from(value: FloatError) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl std::error::Error for SliceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid { error, .. } | Self::Float(error) => Some(error),
+            Self::LengthMismatch { .. } | Self::OddLength { .. } | Self::Unsorted { .. } => None,
+        }
+    }
+}
+
+/// Implements the `Display` trait for the `SliceError` enum, providing a user-friendly
+/// description of the error.
+impl std::fmt::Display for SliceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LengthMismatch { left, right } => {
+                write!(f, "The slices have different lengths: {left} and {right}")
+            }
+            Self::OddLength { len } => {
+                write!(f, "The buffer has an odd length: {len}")
+            }
+            Self::Unsorted { index } => {
+                write!(
+                    f,
+                    "The value at index {index} is less than the value before it"
+                )
+            }
+            Self::Invalid { index, error } => {
+                write!(f, "The value at index {index} is invalid: {error}")
+            }
+            Self::Float(error) => error.fmt(f),
+        }
+    }
+}
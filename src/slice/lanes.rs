@@ -0,0 +1,168 @@
+//! Lane-parallel kernels for the `simd` feature.
+//!
+//! Every kernel splits its input into chunks of `LANES` values, accumulates each position of the
+//! chunks in its own lane, and then folds the lanes into the sequential result for the remainder.
+//! On `wasm32` with the `simd128` target feature, the lanes are `v128` registers driven by
+//! `core::arch::wasm32` intrinsics; everywhere else they are plain arrays that the compiler can
+//! vectorize. Both versions perform the same operations in the same order, so they produce the
+//! same bits.
+use crate::{GuardedF64, UnguardedF64};
+
+/// The number of independent accumulators.
+const LANES: usize = 4;
+
+#[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+use portable as kernel;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use wasm32 as kernel;
+
+/// Returns the index of the first value that is NaN or infinite.
+pub fn position_non_finite(values: &[f64]) -> Option<usize> {
+    let (chunks, _) = values.as_chunks::<LANES>();
+
+    // Only the chunk that contains the first invalid value, or the remainder, is scanned one value
+    // at a time.
+    let start = chunks
+        .iter()
+        .position(|chunk| !kernel::all_finite(chunk))
+        .unwrap_or(chunks.len())
+        * LANES;
+    values
+        .get(start..)?
+        .iter()
+        .position(|value| !value.is_finite())
+        .map(|index| start + index)
+}
+
+/// Sums the values in independent lanes.
+pub fn sum(values: &[GuardedF64]) -> UnguardedF64 {
+    let (chunks, tail) = values.as_chunks::<LANES>();
+    let tail = tail
+        .iter()
+        .fold(UnguardedF64::new(0.0), |sum, value| sum + value);
+
+    kernel::sum(chunks)
+        .into_iter()
+        .fold(tail, |sum, lane| sum + lane)
+}
+
+/// Sums the products of two slices of the same length in independent lanes.
+pub fn dot(a: &[GuardedF64], b: &[GuardedF64]) -> UnguardedF64 {
+    let (a_chunks, a_tail) = a.as_chunks::<LANES>();
+    let (b_chunks, b_tail) = b.as_chunks::<LANES>();
+    let tail = a_tail
+        .iter()
+        .zip(b_tail)
+        .fold(UnguardedF64::new(0.0), |sum, (x, y)| sum + x * y);
+
+    kernel::dot(a_chunks, b_chunks)
+        .into_iter()
+        .fold(tail, |sum, lane| sum + lane)
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+mod portable {
+    use super::LANES;
+    use crate::GuardedF64;
+
+    /// Returns `true` if every value in the chunk is finite, without branching between values.
+    pub fn all_finite(chunk: &[f64; LANES]) -> bool {
+        chunk
+            .iter()
+            .fold(true, |finite, value| finite & value.is_finite())
+    }
+
+    pub fn sum(chunks: &[[GuardedF64; LANES]]) -> [f64; LANES] {
+        let mut lanes = [0.0; LANES];
+        for chunk in chunks {
+            for (lane, value) in lanes.iter_mut().zip(chunk) {
+                *lane += value.0;
+            }
+        }
+        lanes
+    }
+
+    pub fn dot(a: &[[GuardedF64; LANES]], b: &[[GuardedF64; LANES]]) -> [f64; LANES] {
+        let mut lanes = [0.0; LANES];
+        for (x, y) in a.iter().zip(b) {
+            for ((lane, x), y) in lanes.iter_mut().zip(x).zip(y) {
+                *lane += x.0 * y.0;
+            }
+        }
+        lanes
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm32 {
+    use super::LANES;
+    use crate::GuardedF64;
+    use core::arch::wasm32::{
+        f64x2, f64x2_abs, f64x2_add, f64x2_extract_lane, f64x2_lt, f64x2_mul, f64x2_splat,
+        i64x2_all_true, v128, v128_and,
+    };
+
+    /// Splits a chunk into its low and high pairs of lanes.
+    const fn split(chunk: &[GuardedF64; LANES]) -> (v128, v128) {
+        let [a, b, c, d] = *chunk;
+        (f64x2(a.0, b.0), f64x2(c.0, d.0))
+    }
+
+    /// Joins the low and high pairs of lanes back into an array.
+    fn join(low: v128, high: v128) -> [f64; LANES] {
+        [
+            f64x2_extract_lane::<0>(low),
+            f64x2_extract_lane::<1>(low),
+            f64x2_extract_lane::<0>(high),
+            f64x2_extract_lane::<1>(high),
+        ]
+    }
+
+    /// Returns `true` if every value in the chunk is finite. NaN compares false, so it fails the
+    /// comparison along with the infinities.
+    pub fn all_finite(chunk: &[f64; LANES]) -> bool {
+        let [a, b, c, d] = *chunk;
+        let infinity = f64x2_splat(f64::INFINITY);
+        let low = f64x2_lt(f64x2_abs(f64x2(a, b)), infinity);
+        let high = f64x2_lt(f64x2_abs(f64x2(c, d)), infinity);
+        i64x2_all_true(v128_and(low, high))
+    }
+
+    pub fn sum(chunks: &[[GuardedF64; LANES]]) -> [f64; LANES] {
+        let (mut low, mut high) = (f64x2_splat(0.0), f64x2_splat(0.0));
+        for chunk in chunks {
+            let (x_low, x_high) = split(chunk);
+            low = f64x2_add(low, x_low);
+            high = f64x2_add(high, x_high);
+        }
+        join(low, high)
+    }
+
+    pub fn dot(a: &[[GuardedF64; LANES]], b: &[[GuardedF64; LANES]]) -> [f64; LANES] {
+        let (mut low, mut high) = (f64x2_splat(0.0), f64x2_splat(0.0));
+        for (x, y) in a.iter().zip(b) {
+            let ((x_low, x_high), (y_low, y_high)) = (split(x), split(y));
+            low = f64x2_add(low, f64x2_mul(x_low, y_low));
+            high = f64x2_add(high, f64x2_mul(x_high, y_high));
+        }
+        join(low, high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_position_non_finite(
+            values in prop::collection::vec(prop_oneof![4 => -1e6..1e6f64, 1 => any::<f64>()], 0..32)
+        ) {
+            prop_assert_eq!(
+                position_non_finite(&values),
+                values.iter().position(|value| !value.is_finite())
+            );
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::GuardedSortedSlice;
+use crate::GuardedF64;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Merges two sorted views into a single sorted `Vec`, in linear time.
+///
+/// The merge is stable: when values from `a` and `b` compare equal, like `0.0` and `-0.0`, the
+/// values from `a` come first, so the result is exactly what a stable sort of `a` followed by `b`
+/// would produce.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::slice::{GuardedSortedSlice, merge_sorted};
+///
+/// let a = [1.0, 3.0, 5.0].map(|v| GuardedF64::new(v).unwrap());
+/// let b = [2.0, 3.0, 8.0].map(|v| GuardedF64::new(v).unwrap());
+///
+/// let merged = merge_sorted(GuardedSortedSlice::new(&a).unwrap(), GuardedSortedSlice::new(&b).unwrap());
+/// assert_eq!(merged, [1.0, 2.0, 3.0, 3.0, 5.0, 8.0].map(|v| GuardedF64::new(v).unwrap()));
+/// ```
+#[must_use]
+pub fn merge_sorted(a: GuardedSortedSlice<'_>, b: GuardedSortedSlice<'_>) -> Vec<GuardedF64> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut a, mut b) = (a.as_slice(), b.as_slice());
+
+    while let (Some((x, a_rest)), Some((y, b_rest))) = (a.split_first(), b.split_first()) {
+        if y < x {
+            merged.push(*y);
+            b = b_rest;
+        } else {
+            merged.push(*x);
+            a = a_rest;
+        }
+    }
+
+    merged.extend_from_slice(a);
+    merged.extend_from_slice(b);
+    merged
+}
+
+/// Merges any number of sorted views into a single sorted `Vec`, in `O(n log k)` time for `n`
+/// values in `k` views.
+///
+/// Like `merge_sorted`, the merge is stable: values that compare equal keep the order of the views
+/// they came from.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::slice::{GuardedSortedSlice, merge_sorted_all};
+///
+/// let series = [vec![0.0, 30.0], vec![10.0, 40.0], vec![], vec![20.0]]
+///     .map(|s| s.into_iter().map(|v| GuardedF64::new(v).unwrap()).collect::<Vec<_>>());
+/// let views = series.each_ref().map(|s| GuardedSortedSlice::new(s).unwrap());
+///
+/// let merged = merge_sorted_all(&views);
+/// assert_eq!(merged, [0.0, 10.0, 20.0, 30.0, 40.0].map(|v| GuardedF64::new(v).unwrap()));
+/// ```
+#[must_use]
+pub fn merge_sorted_all(views: &[GuardedSortedSlice<'_>]) -> Vec<GuardedF64> {
+    let mut merged = Vec::with_capacity(views.iter().map(|view| view.len()).sum());
+
+    // The heap holds the next value of each view, and its index, which breaks ties between equal
+    // values in favor of the earlier view.
+    let mut remaining: Vec<&[GuardedF64]> =
+        views.iter().map(GuardedSortedSlice::as_slice).collect();
+    let mut heap: BinaryHeap<Reverse<(GuardedF64, usize)>> = remaining
+        .iter()
+        .enumerate()
+        .filter_map(|(index, view)| view.first().map(|value| Reverse((*value, index))))
+        .collect();
+
+    while let Some(Reverse((value, index))) = heap.pop() {
+        merged.push(value);
+        if let Some(view) = remaining.get_mut(index) {
+            *view = view.get(1..).unwrap_or_default();
+            if let Some(next) = view.first() {
+                heap.push(Reverse((*next, index)));
+            }
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn sorted_values() -> impl Strategy<Value = Vec<GuardedF64>> {
+        prop::collection::vec(
+            (-20..20i32).prop_map(|v| GuardedF64(f64::from(v) / 2.0)),
+            0..32,
+        )
+        .prop_map(|mut values| {
+            values.sort_unstable();
+            values
+        })
+    }
+
+    fn bits(values: &[GuardedF64]) -> Vec<u64> {
+        values.iter().map(|value| value.0.to_bits()).collect()
+    }
+
+    #[test]
+    fn test_stable_for_signed_zeros() {
+        let a = [GuardedF64(0.0), GuardedF64(-0.0)];
+        let b = [GuardedF64(-0.0), GuardedF64(0.0)];
+        let (a, b) = (
+            GuardedSortedSlice::new(&a).unwrap(),
+            GuardedSortedSlice::new(&b).unwrap(),
+        );
+
+        let expected = bits(&[a[0], a[1], b[0], b[1]]);
+        assert_eq!(bits(&merge_sorted(a, b)), expected);
+        assert_eq!(bits(&merge_sorted_all(&[a, b])), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_stable_sort(series in prop::collection::vec(sorted_values(), 0..6)) {
+            let views: Vec<_> = series.iter().map(|s| GuardedSortedSlice::new(s).unwrap()).collect();
+            let mut expected = series.concat();
+            expected.sort();
+
+            prop_assert_eq!(bits(&merge_sorted_all(&views)), bits(&expected));
+            if let [a, b, ..] = views[..] {
+                let mut expected = [a.as_slice(), b.as_slice()].concat();
+                expected.sort();
+                prop_assert_eq!(bits(&merge_sorted(a, b)), bits(&expected));
+            }
+        }
+    }
+}
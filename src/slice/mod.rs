@@ -0,0 +1,43 @@
+//! Reductions over slices of guarded values.
+//!
+//! The functions in this module, like `dot` and `sum_checked`, take `GuardedF64` inputs, so the
+//! only way for a reduction to fail is for its result to overflow, or for its inputs to have
+//! mismatched lengths. Both are reported as a `SliceError`. `TryCollectGuarded` validates raw
+//! values on their way into a `Vec<GuardedF64>`, and reports the index of the first invalid one.
+//!
+//! The module also provides casts between slices of raw `f64` values and slices of guarded or
+//! unguarded values, so existing buffers can be adopted without copying, and `GuardedSortedSlice`,
+//! a view of sorted values that supports binary searches and range queries. `merge_sorted` and
+//! `merge_sorted_all` merge sorted views stably. With the `rayon` feature, `par_check_all`
+//! validates large buffers in parallel. `dedup_approx` and `unique_approx` merge values that are
+//! within a tolerance of each other, for cleaning noisy data. For signal processing,
+//! `validate_interleaved_complex` validates FFT input buffers, and `hann` and `hamming` generate
+//! window functions.
+mod cast;
+mod collect;
+mod dedup;
+mod dot;
+mod error;
+#[cfg(feature = "simd")]
+mod lanes;
+mod merge;
+#[cfg(feature = "rayon")]
+mod par;
+mod sorted;
+mod sum;
+mod window;
+
+pub use cast::{
+    as_raw_slice, as_raw_slice_mut, from_raw_slice_guarded, from_raw_slice_unguarded,
+    from_raw_slice_unguarded_mut,
+};
+pub use collect::TryCollectGuarded;
+pub use dedup::{dedup_approx, unique_approx};
+pub use dot::{dot, dot_compensated};
+pub use error::SliceError;
+pub use merge::{merge_sorted, merge_sorted_all};
+#[cfg(feature = "rayon")]
+pub use par::par_check_all;
+pub use sorted::GuardedSortedSlice;
+pub use sum::sum_checked;
+pub use window::{hamming, hann, validate_interleaved_complex};
@@ -0,0 +1,58 @@
+use crate::{FloatError, GuardedF64, UnguardedF64};
+use rayon::prelude::*;
+
+/// Reinterprets a slice of raw `f64` values as `GuardedF64` values, like `from_raw_slice_guarded`,
+/// but validates the values in parallel.
+///
+/// The values are scanned on the rayon thread pool, so validating a buffer of billions of samples
+/// does not have to give up data-parallelism. The result is the same as the sequential version,
+/// including which error is reported.
+///
+/// # Errors
+///
+/// Returns the `FloatError` of the first value that is NaN or infinite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::FloatError;
+/// use floatguard::slice::par_check_all;
+///
+/// let buffer: Vec<f64> = (0..10_000).map(f64::from).collect();
+/// assert_eq!(par_check_all(&buffer).map(<[_]>::len), Ok(10_000));
+///
+/// assert_eq!(par_check_all(&[1.0, f64::INFINITY, f64::NAN]), Err(FloatError::Infinity));
+/// ```
+pub fn par_check_all(values: &[f64]) -> Result<&[GuardedF64], FloatError> {
+    if let Some(index) = values.par_iter().position_first(|value| !value.is_finite()) {
+        // Only the invalid value is reported to hooks and statistics.
+        UnguardedF64(values[index]).check_reported()?;
+    }
+
+    // SAFETY: Every value was checked to be finite above.
+    Ok(unsafe { super::cast::assume_guarded(values) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slice::from_raw_slice_guarded;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_matches_sequential(values in prop::collection::vec(any::<f64>(), 0..256)) {
+            prop_assert_eq!(par_check_all(&values), from_raw_slice_guarded(&values));
+        }
+
+        #[test]
+        fn test_parallel_sum(values in prop::collection::vec(-1e6..1e6f64, 0..256)) {
+            let guarded = par_check_all(&values).unwrap();
+            let total: UnguardedF64 = guarded.par_iter().copied().sum();
+            let expected: UnguardedF64 = guarded.iter().sum();
+
+            // The parallel sum may add the values in a different order.
+            prop_assert!((total - expected).abs().check().unwrap() < 1e-6);
+        }
+    }
+}
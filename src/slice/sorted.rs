@@ -0,0 +1,190 @@
+use super::SliceError;
+use crate::GuardedF64;
+use std::borrow::Borrow;
+use std::ops::{Bound, RangeBounds};
+
+/// A view of a slice of guarded values that is known to be sorted in ascending order.
+///
+/// The order is checked once, when the view is created, so lookups can use binary search without
+/// re-sorting or falling back to raw floats. Guarded values are totally ordered, so the searches
+/// are well-defined for every value.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::slice::{GuardedSortedSlice, SliceError};
+///
+/// let keys = [0.0, 1.5, 1.5, 4.0, 10.0].map(|v| GuardedF64::new(v).unwrap());
+/// let sorted = GuardedSortedSlice::new(&keys).unwrap();
+///
+/// assert_eq!(sorted.binary_search(GuardedF64::new(4.0).unwrap()), Ok(3));
+/// assert_eq!(sorted.binary_search(GuardedF64::new(2.0).unwrap()), Err(3));
+///
+/// let lo = GuardedF64::new(1.0).unwrap();
+/// let hi = GuardedF64::new(4.0).unwrap();
+/// assert_eq!(sorted.range(lo..hi), &keys[1..3]);
+/// assert_eq!(sorted.range(lo..=hi), &keys[1..4]);
+///
+/// let unsorted = [2.0, 1.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(GuardedSortedSlice::new(&unsorted), Err(SliceError::Unsorted { index: 1 }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardedSortedSlice<'a>(&'a [GuardedF64]);
+
+impl<'a> GuardedSortedSlice<'a> {
+    /// Creates a view of `values`, after checking that they are sorted in ascending order. Equal
+    /// values may appear next to each other.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SliceError::Unsorted` with the index of the first value that is less than the value
+    /// before it.
+    pub fn new(values: &'a [GuardedF64]) -> Result<Self, SliceError> {
+        if let Some(index) = values.windows(2).position(|pair| pair[0] > pair[1]) {
+            return Err(SliceError::Unsorted { index: index + 1 });
+        }
+
+        Ok(Self(values))
+    }
+
+    /// Sorts `values` in ascending order, and returns a view of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use floatguard::slice::GuardedSortedSlice;
+    ///
+    /// let mut values = [3.0, -1.0, 2.0].map(|v| GuardedF64::new(v).unwrap());
+    /// let sorted = GuardedSortedSlice::sort(&mut values);
+    /// assert_eq!(sorted.as_slice(), [-1.0, 2.0, 3.0].map(|v| GuardedF64::new(v).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn sort(values: &'a mut [GuardedF64]) -> Self {
+        values.sort_unstable();
+        Self(values)
+    }
+
+    /// Returns the underlying slice.
+    #[must_use]
+    pub const fn as_slice(&self) -> &'a [GuardedF64] {
+        self.0
+    }
+
+    /// Searches for `value`, like `slice::binary_search`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the index where `value` could be inserted while keeping the slice sorted if
+    /// it is not found. If there are several matches, any one of them may be returned.
+    pub fn binary_search(&self, value: impl Borrow<GuardedF64>) -> Result<usize, usize> {
+        self.0.binary_search(value.borrow())
+    }
+
+    /// Returns the index of the first value for which `predicate` is `false`, like
+    /// `slice::partition_point`. `predicate` must be `true` for a prefix of the slice and `false`
+    /// for the rest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::GuardedF64;
+    /// use floatguard::slice::GuardedSortedSlice;
+    ///
+    /// let values = [1.0, 2.0, 3.0].map(|v| GuardedF64::new(v).unwrap());
+    /// let sorted = GuardedSortedSlice::new(&values).unwrap();
+    /// assert_eq!(sorted.partition_point(|value| *value < 2.5), 2);
+    /// ```
+    pub fn partition_point(&self, predicate: impl FnMut(&GuardedF64) -> bool) -> usize {
+        self.0.partition_point(predicate)
+    }
+
+    /// Returns the values within `range`. A range whose start is after its end is empty.
+    pub fn range(&self, range: impl RangeBounds<GuardedF64>) -> &'a [GuardedF64] {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.partition_point(|value| value < start),
+            Bound::Excluded(start) => self.partition_point(|value| value <= start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.partition_point(|value| value <= end),
+            Bound::Excluded(end) => self.partition_point(|value| value < end),
+            Bound::Unbounded => self.0.len(),
+        };
+
+        &self.0[start..end.max(start)]
+    }
+}
+
+impl std::ops::Deref for GuardedSortedSlice<'_> {
+    type Target = [GuardedF64];
+
+    /// Dereferences the view to the underlying slice.
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn values() -> impl Strategy<Value = Vec<GuardedF64>> {
+        prop::collection::vec(
+            (-20..20i32).prop_map(|v| GuardedF64(f64::from(v) / 2.0)),
+            0..64,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_new_checks_order(values in prop::collection::vec(valid_f64().prop_map(GuardedF64), 0..32)) {
+            let mut sorted = values.clone();
+            sorted.sort_unstable();
+            prop_assert!(GuardedSortedSlice::new(&sorted).is_ok());
+
+            if let Err(SliceError::Unsorted { index }) = GuardedSortedSlice::new(&values) {
+                prop_assert!(values[index] < values[index - 1]);
+                prop_assert!(values[..index].windows(2).all(|pair| pair[0] <= pair[1]));
+            } else {
+                prop_assert_eq!(&values, &sorted);
+            }
+        }
+
+        #[test]
+        fn test_range_matches_filter(mut values in values(), a in -12..12i32, b in -12..12i32) {
+            let sorted = GuardedSortedSlice::sort(&mut values);
+            let (a, b) = (GuardedF64(f64::from(a)), GuardedF64(f64::from(b)));
+
+            let filter = |keep: &dyn Fn(&GuardedF64) -> bool| {
+                sorted.iter().copied().filter(|value| keep(value)).collect::<Vec<_>>()
+            };
+            prop_assert_eq!(sorted.range(a..b), filter(&|v| (a..b).contains(v)));
+            prop_assert_eq!(sorted.range(a..=b), filter(&|v| (a..=b).contains(v)));
+            prop_assert_eq!(sorted.range(..b), filter(&|v| *v < b));
+            prop_assert_eq!(sorted.range(a..), filter(&|v| *v >= a));
+            prop_assert_eq!(
+                sorted.range((Bound::Excluded(a), Bound::Unbounded)),
+                filter(&|v| *v > a)
+            );
+        }
+
+        #[test]
+        fn test_binary_search(mut values in values(), a in -12..12i32) {
+            let sorted = GuardedSortedSlice::sort(&mut values);
+            let a = GuardedF64(f64::from(a));
+
+            match sorted.binary_search(a) {
+                Ok(index) => prop_assert_eq!(sorted[index], a),
+                Err(index) => {
+                    prop_assert!(!sorted.contains(&a));
+                    prop_assert!(sorted[..index].iter().all(|value| *value < a));
+                    prop_assert!(sorted[index..].iter().all(|value| *value > a));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::SliceError;
+use crate::{GuardedF64, UnguardedF64};
+
+/// Sums a slice of guarded values, checking the result.
+///
+/// With the `simd` feature enabled, the values are accumulated in several independent lanes, like
+/// [`dot`](super::dot()), so the result may differ in the last few bits from a sequential sum.
+///
+/// # Errors
+///
+/// Returns `SliceError::Float` if the sum is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::slice::{sum_checked, SliceError};
+///
+/// let values = [1.0, 2.5, -4.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(sum_checked(&values), Ok(GuardedF64::new(-0.5).unwrap()));
+///
+/// let large = [GuardedF64::MAX, GuardedF64::MAX];
+/// assert_eq!(sum_checked(&large), Err(SliceError::Float(FloatError::Infinity)));
+/// ```
+pub fn sum_checked(values: &[GuardedF64]) -> Result<GuardedF64, SliceError> {
+    Ok(accumulate(values).check_reported()?)
+}
+
+/// Sums the values sequentially.
+#[cfg(not(feature = "simd"))]
+fn accumulate(values: &[GuardedF64]) -> UnguardedF64 {
+    values
+        .iter()
+        .fold(UnguardedF64::new(0.0), |sum, value| sum + value)
+}
+
+/// Sums the values in independent lanes, so that the loop can be vectorized.
+#[cfg(feature = "simd")]
+fn accumulate(values: &[GuardedF64]) -> UnguardedF64 {
+    super::lanes::sum(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_edge_cases() {
+        assert_eq!(sum_checked(&[]), Ok(GuardedF64(0.0)));
+        assert_eq!(
+            sum_checked(&[GuardedF64::MAX; 9]),
+            Err(SliceError::Float(FloatError::Infinity))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_sequential(values in prop::collection::vec(-1e6..1e6f64, 0..64)) {
+            let guarded = values.iter().map(|&v| GuardedF64(v)).collect::<Vec<_>>();
+            let magnitude = values.iter().map(|v| v.abs()).sum::<f64>();
+
+            let sum = sum_checked(&guarded).unwrap();
+            prop_assert!((sum.0 - values.iter().sum::<f64>()).abs() <= magnitude * 1e-12);
+        }
+    }
+}
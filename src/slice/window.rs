@@ -0,0 +1,150 @@
+use super::{SliceError, from_raw_slice_guarded};
+use crate::GuardedF64;
+
+/// Reinterprets a buffer of interleaved complex numbers, `[re0, im0, re1, im1, ...]`, as a slice of
+/// `GuardedF64` without copying, after validating every component.
+///
+/// This is the layout that FFT libraries expect for complex input, so a buffer can be validated
+/// once before it is handed off, like `from_raw_slice_guarded`.
+///
+/// # Errors
+///
+/// Returns `SliceError::OddLength` if the buffer has an odd length, or `SliceError::Invalid` with the
+/// index of the first component that is NaN or infinite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::FloatError;
+/// use floatguard::slice::{SliceError, validate_interleaved_complex};
+///
+/// let buffer = [1.0, 0.0, 0.5, -0.5];
+/// let values = validate_interleaved_complex(&buffer).unwrap();
+/// assert_eq!(values[3], -0.5);
+///
+/// assert_eq!(
+///     validate_interleaved_complex(&[1.0, 0.0, 2.0]),
+///     Err(SliceError::OddLength { len: 3 })
+/// );
+/// assert_eq!(
+///     validate_interleaved_complex(&[1.0, f64::NAN]),
+///     Err(SliceError::Invalid { index: 1, error: FloatError::NaN })
+/// );
+/// ```
+pub fn validate_interleaved_complex(values: &[f64]) -> Result<&[GuardedF64], SliceError> {
+    if values.len() % 2 == 1 {
+        return Err(SliceError::OddLength { len: values.len() });
+    }
+
+    from_raw_slice_guarded(values).map_err(|error| SliceError::Invalid {
+        // The check only fails if there is a value that is not finite.
+        index: values
+            .iter()
+            .position(|value| !value.is_finite())
+            .unwrap_or_default(),
+        error,
+    })
+}
+
+/// Returns a periodic Hann window of length `n`, `sin^2(π * k / n)` for `k` in `0..n`.
+///
+/// Periodic windows are the ones to use for spectral analysis with an FFT of length `n`. For a
+/// symmetric window of length `n`, as used for filter design, take a periodic window of length
+/// `n - 1` and append its first value. Every value is in the range [0, 1].
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::slice::hann;
+///
+/// let window = hann(4);
+/// for (value, expected) in window.iter().zip([0.0, 0.5, 1.0, 0.5]) {
+///     assert!((value - expected).abs().check().unwrap() < 1e-15);
+/// }
+/// assert!(hann(0).is_empty());
+/// ```
+#[must_use = "function returns a new vector and does not mutate any input"]
+pub fn hann(n: usize) -> Vec<GuardedF64> {
+    window(n, |phase| {
+        // `sin^2` is exactly zero at the start, where `0.5 - 0.5 * cos` can round away from it.
+        let sin = (0.5 * phase).sin();
+        sin * sin
+    })
+}
+
+/// Returns a periodic Hamming window of length `n`, `0.54 - 0.46 * cos(2π * k / n)` for `k` in
+/// `0..n`.
+///
+/// Like `hann`, the window is periodic, for spectral analysis with an FFT of length `n`. Every value
+/// is in the range [0, 1], and no smaller than about `0.08`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::slice::hamming;
+///
+/// let window = hamming(4);
+/// assert!((window[0] - 0.08).abs().check().unwrap() < 1e-15);
+/// assert_eq!(window[2], 1.0);
+/// ```
+#[must_use = "function returns a new vector and does not mutate any input"]
+pub fn hamming(n: usize) -> Vec<GuardedF64> {
+    window(n, |phase| 0.46f64.mul_add(-phase.cos(), 0.54))
+}
+
+/// Evaluates `f` at the phases `2π * k / n` for `k` in `0..n`, and clamps the results to [0, 1].
+fn window(n: usize, f: impl Fn(f64) -> f64) -> Vec<GuardedF64> {
+    #[allow(clippy::cast_precision_loss)]
+    let step = GuardedF64::TAU.0 / n as f64;
+
+    #[allow(clippy::cast_precision_loss)]
+    (0..n)
+        .map(|k| GuardedF64(f(step * k as f64).clamp(0.0, 1.0)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_windows() {
+        assert_eq!(hann(1), [0.0]);
+        assert!((hamming(1)[0].0 - 0.08).abs() < 1e-15);
+
+        let window = hann(8);
+        assert_eq!(window[4], 1.0);
+        for k in 1..8 {
+            assert!((window[k].0 - window[8 - k].0).abs() < 1e-15);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_validate(values in prop::collection::vec(prop_oneof![any::<f64>(), -1e3..1e3f64], 0..32)) {
+            let result = validate_interleaved_complex(&values);
+            let invalid = values.iter().position(|v| !v.is_finite());
+
+            match (values.len() % 2, invalid) {
+                (1, _) => prop_assert!(matches!(result, Err(SliceError::OddLength { .. })), "{:?}", result),
+                (_, Some(index)) => prop_assert_eq!(
+                    result,
+                    Err(SliceError::Invalid { index, error: GuardedF64::new(values[index]).unwrap_err() })
+                ),
+                _ => prop_assert_eq!(result.map(<[_]>::len), Ok(values.len())),
+            }
+        }
+
+        #[test]
+        fn test_window_range(n in 0usize..512) {
+            let hann = hann(n);
+            let hamming = hamming(n);
+            prop_assert_eq!(hann.len(), n);
+            prop_assert!(hann.iter().all(|v| (0.0..=1.0).contains(&v.0)));
+            prop_assert!(hamming.iter().all(|v| (0.079..=1.0).contains(&v.0)));
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Evenly spaced sequences of guarded values.
+//!
+//! Every generated value is interpolated between the two guarded endpoints and clamped to them, so
+//! `linspace` cannot produce an invalid value and returns its points directly. `logspace`
+//! interpolates in log space, which is only defined for strictly positive endpoints, so it
+//! validates them first. Both include `start` and `end` exactly.
+use crate::{FloatError, GuardedF64};
+
+/// Returns `n` evenly spaced values from `start` to `end`, inclusive.
+///
+/// A single value is just `start`, and zero values is an empty vector. The endpoints may be in
+/// either order.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::space::linspace;
+///
+/// let start = GuardedF64::new(0.0).unwrap();
+/// let end = GuardedF64::new(1.0).unwrap();
+/// assert_eq!(linspace(start, end, 5), [0.0, 0.25, 0.5, 0.75, 1.0].map(|v| GuardedF64::new(v).unwrap()));
+///
+/// let points = linspace(GuardedF64::MIN, GuardedF64::MAX, 3);
+/// assert_eq!(points, [GuardedF64::MIN, GuardedF64::new(0.0).unwrap(), GuardedF64::MAX]);
+/// ```
+#[must_use = "function returns a new value and does not mutate the original value"]
+pub fn linspace(start: GuardedF64, end: GuardedF64, n: usize) -> Vec<GuardedF64> {
+    spaced(start, end, n, |v| v, |v| v)
+}
+
+/// Returns `n` values from `start` to `end`, inclusive, that are evenly spaced on a logarithmic
+/// scale, so each value is a constant multiple of the one before it.
+///
+/// A single value is just `start`, and zero values is an empty vector. The endpoints may be in
+/// either order.
+///
+/// # Errors
+///
+/// Returns `FloatError::Domain` if either endpoint is negative, or `FloatError::Underflow` if either
+/// endpoint is zero, since neither has a finite logarithm.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::space::logspace;
+///
+/// let start = GuardedF64::new(1.0).unwrap();
+/// let end = GuardedF64::new(1000.0).unwrap();
+/// let points = logspace(start, end, 4).unwrap();
+/// assert_eq!(points.first(), Some(&start));
+/// assert!((points[1] - 10.0).abs().check().unwrap() < 1e-12);
+/// assert_eq!(points.last(), Some(&end));
+///
+/// let zero = GuardedF64::new(0.0).unwrap();
+/// assert_eq!(logspace(zero, end, 4), Err(FloatError::Underflow));
+/// assert_eq!(logspace(-start, end, 4), Err(FloatError::Domain));
+/// ```
+pub fn logspace(
+    start: GuardedF64,
+    end: GuardedF64,
+    n: usize,
+) -> Result<Vec<GuardedF64>, FloatError> {
+    for endpoint in [start, end] {
+        if endpoint < 0.0 {
+            return Err(FloatError::Domain);
+        }
+        if endpoint == 0.0 {
+            return Err(FloatError::Underflow);
+        }
+    }
+
+    Ok(spaced(start, end, n, f64::ln, f64::exp))
+}
+
+/// Interpolates `n` points from `start` to `end` in the space given by `into`, maps each back with
+/// `from`, and clamps the result between the endpoints. The first and last points are the endpoints
+/// exactly.
+#[allow(clippy::cast_precision_loss)]
+fn spaced(
+    start: GuardedF64,
+    end: GuardedF64,
+    n: usize,
+    into: impl Fn(f64) -> f64,
+    from: impl Fn(f64) -> f64,
+) -> Vec<GuardedF64> {
+    let (low, high) = (start.min(end), start.max(end));
+    let (a, b) = (into(start.0), into(end.0));
+    let last = n.saturating_sub(1);
+
+    (0..n)
+        .map(|index| match index {
+            0 => start,
+            _ if index == last => end,
+            _ => {
+                // Interpolating between the endpoints, rather than stepping from `start`, keeps
+                // the intermediate values finite even when `end - start` would overflow.
+                let t = index as f64 / last as f64;
+                GuardedF64(from(a.mul_add(1.0 - t, b * t)).clamp(low.0, high.0))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_linspace(start in valid_f64(), end in valid_f64(), n in 0usize..64) {
+            let start = GuardedF64::new(start).unwrap();
+            let end = GuardedF64::new(end).unwrap();
+            let points = linspace(start, end, n);
+
+            prop_assert_eq!(points.len(), n);
+            prop_assert_eq!(points.first(), (n > 0).then_some(&start));
+            if n > 1 {
+                prop_assert_eq!(points.last(), Some(&end));
+            }
+            prop_assert!(points.iter().all(|point| start.min(end) <= *point && *point <= start.max(end)));
+        }
+
+        #[test]
+        fn test_logspace(start in 1e-300..1e300f64, end in 1e-300..1e300f64, n in 0usize..64) {
+            let start = GuardedF64::new(start).unwrap();
+            let end = GuardedF64::new(end).unwrap();
+            let points = logspace(start, end, n).unwrap();
+
+            prop_assert_eq!(points.len(), n);
+            prop_assert_eq!(points.first(), (n > 0).then_some(&start));
+            if n > 1 {
+                prop_assert_eq!(points.last(), Some(&end));
+            }
+            prop_assert!(points.iter().all(|point| *point > 0.0));
+            prop_assert!(points.iter().all(|point| start.min(end) <= *point && *point <= start.max(end)));
+        }
+    }
+}
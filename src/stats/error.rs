@@ -0,0 +1,90 @@
+use crate::FloatError;
+
+/// An error occurred while computing a statistic over a slice of guarded values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsError {
+    /// Indicates that the input slice was empty, so the statistic is undefined.
+    Empty,
+
+    /// Indicates that the value at `index` is outside of the domain of the statistic.
+    Domain {
+        /// The index of the offending value in the input slice.
+        index: usize,
+    },
+
+    /// Indicates that the values and weights passed to a weighted statistic have different lengths.
+    LengthMismatch {
+        /// The number of values.
+        values: usize,
+
+        /// The number of weights.
+        weights: usize,
+    },
+
+    /// Indicates that the values being divided by their total sum to zero.
+    ZeroTotal,
+
+    /// Indicates that the values being divided by their total sum to a negative number.
+    NegativeTotal,
+
+    /// Indicates that the fraction of values to trim is not in `[0.0, 0.5)`.
+    InvalidFraction,
+
+    /// Indicates that the values being rescaled to `[0.0, 1.0]` are all the same, so their range is
+    /// zero.
+    ZeroRange,
+
+    /// Indicates that the values being standardized are all the same, so their standard deviation
+    /// is zero.
+    ZeroStdDev,
+
+    /// Indicates that the result of the computation was not a valid floating-point value.
+    Float(FloatError),
+}
+
+impl From<FloatError> for StatsError {
+    /// Wraps a `FloatError` produced while checking the result of a statistic.
+    fn from(value: FloatError) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl std::error::Error for StatsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Float(error) => Some(error),
+            Self::Empty
+            | Self::Domain { .. }
+            | Self::LengthMismatch { .. }
+            | Self::ZeroTotal
+            | Self::NegativeTotal
+            | Self::InvalidFraction
+            | Self::ZeroRange
+            | Self::ZeroStdDev => None,
+        }
+    }
+}
+
+/// Implements the `Display` trait for the `StatsError` enum, providing a user-friendly
+/// description of the error.
+impl std::fmt::Display for StatsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "The statistic is undefined for an empty input"),
+            Self::Domain { index } => write!(
+                f,
+                "The value at index {index} is outside the domain of the statistic"
+            ),
+            Self::LengthMismatch { values, weights } => write!(
+                f,
+                "Expected one weight per value, but got {values} values and {weights} weights"
+            ),
+            Self::ZeroTotal => write!(f, "The total is zero"),
+            Self::NegativeTotal => write!(f, "The total is negative"),
+            Self::InvalidFraction => write!(f, "The fraction to trim must be in [0.0, 0.5)"),
+            Self::ZeroRange => write!(f, "The values have a range of zero"),
+            Self::ZeroStdDev => write!(f, "The values have a standard deviation of zero"),
+            Self::Float(error) => error.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use super::StatsError;
+use crate::{GuardedF64, UnguardedF64};
+
+/// Computes the geometric mean of a slice of strictly positive values.
+///
+/// The mean is computed in log space, as `exp(sum(ln(x)) / n)`, so that the intermediate product
+/// cannot overflow or underflow even for long inputs with large or tiny values.
+///
+/// # Errors
+///
+/// Returns `StatsError::Empty` if `values` is empty, `StatsError::Domain` with the index of the
+/// first value that is not strictly positive, or `StatsError::Float` if the result is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::stats::{geometric_mean, StatsError};
+///
+/// let values = [2.0, 8.0].map(|v| GuardedF64::new(v).unwrap());
+/// let mean = geometric_mean(&values).unwrap();
+/// assert!((mean - 4.0).abs().check().unwrap() < 1e-12);
+///
+/// let values = [2.0, 0.0, -1.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(geometric_mean(&values), Err(StatsError::Domain { index: 1 }));
+///
+/// assert_eq!(geometric_mean(&[]), Err(StatsError::Empty));
+/// ```
+pub fn geometric_mean(values: &[GuardedF64]) -> Result<GuardedF64, StatsError> {
+    let count = count(values)?;
+
+    let mut log_sum = UnguardedF64::new(0.0);
+    for (index, value) in values.iter().enumerate() {
+        if value.0 <= 0.0 {
+            return Err(StatsError::Domain { index });
+        }
+        log_sum += value.ln();
+    }
+
+    Ok((log_sum / count).exp().check_reported()?)
+}
+
+/// Computes the harmonic mean of a slice of strictly positive values.
+///
+/// The reciprocals are scaled by the smallest value before they are summed, as
+/// `min * n / sum(min / x)`, so that the reciprocal of a tiny value cannot overflow the sum.
+///
+/// # Errors
+///
+/// Returns `StatsError::Empty` if `values` is empty, `StatsError::Domain` with the index of the
+/// first value that is not strictly positive, or `StatsError::Float` if the result is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::stats::{harmonic_mean, StatsError};
+///
+/// let values = [1.0, 4.0, 4.0].map(|v| GuardedF64::new(v).unwrap());
+/// let mean = harmonic_mean(&values).unwrap();
+/// assert!((mean - 2.0).abs().check().unwrap() < 1e-12);
+///
+/// let values = [1.0, -4.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(harmonic_mean(&values), Err(StatsError::Domain { index: 1 }));
+///
+/// assert_eq!(harmonic_mean(&[]), Err(StatsError::Empty));
+/// ```
+pub fn harmonic_mean(values: &[GuardedF64]) -> Result<GuardedF64, StatsError> {
+    let count = count(values)?;
+
+    let mut min = GuardedF64::MAX;
+    for (index, value) in values.iter().enumerate() {
+        if value.0 <= 0.0 {
+            return Err(StatsError::Domain { index });
+        }
+        min = min.min(*value);
+    }
+
+    let mut scaled_sum = UnguardedF64::new(0.0);
+    for value in values {
+        scaled_sum += min / value;
+    }
+
+    Ok((min * (count / scaled_sum)).check_reported()?)
+}
+
+/// Returns the number of values as a `GuardedF64`, or `StatsError::Empty` if there are none.
+#[allow(clippy::cast_precision_loss)]
+pub(super) const fn count(values: &[GuardedF64]) -> Result<GuardedF64, StatsError> {
+    if values.is_empty() {
+        Err(StatsError::Empty)
+    } else {
+        // Slices long enough to lose precision here cannot fit in memory.
+        Ok(GuardedF64(values.len() as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn positive_f64() -> impl Strategy<Value = GuardedF64> {
+        valid_f64()
+            .prop_filter("Reject non-positive values", |v| *v > 0.0)
+            .prop_map(|v| GuardedF64::new(v).unwrap())
+    }
+
+    #[test]
+    fn test_extreme_values() {
+        let values = [GuardedF64::MAX, GuardedF64::MAX, GuardedF64::MAX];
+        assert!(geometric_mean(&values).is_ok());
+        assert_eq!(harmonic_mean(&values), Ok(GuardedF64::MAX));
+
+        let tiny = GuardedF64::new(f64::from_bits(1)).unwrap();
+        let values = [tiny, GuardedF64::new(1.0).unwrap()];
+        assert!(geometric_mean(&values).unwrap() > 0.0);
+        assert!(harmonic_mean(&values).unwrap() > 0.0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_means_are_bounded(values in prop::collection::vec(positive_f64(), 1..32)) {
+            let min = *values.iter().min().unwrap();
+            let max = *values.iter().max().unwrap();
+            let tolerance = 1e-9;
+
+            let geometric = geometric_mean(&values).unwrap();
+            prop_assert!(geometric.0 >= min.0 * (1.0 - tolerance));
+            prop_assert!(geometric.0 <= max.0 * (1.0 + tolerance));
+
+            let harmonic = harmonic_mean(&values).unwrap();
+            prop_assert!(harmonic.0 >= min.0 * (1.0 - tolerance));
+            prop_assert!(harmonic.0 <= max.0 * (1.0 + tolerance));
+            prop_assert!(harmonic.0 <= geometric.0 * (1.0 + tolerance));
+        }
+
+        #[test]
+        fn test_means_of_constant(value in positive_f64(), len in 1usize..32) {
+            let values = vec![value; len];
+            let tolerance = value.0 * 1e-12;
+
+            prop_assert!((geometric_mean(&values).unwrap() - value).abs().check().unwrap() <= tolerance);
+            prop_assert!((harmonic_mean(&values).unwrap() - value).abs().check().unwrap() <= tolerance);
+        }
+
+        #[test]
+        fn test_domain_error_index(
+            mut values in prop::collection::vec(positive_f64(), 1..32),
+            index in any::<prop::sample::Index>(),
+            invalid in valid_f64().prop_filter("Reject positive values", |v| *v <= 0.0),
+        ) {
+            let index = index.index(values.len());
+            values[index] = GuardedF64::new(invalid).unwrap();
+
+            prop_assert_eq!(geometric_mean(&values), Err(StatsError::Domain { index }));
+            prop_assert_eq!(harmonic_mean(&values), Err(StatsError::Domain { index }));
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Statistical helpers over slices of guarded values.
+//!
+//! The functions in this module take `GuardedF64` inputs, so they never have to account for NaN or
+//! infinite samples. They do still have to account for inputs outside of a statistic's domain, like
+//! a zero in a harmonic mean, which are reported as a `StatsError` with the index of the offending
+//! value. `RunningStats` and `P2Quantile` are the exceptions: they accept unguarded values as they
+//! stream in, and report the first invalid one as a `FloatError` when a statistic is read.
+mod error;
+mod mean;
+mod p2;
+mod running;
+mod scale;
+mod select;
+mod trimmed;
+mod weighted;
+
+pub use error::StatsError;
+pub use mean::{geometric_mean, harmonic_mean};
+pub use p2::P2Quantile;
+pub use running::RunningStats;
+pub use scale::{minmax_scale, standardize};
+pub use select::select_kth;
+pub use trimmed::{trimmed_mean, winsorized_mean};
+pub use weighted::{normalize, weighted_mean};
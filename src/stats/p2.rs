@@ -0,0 +1,254 @@
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// The number of markers tracked by the P² algorithm.
+const MARKERS: usize = 5;
+
+/// Streaming quantile estimation with the P² algorithm of Jain and Chlamtac.
+///
+/// The estimator keeps five markers, at the minimum, the maximum, the target quantile, and halfway
+/// to it on either side, and adjusts their heights with a piecewise-parabolic fit as values arrive.
+/// It uses constant memory and time per value, which makes it suitable for tracking latency
+/// percentiles. Until five values have been pushed, the quantile is the nearest of the values seen.
+///
+/// Like `RunningStats`, values are validated as they are pushed, and an invalid value is not
+/// counted but makes `quantile` report the error of the first invalid value, so a single NaN cannot
+/// silently corrupt the markers.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::FloatError;
+/// use floatguard::stats::P2Quantile;
+///
+/// let mut p90 = P2Quantile::new(0.9).unwrap();
+/// for value in 1..=1000 {
+///     p90.push(f64::from(value));
+/// }
+/// assert_eq!(p90.count(), 1000);
+/// assert!((p90.quantile().unwrap().get() - 900.0).abs() < 10.0);
+///
+/// p90.push(f64::NAN);
+/// assert_eq!(p90.quantile(), Err(FloatError::NaN));
+///
+/// assert_eq!(P2Quantile::new(1.5).map(|p| p.count()), Err(FloatError::Domain));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct P2Quantile {
+    probability: f64,
+    count: u64,
+    heights: [f64; MARKERS],
+    positions: [f64; MARKERS],
+    desired: [f64; MARKERS],
+    increments: [f64; MARKERS],
+    error: Option<FloatError>,
+}
+
+impl P2Quantile {
+    /// Creates an estimator for the quantile at `probability`, like `0.99` for the 99th percentile.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Domain` if `probability` is not strictly between zero and one, or the
+    /// error of `probability` if it is invalid.
+    pub fn new(probability: impl Into<UnguardedF64>) -> Result<Self, FloatError> {
+        let p = probability
+            .into()
+            .check_where(|p| p > 0.0 && p < 1.0, FloatError::Domain)?
+            .0;
+
+        Ok(Self {
+            probability: p,
+            count: 0,
+            heights: [0.0; MARKERS],
+            positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+            desired: [0.0, 2.0 * p, 4.0 * p, 2.0f64.mul_add(p, 2.0), 4.0],
+            increments: [0.0, p / 2.0, p, f64::midpoint(1.0, p), 1.0],
+            error: None,
+        })
+    }
+
+    /// Returns the probability of the quantile being estimated.
+    #[must_use]
+    pub const fn probability(&self) -> GuardedF64 {
+        GuardedF64(self.probability)
+    }
+
+    /// Returns the number of valid values that have been pushed.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Adds a value to the estimate.
+    ///
+    /// An invalid value is not counted, and makes `quantile` report its error.
+    pub fn push(&mut self, value: impl Into<UnguardedF64>) {
+        let value = match value.into().check_reported() {
+            Ok(value) => value.0,
+            Err(error) => {
+                self.error.get_or_insert(error);
+                return;
+            }
+        };
+
+        // The first values are stored directly, and become the initial markers once sorted.
+        if let Some(index) = usize::try_from(self.count).ok().filter(|&i| i < MARKERS) {
+            self.heights[index] = value;
+            self.count += 1;
+            if index == MARKERS - 1 {
+                self.heights.sort_unstable_by(f64::total_cmp);
+            }
+            return;
+        }
+        self.count += 1;
+
+        // Find the cell that contains the value, extending the extremes if needed.
+        let cell = if value < self.heights[0] {
+            self.heights[0] = value;
+            0
+        } else if value >= self.heights[MARKERS - 1] {
+            self.heights[MARKERS - 1] = value;
+            MARKERS - 2
+        } else {
+            (1..MARKERS)
+                .find(|&i| value < self.heights[i])
+                .map_or(MARKERS - 2, |i| i - 1)
+        };
+
+        for position in &mut self.positions[cell + 1..] {
+            *position += 1.0;
+        }
+        for (desired, increment) in self.desired.iter_mut().zip(self.increments) {
+            *desired += increment;
+        }
+
+        for i in 1..MARKERS - 1 {
+            self.adjust(i);
+        }
+    }
+
+    /// Returns the estimate of the quantile.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first invalid value that was pushed, or `FloatError::NaN` if no
+    /// values have been pushed.
+    pub fn quantile(&self) -> Result<GuardedF64, FloatError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        match usize::try_from(self.count) {
+            Ok(0) => Err(FloatError::NaN),
+            Ok(count) if count < MARKERS => {
+                let mut seen = [0.0; MARKERS];
+                seen[..count].copy_from_slice(&self.heights[..count]);
+                seen[..count].sort_unstable_by(f64::total_cmp);
+
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    clippy::cast_precision_loss
+                )]
+                let index = (self.probability * (count - 1) as f64).round() as usize;
+                Ok(GuardedF64(seen[index]))
+            }
+            _ => Ok(GuardedF64(self.heights[MARKERS / 2])),
+        }
+    }
+
+    /// Moves marker `i` toward its desired position if it is off by at least one, adjusting its
+    /// height with the parabolic formula, or linearly if that would leave it out of order.
+    fn adjust(&mut self, i: usize) {
+        let offset = self.desired[i] - self.positions[i];
+        let (step, next) = if offset >= 1.0 && self.positions[i + 1] - self.positions[i] > 1.0 {
+            (1.0, i + 1)
+        } else if offset <= -1.0 && self.positions[i - 1] - self.positions[i] < -1.0 {
+            (-1.0, i - 1)
+        } else {
+            return;
+        };
+
+        let (q, n) = (&self.heights, &self.positions);
+        let above = (n[i] - n[i - 1] + step) * (q[i + 1] - q[i]) / (n[i + 1] - n[i]);
+        let below = (n[i + 1] - n[i] - step) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]);
+        let parabolic = (step / (n[i + 1] - n[i - 1])).mul_add(above + below, q[i]);
+
+        // The comparison also rejects a parabolic estimate that overflowed.
+        self.heights[i] = if q[i - 1] < parabolic && parabolic < q[i + 1] {
+            parabolic
+        } else {
+            // A convex combination of the two heights cannot overflow, unlike their difference.
+            let weight = 1.0 / (n[next] - n[i]).abs();
+            let linear = q[i].mul_add(1.0 - weight, q[next] * weight);
+            linear.clamp(q[i].min(q[next]), q[i].max(q[next]))
+        };
+        self.positions[i] += step;
+    }
+}
+
+impl<T: Into<UnguardedF64>> Extend<T> for P2Quantile {
+    /// Pushes every value of the iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_small_counts_are_exact() {
+        let mut median = P2Quantile::new(0.5).unwrap();
+        assert_eq!(median.quantile(), Err(FloatError::NaN));
+
+        median.extend([3.0, 1.0, 2.0]);
+        assert_eq!(median.quantile(), Ok(GuardedF64(2.0)));
+    }
+
+    #[test]
+    fn test_extreme_values() {
+        let mut median = P2Quantile::new(0.5).unwrap();
+        median.extend(
+            [GuardedF64::MAX, -GuardedF64::MAX]
+                .into_iter()
+                .cycle()
+                .take(101),
+        );
+        assert!(median.quantile().is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_sorted_quantile(
+            values in prop::collection::vec(0.0..1.0f64, 500..2000),
+            probability in 0.05..0.95f64,
+        ) {
+            let mut estimator = P2Quantile::new(probability).unwrap();
+            estimator.extend(values.iter().copied());
+
+            let mut sorted = values;
+            sorted.sort_unstable_by(f64::total_cmp);
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+            let exact = sorted[(probability * (sorted.len() - 1) as f64).round() as usize];
+
+            prop_assert!((estimator.quantile().unwrap().0 - exact).abs() < 0.05);
+        }
+
+        #[test]
+        fn test_stays_within_range(values in prop::collection::vec(valid_f64(), 1..200)) {
+            let mut estimator = P2Quantile::new(0.9).unwrap();
+            estimator.extend(values.iter().copied());
+
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let estimate = estimator.quantile().unwrap().0;
+            prop_assert!((min..=max).contains(&estimate));
+        }
+    }
+}
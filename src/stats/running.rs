@@ -0,0 +1,198 @@
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// Streaming mean and variance, computed with Welford's algorithm.
+///
+/// Values are validated as they are pushed, but errors are only reported when a statistic is read,
+/// like the lazy arithmetic of `UnguardedF64`. Once an invalid value has been pushed, every
+/// statistic reports the error of the first invalid value, and the invalid values are not counted.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::stats::RunningStats;
+///
+/// let mut stats = RunningStats::default();
+/// for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+///     stats.push(value);
+/// }
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), GuardedF64::new(5.0));
+/// assert_eq!(stats.variance(), GuardedF64::new(4.0));
+/// assert_eq!(stats.stddev(), GuardedF64::new(2.0));
+///
+/// stats.push(f64::NAN);
+/// assert_eq!(stats.mean(), Err(FloatError::NaN));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RunningStats {
+    count: u64,
+    mean: UnguardedF64,
+    m2: UnguardedF64,
+    error: Option<FloatError>,
+}
+
+impl RunningStats {
+    /// Adds a value to the statistics.
+    ///
+    /// An invalid value is not counted, and makes every statistic report its error.
+    pub fn push(&mut self, value: impl Into<UnguardedF64>) {
+        let value = match value.into().check_reported() {
+            Ok(value) => value,
+            Err(error) => {
+                self.error.get_or_insert(error);
+                return;
+            }
+        };
+
+        self.count += 1;
+        let count = self.float_count();
+        let delta = value - self.mean;
+
+        // Scaling both terms before subtracting keeps the mean from overflowing when `delta` does.
+        self.mean += value / count - self.mean / count;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Returns the number of valid values that have been pushed.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of the values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first invalid value that was pushed, or `FloatError::NaN` if no
+    /// values have been pushed.
+    pub fn mean(&self) -> Result<GuardedF64, FloatError> {
+        self.ensure_count(1)?;
+        self.mean.check_reported()
+    }
+
+    /// Returns the population variance of the values, which divides by `n`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first invalid value that was pushed, `FloatError::NaN` if no values
+    /// have been pushed, or `FloatError::Infinity` if the variance is too large to represent.
+    pub fn variance(&self) -> Result<GuardedF64, FloatError> {
+        self.ensure_count(1)?;
+        (self.m2 / self.float_count()).check_reported()
+    }
+
+    /// Returns the sample variance of the values, which divides by `n - 1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first invalid value that was pushed, `FloatError::NaN` if fewer than
+    /// two values have been pushed, or `FloatError::Infinity` if the variance is too large to
+    /// represent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, GuardedF64};
+    /// use floatguard::stats::RunningStats;
+    ///
+    /// let mut stats = RunningStats::default();
+    /// stats.push(1.0);
+    /// assert_eq!(stats.sample_variance(), Err(FloatError::NaN));
+    ///
+    /// stats.push(3.0);
+    /// assert_eq!(stats.sample_variance(), GuardedF64::new(2.0));
+    /// ```
+    pub fn sample_variance(&self) -> Result<GuardedF64, FloatError> {
+        self.ensure_count(2)?;
+        (self.m2 / (self.float_count() - 1.0)).check_reported()
+    }
+
+    /// Returns the population standard deviation of the values, the square root of `variance`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `variance`.
+    pub fn stddev(&self) -> Result<GuardedF64, FloatError> {
+        self.variance()?.sqrt().check_reported()
+    }
+
+    /// Returns the number of values as a float, for dividing by it.
+    #[allow(clippy::cast_precision_loss)]
+    const fn float_count(&self) -> GuardedF64 {
+        GuardedF64(self.count as f64)
+    }
+
+    /// Reports the first invalid value, or `FloatError::NaN` if fewer than `min` values have been
+    /// pushed.
+    const fn ensure_count(&self, min: u64) -> Result<(), FloatError> {
+        match self.error {
+            Some(error) => Err(error),
+            None if self.count < min => Err(FloatError::NaN),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Into<UnguardedF64>> Extend<T> for RunningStats {
+    /// Pushes every value of the iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_first_error_is_reported() {
+        let mut stats = RunningStats::default();
+        assert_eq!(stats.mean(), Err(FloatError::NaN));
+        assert_eq!(stats.variance(), Err(FloatError::NaN));
+
+        stats.extend([1.0, f64::INFINITY, f64::NAN, 2.0]);
+        assert_eq!(stats.count(), 2);
+        assert_eq!(stats.mean(), Err(FloatError::Infinity));
+        assert_eq!(stats.stddev(), Err(FloatError::Infinity));
+    }
+
+    #[test]
+    fn test_extreme_values() {
+        let mut stats = RunningStats::default();
+        stats.extend([GuardedF64::MAX, -GuardedF64::MAX]);
+        assert_eq!(stats.mean(), Ok(GuardedF64(0.0)));
+        assert_eq!(stats.variance(), Err(FloatError::Infinity));
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_two_pass(values in prop::collection::vec(-1e6..1e6f64, 2..64)) {
+            let mut stats = RunningStats::default();
+            stats.extend(values.iter().copied());
+
+            #[allow(clippy::cast_precision_loss)]
+            let n = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / n;
+            let m2 = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+            let tolerance = 1e-6;
+
+            prop_assert!((stats.mean().unwrap().0 - mean).abs() < tolerance);
+            prop_assert!((stats.variance().unwrap().0 - m2 / n).abs() < tolerance * m2.max(1.0));
+            prop_assert!((stats.sample_variance().unwrap().0 - m2 / (n - 1.0)).abs() < tolerance * m2.max(1.0));
+        }
+
+        #[test]
+        fn test_constant_has_zero_variance(value in valid_f64(), len in 1usize..64) {
+            let mut stats = RunningStats::default();
+            stats.extend(std::iter::repeat_n(value, len));
+
+            prop_assert_eq!(stats.mean(), GuardedF64::new(value));
+            prop_assert_eq!(stats.variance(), Ok(GuardedF64(0.0)));
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use super::{RunningStats, StatsError};
+use crate::{GuardedF64, UnguardedF64};
+
+/// Rescales `values` in place so that the smallest becomes `0.0` and the largest becomes `1.0`.
+///
+/// The range is computed from halved values when it would overflow, so values spanning the whole
+/// `f64` range can still be scaled. The slice is left untouched on error.
+///
+/// # Errors
+///
+/// Returns `StatsError::Empty` if `values` is empty, or `StatsError::ZeroRange` if every value is
+/// the same, which would divide by zero.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::stats::{minmax_scale, StatsError};
+///
+/// let mut values = [2.0, 4.0, 10.0].map(|v| GuardedF64::new(v).unwrap());
+/// minmax_scale(&mut values).unwrap();
+/// assert_eq!(values, [0.0, 0.25, 1.0].map(|v| GuardedF64::new(v).unwrap()));
+///
+/// let mut values = [3.0, 3.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(minmax_scale(&mut values), Err(StatsError::ZeroRange));
+/// ```
+pub fn minmax_scale(values: &mut [GuardedF64]) -> Result<(), StatsError> {
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return Err(StatsError::Empty);
+    };
+    if min == max {
+        return Err(StatsError::ZeroRange);
+    }
+    let (min, max) = (min.0, max.0);
+
+    // Halving both ends keeps the range finite, at the cost of the lowest bit of subnormals.
+    let halve = (max - min).is_infinite();
+    let scale = |value: f64| {
+        if halve {
+            (value / 2.0 - min / 2.0) / (max / 2.0 - min / 2.0)
+        } else {
+            (value - min) / (max - min)
+        }
+    };
+
+    for value in values.iter_mut() {
+        // Every value is between `min` and `max`, so the result is between zero and one.
+        *value = GuardedF64(scale(value.0));
+    }
+
+    Ok(())
+}
+
+/// Standardizes `values` in place, subtracting their mean and dividing by their population
+/// standard deviation, so that they have a mean of `0.0` and a standard deviation of `1.0`.
+///
+/// The slice is only modified if every standardized value is finite, so it is left untouched on
+/// error.
+///
+/// # Errors
+///
+/// Returns `StatsError::Empty` if `values` is empty, `StatsError::ZeroStdDev` if every value is
+/// the same, which would divide by zero, or `StatsError::Float` if the standard deviation or any of
+/// the standardized values is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::stats::{standardize, StatsError};
+///
+/// let mut values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].map(|v| GuardedF64::new(v).unwrap());
+/// standardize(&mut values).unwrap();
+/// assert_eq!(values[0], GuardedF64::new(-1.5).unwrap());
+/// assert_eq!(values[7], GuardedF64::new(2.0).unwrap());
+///
+/// let mut values = [3.0, 3.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(standardize(&mut values), Err(StatsError::ZeroStdDev));
+/// ```
+pub fn standardize(values: &mut [GuardedF64]) -> Result<(), StatsError> {
+    if values.is_empty() {
+        return Err(StatsError::Empty);
+    }
+
+    let mut stats = RunningStats::default();
+    for value in values.iter() {
+        stats.push(*value);
+    }
+    let (mean, stddev) = (stats.mean()?, stats.stddev()?);
+    if stddev == 0.0 {
+        return Err(StatsError::ZeroStdDev);
+    }
+
+    let standardize = |value: GuardedF64| (UnguardedF64::from(value) - mean) / stddev;
+    for value in values.iter() {
+        standardize(*value).check_reported()?;
+    }
+
+    for value in values.iter_mut() {
+        *value = GuardedF64(standardize(*value).0);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::FloatError;
+    use proptest::prelude::*;
+
+    fn values() -> impl Strategy<Value = Vec<GuardedF64>> {
+        prop::collection::vec((-1e6..1e6f64).prop_map(GuardedF64), 2..50)
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(minmax_scale(&mut []), Err(StatsError::Empty));
+        assert_eq!(standardize(&mut []), Err(StatsError::Empty));
+
+        let mut values = [GuardedF64(1.0)];
+        assert_eq!(minmax_scale(&mut values), Err(StatsError::ZeroRange));
+        assert_eq!(standardize(&mut values), Err(StatsError::ZeroStdDev));
+
+        let mut values = [GuardedF64::MAX, GuardedF64::MIN];
+        assert_eq!(
+            standardize(&mut values),
+            Err(StatsError::Float(FloatError::Infinity))
+        );
+        assert_eq!(values, [GuardedF64::MAX, GuardedF64::MIN]);
+    }
+
+    #[test]
+    fn test_minmax_full_range() {
+        let mut values = [GuardedF64::MAX, GuardedF64(0.0), GuardedF64::MIN];
+        minmax_scale(&mut values).unwrap();
+        assert_eq!(values, [GuardedF64(1.0), GuardedF64(0.5), GuardedF64(0.0)]);
+    }
+
+    proptest! {
+        #[test]
+        fn test_minmax_bounds(mut values in values()) {
+            let original = values.clone();
+            match minmax_scale(&mut values) {
+                Ok(()) => {
+                    prop_assert!(values.iter().all(|v| (0.0..=1.0).contains(&v.0)));
+                    prop_assert!(values.contains(&GuardedF64(0.0)));
+                    prop_assert!(values.contains(&GuardedF64(1.0)));
+                }
+                Err(error) => {
+                    prop_assert_eq!(error, StatsError::ZeroRange);
+                    prop_assert_eq!(values, original);
+                }
+            }
+        }
+
+        #[test]
+        fn test_standardize_moments(mut values in values()) {
+            if standardize(&mut values).is_ok() {
+                let mut stats = RunningStats::default();
+                for value in &values {
+                    stats.push(*value);
+                }
+                prop_assert!(stats.mean().unwrap().0.abs() < 1e-9);
+                prop_assert!((stats.stddev().unwrap().0 - 1.0).abs() < 1e-9);
+            }
+        }
+    }
+}
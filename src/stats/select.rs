@@ -0,0 +1,143 @@
+use crate::GuardedF64;
+use std::cmp::Ordering;
+
+/// Inputs at or below this length are sorted directly instead of partitioned.
+const SMALL: usize = 5;
+
+/// Returns the value that would be at index `k` if `values` were sorted, or `None` if `k` is out of
+/// bounds.
+///
+/// This uses the median-of-medians algorithm, so it runs in O(n) time in the worst case, and relies
+/// on guarded values having a total order. Like `slice::select_nth_unstable`, it reorders `values`
+/// so that every value before index `k` is less than or equal to the result and every value after
+/// it is greater than or equal to the result.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::stats::select_kth;
+///
+/// let mut values = [5.0, 1.0, 4.0, 2.0, 3.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(select_kth(&mut values, 1), Some(GuardedF64::new(2.0).unwrap()));
+/// assert!(values[..1].iter().all(|v| *v <= 2.0));
+/// assert!(values[2..].iter().all(|v| *v >= 2.0));
+///
+/// assert_eq!(select_kth(&mut values, 5), None);
+/// ```
+pub fn select_kth(values: &mut [GuardedF64], k: usize) -> Option<GuardedF64> {
+    if k >= values.len() {
+        return None;
+    }
+
+    select(values, k);
+    Some(values[k])
+}
+
+/// Partitions `values` around index `k`, which must be in bounds.
+fn select(values: &mut [GuardedF64], mut k: usize) {
+    let (mut lo, mut hi) = (0, values.len());
+
+    loop {
+        let window = &mut values[lo..hi];
+        if window.len() <= SMALL {
+            window.sort_unstable();
+            return;
+        }
+
+        let pivot = median_of_medians(window);
+        let (less, greater) = partition(window, pivot);
+
+        if k < less {
+            hi = lo + less;
+        } else if k < greater {
+            return;
+        } else {
+            lo += greater;
+            k -= greater;
+        }
+    }
+}
+
+/// Chooses a pivot that is guaranteed to have at least 30% of `values` on either side of it.
+///
+/// The median of each group of five values is moved to the front of the slice, and the median of
+/// those medians is selected recursively.
+fn median_of_medians(values: &mut [GuardedF64]) -> GuardedF64 {
+    let len = values.len();
+    let mut medians = 0;
+    for start in (0..len).step_by(SMALL) {
+        let group = &mut values[start..(start + SMALL).min(len)];
+        group.sort_unstable();
+        let median = start + group.len() / 2;
+        values.swap(medians, median);
+        medians += 1;
+    }
+
+    let middle = medians / 2;
+    select(&mut values[..medians], middle);
+    values[middle]
+}
+
+/// Reorders `values` into the values less than, equal to, and greater than `pivot`, and returns the
+/// start and end of the equal values.
+fn partition(values: &mut [GuardedF64], pivot: GuardedF64) -> (usize, usize) {
+    let (mut less, mut index, mut greater) = (0, 0, values.len());
+
+    while index < greater {
+        match values[index].cmp(&pivot) {
+            Ordering::Less => {
+                values.swap(less, index);
+                less += 1;
+                index += 1;
+            }
+            Ordering::Greater => {
+                greater -= 1;
+                values.swap(index, greater);
+            }
+            Ordering::Equal => index += 1,
+        }
+    }
+
+    (less, greater)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_select_kth_matches_sort(
+            values in prop::collection::vec(valid_f64().prop_map(GuardedF64), 1..200),
+            k in any::<prop::sample::Index>(),
+        ) {
+            let k = k.index(values.len());
+            let mut sorted = values.clone();
+            sorted.sort_unstable();
+
+            let mut selected = values;
+            prop_assert_eq!(select_kth(&mut selected, k), Some(sorted[k]));
+            prop_assert!(selected[..k].iter().all(|v| *v <= sorted[k]));
+            prop_assert!(selected[k + 1..].iter().all(|v| *v >= sorted[k]));
+
+            selected.sort_unstable();
+            prop_assert_eq!(selected, sorted);
+        }
+
+        #[test]
+        fn test_select_kth_with_duplicates(
+            values in prop::collection::vec((0..4i32).prop_map(|v| GuardedF64(f64::from(v))), 1..200),
+            k in any::<prop::sample::Index>(),
+        ) {
+            let k = k.index(values.len());
+            let mut sorted = values.clone();
+            sorted.sort_unstable();
+
+            let mut selected = values;
+            prop_assert_eq!(select_kth(&mut selected, k), Some(sorted[k]));
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use super::StatsError;
+use super::mean::count;
+use super::select::select_kth;
+use crate::{GuardedF64, UnguardedF64};
+
+/// Computes the mean of `values` after discarding `floor(n * fraction)` of the smallest and of the
+/// largest values.
+///
+/// The values to discard are found with `select_kth`, so this runs in O(n) time. `values` is not
+/// modified.
+///
+/// # Errors
+///
+/// Returns `StatsError::Empty` if `values` is empty, `StatsError::Float` if `fraction` is NaN or
+/// infinite or the result is not finite, or `StatsError::InvalidFraction` if `fraction` is not in
+/// `[0.0, 0.5)`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::stats::{trimmed_mean, StatsError};
+///
+/// let values = [1.0, 2.0, 3.0, 4.0, 100.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(trimmed_mean(&values, 0.2), Ok(GuardedF64::new(3.0).unwrap()));
+///
+/// assert_eq!(trimmed_mean(&values, 0.5), Err(StatsError::InvalidFraction));
+/// assert_eq!(trimmed_mean(&[], 0.2), Err(StatsError::Empty));
+/// ```
+pub fn trimmed_mean(
+    values: &[GuardedF64],
+    fraction: impl Into<UnguardedF64>,
+) -> Result<GuardedF64, StatsError> {
+    let (sorted, cut) = partition_tails(values, fraction)?;
+    let kept = &sorted[cut..sorted.len() - cut];
+
+    Ok((sum(kept) / count(kept)?).check_reported()?)
+}
+
+/// Computes the mean of `values` after replacing `floor(n * fraction)` of the smallest and of the
+/// largest values with the nearest value that is kept.
+///
+/// The values to replace are found with `select_kth`, so this runs in O(n) time. `values` is not
+/// modified.
+///
+/// # Errors
+///
+/// Returns `StatsError::Empty` if `values` is empty, `StatsError::Float` if `fraction` is NaN or
+/// infinite or the result is not finite, or `StatsError::InvalidFraction` if `fraction` is not in
+/// `[0.0, 0.5)`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::stats::winsorized_mean;
+///
+/// // Winsorized to [2.0, 2.0, 3.0, 4.0, 4.0].
+/// let values = [1.0, 2.0, 3.0, 4.0, 100.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(winsorized_mean(&values, 0.2), Ok(GuardedF64::new(3.0).unwrap()));
+/// ```
+pub fn winsorized_mean(
+    values: &[GuardedF64],
+    fraction: impl Into<UnguardedF64>,
+) -> Result<GuardedF64, StatsError> {
+    let (sorted, cut) = partition_tails(values, fraction)?;
+    let kept = &sorted[cut..sorted.len() - cut];
+
+    #[allow(clippy::cast_precision_loss)]
+    let replaced = GuardedF64(cut as f64);
+    let low = kept[0];
+    let high = kept[kept.len() - 1];
+
+    let total = sum(kept) + low * replaced + high * replaced;
+    Ok((total / count(values)?).check_reported()?)
+}
+
+/// Copies `values` and partitions the copy so that the `cut` smallest values are at the front and
+/// the `cut` largest values are at the back, where `cut` is `floor(n * fraction)`. The smallest and
+/// largest of the remaining values are at indices `cut` and `n - 1 - cut`.
+fn partition_tails(
+    values: &[GuardedF64],
+    fraction: impl Into<UnguardedF64>,
+) -> Result<(Vec<GuardedF64>, usize), StatsError> {
+    let fraction = fraction.into().check_reported()?;
+    if !(0.0..0.5).contains(&fraction.0) {
+        return Err(StatsError::InvalidFraction);
+    }
+
+    if values.is_empty() {
+        return Err(StatsError::Empty);
+    }
+
+    let len = values.len();
+    let mut sorted = values.to_vec();
+
+    // Truncation is the intended rounding, and the product is less than `len / 2`.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let cut = (len as f64 * fraction.0) as usize;
+
+    // The largest kept value is selected first, so that selecting the smallest kept value from the
+    // values before it leaves both of them in place.
+    if cut > 0 {
+        let last = len - 1 - cut;
+        select_kth(&mut sorted, last);
+        select_kth(&mut sorted[..last], cut);
+    }
+
+    Ok((sorted, cut))
+}
+
+/// Sums `values` without checking the result.
+fn sum(values: &[GuardedF64]) -> UnguardedF64 {
+    let mut total = UnguardedF64::new(0.0);
+    for value in values {
+        total += value;
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use proptest::prelude::*;
+
+    fn value() -> impl Strategy<Value = GuardedF64> {
+        (-1e6..1e6f64).prop_map(|v| GuardedF64::new(v).unwrap())
+    }
+
+    #[test]
+    fn test_invalid_fraction() {
+        let values = [GuardedF64::new(1.0).unwrap()];
+        assert_eq!(
+            trimmed_mean(&values, -0.1),
+            Err(StatsError::InvalidFraction)
+        );
+        assert_eq!(
+            winsorized_mean(&values, 0.5),
+            Err(StatsError::InvalidFraction)
+        );
+        assert_eq!(
+            trimmed_mean(&values, f64::NAN),
+            Err(StatsError::Float(FloatError::NaN))
+        );
+        assert_eq!(winsorized_mean(&[], 0.1), Err(StatsError::Empty));
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_sorted_definition(
+            values in prop::collection::vec(value(), 1..64),
+            fraction in 0.0..0.5f64,
+        ) {
+            let mut sorted = values.clone();
+            sorted.sort_unstable();
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+            let cut = (sorted.len() as f64 * fraction) as usize;
+            let kept = &sorted[cut..sorted.len() - cut];
+            let winsorized: Vec<_> = sorted
+                .iter()
+                .map(|v| (*v).clamp(kept[0], kept[kept.len() - 1]).0)
+                .collect();
+
+            #[allow(clippy::cast_precision_loss)]
+            let expected_trimmed = kept.iter().map(|v| v.0).sum::<f64>() / kept.len() as f64;
+            #[allow(clippy::cast_precision_loss)]
+            let expected_winsorized = winsorized.iter().sum::<f64>() / winsorized.len() as f64;
+            let tolerance = 1e-6;
+
+            let trimmed = trimmed_mean(&values, fraction).unwrap();
+            prop_assert!((trimmed.0 - expected_trimmed).abs() < tolerance);
+
+            let winsorized = winsorized_mean(&values, fraction).unwrap();
+            prop_assert!((winsorized.0 - expected_winsorized).abs() < tolerance);
+        }
+
+        #[test]
+        fn test_zero_fraction_is_mean(values in prop::collection::vec(value(), 1..64)) {
+            prop_assert_eq!(trimmed_mean(&values, 0.0), winsorized_mean(&values, 0.0));
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use super::StatsError;
+use crate::{GuardedF64, UnguardedF64};
+use std::cmp::Ordering;
+
+/// Computes the mean of `values`, with each value weighted by the weight at the same index.
+///
+/// Weights may be negative, as long as they do not sum to zero or a negative number.
+///
+/// # Errors
+///
+/// Returns `StatsError::LengthMismatch` if `values` and `weights` have different lengths,
+/// `StatsError::Empty` if they are empty, `StatsError::ZeroTotal` or `StatsError::NegativeTotal`
+/// if the weights do not sum to a positive number, or `StatsError::Float` if the total weight or
+/// the result is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::stats::{weighted_mean, StatsError};
+///
+/// let values = [1.0, 2.0, 4.0].map(|v| GuardedF64::new(v).unwrap());
+/// let weights = [1.0, 2.0, 1.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(weighted_mean(&values, &weights), Ok(GuardedF64::new(2.25).unwrap()));
+///
+/// let weights = [1.0, -1.0, 0.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(weighted_mean(&values, &weights), Err(StatsError::ZeroTotal));
+///
+/// assert_eq!(
+///     weighted_mean(&values, &weights[..2]),
+///     Err(StatsError::LengthMismatch { values: 3, weights: 2 })
+/// );
+/// ```
+pub fn weighted_mean(
+    values: &[GuardedF64],
+    weights: &[GuardedF64],
+) -> Result<GuardedF64, StatsError> {
+    if values.len() != weights.len() {
+        return Err(StatsError::LengthMismatch {
+            values: values.len(),
+            weights: weights.len(),
+        });
+    }
+
+    let total = positive_total(weights)?;
+
+    let mut weighted_sum = UnguardedF64::new(0.0);
+    for (value, weight) in values.iter().zip(weights) {
+        weighted_sum += value * weight;
+    }
+
+    Ok((weighted_sum / total).check_reported()?)
+}
+
+/// Scales `values` in place so that they sum to one.
+///
+/// The slice is only modified if every scaled value is finite, so it is left untouched on error.
+///
+/// # Errors
+///
+/// Returns `StatsError::Empty` if `values` is empty, `StatsError::ZeroTotal` or
+/// `StatsError::NegativeTotal` if the values do not sum to a positive number, or
+/// `StatsError::Float` if the total or any of the scaled values is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::stats::{normalize, StatsError};
+///
+/// let mut values = [1.0, 3.0].map(|v| GuardedF64::new(v).unwrap());
+/// normalize(&mut values).unwrap();
+/// assert_eq!(values, [0.25, 0.75].map(|v| GuardedF64::new(v).unwrap()));
+///
+/// let mut values = [-1.0, -3.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(normalize(&mut values), Err(StatsError::NegativeTotal));
+/// ```
+pub fn normalize(values: &mut [GuardedF64]) -> Result<(), StatsError> {
+    let total = positive_total(values)?;
+
+    for value in values.iter() {
+        (*value / total).check_reported()?;
+    }
+
+    for value in values.iter_mut() {
+        *value = GuardedF64(value.0 / total.0);
+    }
+
+    Ok(())
+}
+
+/// Sums `values`, rejecting empty inputs and totals that are not strictly positive.
+fn positive_total(values: &[GuardedF64]) -> Result<GuardedF64, StatsError> {
+    if values.is_empty() {
+        return Err(StatsError::Empty);
+    }
+
+    let mut total = UnguardedF64::new(0.0);
+    for value in values {
+        total += value;
+    }
+
+    let total = total.check_reported()?;
+    match total.cmp(&GuardedF64(0.0)) {
+        Ordering::Equal => Err(StatsError::ZeroTotal),
+        Ordering::Less => Err(StatsError::NegativeTotal),
+        Ordering::Greater => Ok(total),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FloatError;
+    use proptest::prelude::*;
+
+    fn weight() -> impl Strategy<Value = GuardedF64> {
+        (1e-6..1e6f64).prop_map(|v| GuardedF64::new(v).unwrap())
+    }
+
+    fn value() -> impl Strategy<Value = GuardedF64> {
+        (-1e6..1e6f64).prop_map(|v| GuardedF64::new(v).unwrap())
+    }
+
+    #[test]
+    fn test_empty_and_overflow() {
+        assert_eq!(weighted_mean(&[], &[]), Err(StatsError::Empty));
+        assert_eq!(normalize(&mut []), Err(StatsError::Empty));
+
+        let mut values = [GuardedF64::MAX, GuardedF64::MAX];
+        assert_eq!(
+            normalize(&mut values),
+            Err(StatsError::Float(FloatError::Infinity))
+        );
+        assert_eq!(values, [GuardedF64::MAX, GuardedF64::MAX]);
+
+        let tiny = GuardedF64::new(f64::MIN_POSITIVE).unwrap();
+        let mut values = [GuardedF64::MAX, -GuardedF64::MAX, tiny];
+        assert_eq!(
+            normalize(&mut values),
+            Err(StatsError::Float(FloatError::Infinity))
+        );
+        assert_eq!(values, [GuardedF64::MAX, -GuardedF64::MAX, tiny]);
+    }
+
+    proptest! {
+        #[test]
+        fn test_weighted_mean_is_bounded(
+            pairs in prop::collection::vec((value(), weight()), 1..32),
+        ) {
+            let (values, weights): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+            let min = values.iter().min().unwrap().0;
+            let max = values.iter().max().unwrap().0;
+            let tolerance = 1e-9 * min.abs().max(max.abs()).max(1.0);
+
+            let mean = weighted_mean(&values, &weights).unwrap();
+            prop_assert!(mean.0 >= min - tolerance);
+            prop_assert!(mean.0 <= max + tolerance);
+        }
+
+        #[test]
+        fn test_normalize_sums_to_one(mut values in prop::collection::vec(weight(), 1..32)) {
+            normalize(&mut values).unwrap();
+
+            let sum: f64 = values.iter().map(|v| v.0).sum();
+            prop_assert!((sum - 1.0).abs() < 1e-12);
+        }
+
+        #[test]
+        fn test_non_positive_totals(values in prop::collection::vec(weight(), 1..32)) {
+            let negated: Vec<_> = values.iter().map(|v| -*v).collect();
+            prop_assert_eq!(weighted_mean(&values, &negated), Err(StatsError::NegativeTotal));
+
+            let zeros = vec![GuardedF64(0.0); values.len()];
+            prop_assert_eq!(weighted_mean(&values, &zeros), Err(StatsError::ZeroTotal));
+
+            let mut negated = negated;
+            prop_assert_eq!(normalize(&mut negated), Err(StatsError::NegativeTotal));
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Angle utilities for guarded values.
+//!
+//! Wrapping an angle with `rem_euclid` cannot produce an invalid value for a finite input, so the
+//! wrapping functions return `GuardedF64` directly. Unwrapping a sequence of phases accumulates
+//! corrections, which are validated as they are applied. Of the coordinate conversions, only the
+//! radius of `to_polar` can overflow, so it is the only output that is validated. The vector
+//! helpers normalize their inputs before multiplying them, so that only a projection that is itself
+//! too large can overflow.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+
+/// Wraps an angle, in radians, into the range `[0, 2π)`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::trig::wrap_to_2pi;
+///
+/// let angle = GuardedF64::new(-std::f64::consts::FRAC_PI_2).unwrap();
+/// let wrapped = wrap_to_2pi(angle);
+/// assert!((wrapped - 3.0 * std::f64::consts::FRAC_PI_2).abs().check().unwrap() < 1e-12);
+///
+/// assert_eq!(wrap_to_2pi(GuardedF64::TAU), 0.0);
+/// ```
+#[must_use = "function returns a new value and does not mutate the original value"]
+pub fn wrap_to_2pi(angle: GuardedF64) -> GuardedF64 {
+    let wrapped = angle.0.rem_euclid(GuardedF64::TAU.0);
+
+    // `rem_euclid` rounds up to exactly `2π` for tiny negative angles.
+    if wrapped < GuardedF64::TAU.0 {
+        GuardedF64(wrapped)
+    } else {
+        GuardedF64(0.0)
+    }
+}
+
+/// Wraps an angle, in radians, into the range `[-π, π)`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::trig::wrap_to_pi;
+///
+/// let angle = GuardedF64::new(3.0 * std::f64::consts::FRAC_PI_2).unwrap();
+/// let wrapped = wrap_to_pi(angle);
+/// assert!((wrapped + std::f64::consts::FRAC_PI_2).abs().check().unwrap() < 1e-12);
+///
+/// assert_eq!(wrap_to_pi(GuardedF64::PI), -std::f64::consts::PI);
+/// ```
+#[must_use = "function returns a new value and does not mutate the original value"]
+pub fn wrap_to_pi(angle: GuardedF64) -> GuardedF64 {
+    let wrapped = wrap_to_2pi(angle).0;
+
+    if wrapped < GuardedF64::PI.0 {
+        GuardedF64(wrapped)
+    } else {
+        GuardedF64(wrapped - GuardedF64::TAU.0)
+    }
+}
+
+/// Unwraps a sequence of phase angles, in radians, in place.
+///
+/// Whenever the difference between consecutive phases is at least `π`, a multiple of `2π` is added
+/// to the rest of the sequence so that the difference falls within `[-π, π]`. This reconstructs a
+/// continuous phase from values that were wrapped into a `2π` interval.
+///
+/// # Errors
+///
+/// Returns `FloatError` if a difference between phases or an unwrapped phase is not finite. The
+/// phases before the failing index have already been unwrapped when this happens.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::trig::unwrap_phases;
+///
+/// let mut phases = [0.0, 3.0, -3.0, -0.5].map(|v| GuardedF64::new(v).unwrap());
+/// unwrap_phases(&mut phases).unwrap();
+///
+/// let tau = std::f64::consts::TAU;
+/// let expected = [0.0, 3.0, tau - 3.0, tau - 0.5];
+/// for (phase, expected) in phases.iter().zip(expected) {
+///     assert!((phase - expected).abs().check().unwrap() < 1e-12);
+/// }
+/// ```
+pub fn unwrap_phases(phases: &mut [GuardedF64]) -> Result<(), FloatError> {
+    let Some((first, rest)) = phases.split_first_mut() else {
+        return Ok(());
+    };
+
+    let mut previous = *first;
+    let mut correction = UnguardedF64::new(0.0);
+    for phase in rest {
+        let difference = (*phase - previous).check_reported()?;
+        if difference.abs() >= GuardedF64::PI {
+            let mut wrapped = wrap_to_pi(difference);
+            if wrapped == -GuardedF64::PI && difference > 0.0 {
+                wrapped = GuardedF64::PI;
+            }
+            correction += wrapped - difference;
+        }
+
+        previous = *phase;
+        *phase = (*phase + correction).check_reported()?;
+    }
+
+    Ok(())
+}
+
+/// Converts cartesian coordinates to polar coordinates `(r, theta)`, with `theta` in radians in the
+/// range `[-π, π]`.
+///
+/// The radius is computed with `hypot`, so it does not overflow unless the result itself is too
+/// large to represent.
+///
+/// # Errors
+///
+/// Returns `FloatError::Infinity` if the radius is too large to represent.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::trig::to_polar;
+///
+/// let x = GuardedF64::new(0.0).unwrap();
+/// let y = GuardedF64::new(2.0).unwrap();
+/// let (r, theta) = to_polar(x, y).unwrap();
+/// assert_eq!(r, 2.0);
+/// assert_eq!(theta, GuardedF64::FRAC_PI_2);
+///
+/// assert_eq!(to_polar(GuardedF64::MAX, GuardedF64::MAX), Err(FloatError::Infinity));
+/// ```
+pub fn to_polar(x: GuardedF64, y: GuardedF64) -> Result<(GuardedF64, GuardedF64), FloatError> {
+    let r = UnguardedF64::new(x.0.hypot(y.0)).check_reported()?;
+    Ok((r, GuardedF64(y.0.atan2(x.0))))
+}
+
+/// Converts polar coordinates `(r, theta)`, with `theta` in radians, to cartesian coordinates
+/// `(x, y)`.
+///
+/// Neither coordinate can be larger than `r` in magnitude, so the result is always valid. A negative
+/// `r` produces the point on the opposite side of the origin.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::GuardedF64;
+/// use floatguard::trig::from_polar;
+///
+/// let r = GuardedF64::new(2.0).unwrap();
+/// let (x, y) = from_polar(r, GuardedF64::PI);
+/// assert!((x + 2.0).abs().check().unwrap() < 1e-12);
+/// assert!(y.abs() < 1e-12);
+/// ```
+#[must_use = "function returns new values and does not mutate the original values"]
+pub fn from_polar(r: GuardedF64, theta: GuardedF64) -> (GuardedF64, GuardedF64) {
+    let (sin, cos) = theta.0.sin_cos();
+    (GuardedF64(r.0 * cos), GuardedF64(r.0 * sin))
+}
+
+/// Returns the angle, in radians in the range `[0, π]`, between the vectors `a` and `b`.
+///
+/// Rounding can push the cosine of the angle between two unit vectors slightly outside of
+/// `[-1, 1]`, where `acos` returns NaN, most often for vectors that are nearly parallel. The cosine
+/// is clamped into `[-1, 1]` before `acos` is evaluated, so such vectors have an angle near `0` or
+/// `π` instead. Because `acos` is so steep near those ends, the angle between nearly parallel
+/// vectors is only accurate to about `1e-8`.
+///
+/// # Errors
+///
+/// Returns `FloatError::DivisionByZero` if either vector has a length of zero, because it has no
+/// direction.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::trig::angle_between;
+///
+/// let [zero, one, third] = [0.0, 1.0, 1.0 / 3.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(angle_between((one, zero), (zero, one)), Ok(GuardedF64::FRAC_PI_2));
+/// assert_eq!(angle_between((one, zero), (-one, zero)), Ok(GuardedF64::PI));
+/// assert!(angle_between((third, third), (one, one)).unwrap() < 1e-7);
+/// assert_eq!(angle_between((one, one), (zero, zero)), Err(FloatError::DivisionByZero));
+/// ```
+pub fn angle_between(
+    a: (GuardedF64, GuardedF64),
+    b: (GuardedF64, GuardedF64),
+) -> Result<GuardedF64, FloatError> {
+    let (ax, ay) = normalize(a)?;
+    let (bx, by) = normalize(b)?;
+    let cosine = ax.mul_add(bx, ay * by).clamp(-1.0, 1.0);
+    Ok(GuardedF64(cosine.acos()))
+}
+
+/// Projects the vector `v` onto the direction of the vector `onto`.
+///
+/// # Errors
+///
+/// Returns `FloatError::DivisionByZero` if `onto` has a length of zero, or `FloatError::Infinity`
+/// if the projection is too large to represent.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64};
+/// use floatguard::trig::project;
+///
+/// let [zero, two, three] = [0.0, 2.0, 3.0].map(|v| GuardedF64::new(v).unwrap());
+/// assert_eq!(project((two, three), (GuardedF64::MAX, zero)), Ok((two, zero)));
+/// assert_eq!(project((two, three), (zero, zero)), Err(FloatError::DivisionByZero));
+/// ```
+pub fn project(
+    v: (GuardedF64, GuardedF64),
+    onto: (GuardedF64, GuardedF64),
+) -> Result<(GuardedF64, GuardedF64), FloatError> {
+    let (ux, uy) = normalize(onto)?;
+    let length = UnguardedF64::new(v.0.0.mul_add(ux, v.1.0 * uy)).check_reported()?;
+    let x = UnguardedF64::new(length.0 * ux).check_reported()?;
+    let y = UnguardedF64::new(length.0 * uy).check_reported()?;
+    Ok((x, y))
+}
+
+/// Returns the unit vector in the direction of `(x, y)`. The components are scaled by the larger
+/// magnitude first, so that the length cannot overflow or underflow.
+fn normalize((x, y): (GuardedF64, GuardedF64)) -> Result<(f64, f64), FloatError> {
+    let scale = x.0.abs().max(y.0.abs());
+    if scale == 0.0 {
+        return Err(FloatError::DivisionByZero);
+    }
+
+    let (x, y) = (x.0 / scale, y.0 / scale);
+    let length = x.hypot(y);
+    Ok((x / length, y / length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_wrap_ranges(a in valid_f64()) {
+            let angle = GuardedF64::new(a).unwrap();
+
+            let wrapped = wrap_to_2pi(angle);
+            prop_assert!(wrapped >= 0.0 && wrapped < GuardedF64::TAU);
+
+            let wrapped = wrap_to_pi(angle);
+            prop_assert!(wrapped >= -GuardedF64::PI && wrapped < GuardedF64::PI);
+        }
+
+        #[test]
+        fn test_wrap_preserves_angle(a in -1e6..1e6f64) {
+            let angle = GuardedF64::new(a).unwrap();
+
+            for wrapped in [wrap_to_2pi(angle), wrap_to_pi(angle)] {
+                prop_assert!((wrapped.sin() - angle.sin()).abs().check().unwrap() < 1e-9);
+                prop_assert!((wrapped.cos() - angle.cos()).abs().check().unwrap() < 1e-9);
+            }
+        }
+
+        #[test]
+        fn test_unwrap_restores_continuous_phase(
+            start in -10.0..10.0f64,
+            steps in prop::collection::vec(-3.0..3.0f64, 0..64),
+        ) {
+            let mut continuous = vec![GuardedF64::new(start).unwrap()];
+            for step in steps {
+                let last = *continuous.last().unwrap();
+                continuous.push((last + step).check().unwrap());
+            }
+
+            let mut phases: Vec<_> = continuous.iter().copied().map(wrap_to_pi).collect();
+            unwrap_phases(&mut phases).unwrap();
+
+            let offset = (continuous[0] - phases[0]).check().unwrap();
+            for (phase, expected) in phases.iter().zip(&continuous) {
+                prop_assert!((phase + offset - expected).abs().check().unwrap() < 1e-9);
+            }
+        }
+
+        #[test]
+        fn test_polar_round_trip(x in -1e150..1e150f64, y in -1e150..1e150f64) {
+            let (x, y) = (GuardedF64::new(x).unwrap(), GuardedF64::new(y).unwrap());
+            let (r, theta) = to_polar(x, y).unwrap();
+            prop_assert!(r >= 0.0);
+            prop_assert!(theta.abs() <= GuardedF64::PI);
+
+            let (round_x, round_y) = from_polar(r, theta);
+            let tolerance = r.0 * 1e-12;
+            prop_assert!((round_x - x).abs().check().unwrap() <= tolerance);
+            prop_assert!((round_y - y).abs().check().unwrap() <= tolerance);
+        }
+
+        #[test]
+        fn test_angle_between_is_valid(ax in valid_f64(), ay in valid_f64(), bx in valid_f64(), by in valid_f64()) {
+            let a = (GuardedF64(ax), GuardedF64(ay));
+            let b = (GuardedF64(bx), GuardedF64(by));
+            if let Ok(angle) = angle_between(a, b) {
+                prop_assert!(angle >= 0.0 && angle <= GuardedF64::PI);
+                prop_assert_eq!(angle_between(b, a), Ok(angle));
+            } else {
+                prop_assert!((ax == 0.0 && ay == 0.0) || (bx == 0.0 && by == 0.0));
+            }
+        }
+
+        #[test]
+        fn test_projection_is_parallel(vx in -1e6..1e6f64, vy in -1e6..1e6f64, theta in -4.0..4.0f64) {
+            let onto = from_polar(GuardedF64(1.0), GuardedF64(theta));
+            let (x, y) = project((GuardedF64(vx), GuardedF64(vy)), onto).unwrap();
+
+            // The rejection `v - projection` is perpendicular to `onto`.
+            let (rx, ry) = (vx - x.0, vy - y.0);
+            prop_assert!(rx.mul_add(onto.0.0, ry * onto.1.0).abs() <= 1e-9 * vx.abs().max(vy.abs()).max(1.0));
+        }
+
+        #[test]
+        fn test_from_polar_is_bounded(r in valid_f64(), theta in valid_f64()) {
+            let r = GuardedF64::new(r).unwrap();
+            let (x, y) = from_polar(r, GuardedF64::new(theta).unwrap());
+            prop_assert!(x.abs() <= r.abs());
+            prop_assert!(y.abs() <= r.abs());
+        }
+    }
+
+    #[test]
+    fn test_vector_edge_cases() {
+        let (one, tiny) = (GuardedF64(1.0), GuardedF64(f64::MIN_POSITIVE / 8.0));
+        let angle = angle_between((tiny, tiny), (GuardedF64::MAX, GuardedF64::MAX)).unwrap();
+        assert!(angle < 1e-7);
+        let angle = angle_between((one, one), (-GuardedF64::MAX, -GuardedF64::MAX)).unwrap();
+        assert!((angle - GuardedF64::PI).abs().check().unwrap() < 1e-7);
+        assert_eq!(
+            angle_between((tiny, GuardedF64(0.0)), (GuardedF64::MAX, GuardedF64(0.0))),
+            Ok(GuardedF64(0.0))
+        );
+        assert_eq!(
+            project((GuardedF64::MAX, GuardedF64::MAX), (one, one)),
+            Err(FloatError::Infinity)
+        );
+    }
+
+    #[test]
+    fn test_unwrap_overflow() {
+        let mut phases = [GuardedF64::MIN, GuardedF64::MAX];
+        assert_eq!(unwrap_phases(&mut phases), Err(FloatError::Infinity));
+
+        let mut phases: [GuardedF64; 0] = [];
+        assert_eq!(unwrap_phases(&mut phases), Ok(()));
+    }
+}
@@ -0,0 +1,236 @@
+//! This module provides the `UnitF64` type, a guarded value that is known to be in the range [0, 1].
+use crate::{FloatError, GuardedF64, UnguardedF64};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A guarded value in the range [0, 1], such as an alpha channel, a weight, or a probability.
+///
+/// `GuardedF64` rules out NaN and infinities, but not a weight of `1.2` or a probability of `-0.1`.
+/// `UnitF64` also checks the range when it is created. Multiplying two values and taking the
+/// complement cannot leave the range, so they return a `UnitF64` directly. Every other operation
+/// returns an `UnguardedF64`, and the range is validated again, once, when the result is turned
+/// back into a `UnitF64` with `UnitF64::new`.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64, UnitF64};
+///
+/// let alpha = UnitF64::new(0.25).unwrap();
+/// let beta = UnitF64::new(0.5).unwrap();
+/// assert_eq!((alpha * beta).get(), 0.125);
+/// assert_eq!(alpha.complement().get(), 0.75);
+///
+/// assert_eq!(UnitF64::new(alpha + beta).map(UnitF64::get), GuardedF64::new(0.75));
+/// assert_eq!(UnitF64::new(beta / alpha), Err(FloatError::Domain));
+///
+/// assert_eq!(GuardedF64::new(1.2).unwrap().clamp_unit(), UnitF64::ONE);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnitF64(GuardedF64);
+
+impl UnitF64 {
+    /// The lower end of the range, `0.0`.
+    pub const ZERO: Self = Self(GuardedF64(0.0));
+
+    /// The upper end of the range, `1.0`.
+    pub const ONE: Self = Self(GuardedF64(1.0));
+
+    /// Creates a `UnitF64`, checking that `value` is in the range [0, 1].
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::NaN` or `FloatError::Infinity` if `value` is invalid, or
+    /// `FloatError::Domain` if it is outside of the range [0, 1].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, UnitF64};
+    ///
+    /// assert_eq!(UnitF64::new(1.0), Ok(UnitF64::ONE));
+    /// assert_eq!(UnitF64::new(1.5), Err(FloatError::Domain));
+    /// assert_eq!(UnitF64::new(f64::NAN), Err(FloatError::NaN));
+    /// ```
+    pub fn new(value: impl Into<UnguardedF64>) -> Result<Self, FloatError> {
+        value
+            .into()
+            .check_where(|value| (0.0..=1.0).contains(&value), FloatError::Domain)
+            .map(Self)
+    }
+
+    /// Creates a `UnitF64` by clamping `value` to the range [0, 1], like the `saturate` function of
+    /// shading languages. Infinities are clamped like any other value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::NaN` if `value` is NaN, because it has no place in the range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{FloatError, UnitF64};
+    ///
+    /// assert_eq!(UnitF64::saturate(-3.0), Ok(UnitF64::ZERO));
+    /// assert_eq!(UnitF64::saturate(f64::INFINITY), Ok(UnitF64::ONE));
+    /// assert_eq!(UnitF64::saturate(f64::NAN), Err(FloatError::NaN));
+    /// ```
+    pub fn saturate(value: impl Into<UnguardedF64>) -> Result<Self, FloatError> {
+        UnguardedF64::new(value.into().0.clamp(0.0, 1.0))
+            .check_reported()
+            .map(Self)
+    }
+
+    /// Returns the value as a `GuardedF64`.
+    #[must_use]
+    pub const fn get(self) -> GuardedF64 {
+        self.0
+    }
+
+    /// Returns `1 - self`, which is also in the range [0, 1].
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn complement(self) -> Self {
+        Self(GuardedF64(1.0 - self.0.0))
+    }
+}
+
+impl GuardedF64 {
+    /// Clamps the value to the range [0, 1], and returns it as a `UnitF64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, UnitF64};
+    ///
+    /// assert_eq!(GuardedF64::new(0.4).unwrap().clamp_unit().get(), 0.4);
+    /// assert_eq!(GuardedF64::new(-2.0).unwrap().clamp_unit(), UnitF64::ZERO);
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub const fn clamp_unit(self) -> UnitF64 {
+        UnitF64(Self(self.0.clamp(0.0, 1.0)))
+    }
+}
+
+impl TryFrom<f64> for UnitF64 {
+    type Error = FloatError;
+
+    /// Converts an `f64` to a `UnitF64`, like `UnitF64::new`.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<GuardedF64> for UnitF64 {
+    type Error = FloatError;
+
+    /// Converts a `GuardedF64` to a `UnitF64`, like `UnitF64::new`.
+    fn try_from(value: GuardedF64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<UnguardedF64> for UnitF64 {
+    type Error = FloatError;
+
+    /// Converts an `UnguardedF64` to a `UnitF64`, like `UnitF64::new`.
+    fn try_from(value: UnguardedF64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<UnitF64> for GuardedF64 {
+    /// Unwraps the value of a `UnitF64`.
+    fn from(value: UnitF64) -> Self {
+        value.0
+    }
+}
+
+impl From<UnitF64> for UnguardedF64 {
+    /// Unwraps the value of a `UnitF64` for arithmetic that may leave the range.
+    fn from(value: UnitF64) -> Self {
+        Self(value.0.0)
+    }
+}
+
+impl From<UnitF64> for f64 {
+    /// Unwraps the value of a `UnitF64` as an `f64`.
+    fn from(value: UnitF64) -> Self {
+        value.0.0
+    }
+}
+
+impl Mul for UnitF64 {
+    type Output = Self;
+
+    /// Multiplies two values. The product of two values in [0, 1] is also in [0, 1].
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(GuardedF64(self.0.0 * rhs.0.0))
+    }
+}
+
+impl<T: Into<UnguardedF64>> Add<T> for UnitF64 {
+    type Output = UnguardedF64;
+
+    /// Adds a value. The sum may be outside of [0, 1], so it is checked when it is converted back
+    /// with `UnitF64::new`.
+    fn add(self, rhs: T) -> Self::Output {
+        self.0 + rhs
+    }
+}
+
+impl<T: Into<UnguardedF64>> Sub<T> for UnitF64 {
+    type Output = UnguardedF64;
+
+    /// Subtracts a value. The difference may be outside of [0, 1], so it is checked when it is
+    /// converted back with `UnitF64::new`.
+    fn sub(self, rhs: T) -> Self::Output {
+        self.0 - rhs
+    }
+}
+
+impl<T: Into<UnguardedF64>> Div<T> for UnitF64 {
+    type Output = UnguardedF64;
+
+    /// Divides by a value. The quotient may be outside of [0, 1], or invalid, so it is checked
+    /// when it is converted back with `UnitF64::new`.
+    fn div(self, rhs: T) -> Self::Output {
+        self.0 / rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::{invalid_f64, valid_f64};
+    use proptest::prelude::*;
+
+    fn unit() -> impl Strategy<Value = UnitF64> {
+        (0.0..=1.0f64).prop_map(|v| UnitF64(GuardedF64(v)))
+    }
+
+    proptest! {
+        #[test]
+        fn test_new(a in valid_f64()) {
+            let expected = if (0.0..=1.0).contains(&a) { Ok(UnitF64(GuardedF64(a))) } else { Err(FloatError::Domain) };
+            prop_assert_eq!(UnitF64::new(a), expected);
+            prop_assert_eq!(UnitF64::try_from(a), expected);
+            prop_assert_eq!(UnitF64::saturate(a), Ok(GuardedF64(a).clamp_unit()));
+            prop_assert!((0.0..=1.0).contains(&GuardedF64(a).clamp_unit().get().0));
+        }
+
+        #[test]
+        fn test_invalid(a in invalid_f64()) {
+            prop_assert_eq!(UnitF64::new(a), Err(GuardedF64::new(a).unwrap_err()));
+            prop_assert_eq!(UnitF64::saturate(a).is_err(), a.is_nan());
+        }
+
+        #[test]
+        fn test_closed_operations(a in unit(), b in unit()) {
+            prop_assert!((0.0..=1.0).contains(&(a * b).get().0));
+            prop_assert!((0.0..=1.0).contains(&a.complement().get().0));
+            prop_assert_eq!(UnitF64::new(a - b).is_ok(), a >= b);
+            prop_assert_eq!((a + b).check(), (a.get() + b.get()).check());
+        }
+    }
+}
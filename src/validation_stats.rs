@@ -0,0 +1,142 @@
+//! Per-thread counters of validations and their failures.
+//!
+//! This module is only available when the `validation-stats` feature is enabled. Validations are
+//! counted at the same point where they are reported to the invalid-value hook, so every
+//! validation in the crate is counted except for those of the `const fn`s `GuardedF64::new`,
+//! `UnguardedF64::check`, `check_clamped`, and the byte constructors, which cannot touch the
+//! thread-local counters. Each validation is counted on the thread that performed it, with relaxed
+//! atomic increments, so the counters are cheap enough to leave on in production.
+use crate::FloatError;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The number of `FloatError` variants.
+const KINDS: usize = FloatError::ALL.len();
+
+struct Counters {
+    checks: AtomicU64,
+    failures: [AtomicU64; KINDS],
+}
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            checks: AtomicU64::new(0),
+            failures: [const { AtomicU64::new(0) }; KINDS],
+        }
+    };
+}
+
+/// A snapshot of the validation counters of one thread.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64, UnguardedF64};
+///
+/// floatguard::stats_reset();
+///
+/// assert!(GuardedF64::new_reported(1.0).is_ok());
+/// assert!((UnguardedF64::new(1.0) / 0.0).check_reported().is_err());
+///
+/// let stats = floatguard::stats_snapshot();
+/// assert_eq!(stats.checks(), 2);
+/// assert_eq!(stats.failures(FloatError::Infinity), 1);
+/// assert_eq!(stats.total_failures(), 1);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationStats {
+    checks: u64,
+    failures: [u64; KINDS],
+}
+
+impl ValidationStats {
+    /// Returns the number of validations performed.
+    #[must_use]
+    pub const fn checks(&self) -> u64 {
+        self.checks
+    }
+
+    /// Returns the number of validations that failed with `error`.
+    #[must_use]
+    pub const fn failures(&self, error: FloatError) -> u64 {
+        self.failures[error as usize]
+    }
+
+    /// Returns the number of validations that failed with any error.
+    #[must_use]
+    pub fn total_failures(&self) -> u64 {
+        self.failures.iter().sum()
+    }
+}
+
+/// Returns a snapshot of the validation counters of the current thread.
+#[must_use]
+pub fn stats_snapshot() -> ValidationStats {
+    COUNTERS.with(|counters| ValidationStats {
+        checks: counters.checks.load(Ordering::Relaxed),
+        failures: std::array::from_fn(|kind| counters.failures[kind].load(Ordering::Relaxed)),
+    })
+}
+
+/// Resets the validation counters of the current thread to zero.
+pub fn stats_reset() {
+    COUNTERS.with(|counters| {
+        counters.checks.store(0, Ordering::Relaxed);
+        for failures in &counters.failures {
+            failures.store(0, Ordering::Relaxed);
+        }
+    });
+}
+
+/// Counts a validation on the current thread.
+pub fn record_check() {
+    COUNTERS.with(|counters| counters.checks.fetch_add(1, Ordering::Relaxed));
+}
+
+/// Counts a failed validation on the current thread.
+pub fn record_failure(error: FloatError) {
+    COUNTERS.with(|counters| counters.failures[error as usize].fetch_add(1, Ordering::Relaxed));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GuardedF32, GuardedF64, UnguardedF32, UnguardedF64};
+
+    #[test]
+    fn test_counts_validations() {
+        stats_reset();
+
+        assert!(GuardedF64::new_reported(1.0).is_ok());
+        assert!(GuardedF64::new_reported(f64::NAN).is_err());
+        assert!(UnguardedF64::new(2.0).check_reported().is_ok());
+        assert!(GuardedF32::try_from(f32::NEG_INFINITY).is_err());
+        assert!(UnguardedF32::new(f32::NAN).check_reported().is_err());
+        assert!(
+            UnguardedF64::new(2.0)
+                .check_where(|value| value < 1.0, FloatError::Domain)
+                .is_err()
+        );
+
+        // Validation inside the rest of the crate is counted too.
+        assert!(crate::slice::sum_checked(&[GuardedF64::MAX, GuardedF64::MAX]).is_err());
+
+        // The `const` validating functions are not counted.
+        assert!(GuardedF64::new(f64::NAN).is_err());
+        assert!(UnguardedF64::new(f64::INFINITY).check_clamped().is_ok());
+
+        let stats = stats_snapshot();
+        assert_eq!(stats.checks(), 7);
+        assert_eq!(stats.failures(FloatError::NaN), 2);
+        assert_eq!(stats.failures(FloatError::Infinity), 2);
+        assert_eq!(stats.failures(FloatError::Domain), 1);
+        assert_eq!(stats.total_failures(), 5);
+
+        // Counters are per thread.
+        let other = std::thread::spawn(stats_snapshot).join().unwrap();
+        assert_eq!(other, ValidationStats::default());
+
+        stats_reset();
+        assert_eq!(stats_snapshot(), ValidationStats::default());
+    }
+}
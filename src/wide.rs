@@ -0,0 +1,313 @@
+//! This module provides the `WideF64` type, a floating-point accumulator with an extended exponent
+//! range, for sums and products whose intermediate values do not fit in an `f64`.
+//!
+//! A `WideF64` has the same 53-bit precision as an `f64`, but its exponent is an `i64`, so a
+//! product of thousands of large or tiny factors stays representable. Range is only checked when
+//! the result is converted back to a `GuardedF64`.
+use crate::{FloatError, GuardedF64, UnguardedF64};
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg};
+
+/// A floating-point value `significand * 2^exponent`, with an `f64` significand and an `i64`
+/// exponent.
+///
+/// The significand is either zero or in `[0.5, 1)` in magnitude. Arithmetic rounds like `f64`
+/// arithmetic, but cannot overflow or underflow, so only the conversion back to `GuardedF64` can
+/// fail.
+///
+/// # Example
+///
+/// ```rust
+/// use floatguard::{FloatError, GuardedF64, WideF64};
+///
+/// let large = GuardedF64::new(1e300).unwrap();
+/// let small = GuardedF64::new(1e-300).unwrap();
+///
+/// // 1e300 * 1e300 * 1e-300 * 1e-300 would overflow as an `f64`.
+/// let product: WideF64 = [large, large, small, small].into_iter().product();
+/// let product = GuardedF64::try_from(product).unwrap();
+/// assert!((product - 1.0).abs().check().unwrap() < 1e-12);
+///
+/// let product: WideF64 = [large, large].into_iter().product();
+/// assert_eq!(GuardedF64::try_from(product), Err(FloatError::Infinity));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WideF64 {
+    significand: f64,
+    exponent: i64,
+}
+
+impl WideF64 {
+    /// Zero.
+    pub const ZERO: Self = Self {
+        significand: 0.0,
+        exponent: 0,
+    };
+
+    /// One.
+    pub const ONE: Self = Self {
+        significand: 0.5,
+        exponent: 1,
+    };
+
+    /// Returns the significand, which is either zero or in `[0.5, 1)` in magnitude.
+    #[must_use]
+    pub const fn significand(self) -> f64 {
+        self.significand
+    }
+
+    /// Returns the exponent, the power of two that the significand is multiplied by.
+    #[must_use]
+    pub const fn exponent(self) -> i64 {
+        self.exponent
+    }
+
+    /// Returns the base-2 logarithm of the absolute value, which is finite for every non-zero
+    /// value, even one that is too large or too small to convert to a `GuardedF64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use floatguard::{GuardedF64, WideF64};
+    ///
+    /// let large = GuardedF64::new(2f64.powi(1000)).unwrap();
+    /// let product = WideF64::from(large) * WideF64::from(large);
+    /// assert_eq!(product.log2_abs().check(), GuardedF64::new(2000.0));
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn log2_abs(self) -> UnguardedF64 {
+        #[allow(clippy::cast_precision_loss)]
+        let exponent = self.exponent as f64;
+        UnguardedF64::new(self.significand.abs().log2() + exponent)
+    }
+
+    /// Creates a normalized value from a finite `f64` significand and an exponent.
+    fn normalize(significand: f64, exponent: i64) -> Self {
+        if significand == 0.0 {
+            return Self {
+                significand,
+                exponent: 0,
+            };
+        }
+
+        let (significand, shift) = frexp(significand);
+        Self {
+            significand,
+            exponent: exponent.saturating_add(shift),
+        }
+    }
+}
+
+/// Splits a finite, non-zero value into a significand in `[0.5, 1)` in magnitude and an exponent.
+fn frexp(value: f64) -> (f64, i64) {
+    const EXPONENT_MASK: u64 = 0x7ff << 52;
+
+    let bits = value.to_bits();
+    let biased = (bits & EXPONENT_MASK) >> 52;
+    if biased == 0 {
+        // Subnormal values are scaled into the normal range first.
+        let (significand, exponent) = frexp(value * pow2(64));
+        return (significand, exponent - 64);
+    }
+
+    let significand = f64::from_bits((bits & !EXPONENT_MASK) | (1022 << 52));
+    #[allow(clippy::cast_possible_wrap)]
+    (significand, biased as i64 - 1022)
+}
+
+/// Returns `2^exponent` for an exponent in the normal range, `[-1022, 1023]`.
+const fn pow2(exponent: i64) -> f64 {
+    #[allow(clippy::cast_sign_loss)]
+    f64::from_bits(((exponent + 1023) as u64) << 52)
+}
+
+impl From<GuardedF64> for WideF64 {
+    /// Converts a `GuardedF64` exactly.
+    fn from(value: GuardedF64) -> Self {
+        Self::normalize(value.0, 0)
+    }
+}
+
+impl TryFrom<WideF64> for GuardedF64 {
+    type Error = FloatError;
+
+    /// Converts a `WideF64` to the nearest `GuardedF64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::Infinity` if the value is too large to represent, or
+    /// `FloatError::Underflow` if it is non-zero but rounds to zero.
+    fn try_from(value: WideF64) -> Result<Self, Self::Error> {
+        let WideF64 {
+            significand,
+            exponent,
+        } = value;
+
+        let value = if significand == 0.0 {
+            significand
+        } else if exponent > 1024 {
+            significand * f64::INFINITY
+        } else if exponent < -1076 {
+            significand * 0.0
+        } else {
+            // Both halves are normal powers of two, so only the second product rounds.
+            let half = exponent / 2;
+            significand * pow2(half) * pow2(exponent - half)
+        };
+
+        UnguardedF64::new(value).check_where(
+            |result| result != 0.0 || significand == 0.0,
+            FloatError::Underflow,
+        )
+    }
+}
+
+impl Neg for WideF64 {
+    type Output = Self;
+
+    /// Negates the value.
+    fn neg(self) -> Self::Output {
+        Self {
+            significand: -self.significand,
+            exponent: self.exponent,
+        }
+    }
+}
+
+impl Add for WideF64 {
+    type Output = Self;
+
+    /// Adds two values, rounding the sum like `f64` addition.
+    fn add(self, rhs: Self) -> Self::Output {
+        if rhs.significand == 0.0 {
+            return self;
+        }
+        if self.significand == 0.0 {
+            return rhs;
+        }
+
+        let (larger, smaller) = if self.exponent >= rhs.exponent {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        // A difference this large is far below the precision of the larger value.
+        let difference = larger.exponent.saturating_sub(smaller.exponent);
+        if difference > 1000 {
+            return larger;
+        }
+
+        let shifted = smaller.significand * pow2(-difference);
+        Self::normalize(larger.significand + shifted, larger.exponent)
+    }
+}
+
+impl Mul for WideF64 {
+    type Output = Self;
+
+    /// Multiplies two values, rounding the product like `f64` multiplication.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::normalize(
+            self.significand * rhs.significand,
+            self.exponent.saturating_add(rhs.exponent),
+        )
+    }
+}
+
+impl Div for WideF64 {
+    type Output = Result<Self, FloatError>;
+
+    /// Divides two values, rounding the quotient like `f64` division.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FloatError::DivisionByZero` if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.significand == 0.0 {
+            return Err(FloatError::DivisionByZero);
+        }
+
+        Ok(Self::normalize(
+            self.significand / rhs.significand,
+            self.exponent.saturating_sub(rhs.exponent),
+        ))
+    }
+}
+
+impl Sum<GuardedF64> for WideF64 {
+    /// Sums guarded values without overflowing.
+    fn sum<I: Iterator<Item = GuardedF64>>(iter: I) -> Self {
+        iter.map(Self::from).fold(Self::ZERO, Add::add)
+    }
+}
+
+impl Product<GuardedF64> for WideF64 {
+    /// Multiplies guarded values without overflowing or underflowing.
+    fn product<I: Iterator<Item = GuardedF64>>(iter: I) -> Self {
+        iter.map(Self::from).fold(Self::ONE, Mul::mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::f64::tests::valid_f64;
+    use proptest::prelude::*;
+
+    fn guarded() -> impl Strategy<Value = GuardedF64> {
+        valid_f64().prop_map(GuardedF64)
+    }
+
+    #[test]
+    fn test_range_limits() {
+        let tiny = GuardedF64(f64::from_bits(1));
+        assert_eq!(GuardedF64::try_from(WideF64::from(tiny)), Ok(tiny));
+        assert_eq!(
+            GuardedF64::try_from(WideF64::from(tiny) * WideF64::from(GuardedF64(0.5))),
+            Err(FloatError::Underflow)
+        );
+
+        let max = WideF64::from(GuardedF64::MAX);
+        assert_eq!(GuardedF64::try_from(max), Ok(GuardedF64::MAX));
+        assert_eq!(GuardedF64::try_from(max + max), Err(FloatError::Infinity));
+        assert_eq!(
+            GuardedF64::try_from(-(max + max)),
+            Err(FloatError::Infinity)
+        );
+        assert_eq!(GuardedF64::try_from(max + -max), Ok(GuardedF64(0.0)));
+        assert_eq!(max / WideF64::ZERO, Err(FloatError::DivisionByZero));
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(a in guarded()) {
+            let wide = WideF64::from(a);
+            prop_assert!(wide.significand == 0.0 || (0.5..1.0).contains(&wide.significand.abs()));
+            prop_assert_eq!(GuardedF64::try_from(wide), Ok(a));
+        }
+
+        #[test]
+        fn test_matches_f64_in_range(a in -1e100..1e100f64, b in -1e100..1e100f64) {
+            let (wide_a, wide_b) = (WideF64::from(GuardedF64(a)), WideF64::from(GuardedF64(b)));
+
+            prop_assert_eq!(GuardedF64::try_from(wide_a * wide_b), Ok(GuardedF64(a * b)));
+            prop_assert_eq!(GuardedF64::try_from(wide_a + wide_b), Ok(GuardedF64(a + b)));
+            if b != 0.0 {
+                prop_assert_eq!(GuardedF64::try_from((wide_a / wide_b).unwrap()), Ok(GuardedF64(a / b)));
+            }
+        }
+
+        #[test]
+        fn test_product_of_inverses(values in prop::collection::vec(1e-300..1e300f64, 1..64)) {
+            let values: Vec<_> = values.into_iter().map(GuardedF64).collect();
+            let inverses: Vec<_> = values.iter().map(|v| GuardedF64(v.0.recip())).collect();
+
+            let product: WideF64 = values.iter().chain(&inverses).copied().product();
+            let product = GuardedF64::try_from(product).unwrap();
+            prop_assert!((product.0 - 1.0).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use floatguard::{FloatError, GuardedF32, GuardedF64, UnguardedF32, UnguardedF64};
+
+const TWO: GuardedF64 = match GuardedF64::new(2.0) {
+    Ok(value) => value,
+    Err(_) => panic!("2.0 is finite"),
+};
+
+const NAN: Result<GuardedF64, FloatError> = UnguardedF64::new(f64::NAN).check();
+
+const CLAMPED: Result<GuardedF64, FloatError> = UnguardedF64::new(f64::INFINITY).check_clamped();
+
+const FROM_BYTES: Result<GuardedF64, FloatError> = GuardedF64::from_le_bytes(1.0f64.to_le_bytes());
+
+const HALF: Result<GuardedF32, FloatError> = UnguardedF32::new(0.5).check();
+
+#[test]
+fn test_validation_is_const() {
+    assert_eq!(TWO, 2.0);
+    assert_eq!(NAN, Err(FloatError::NaN));
+    assert_eq!(CLAMPED, Ok(GuardedF64::MAX));
+    assert_eq!(FROM_BYTES, GuardedF64::new(1.0));
+    assert_eq!(HALF, GuardedF32::new(0.5));
+}
@@ -0,0 +1,16 @@
+#![allow(deprecated)]
+
+use floatguard::{
+    CheckedF32, CheckedF64, FloatError, GuardedF32, GuardedF64, UncheckedF32, UncheckedF64,
+};
+
+#[test]
+fn test_checked_aliases() {
+    let value: CheckedF64 = GuardedF64::new(2.0).unwrap();
+    let result: UncheckedF64 = value / 0.0;
+    assert_eq!(result.check(), Err(FloatError::Infinity));
+
+    let value: CheckedF32 = GuardedF32::new(2.0).unwrap();
+    let result: UncheckedF32 = value * 2.0;
+    assert_eq!(result.check(), GuardedF32::new(4.0));
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "derive")]
+
+use floatguard::{FloatError, GuardedF32, GuardedF64, GuardedNewtype, UnguardedF64};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, GuardedNewtype)]
+struct Meters(GuardedF64);
+
+#[derive(Debug, Clone, Copy, PartialEq, GuardedNewtype)]
+struct Seconds(floatguard::GuardedF32);
+
+fn meters(value: f64) -> Meters {
+    Meters::try_from(value).unwrap()
+}
+
+#[test]
+fn test_same_type_arithmetic() {
+    assert_eq!(meters(1.5) + meters(2.0), Ok(meters(3.5)));
+    assert_eq!(meters(1.5) - meters(2.0), Ok(meters(-0.5)));
+    assert_eq!(meters(5.0) % meters(2.0), Ok(meters(1.0)));
+    assert_eq!(meters(5.0) % meters(0.0), Err(FloatError::NaN));
+    assert_eq!(
+        Meters(GuardedF64::MAX) + Meters(GuardedF64::MAX),
+        Err(FloatError::Infinity)
+    );
+    assert_eq!(-meters(2.0), meters(-2.0));
+}
+
+#[test]
+fn test_scalar_arithmetic() {
+    let scale = GuardedF64::new(4.0).unwrap();
+
+    assert_eq!(meters(2.0) * 3.0, Ok(meters(6.0)));
+    assert_eq!(3.0 * meters(2.0), Ok(meters(6.0)));
+    assert_eq!(meters(2.0) * scale, Ok(meters(8.0)));
+    assert_eq!(scale * meters(2.0), Ok(meters(8.0)));
+    assert_eq!(
+        meters(2.0) * UnguardedF64::new(f64::NAN),
+        Err(FloatError::NaN)
+    );
+    assert_eq!(meters(2.0) / 4.0, Ok(meters(0.5)));
+    assert_eq!(meters(2.0) / 0.0, Err(FloatError::Infinity));
+
+    assert_eq!((meters(3.0) / meters(2.0)).check(), GuardedF64::new(1.5));
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(Meters::try_from(f64::NAN), Err(FloatError::NaN));
+    assert_eq!(Meters::try_from(UnguardedF64::new(2.0)), Ok(meters(2.0)));
+    assert_eq!(f64::from(meters(2.0)), 2.0);
+    assert_eq!(GuardedF64::from(meters(2.0)), GuardedF64::new(2.0).unwrap());
+    assert_eq!(Meters::from(GuardedF64::new(2.0).unwrap()), meters(2.0));
+    assert_eq!(meters(2.5).to_string(), "2.5");
+}
+
+#[test]
+fn test_f32_newtype() {
+    let seconds = Seconds::try_from(1.5f32).unwrap();
+
+    assert_eq!((seconds + seconds).map(f32::from), Ok(3.0));
+    assert_eq!(
+        (seconds * 2.0f32).map(GuardedF32::from),
+        GuardedF32::new(3.0)
+    );
+    assert_eq!(Seconds::try_from(f32::INFINITY), Err(FloatError::Infinity));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    assert_eq!(serde_json::to_string(&meters(2.5)).unwrap(), "2.5");
+    assert_eq!(serde_json::from_str::<Meters>("2.5").unwrap(), meters(2.5));
+    assert!(serde_json::from_str::<Meters>("null").is_err());
+}
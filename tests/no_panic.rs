@@ -0,0 +1,96 @@
+//! Exercises the guarded and unguarded types with every class of input, to back the
+//! `floatguard_no_panic` lints with evidence: none of these calls may panic, whatever their inputs.
+use floatguard::expr::Expr;
+use floatguard::parse::ParseOptions;
+use floatguard::{FloatError, GuardedF32, GuardedF64, RawF64, UnguardedF32, UnguardedF64};
+use proptest::prelude::*;
+use std::hint::black_box;
+
+fn any_f64() -> impl Strategy<Value = f64> {
+    prop_oneof![
+        any::<u64>().prop_map(f64::from_bits),
+        prop::sample::select(vec![
+            0.0,
+            -0.0,
+            f64::MIN_POSITIVE,
+            5e-324,
+            f64::MAX,
+            f64::MIN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ]),
+    ]
+}
+
+fn exercise_f64(a: f64, b: f64) -> Result<(), FloatError> {
+    let (x, y) = (UnguardedF64::new(a), UnguardedF64::new(b));
+    black_box([x + y, x - y, x * y, x / y, x % y, -x, x.abs(), x.signum()]);
+    black_box([x.sqrt(), x.ln(), x.exp(), x.recip()]);
+    black_box([x.powf(y), x.powi(i32::MIN)]);
+    black_box([x.sin(), x.atan2(y), x.sinh()]);
+    black_box(RawF64::new(a).nan_payload());
+
+    let (x, y) = (x.check()?, y.check()?);
+    black_box([x + y, x - y, x * y, x / y, x % y]);
+    black_box([-x, x.min(y), x.max(y)]);
+    black_box(x.cmp(&y));
+    let _ = black_box([x.exp_checked(), x.powf_checked(y), x.recip_checked()]);
+    let _ = black_box([x.sqrt_checked(), x.ln_checked(), x.asin_checked()]);
+    let _ = black_box((x.div_rem_checked(y), x.to_le_bytes()));
+    black_box([x.format_sig(u8::MAX), x.format_fixed(0), x.to_hex_string()]);
+    Ok(())
+}
+
+fn exercise_f32(a: f32, b: f32) -> Result<(), FloatError> {
+    let (x, y) = (UnguardedF32::new(a), UnguardedF32::new(b));
+    black_box([x + y, x - y, x * y, x / y, x % y, -x]);
+    black_box([x.sqrt(), x.ln(), x.exp(), x.recip()]);
+    black_box([x.powf(y), x.powi(i32::MIN)]);
+
+    let (x, y) = (x.check()?, y.check()?);
+    black_box([x + y, x - y, x * y, x / y, x % y]);
+    black_box([-x, x.min(y), x.max(y)]);
+    black_box(x.cmp(&y));
+    let _ = black_box([x.exp_checked(), x.powf_checked(y), x.recip_checked()]);
+    let _ = black_box(x.div_rem_checked(y));
+    black_box([x.format_sig(u8::MAX), x.format_fixed(0), x.to_hex_string()]);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn test_arithmetic_never_panics(a in any_f64(), b in any_f64()) {
+        let _ = exercise_f64(a, b);
+        #[allow(clippy::cast_possible_truncation)]
+        let _ = exercise_f32(a as f32, b as f32);
+    }
+
+    #[test]
+    fn test_parsing_never_panics(bytes in prop::collection::vec(any::<u8>(), 0..32)) {
+        let _ = GuardedF64::from_bytes_auto(&bytes);
+        let _ = GuardedF32::from_bytes_auto(&bytes);
+
+        let text = String::from_utf8_lossy(&bytes);
+        let _ = text.parse::<GuardedF64>();
+        let _ = GuardedF64::from_hex_str(&text);
+        let _ = GuardedF64::parse_with(&text, ParseOptions::default());
+        let _ = GuardedF32::from_hex_str(&text);
+        let _ = GuardedF64::parse_percent(&text);
+        let _ = Expr::parse(&text);
+    }
+
+    #[test]
+    fn test_number_like_parsing_never_panics(text in "[-+]?(0x)?[0-9a-fA-F.]{0,24}([eEpP][-+]?[0-9]{0,6})?") {
+        let _ = text.parse::<GuardedF64>();
+        let _ = GuardedF64::from_hex_str(&text);
+        let _ = GuardedF64::from_bytes_auto(text.as_bytes());
+        let _ = GuardedF32::from_hex_str(&text);
+    }
+
+    #[test]
+    fn test_percent_parsing_never_panics(text in "%?[-+]?[0-9.,]{0,16} ?%?") {
+        let _ = GuardedF64::parse_percent(&text);
+        let _ = GuardedF32::parse_percent(&text);
+    }
+}